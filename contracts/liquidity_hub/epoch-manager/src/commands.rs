@@ -1,98 +1,328 @@
-use cosmwasm_std::{Api, DepsMut, Env, MessageInfo, Response, SubMsg};
+use cosmwasm_std::{
+    Addr, Api, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult, Storage, SubMsg,
+};
 
-use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::epoch_manager::epoch_manager::{
+    CatchUpMode, EpochConfig, EpochV2, FailedHook,
+};
 use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
+use white_whale_std::ownership::{self, OwnershipAction};
 
-use crate::state::{ADMIN, CONFIG, EPOCH, HOOKS};
+use crate::state::{
+    HookDispatch, ADMIN, CONFIG, EPOCH, FAILED_HOOKS, HOOKS, HOOK_DISPATCHES, HOOK_DISPATCH_ID,
+    HOOK_MANAGERS, MAX_CATCH_UP_EPOCHS, MAX_HOOK_ATTEMPTS,
+};
 use crate::ContractError;
 
-/// Adds a new hook to the contract.
+/// Adds a new hook to the contract. Can be called by the owner or by a hook manager.
 pub fn add_hook(
     deps: DepsMut,
     info: MessageInfo,
     api: &dyn Api,
     contract_addr: &str,
 ) -> Result<Response, ContractError> {
-    Ok(HOOKS.execute_add_hook(&ADMIN, deps, info, api.addr_validate(contract_addr)?)?)
+    let hook = api.addr_validate(contract_addr)?;
+
+    if !HOOK_MANAGERS.has(deps.storage, &info.sender) {
+        return Ok(HOOKS.execute_add_hook(&ADMIN, deps, info, hook)?);
+    }
+
+    HOOKS.add_hook(deps.storage, hook.clone())?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "add_hook".to_string()),
+        ("hook", hook.to_string()),
+        ("sender", info.sender.to_string()),
+    ]))
 }
 
+/// Removes a hook from the contract. Can be called by the owner or by a hook manager.
 pub(crate) fn remove_hook(
     deps: DepsMut,
     info: MessageInfo,
     api: &dyn Api,
     contract_addr: &str,
 ) -> Result<Response, ContractError> {
-    Ok(HOOKS.execute_remove_hook(&ADMIN, deps, info, api.addr_validate(contract_addr)?)?)
+    let hook = api.addr_validate(contract_addr)?;
+
+    if !HOOK_MANAGERS.has(deps.storage, &info.sender) {
+        return Ok(HOOKS.execute_remove_hook(&ADMIN, deps, info, hook)?);
+    }
+
+    HOOKS.remove_hook(deps.storage, hook.clone())?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "remove_hook".to_string()),
+        ("hook", hook.to_string()),
+        ("sender", info.sender.to_string()),
+    ]))
 }
 
-/// Creates a new epoch.
+/// Adds and removes hook managers, which are allowed to add and remove hooks.
+pub fn update_hook_managers(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    for hook_manager in add.iter() {
+        let hook_manager = deps.api.addr_validate(hook_manager)?;
+        HOOK_MANAGERS.save(deps.storage, &hook_manager, &Empty {})?;
+    }
+
+    for hook_manager in remove.iter() {
+        let hook_manager = deps.api.addr_validate(hook_manager)?;
+        HOOK_MANAGERS.remove(deps.storage, &hook_manager);
+    }
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_hook_managers".to_string()),
+        ("added", add.join(", ")),
+        ("removed", remove.join(", ")),
+    ]))
+}
+
+/// Creates a new epoch. Depending on the configured [CatchUpMode], it creates all the epochs
+/// missed since the current one started, firing the hooks once or for each of them.
 pub fn create_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
     let mut current_epoch = EPOCH.load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
+    let duration = config.epoch_config.duration.u64();
 
-    if env
+    let elapsed_time = env
         .block
         .time
         .minus_nanos(current_epoch.start_time.nanos())
-        .nanos()
-        < config.epoch_config.duration.u64()
-    {
+        .nanos();
+
+    if elapsed_time < duration {
         return Err(ContractError::CurrentEpochNotExpired);
     }
 
+    // number of epochs to move forward in this call
+    let epochs_to_create = match config.catch_up_mode {
+        CatchUpMode::Disabled => 1u64,
+        CatchUpMode::FireOnce => elapsed_time / duration,
+        CatchUpMode::FireForEach => (elapsed_time / duration).min(MAX_CATCH_UP_EPOCHS),
+    };
+
+    // jump straight to the new epoch, epochs_to_create * duration never exceeds elapsed_time
+    let previous_epoch = current_epoch.clone();
     current_epoch.id = current_epoch
         .id
-        .checked_add(1u64)
+        .checked_add(epochs_to_create)
         .ok_or(ContractError::EpochOverflow)?;
     current_epoch.start_time = current_epoch
         .start_time
-        .plus_nanos(config.epoch_config.duration.u64());
+        .plus_nanos(duration * epochs_to_create);
+
+    // the epochs the hooks are fired for
+    let hook_epochs: Vec<EpochV2> = match config.catch_up_mode {
+        CatchUpMode::FireForEach => (1..=epochs_to_create)
+            .map(|offset| EpochV2 {
+                id: previous_epoch.id + offset,
+                start_time: previous_epoch.start_time.plus_nanos(duration * offset),
+            })
+            .collect(),
+        CatchUpMode::Disabled | CatchUpMode::FireOnce => vec![current_epoch.clone()],
+    };
 
     EPOCH.save(deps.storage, &current_epoch)?;
 
-    let messages = HOOKS.prepare_hooks(deps.storage, |hook| {
-        EpochChangedHookMsg {
-            current_epoch: current_epoch.clone(),
+    // hooks are dispatched with reply always, so a failing hook doesn't revert the epoch creation
+    let hooks = HOOKS.query_hooks(deps.as_ref())?.hooks;
+    let mut messages = vec![];
+    for epoch in hook_epochs.iter() {
+        for hook in hooks.iter() {
+            messages.push(dispatch_hook(
+                deps.storage,
+                Addr::unchecked(hook),
+                epoch,
+                0u64,
+            )?);
         }
-        .into_cosmos_msg(hook)
-        .map(SubMsg::new)
-    })?;
+    }
 
     Ok(Response::default()
         .add_submessages(messages)
         .add_attributes(vec![
             ("action", "create_epoch".to_string()),
             ("current_epoch", current_epoch.to_string()),
+            ("epochs_created", epochs_to_create.to_string()),
+            ("catch_up_mode", config.catch_up_mode.to_string()),
         ]))
 }
 
+/// Dispatches the epoch changed hook again to a hook that failed for the given epoch, sending the
+/// same epoch the hook failed for.
+pub fn retry_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    contract_addr: String,
+    epoch_id: u64,
+) -> Result<Response, ContractError> {
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    let contract_addr = deps.api.addr_validate(&contract_addr)?;
+    let failed_hook = FAILED_HOOKS
+        .may_load(deps.storage, (epoch_id, &contract_addr))?
+        .ok_or_else(|| ContractError::NoFailedHook {
+            contract_addr: contract_addr.to_string(),
+            epoch_id,
+        })?;
+
+    // the failure is recorded again on reply if the hook keeps failing
+    FAILED_HOOKS.remove(deps.storage, (epoch_id, &contract_addr));
+
+    let message = dispatch_hook(
+        deps.storage,
+        contract_addr.clone(),
+        &failed_hook.epoch,
+        failed_hook.attempts,
+    )?;
+
+    Ok(Response::default()
+        .add_submessage(message)
+        .add_attributes(vec![
+            ("action", "retry_hook".to_string()),
+            ("contract_addr", contract_addr.to_string()),
+            ("epoch_id", epoch_id.to_string()),
+            ("attempt", (failed_hook.attempts + 1u64).to_string()),
+        ]))
+}
+
+/// Records the failure of a hook dispatched with the given reply id. Removes the hook if it
+/// reached [MAX_HOOK_ATTEMPTS] for the epoch.
+pub fn record_failed_hook(
+    deps: DepsMut,
+    reply_id: u64,
+    error: String,
+) -> Result<Response, ContractError> {
+    let dispatch = HOOK_DISPATCHES.load(deps.storage, reply_id)?;
+    HOOK_DISPATCHES.remove(deps.storage, reply_id);
+
+    let attempts = dispatch.previous_attempts + 1u64;
+    FAILED_HOOKS.save(
+        deps.storage,
+        (dispatch.epoch.id, &dispatch.contract_addr),
+        &FailedHook {
+            contract_addr: dispatch.contract_addr.clone(),
+            epoch: dispatch.epoch.clone(),
+            error: error.clone(),
+            attempts,
+        },
+    )?;
+
+    let mut attributes = vec![
+        ("action", "failed_hook".to_string()),
+        ("contract_addr", dispatch.contract_addr.to_string()),
+        ("epoch_id", dispatch.epoch.id.to_string()),
+        ("attempts", attempts.to_string()),
+        ("error", error),
+    ];
+
+    if attempts >= MAX_HOOK_ATTEMPTS
+        && HOOKS
+            .query_hooks(deps.as_ref())?
+            .hooks
+            .contains(&dispatch.contract_addr.to_string())
+    {
+        HOOKS.remove_hook(deps.storage, dispatch.contract_addr)?;
+        attributes.push(("hook_removed", true.to_string()));
+    }
+
+    Ok(Response::default().add_attributes(attributes))
+}
+
+/// Removes the dispatch of a hook that processed the epoch changed hook successfully.
+pub fn clear_hook_dispatch(deps: DepsMut, reply_id: u64) -> Result<Response, ContractError> {
+    HOOK_DISPATCHES.remove(deps.storage, reply_id);
+
+    Ok(Response::default())
+}
+
+/// Builds the epoch changed hook submessage for the given hook under a new reply id, saving the
+/// dispatch so the hook can be identified if it fails.
+fn dispatch_hook(
+    storage: &mut dyn Storage,
+    contract_addr: Addr,
+    epoch: &EpochV2,
+    previous_attempts: u64,
+) -> StdResult<SubMsg> {
+    let msg = EpochChangedHookMsg {
+        current_epoch: epoch.clone(),
+    }
+    .into_cosmos_msg(contract_addr.clone())?;
+
+    let reply_id = HOOK_DISPATCH_ID
+        .may_load(storage)?
+        .unwrap_or_default()
+        .wrapping_add(1u64);
+    HOOK_DISPATCH_ID.save(storage, &reply_id)?;
+
+    HOOK_DISPATCHES.save(
+        storage,
+        reply_id,
+        &HookDispatch {
+            contract_addr,
+            epoch: epoch.clone(),
+            previous_attempts,
+        },
+    )?;
+
+    Ok(SubMsg::reply_always(msg, reply_id))
+}
+
 /// Updates the config of the contract.
 pub fn update_config(
-    mut deps: DepsMut,
+    deps: DepsMut,
     info: &MessageInfo,
-    owner: Option<String>,
     epoch_config: Option<EpochConfig>,
+    catch_up_mode: Option<CatchUpMode>,
 ) -> Result<Response, ContractError> {
     ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
 
-    if let Some(owner) = owner.clone() {
-        let new_admin = deps.api.addr_validate(owner.as_str())?;
-        ADMIN.set(deps.branch(), Some(new_admin))?;
-    }
-
     let mut config = CONFIG.load(deps.storage)?;
 
     if let Some(epoch_config) = epoch_config.clone() {
         config.epoch_config = epoch_config;
-        CONFIG.save(deps.storage, &config)?;
     }
 
+    if let Some(catch_up_mode) = catch_up_mode {
+        config.catch_up_mode = catch_up_mode;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
     Ok(Response::default().add_attributes(vec![
         ("action", "update_config".to_string()),
-        ("owner", owner.unwrap_or_else(|| info.sender.to_string())),
         (
             "epoch_config",
             epoch_config.unwrap_or(config.epoch_config).to_string(),
         ),
+        ("catch_up_mode", config.catch_up_mode.to_string()),
     ]))
 }
+
+/// Proposes, accepts or cancels a transfer of the ownership of the contract. Once the ownership is
+/// accepted, the new owner is set as the admin.
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let owner = ADMIN
+        .get(deps.as_ref())?
+        .ok_or_else(|| StdError::generic_err("The contract has no owner"))?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        ADMIN.set(deps.branch(), Some(new_owner))?;
+    }
+
+    Ok(response)
+}
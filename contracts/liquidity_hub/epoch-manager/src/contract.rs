@@ -1,4 +1,4 @@
-use cosmwasm_std::{entry_point, to_json_binary, StdError};
+use cosmwasm_std::{entry_point, to_json_binary, Reply, StdError, SubMsgResult};
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
@@ -7,10 +7,11 @@ use white_whale_std::epoch_manager::epoch_manager::{
     Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
 use white_whale_std::migrate_guards::check_contract_name;
+use white_whale_std::ownership::query_ownership_proposal;
 
 use crate::error::ContractError;
 use crate::state::{ADMIN, CONFIG, EPOCH};
-use crate::{commands, queries};
+use crate::{commands, migrations, queries};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "white_whale-epoch-manager";
@@ -44,12 +45,14 @@ pub fn instantiate(
         deps.storage,
         &Config {
             epoch_config: msg.epoch_config.clone(),
+            catch_up_mode: msg.catch_up_mode.clone(),
         },
     )?;
     Ok(Response::default().add_attributes(vec![
         ("action", "instantiate".to_string()),
         ("start_epoch", msg.start_epoch.to_string()),
         ("epoch_config", msg.epoch_config.to_string()),
+        ("catch_up_mode", msg.catch_up_mode.to_string()),
     ]))
 }
 
@@ -70,9 +73,26 @@ pub fn execute(
         }
         ExecuteMsg::CreateEpoch {} => commands::create_epoch(deps, env),
         ExecuteMsg::UpdateConfig {
-            owner,
             epoch_config,
-        } => commands::update_config(deps, &info, owner, epoch_config),
+            catch_up_mode,
+        } => commands::update_config(deps, &info, epoch_config, catch_up_mode),
+        ExecuteMsg::RetryHook {
+            contract_addr,
+            epoch_id,
+        } => commands::retry_hook(deps, info, contract_addr, epoch_id),
+        ExecuteMsg::UpdateHookManagers { add, remove } => {
+            commands::update_hook_managers(deps, info, add, remove)
+        }
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
+    }
+}
+
+/// Handles the replies of the dispatched hooks, recording the ones that failed.
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.result {
+        SubMsgResult::Err(error) => commands::record_failed_hook(deps, msg.id, error),
+        SubMsgResult::Ok(_) => commands::clear_hook_dispatch(deps, msg.id),
     }
 }
 
@@ -82,11 +102,19 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Config {} => Ok(to_json_binary(&queries::query_config(deps)?)?),
         QueryMsg::CurrentEpoch {} => Ok(to_json_binary(&queries::query_current_epoch(deps)?)?),
         QueryMsg::Epoch { id } => Ok(to_json_binary(&queries::query_epoch(deps, id)?)?),
+        QueryMsg::FailedHooks { epoch_id } => Ok(to_json_binary(&queries::query_failed_hooks(
+            deps, epoch_id,
+        )?)?),
+        QueryMsg::Hooks {} => Ok(to_json_binary(&queries::query_hooks(deps)?)?),
+        QueryMsg::HookManagers {} => Ok(to_json_binary(&queries::query_hook_managers(deps)?)?),
+        QueryMsg::OwnershipProposal {} => {
+            Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+        }
     }
 }
 
 #[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     check_contract_name(deps.storage, CONTRACT_NAME.to_string())?;
     let version: Version = CONTRACT_VERSION.parse()?;
     let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
@@ -98,6 +126,10 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         });
     }
 
+    if storage_version < Version::parse("0.3.0")? {
+        migrations::migrate_to_v030(deps.branch())?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     Ok(Response::default())
@@ -28,6 +28,12 @@ pub enum ContractError {
 
     #[error("The current epoch epoch has not expired yet.")]
     CurrentEpochNotExpired,
+
+    #[error("The hook {contract_addr} has no recorded failure for epoch {epoch_id}.")]
+    NoFailedHook {
+        contract_addr: String,
+        epoch_id: u64,
+    },
 }
 
 impl From<semver::Error> for ContractError {
@@ -3,5 +3,10 @@ pub use crate::error::ContractError;
 pub mod commands;
 pub mod contract;
 mod error;
+mod migrations;
 pub mod queries;
 pub mod state;
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tests;
@@ -0,0 +1,31 @@
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{DepsMut, StdError};
+use cw_storage_plus::Item;
+
+use white_whale_std::epoch_manager::epoch_manager::{CatchUpMode, Config, EpochConfig};
+
+use crate::state::CONFIG;
+
+/// Migrates the config to v0.3.0, which adds the catch up mode. Existing deployments keep moving
+/// forward a single epoch per call.
+pub fn migrate_to_v030(deps: DepsMut) -> Result<(), StdError> {
+    #[cw_serde]
+    struct ConfigV020 {
+        pub epoch_config: EpochConfig,
+    }
+
+    const CONFIG_V020: Item<ConfigV020> = Item::new("config");
+    let config_v020 = CONFIG_V020.load(deps.storage)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            epoch_config: config_v020.epoch_config,
+            catch_up_mode: CatchUpMode::Disabled,
+        },
+    )?;
+
+    Ok(())
+}
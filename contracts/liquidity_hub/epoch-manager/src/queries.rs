@@ -1,8 +1,11 @@
-use cosmwasm_std::{Addr, Deps, StdResult};
+use cosmwasm_std::{Addr, Deps, Order, StdResult};
 
-use white_whale_std::epoch_manager::epoch_manager::{ConfigResponse, EpochResponse, EpochV2};
+use white_whale_std::epoch_manager::epoch_manager::{
+    ConfigResponse, EpochResponse, EpochV2, FailedHook, FailedHooksResponse, HookManagersResponse,
+    HooksResponse,
+};
 
-use crate::state::{ADMIN, CONFIG, EPOCH};
+use crate::state::{ADMIN, CONFIG, EPOCH, FAILED_HOOKS, HOOKS, HOOK_MANAGERS};
 
 /// Queries the config. Returns a [ConfigResponse].
 pub(crate) fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
@@ -12,6 +15,7 @@ pub(crate) fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     Ok(ConfigResponse {
         owner: admin,
         epoch_config: config.epoch_config,
+        catch_up_mode: config.catch_up_mode,
     })
 }
 
@@ -40,3 +44,30 @@ pub(crate) fn query_epoch(deps: Deps, id: u64) -> StdResult<EpochResponse> {
         Ok(epoch.to_epoch_response())
     }
 }
+
+/// Queries the hooks that failed for the given epoch. Returns a [FailedHooksResponse].
+pub(crate) fn query_failed_hooks(deps: Deps, epoch_id: u64) -> StdResult<FailedHooksResponse> {
+    let hooks = FAILED_HOOKS
+        .prefix(epoch_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, failed_hook)| failed_hook))
+        .collect::<StdResult<Vec<FailedHook>>>()?;
+
+    Ok(FailedHooksResponse { epoch_id, hooks })
+}
+
+/// Queries the hooks registered in the contract. Returns a [HooksResponse].
+pub(crate) fn query_hooks(deps: Deps) -> StdResult<HooksResponse> {
+    Ok(HooksResponse {
+        hooks: HOOKS.query_hooks(deps)?.hooks,
+    })
+}
+
+/// Queries the hook managers. Returns a [HookManagersResponse].
+pub(crate) fn query_hook_managers(deps: Deps) -> StdResult<HookManagersResponse> {
+    let hook_managers = HOOK_MANAGERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    Ok(HookManagersResponse { hook_managers })
+}
@@ -1,8 +1,35 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty};
 use cw_controllers::{Admin, Hooks};
-use cw_storage_plus::Item;
-use white_whale_std::epoch_manager::epoch_manager::{Config, EpochV2};
+use cw_storage_plus::{Item, Map};
+use white_whale_std::epoch_manager::epoch_manager::{Config, EpochV2, FailedHook};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const ADMIN: Admin = Admin::new("admin");
 pub const HOOKS: Hooks = Hooks::new("hooks");
 pub const EPOCH: Item<EpochV2> = Item::new("epoch");
+/// Addresses allowed to add and remove hooks besides the admin.
+pub const HOOK_MANAGERS: Map<&Addr, Empty> = Map::new("hook_managers");
+
+/// Hooks that failed to process the epoch changed hook, keyed by (epoch_id, hook).
+pub const FAILED_HOOKS: Map<(u64, &Addr), FailedHook> = Map::new("failed_hooks");
+/// Hook dispatches keyed by the reply id of the submessage that carried them. Used to identify
+/// the hook and epoch when a hook fails. Entries are removed when the reply is processed.
+pub const HOOK_DISPATCHES: Map<u64, HookDispatch> = Map::new("hook_dispatches");
+/// Reply id assigned to the last hook dispatch, so reply ids are unique across calls.
+pub const HOOK_DISPATCH_ID: Item<u64> = Item::new("hook_dispatch_id");
+
+/// Maximum number of epochs created in a single call when firing the hooks for each missed epoch.
+pub const MAX_CATCH_UP_EPOCHS: u64 = 30;
+
+/// Maximum number of failed attempts for a hook on a given epoch before it gets removed.
+pub const MAX_HOOK_ATTEMPTS: u64 = 3;
+
+#[cw_serde]
+pub struct HookDispatch {
+    pub contract_addr: Addr,
+    /// The epoch sent to the hook.
+    pub epoch: EpochV2,
+    /// Number of attempts made before this dispatch.
+    pub previous_attempts: u64,
+}
@@ -0,0 +1,170 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{Binary, CosmosMsg, Deps, DepsMut, Response, Timestamp, WasmMsg};
+
+use white_whale_std::epoch_manager::epoch_manager::{
+    CatchUpMode, EpochResponse, EpochV2, ExecuteMsg, QueryMsg,
+};
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
+
+use crate::contract::{execute, query};
+use crate::state::MAX_CATCH_UP_EPOCHS;
+use crate::tests::helpers::{add_hooks, mock_instantiation, parse, DURATION};
+use crate::ContractError;
+
+/// Creates an epoch half an epoch after the given number of epochs elapsed since the first one
+/// started. Returns the start time of the first epoch along with the response.
+fn create_epoch_after(
+    mut deps: DepsMut,
+    catch_up_mode: CatchUpMode,
+    elapsed_epochs: u64,
+) -> (Timestamp, Result<Response, ContractError>) {
+    let mut env = mock_instantiation(deps.branch(), catch_up_mode);
+    add_hooks(deps.branch(), &["hook"]);
+
+    let start_time = env.block.time;
+    env.block.time = start_time.plus_nanos(DURATION * elapsed_epochs + DURATION / 2);
+
+    let res = execute(
+        deps,
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::CreateEpoch {},
+    );
+
+    (start_time, res)
+}
+
+/// Returns the messages sent to the hooks.
+fn hook_msgs(res: &Response) -> Vec<Binary> {
+    res.messages
+        .iter()
+        .map(|sub_msg| match &sub_msg.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => msg.clone(),
+            _ => panic!("expected a wasm execute message"),
+        })
+        .collect()
+}
+
+/// Returns the epoch changed hook messages for the given epochs, which follow the first epoch.
+fn expected_hook_msgs(start_time: Timestamp, epoch_ids: Vec<u64>) -> Vec<Binary> {
+    epoch_ids
+        .into_iter()
+        .map(|id| {
+            EpochChangedHookMsg {
+                current_epoch: EpochV2 {
+                    id,
+                    start_time: start_time.plus_nanos(DURATION * (id - 1)),
+                },
+            }
+            .into_json_binary()
+            .unwrap()
+        })
+        .collect()
+}
+
+fn current_epoch(deps: Deps) -> EpochV2 {
+    let res: EpochResponse = parse(query(deps, mock_env(), QueryMsg::CurrentEpoch {}).unwrap());
+    res.epoch
+}
+
+#[test]
+fn cannot_create_epoch_before_current_one_expires() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_instantiation(deps.as_mut(), CatchUpMode::FireOnce);
+    env.block.time = env.block.time.plus_nanos(DURATION - 1);
+
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::CreateEpoch {},
+    );
+    match res {
+        Err(ContractError::CurrentEpochNotExpired) => (),
+        _ => panic!("should return ContractError::CurrentEpochNotExpired"),
+    }
+}
+
+#[test]
+fn disabled_catch_up_moves_a_single_epoch() {
+    let mut deps = mock_dependencies();
+    let (start_time, res) = create_epoch_after(deps.as_mut(), CatchUpMode::Disabled, 3);
+
+    assert_eq!(
+        hook_msgs(&res.unwrap()),
+        expected_hook_msgs(start_time, vec![2])
+    );
+    assert_eq!(
+        current_epoch(deps.as_ref()),
+        EpochV2 {
+            id: 2,
+            start_time: start_time.plus_nanos(DURATION),
+        }
+    );
+}
+
+#[test]
+fn fire_once_jumps_to_the_current_epoch() {
+    let mut deps = mock_dependencies();
+    let (start_time, res) = create_epoch_after(deps.as_mut(), CatchUpMode::FireOnce, 3);
+
+    assert_eq!(
+        hook_msgs(&res.unwrap()),
+        expected_hook_msgs(start_time, vec![4])
+    );
+    assert_eq!(
+        current_epoch(deps.as_ref()),
+        EpochV2 {
+            id: 4,
+            start_time: start_time.plus_nanos(DURATION * 3),
+        }
+    );
+
+    // a long downtime is caught up in a single step
+    let mut deps = mock_dependencies();
+    let (start_time, res) = create_epoch_after(deps.as_mut(), CatchUpMode::FireOnce, 100_000);
+
+    assert_eq!(
+        hook_msgs(&res.unwrap()),
+        expected_hook_msgs(start_time, vec![100_001])
+    );
+    assert_eq!(current_epoch(deps.as_ref()).id, 100_001);
+}
+
+#[test]
+fn fire_for_each_fires_hooks_for_every_missed_epoch() {
+    let mut deps = mock_dependencies();
+    let (start_time, res) = create_epoch_after(deps.as_mut(), CatchUpMode::FireForEach, 3);
+
+    assert_eq!(
+        hook_msgs(&res.unwrap()),
+        expected_hook_msgs(start_time, vec![2, 3, 4])
+    );
+    assert_eq!(
+        current_epoch(deps.as_ref()),
+        EpochV2 {
+            id: 4,
+            start_time: start_time.plus_nanos(DURATION * 3),
+        }
+    );
+
+    // the number of epochs created in a single call is capped
+    let mut deps = mock_dependencies();
+    let (start_time, res) = create_epoch_after(
+        deps.as_mut(),
+        CatchUpMode::FireForEach,
+        MAX_CATCH_UP_EPOCHS + 10,
+    );
+
+    assert_eq!(
+        hook_msgs(&res.unwrap()),
+        expected_hook_msgs(start_time, (2..=MAX_CATCH_UP_EPOCHS + 1).collect())
+    );
+    assert_eq!(
+        current_epoch(deps.as_ref()),
+        EpochV2 {
+            id: MAX_CATCH_UP_EPOCHS + 1,
+            start_time: start_time.plus_nanos(DURATION * MAX_CATCH_UP_EPOCHS),
+        }
+    );
+}
@@ -0,0 +1,72 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    from_json, Binary, DepsMut, Env, Reply, Response, SubMsgResponse, SubMsgResult,
+};
+
+use white_whale_std::epoch_manager::epoch_manager::{
+    CatchUpMode, EpochConfig, EpochV2, ExecuteMsg, InstantiateMsg,
+};
+
+use crate::contract::{execute, instantiate, reply};
+use crate::ContractError;
+
+/// One day, in nanoseconds.
+pub(crate) const DURATION: u64 = 86_400_000_000_000;
+pub(crate) const OWNER: &str = "owner";
+
+/// Instantiates the contract with a first epoch starting at the current block time. Returns the
+/// env for that block.
+pub(crate) fn mock_instantiation(deps: DepsMut, catch_up_mode: CatchUpMode) -> Env {
+    let env = mock_env();
+    let msg = InstantiateMsg {
+        start_epoch: EpochV2 {
+            id: 1,
+            start_time: env.block.time,
+        },
+        epoch_config: EpochConfig {
+            duration: DURATION.into(),
+            genesis_epoch: env.block.time.nanos().into(),
+        },
+        catch_up_mode,
+    };
+
+    instantiate(deps, env.clone(), mock_info(OWNER, &[]), msg).unwrap();
+    env
+}
+
+/// Registers the given hooks as the owner.
+pub(crate) fn add_hooks(mut deps: DepsMut, hooks: &[&str]) {
+    for hook in hooks {
+        execute(
+            deps.branch(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::AddHook {
+                contract_addr: hook.to_string(),
+            },
+        )
+        .unwrap();
+    }
+}
+
+/// Sends the reply for the given submessage, either successful or failed with the given error.
+pub(crate) fn mock_reply(
+    deps: DepsMut,
+    id: u64,
+    error: Option<&str>,
+) -> Result<Response, ContractError> {
+    let result = match error {
+        Some(error) => SubMsgResult::Err(error.to_string()),
+        None => SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    };
+
+    reply(deps, mock_env(), Reply { id, result })
+}
+
+/// Deserializes a query response.
+pub(crate) fn parse<T: serde::de::DeserializeOwned>(binary: Binary) -> T {
+    from_json(binary).unwrap()
+}
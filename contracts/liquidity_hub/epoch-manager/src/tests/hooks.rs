@@ -0,0 +1,214 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_info};
+use cosmwasm_std::{Binary, CosmosMsg, Order, ReplyOn, Response, StdResult, WasmMsg};
+use cw_controllers::AdminError;
+
+use white_whale_std::epoch_manager::epoch_manager::{
+    CatchUpMode, EpochV2, ExecuteMsg, FailedHooksResponse, HooksResponse, QueryMsg,
+};
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
+
+use crate::contract::{execute, query};
+use crate::state::HOOK_DISPATCHES;
+use crate::tests::helpers::{add_hooks, mock_instantiation, mock_reply, parse, DURATION, OWNER};
+use crate::ContractError;
+
+/// Returns the reply ids of the hook submessages and checks they are sent with reply always.
+fn reply_ids(res: &Response) -> Vec<u64> {
+    res.messages
+        .iter()
+        .map(|sub_msg| {
+            assert_eq!(sub_msg.reply_on, ReplyOn::Always);
+            sub_msg.id
+        })
+        .collect()
+}
+
+/// Returns the contract and message of the hook submessage at the given index.
+fn hook_msg(res: &Response, index: usize) -> (String, Binary) {
+    match &res.messages[index].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => (contract_addr.clone(), msg.clone()),
+        _ => panic!("expected a wasm execute message"),
+    }
+}
+
+#[test]
+fn records_and_retries_failed_hooks() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_instantiation(deps.as_mut(), CatchUpMode::Disabled);
+    add_hooks(deps.as_mut(), &["hook1", "hook2"]);
+
+    env.block.time = env.block.time.plus_nanos(DURATION);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::CreateEpoch {},
+    )
+    .unwrap();
+    assert_eq!(reply_ids(&res), vec![1, 2]);
+
+    let failed_epoch = EpochV2 {
+        id: 2,
+        start_time: env.block.time,
+    };
+
+    // the successful dispatch is cleared, the failed one is recorded
+    mock_reply(deps.as_mut(), 1, None).unwrap();
+    mock_reply(deps.as_mut(), 2, Some("boom")).unwrap();
+    let dispatches = HOOK_DISPATCHES
+        .keys(&deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()
+        .unwrap();
+    assert!(dispatches.is_empty());
+
+    let failed_hooks: FailedHooksResponse = parse(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::FailedHooks { epoch_id: 2 },
+        )
+        .unwrap(),
+    );
+    assert_eq!(failed_hooks.hooks.len(), 1);
+    assert_eq!(failed_hooks.hooks[0].contract_addr.as_str(), "hook2");
+    assert_eq!(failed_hooks.hooks[0].epoch, failed_epoch);
+    assert_eq!(failed_hooks.hooks[0].error, "boom");
+    assert_eq!(failed_hooks.hooks[0].attempts, 1);
+
+    // reply ids keep increasing across calls
+    env.block.time = env.block.time.plus_nanos(DURATION);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::CreateEpoch {},
+    )
+    .unwrap();
+    assert_eq!(reply_ids(&res), vec![3, 4]);
+    mock_reply(deps.as_mut(), 3, None).unwrap();
+    mock_reply(deps.as_mut(), 4, None).unwrap();
+
+    // only the owner can retry a hook
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::RetryHook {
+            contract_addr: "hook2".to_string(),
+            epoch_id: 2,
+        },
+    );
+    match res {
+        Err(ContractError::AdminError(AdminError::NotAdmin {})) => (),
+        _ => panic!("should return ContractError::AdminError(AdminError::NotAdmin)"),
+    }
+
+    // the retry sends the epoch the hook failed for, not the current one
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::RetryHook {
+            contract_addr: "hook2".to_string(),
+            epoch_id: 2,
+        },
+    )
+    .unwrap();
+    assert_eq!(reply_ids(&res), vec![5]);
+    assert_eq!(
+        hook_msg(&res, 0),
+        (
+            "hook2".to_string(),
+            EpochChangedHookMsg {
+                current_epoch: failed_epoch.clone(),
+            }
+            .into_json_binary()
+            .unwrap()
+        )
+    );
+
+    // the failure is cleared while the retry is in flight
+    let failed_hooks: FailedHooksResponse = parse(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::FailedHooks { epoch_id: 2 },
+        )
+        .unwrap(),
+    );
+    assert!(failed_hooks.hooks.is_empty());
+
+    mock_reply(deps.as_mut(), 5, Some("boom again")).unwrap();
+    let failed_hooks: FailedHooksResponse = parse(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::FailedHooks { epoch_id: 2 },
+        )
+        .unwrap(),
+    );
+    assert_eq!(failed_hooks.hooks[0].error, "boom again");
+    assert_eq!(failed_hooks.hooks[0].attempts, 2);
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::RetryHook {
+            contract_addr: "hook1".to_string(),
+            epoch_id: 2,
+        },
+    );
+    match res {
+        Err(ContractError::NoFailedHook { .. }) => (),
+        _ => panic!("should return ContractError::NoFailedHook"),
+    }
+}
+
+#[test]
+fn removes_hook_after_max_attempts() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_instantiation(deps.as_mut(), CatchUpMode::Disabled);
+    add_hooks(deps.as_mut(), &["hook1", "hook2"]);
+
+    env.block.time = env.block.time.plus_nanos(DURATION);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::CreateEpoch {},
+    )
+    .unwrap();
+    mock_reply(deps.as_mut(), 1, None).unwrap();
+    let res = mock_reply(deps.as_mut(), 2, Some("boom")).unwrap();
+    assert!(!res.attributes.iter().any(|attr| attr.key == "hook_removed"));
+
+    for reply_id in [3, 4] {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(OWNER, &[]),
+            ExecuteMsg::RetryHook {
+                contract_addr: "hook2".to_string(),
+                epoch_id: 2,
+            },
+        )
+        .unwrap();
+        mock_reply(deps.as_mut(), reply_id, Some("boom")).unwrap();
+    }
+
+    let failed_hooks: FailedHooksResponse = parse(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::FailedHooks { epoch_id: 2 },
+        )
+        .unwrap(),
+    );
+    assert_eq!(failed_hooks.hooks[0].attempts, 3);
+
+    let hooks: HooksResponse = parse(query(deps.as_ref(), env, QueryMsg::Hooks {}).unwrap());
+    assert_eq!(hooks.hooks, vec!["hook1".to_string()]);
+}
@@ -0,0 +1,56 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::Addr;
+use cw2::set_contract_version;
+use cw_storage_plus::Item;
+
+use white_whale_std::epoch_manager::epoch_manager::{
+    CatchUpMode, ConfigResponse, EpochConfig, MigrateMsg, QueryMsg,
+};
+
+use crate::contract::{migrate, query};
+use crate::state::ADMIN;
+use crate::tests::helpers::{parse, DURATION};
+use crate::ContractError;
+
+#[cw_serde]
+struct ConfigV020 {
+    epoch_config: EpochConfig,
+}
+
+#[test]
+fn migrates_config_to_v030() {
+    let mut deps = mock_dependencies();
+    let epoch_config = EpochConfig {
+        duration: DURATION.into(),
+        genesis_epoch: 1_000u64.into(),
+    };
+
+    set_contract_version(&mut deps.storage, "white_whale-epoch-manager", "0.2.0").unwrap();
+    Item::<ConfigV020>::new("config")
+        .save(
+            &mut deps.storage,
+            &ConfigV020 {
+                epoch_config: epoch_config.clone(),
+            },
+        )
+        .unwrap();
+    ADMIN
+        .set(deps.as_mut(), Some(Addr::unchecked("owner")))
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let config: ConfigResponse =
+        parse(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap());
+    assert_eq!(config.owner, Addr::unchecked("owner"));
+    assert_eq!(config.epoch_config, epoch_config);
+    assert_eq!(config.catch_up_mode, CatchUpMode::Disabled);
+
+    // migrating again to the same version fails
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {});
+    match res {
+        Err(ContractError::MigrateInvalidVersion { .. }) => (),
+        _ => panic!("should return ContractError::MigrateInvalidVersion"),
+    }
+}
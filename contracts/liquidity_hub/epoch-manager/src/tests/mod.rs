@@ -0,0 +1,5 @@
+mod epoch;
+mod helpers;
+mod hooks;
+mod migrations;
+mod ownership;
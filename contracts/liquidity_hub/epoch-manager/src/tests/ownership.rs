@@ -0,0 +1,75 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_info};
+use cosmwasm_std::{from_json, Addr};
+use cw_controllers::AdminError;
+
+use white_whale_std::epoch_manager::epoch_manager::{
+    CatchUpMode, ConfigResponse, ExecuteMsg, QueryMsg,
+};
+use white_whale_std::ownership::{OwnershipAction, OwnershipProposal};
+
+use crate::contract::{execute, query};
+use crate::tests::helpers::{mock_instantiation, OWNER};
+use crate::ContractError;
+
+#[test]
+fn transfers_ownership_in_two_steps() {
+    let mut deps = mock_dependencies();
+    let env = mock_instantiation(deps.as_mut(), CatchUpMode::Disabled);
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+            new_owner: "new_owner".to_string(),
+            expiry: None,
+        }),
+    )
+    .unwrap();
+
+    let proposal: Option<OwnershipProposal> =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::OwnershipProposal {}).unwrap())
+            .unwrap();
+    assert_eq!(
+        proposal.unwrap().proposed_owner,
+        Addr::unchecked("new_owner")
+    );
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("new_owner", &[]),
+        ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+    )
+    .unwrap();
+
+    let config: ConfigResponse =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.owner, Addr::unchecked("new_owner"));
+
+    // the previous owner is no longer the admin
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateConfig {
+            epoch_config: None,
+            catch_up_mode: Some(CatchUpMode::FireOnce),
+        },
+    );
+    match res {
+        Err(ContractError::AdminError(AdminError::NotAdmin {})) => (),
+        _ => panic!("should return ContractError::AdminError(AdminError::NotAdmin)"),
+    }
+
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("new_owner", &[]),
+        ExecuteMsg::UpdateConfig {
+            epoch_config: None,
+            catch_up_mode: Some(CatchUpMode::FireOnce),
+        },
+    )
+    .unwrap();
+}
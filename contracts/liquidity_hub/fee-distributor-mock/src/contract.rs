@@ -78,6 +78,9 @@ pub fn query(
         white_whale_std::fee_distributor::QueryMsg::Epoch { .. } => {}
         white_whale_std::fee_distributor::QueryMsg::ClaimableEpochs { .. } => {}
         white_whale_std::fee_distributor::QueryMsg::Claimable { .. } => {}
+        white_whale_std::fee_distributor::QueryMsg::ClaimHistory { .. } => {}
+        white_whale_std::fee_distributor::QueryMsg::EstimatedRewards { .. } => {}
+        white_whale_std::fee_distributor::QueryMsg::OwnershipProposal {} => {}
     }
 
     to_json_binary(&"")
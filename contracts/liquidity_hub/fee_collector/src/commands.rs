@@ -1,21 +1,34 @@
 use cosmwasm_std::{
-    ensure, to_json_binary, Addr, BalanceResponse, BankQuery, Coin, CosmosMsg, Decimal, DepsMut,
-    Env, MessageInfo, QueryRequest, ReplyOn, Response, StdResult, SubMsg, Uint128, WasmMsg,
-    WasmQuery,
+    ensure, to_json_binary, Addr, BalanceResponse, BankQuery, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, QueryRequest, ReplyOn, Response, StdResult, SubMsg, SubMsgResult,
+    Uint128, WasmMsg, WasmQuery,
 };
 use cw20::{Cw20ExecuteMsg, Cw20QueryMsg};
 
-use white_whale_std::fee_collector::{Config, ContractType, ExecuteMsg, FactoryType, FeesFor};
+use white_whale_std::fee_collector::{
+    AggregationConfig, AggregationLimit, BuybackConfig, Config, ContractFees, ContractType,
+    ExecuteMsg, FactoryType, FeeCollectionCursor, FeesFor, ReferencePrice, ReferencePriceRecord,
+    SkipReason, SkippedAsset, TakeRateRecipient, TakeRateSplit,
+};
 use white_whale_std::fee_distributor::Epoch;
-use white_whale_std::pool_network::asset::AssetInfo;
-use white_whale_std::pool_network::factory::{PairsResponse, QueryMsg};
+use white_whale_std::ownership::{self, OwnershipAction};
+use white_whale_std::pool_network::asset::{aggregate_assets, Asset, AssetInfo};
+use white_whale_std::pool_network::factory::{PairsResponse, QueryMsg, TriosResponse};
 use white_whale_std::pool_network::router;
 use white_whale_std::pool_network::router::SwapOperation;
 use white_whale_std::vault_network::vault_factory::VaultsResponse;
 
-use crate::contract::{FEES_AGGREGATION_REPLY_ID, FEES_COLLECTION_REPLY_ID};
-use crate::queries::query_distribution_asset;
-use crate::state::{read_temporal_asset_infos, store_temporal_asset_info, CONFIG, TMP_EPOCH};
+use crate::contract::{BUYBACK_REPLY_ID, FEES_AGGREGATION_REPLY_ID, FEES_COLLECTION_REPLY_ID};
+use crate::queries::{query_contract_fees, query_distribution_assets, query_factory_contracts};
+use crate::state::{
+    read_aggregation_config, read_aggregation_limit, read_pools_fee_collection_cursor,
+    read_temporal_asset_infos, read_trios_fee_collection_cursor, read_vaults_fee_collection_cursor,
+    record_burn, store_temporal_asset_info, PendingBuyback, AGGREGATION_CONFIG, AGGREGATION_LIMITS,
+    BUYBACK_CONFIG, CONFIG, CONTRACT_FEES_HISTORY, EPOCH_CONTRACT_FEES,
+    POOLS_FEE_COLLECTION_CURSOR, REFERENCE_PRICES, TAKE_RATE_SPLITS, TMP_BUYBACK,
+    TMP_CARRIED_OVER_ASSETS, TMP_EPOCH, TMP_SKIPPED_ASSETS, TRIOS_FEE_COLLECTION_CURSOR,
+    VAULTS_FEE_COLLECTION_CURSOR,
+};
 use crate::ContractError;
 
 /// Collects fees accrued by the pools and vaults. If a factory is provided then it only collects the
@@ -55,6 +68,9 @@ fn collect_fees_for_contract(contract: Addr, contract_type: ContractType) -> Std
         ContractType::Pool {} => to_json_binary(
             &white_whale_std::pool_network::pair::ExecuteMsg::CollectProtocolFees {},
         )?,
+        ContractType::Trio {} => to_json_binary(
+            &white_whale_std::pool_network::trio::ExecuteMsg::CollectProtocolFees {},
+        )?,
     };
 
     Ok(CosmosMsg::Wasm(WasmMsg::Execute {
@@ -92,11 +108,19 @@ fn collect_fees_for_factory(
                 )?);
             }
         }
-        FactoryType::Pool { start_after, limit } => {
+        FactoryType::Pool {
+            start_after,
+            start_after_variant,
+            limit,
+        } => {
             let response: PairsResponse =
                 deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                     contract_addr: factory.to_string(),
-                    msg: to_json_binary(&QueryMsg::Pairs { start_after, limit })?,
+                    msg: to_json_binary(&QueryMsg::Pairs {
+                        start_after,
+                        start_after_variant,
+                        limit,
+                    })?,
                 }))?;
 
             for pair in response.pairs {
@@ -107,16 +131,49 @@ fn collect_fees_for_factory(
                 )?);
             }
         }
+        FactoryType::Trio { start_after, limit } => {
+            let response: TriosResponse =
+                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                    contract_addr: factory.to_string(),
+                    msg: to_json_binary(&QueryMsg::Trios { start_after, limit })?,
+                }))?;
+
+            for trio in response.trios {
+                result.push(collect_fees_for_contract(
+                    deps.api.addr_validate(trio.contract_addr.as_str())?,
+                    ContractType::Trio {},
+                )?);
+            }
+        }
     }
 
     Ok(result)
 }
 
+/// Proposes, accepts or cancels a transfer of the ownership of the contract. Once the ownership is
+/// accepted, the new owner is stored in the config.
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn update_config(
     deps: DepsMut,
     info: MessageInfo,
-    owner: Option<String>,
     pool_router: Option<String>,
     fee_distributor: Option<String>,
     pool_factory: Option<String>,
@@ -132,11 +189,6 @@ pub fn update_config(
         return Err(ContractError::Unauthorized {});
     }
 
-    if let Some(owner) = owner {
-        let owner_addr = deps.api.addr_validate(&owner)?;
-        config.owner = owner_addr;
-    }
-
     if let Some(pool_router) = pool_router {
         let pool_router = deps.api.addr_validate(&pool_router)?;
         config.pool_router = pool_router;
@@ -179,7 +231,9 @@ pub fn update_config(
 
 const MINIMUM_AGGREGABLE_BALANCE: Uint128 = Uint128::new(1_000u128);
 
-/// Aggregates the fees collected into the given asset_info.
+/// Aggregates the fees collected into the distribution assets, splitting each aggregated asset by the
+/// aggregation ratios. Assets that are distribution assets themselves are not swapped, so the ratios
+/// only apply to the newly aggregated assets and the basket is not rebalanced.
 pub fn aggregate_fees(
     mut deps: DepsMut,
     env: Env,
@@ -187,8 +241,8 @@ pub fn aggregate_fees(
 ) -> Result<Response, ContractError> {
     let config: Config = CONFIG.load(deps.storage)?;
 
-    // query fee collector to get the distribution asset
-    let ask_asset_info = query_distribution_asset(deps.as_ref())?;
+    // query fee distributor to get the distribution assets
+    let distribution_assets = query_distribution_assets(deps.as_ref())?;
 
     let mut aggregate_fees_messages: Vec<CosmosMsg> = Vec::new();
 
@@ -217,11 +271,19 @@ pub fn aggregate_fees(
                         store_temporal_asset_info(deps.branch(), vault_info.asset_info.clone())?;
                     }
                 }
-                FactoryType::Pool { start_after, limit } => {
+                FactoryType::Pool {
+                    start_after,
+                    start_after_variant,
+                    limit,
+                } => {
                     let response: PairsResponse =
                         deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                             contract_addr: factory.to_string(),
-                            msg: to_json_binary(&QueryMsg::Pairs { start_after, limit })?,
+                            msg: to_json_binary(&QueryMsg::Pairs {
+                                start_after,
+                                start_after_variant,
+                                limit,
+                            })?,
                         }))?;
 
                     for pair in response.pairs {
@@ -229,14 +291,36 @@ pub fn aggregate_fees(
                         store_temporal_asset_info(deps.branch(), pair.asset_infos[1].clone())?;
                     }
                 }
+                FactoryType::Trio { start_after, limit } => {
+                    let response: TriosResponse =
+                        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                            contract_addr: factory.to_string(),
+                            msg: to_json_binary(&QueryMsg::Trios { start_after, limit })?,
+                        }))?;
+
+                    for trio in response.trios {
+                        for asset_info in trio.asset_infos {
+                            store_temporal_asset_info(deps.branch(), asset_info)?;
+                        }
+                    }
+                }
             }
         }
     }
 
     let asset_infos: Vec<AssetInfo> = read_temporal_asset_infos(&mut deps)?;
+    let aggregation_config = read_aggregation_config(deps.storage)?;
+
+    // the assets that were not aggregated, or only partially, are kept in the contract and carried
+    // over to the next aggregation
+    let mut skipped_assets: Vec<SkippedAsset> = Vec::new();
+    let mut carried_over_assets: Vec<Asset> = Vec::new();
 
     for offer_asset_info in asset_infos {
-        if offer_asset_info == ask_asset_info {
+        if distribution_assets
+            .iter()
+            .any(|distribution_asset| distribution_asset.asset_info == offer_asset_info)
+        {
             continue;
         }
 
@@ -252,20 +336,6 @@ pub fn aggregate_fees(
                         })?,
                     }))?;
 
-                if balance_response.balance > Uint128::zero() {
-                    // if balance is greater than zero, some swap will occur
-                    // Increase the allowance for the cw20 token so the router can perform the swap
-                    aggregate_fees_messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-                        contract_addr: contract_addr.to_string(),
-                        msg: to_json_binary(&Cw20ExecuteMsg::IncreaseAllowance {
-                            spender: config.pool_router.to_string(),
-                            amount: balance_response.balance,
-                            expires: None,
-                        })?,
-                        funds: vec![],
-                    }));
-                }
-
                 balance_response.balance
             }
             AssetInfo::NativeToken { denom } => {
@@ -278,90 +348,896 @@ pub fn aggregate_fees(
             }
         };
 
-        // if the balance is greater than the minimum aggregable balance, swap the asset to the ask_asset
+        // if the balance is greater than the minimum aggregable balance, split it among the
+        // distribution assets by their aggregation ratios and swap each share into the
+        // corresponding asset
         if balance > MINIMUM_AGGREGABLE_BALANCE {
-            // query swap route from router
-            let operations_res: StdResult<Vec<SwapOperation>> =
-                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-                    contract_addr: config.pool_router.to_string(),
-                    msg: to_json_binary(&router::QueryMsg::SwapRoute {
-                        offer_asset_info: offer_asset_info.clone(),
-                        ask_asset_info: ask_asset_info.clone(),
-                    })?,
-                }));
-
-            match operations_res {
-                Ok(operations) => {
-                    let execute_swap_operations_msg =
-                        to_json_binary(&router::ExecuteMsg::ExecuteSwapOperations {
-                            operations: operations.clone(),
-                            minimum_receive: None,
-                            to: None,
-                            max_spread: Some(Decimal::percent(50u64)),
-                        })?;
-                    let operations_simulation: StdResult<router::SimulateSwapOperationsResponse> =
-                        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-                            contract_addr: config.pool_router.to_string(),
-                            msg: to_json_binary(&router::QueryMsg::SimulateSwapOperations {
-                                offer_amount: balance,
-                                operations: operations.clone(),
-                            })?,
-                        }));
-
-                    match operations_simulation {
-                        Ok(_) => {
-                            if balance < MINIMUM_AGGREGABLE_BALANCE {
-                                continue;
-                            }
-                            match offer_asset_info.clone() {
-                                AssetInfo::Token { contract_addr } => {
-                                    aggregate_fees_messages.push(CosmosMsg::Wasm(
-                                        WasmMsg::Execute {
-                                            contract_addr,
-                                            funds: vec![],
-                                            msg: to_json_binary(&Cw20ExecuteMsg::Send {
-                                                contract: config.pool_router.to_string(),
-                                                amount: balance,
-                                                msg: execute_swap_operations_msg,
-                                            })?,
-                                        },
-                                    ));
-                                }
-                                AssetInfo::NativeToken { denom } => {
-                                    aggregate_fees_messages.push(CosmosMsg::Wasm(
-                                        WasmMsg::Execute {
-                                            contract_addr: config.pool_router.to_string(),
-                                            funds: vec![Coin {
-                                                denom,
-                                                amount: balance,
-                                            }],
-                                            msg: execute_swap_operations_msg,
-                                        },
-                                    ));
-                                }
-                            };
-                        }
-                        Err(_) => {
-                            continue;
-                        }
+            // only aggregate up to the limit set for the asset, if any
+            let aggregable_balance =
+                match read_aggregation_limit(deps.storage, offer_asset_info.to_string())? {
+                    Some(max_amount) => balance.min(max_amount),
+                    None => balance,
+                };
+
+            if aggregable_balance < balance {
+                carried_over_assets.push(Asset {
+                    info: offer_asset_info.clone(),
+                    amount: balance.saturating_sub(aggregable_balance),
+                });
+            }
+
+            let mut remaining_balance = aggregable_balance;
+            let mut swapped_amount = Uint128::zero();
+            let mut swap_messages: Vec<CosmosMsg> = Vec::new();
+
+            for (i, distribution_asset) in distribution_assets.iter().enumerate() {
+                // the last distribution asset takes whatever is left, so rounding doesn't leave
+                // dust behind
+                let offer_amount = if i == distribution_assets.len() - 1 {
+                    remaining_balance
+                } else {
+                    aggregable_balance
+                        .checked_mul_floor(distribution_asset.aggregation_ratio)
+                        .unwrap_or(Uint128::zero())
+                        .min(remaining_balance)
+                };
+                remaining_balance = remaining_balance.saturating_sub(offer_amount);
+
+                if offer_amount.is_zero() {
+                    continue;
+                }
+
+                match build_aggregation_swap_msg(
+                    deps.as_ref(),
+                    &env,
+                    &config,
+                    &aggregation_config,
+                    &offer_asset_info,
+                    &distribution_asset.asset_info,
+                    offer_amount,
+                )? {
+                    AggregationSwap::Swap(swap_msg) => {
+                        swapped_amount = swapped_amount.checked_add(offer_amount)?;
+                        swap_messages.push(swap_msg);
+                    }
+                    AggregationSwap::Skip(reason) => {
+                        skipped_assets.push(SkippedAsset {
+                            offer_asset: Asset {
+                                info: offer_asset_info.clone(),
+                                amount: offer_amount,
+                            },
+                            ask_asset_info: distribution_asset.asset_info.clone(),
+                            reason,
+                        });
                     }
                 }
-                Err(_) => {
-                    // if there is no swap route, skip swap and keep the asset in contract
-                    continue;
+            }
+
+            // Increase the allowance for the cw20 token so the router can perform the swaps
+            if let AssetInfo::Token { contract_addr } = offer_asset_info.clone() {
+                if !swapped_amount.is_zero() {
+                    aggregate_fees_messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                        contract_addr,
+                        msg: to_json_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                            spender: config.pool_router.to_string(),
+                            amount: swapped_amount,
+                            expires: None,
+                        })?,
+                        funds: vec![],
+                    }));
                 }
+            }
+
+            aggregate_fees_messages.append(&mut swap_messages);
+        }
+    }
+
+    let mut attributes = vec![("action", "aggregate_fees".to_string())];
+    for skipped_asset in &skipped_assets {
+        attributes.push((
+            "skipped_asset",
+            format!(
+                "{}:{}",
+                skipped_asset.offer_asset.info, skipped_asset.reason
+            ),
+        ));
+    }
+    for asset in &carried_over_assets {
+        attributes.push(("carried_over_asset", asset.to_string()));
+    }
+
+    // when aggregating while the fees are forwarded, keep track of what was left behind so it's
+    // reported in the forward fees response
+    if TMP_EPOCH.exists(deps.storage) {
+        let mut tmp_skipped_assets = TMP_SKIPPED_ASSETS
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        tmp_skipped_assets.append(&mut skipped_assets);
+        TMP_SKIPPED_ASSETS.save(deps.storage, &tmp_skipped_assets)?;
+
+        let mut tmp_carried_over_assets = TMP_CARRIED_OVER_ASSETS
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        tmp_carried_over_assets.append(&mut carried_over_assets);
+        TMP_CARRIED_OVER_ASSETS.save(deps.storage, &tmp_carried_over_assets)?;
+    }
+
+    Ok(Response::default()
+        .add_attributes(attributes)
+        .add_messages(aggregate_fees_messages))
+}
+
+/// The outcome of building an aggregation swap.
+pub(crate) enum AggregationSwap {
+    /// The message to perform the swap.
+    Swap(CosmosMsg),
+    /// The swap was skipped for the given reason, so the asset is kept in the contract.
+    Skip(SkipReason),
+}
+
+/// Builds the message to swap the given amount of the offer asset into the ask asset through the
+/// router, with the minimum amount to receive derived from the swap simulation and the max spread.
+/// If the owner set a fresh reference price for the pair of assets, the price of the swap is
+/// checked against it as well, and the minimum amount to receive can't go below it minus the max
+/// price deviation. The swap is skipped if there's no swap route for the pair of assets, if the
+/// swap simulation fails or if its price deviates too much from the reference price, in which case
+/// the offer asset is kept in the contract.
+pub(crate) fn build_aggregation_swap_msg(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    aggregation_config: &AggregationConfig,
+    offer_asset_info: &AssetInfo,
+    ask_asset_info: &AssetInfo,
+    offer_amount: Uint128,
+) -> StdResult<AggregationSwap> {
+    // query swap route from router
+    let operations_res: StdResult<Vec<SwapOperation>> =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: config.pool_router.to_string(),
+            msg: to_json_binary(&router::QueryMsg::SwapRoute {
+                offer_asset_info: offer_asset_info.clone(),
+                ask_asset_info: ask_asset_info.clone(),
+            })?,
+        }));
+
+    let operations = match operations_res {
+        Ok(operations) => operations,
+        // if there is no swap route, skip swap and keep the asset in contract
+        Err(_) => return Ok(AggregationSwap::Skip(SkipReason::NoSwapRoute)),
+    };
+
+    let operations_simulation: StdResult<router::SimulateSwapOperationsResponse> =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: config.pool_router.to_string(),
+            msg: to_json_binary(&router::QueryMsg::SimulateSwapOperations {
+                offer_amount,
+                operations: operations.clone(),
+            })?,
+        }));
+
+    let simulated_amount = match operations_simulation {
+        Ok(simulation) if !simulation.amount.is_zero() => simulation.amount,
+        _ => return Ok(AggregationSwap::Skip(SkipReason::SimulationFailed)),
+    };
+
+    let mut minimum_receive =
+        simulated_amount.mul_floor(Decimal::one().checked_sub(aggregation_config.max_spread)?);
+
+    // if the owner set a fresh reference price for the pair of assets, the swap is skipped if its
+    // price, including the price impact of the offer amount, is too far below it. That way the swap
+    // can't be sandwiched by manipulating the pools in the same block
+    if let Some(reference_price) = REFERENCE_PRICES
+        .may_load(
+            deps.storage,
+            (offer_asset_info.to_string(), ask_asset_info.to_string()),
+        )?
+        .filter(|record| {
+            env.block.time.seconds().saturating_sub(record.updated_at)
+                <= aggregation_config.reference_price_ttl
+        })
+    {
+        let min_price = reference_price
+            .reference_price
+            .price
+            .checked_mul(Decimal::one().checked_sub(aggregation_config.max_price_deviation)?)?;
+
+        if Decimal::from_ratio(simulated_amount, offer_amount) < min_price {
+            return Ok(AggregationSwap::Skip(SkipReason::PriceDeviation));
+        }
+
+        // the pools can be moved before the swap is executed, i.e. by the other aggregation swaps,
+        // so the minimum amount to receive doesn't go below the reference price either
+        minimum_receive = minimum_receive.max(offer_amount.mul_floor(min_price));
+    }
+
+    let execute_swap_operations_msg = to_json_binary(&router::ExecuteMsg::ExecuteSwapOperations {
+        operations,
+        minimum_receive: Some(minimum_receive),
+        to: None,
+        max_spread: Some(aggregation_config.max_spread),
+    })?;
+
+    let swap_msg = match offer_asset_info.clone() {
+        AssetInfo::Token { contract_addr } => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            funds: vec![],
+            msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                contract: config.pool_router.to_string(),
+                amount: offer_amount,
+                msg: execute_swap_operations_msg,
+            })?,
+        }),
+        AssetInfo::NativeToken { denom } => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: config.pool_router.to_string(),
+            funds: vec![Coin {
+                denom,
+                amount: offer_amount,
+            }],
+            msg: execute_swap_operations_msg,
+        }),
+    };
+
+    Ok(AggregationSwap::Swap(swap_msg))
+}
+
+/// Updates the settings used to protect the fee aggregation swaps, and sets the given reference
+/// prices.
+pub fn update_aggregation_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    max_spread: Decimal,
+    max_price_deviation: Decimal,
+    reference_price_ttl: u64,
+    reference_prices: Vec<ReferencePrice>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    ensure!(
+        max_spread < Decimal::one(),
+        ContractError::InvalidMaxSpread {}
+    );
+
+    ensure!(
+        max_price_deviation < Decimal::one(),
+        ContractError::InvalidMaxPriceDeviation {}
+    );
+
+    let aggregation_config = AggregationConfig {
+        max_spread,
+        max_price_deviation,
+        reference_price_ttl,
+    };
+    AGGREGATION_CONFIG.save(deps.storage, &aggregation_config)?;
+
+    let mut attributes = vec![
+        ("action", "update_aggregation_config".to_string()),
+        ("max_spread", max_spread.to_string()),
+        ("max_price_deviation", max_price_deviation.to_string()),
+        ("reference_price_ttl", reference_price_ttl.to_string()),
+    ];
+
+    for reference_price in reference_prices {
+        ensure!(
+            !reference_price.price.is_zero()
+                && reference_price.offer_asset_info != reference_price.ask_asset_info,
+            ContractError::InvalidReferencePrice {}
+        );
+
+        attributes.push((
+            "reference_price",
+            format!(
+                "{}:{}:{}",
+                reference_price.offer_asset_info,
+                reference_price.ask_asset_info,
+                reference_price.price
+            ),
+        ));
+
+        REFERENCE_PRICES.save(
+            deps.storage,
+            (
+                reference_price.offer_asset_info.to_string(),
+                reference_price.ask_asset_info.to_string(),
+            ),
+            &ReferencePriceRecord {
+                reference_price,
+                updated_at: env.block.time.seconds(),
+            },
+        )?;
+    }
+
+    Ok(Response::default().add_attributes(attributes))
+}
+
+/// Sets or removes the maximum amount of the given assets that can be aggregated at once.
+pub fn update_aggregation_limits(
+    deps: DepsMut,
+    info: MessageInfo,
+    limits: Vec<AggregationLimit>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attributes = vec![("action", "update_aggregation_limits".to_string())];
+
+    for limit in limits {
+        let asset_key = limit.asset_info.to_string();
+
+        match limit.max_amount {
+            Some(max_amount) => {
+                attributes.push(("limit", format!("{asset_key}:{max_amount}")));
+                AGGREGATION_LIMITS.save(deps.storage, asset_key, &limit)?;
+            }
+            None => {
+                attributes.push(("removed_limit", asset_key.clone()));
+                AGGREGATION_LIMITS.remove(deps.storage, asset_key);
+            }
+        }
+    }
+
+    Ok(Response::default().add_attributes(attributes))
+}
+
+/// Updates the recipients the take rate is split among. The shares must add up to 1. Passing an
+/// empty list removes the splits, sending the whole take rate to the take rate dao address.
+pub fn update_take_rate_splits(
+    deps: DepsMut,
+    info: MessageInfo,
+    splits: Vec<TakeRateSplit>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attributes = vec![("action", "update_take_rate_splits".to_string())];
+    let mut validated_splits: Vec<TakeRateSplit> = Vec::with_capacity(splits.len());
+    let mut total_share = Decimal::zero();
+
+    for split in splits {
+        ensure!(
+            !split.share.is_zero(),
+            ContractError::InvalidTakeRateSplits {}
+        );
+
+        let recipient = match split.recipient {
+            TakeRateRecipient::Address { address } => TakeRateRecipient::Address {
+                address: deps.api.addr_validate(&address)?.to_string(),
+            },
+            TakeRateRecipient::Burn {} => TakeRateRecipient::Burn {},
+        };
+
+        ensure!(
+            !validated_splits
+                .iter()
+                .any(|validated_split| validated_split.recipient == recipient),
+            ContractError::InvalidTakeRateSplits {}
+        );
+
+        total_share = total_share.checked_add(split.share)?;
+        attributes.push(("split", format!("{recipient}:{}", split.share)));
+        validated_splits.push(TakeRateSplit {
+            recipient,
+            share: split.share,
+        });
+    }
+
+    ensure!(
+        validated_splits.is_empty() || total_share == Decimal::one(),
+        ContractError::InvalidTakeRateSplits {}
+    );
+
+    TAKE_RATE_SPLITS.save(deps.storage, &validated_splits)?;
+
+    Ok(Response::default().add_attributes(attributes))
+}
+
+/// Splits the given take rate among the recipients according to their shares. The last recipient
+/// gets whatever is left after rounding, so no funds stay in the contract.
+pub(crate) fn split_take_rate(
+    take_rate_fees: &[Coin],
+    splits: &[TakeRateSplit],
+) -> StdResult<Vec<(TakeRateRecipient, Vec<Coin>)>> {
+    let mut remaining: Vec<Coin> = take_rate_fees.to_vec();
+    let mut split_take_rate = Vec::with_capacity(splits.len());
+
+    for (i, split) in splits.iter().enumerate() {
+        let is_last = i == splits.len() - 1;
+        let mut take_rate = vec![];
+
+        for remaining_coin in remaining.iter_mut() {
+            let amount = if is_last {
+                remaining_coin.amount
+            } else {
+                take_rate_fees
+                    .iter()
+                    .find(|fee| fee.denom == remaining_coin.denom)
+                    .map(|fee| fee.amount.mul_floor(split.share))
+                    .unwrap_or_default()
+                    .min(remaining_coin.amount)
+            };
+
+            if amount.is_zero() {
+                continue;
+            }
+
+            remaining_coin.amount = remaining_coin.amount.checked_sub(amount)?;
+            take_rate.push(Coin {
+                denom: remaining_coin.denom.clone(),
+                amount,
+            });
+        }
+
+        split_take_rate.push((split.recipient.clone(), take_rate));
+    }
+
+    Ok(split_take_rate)
+}
+
+/// Updates the buyback settings. Passing [None] turns the buyback off.
+pub fn update_buyback_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    buyback_config: Option<BuybackConfig>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+
+    // permission check
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let Some(buyback_config) = buyback_config else {
+        BUYBACK_CONFIG.remove(deps.storage);
+
+        return Ok(Response::default().add_attributes(vec![
+            ("action", "update_buyback_config"),
+            ("buyback", "off"),
+        ]));
+    };
+
+    ensure!(
+        !buyback_config.share.is_zero() && buyback_config.share <= Decimal::one(),
+        ContractError::InvalidBuybackShare {}
+    );
+
+    let buyback_config = BuybackConfig {
+        burn_asset: buyback_config.burn_asset.validate(&deps.as_ref())?,
+        share: buyback_config.share,
+    };
+    BUYBACK_CONFIG.save(deps.storage, &buyback_config)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_buyback_config".to_string()),
+        ("burn_asset", buyback_config.burn_asset.to_string()),
+        ("share", buyback_config.share.to_string()),
+    ]))
+}
+
+/// Takes the buyback share out of the fees about to be forwarded for the given epoch. The share of
+/// the fees that already are the burn asset is burned right away, the rest is swapped to the burn
+/// asset and burned once the swap replies. Returns the swap submessages and the burn messages.
+pub(crate) fn buyback_fees(
+    deps: &mut DepsMut,
+    env: &Env,
+    config: &Config,
+    epoch_id: u64,
+    fees: &mut Vec<Asset>,
+) -> Result<(Vec<SubMsg>, Vec<CosmosMsg>), ContractError> {
+    let Some(buyback_config) = BUYBACK_CONFIG.may_load(deps.storage)? else {
+        return Ok((vec![], vec![]));
+    };
+
+    let aggregation_config = read_aggregation_config(deps.storage)?;
+    let mut swap_submsgs = vec![];
+    let mut burn_msgs = vec![];
+
+    for fee in fees.iter_mut() {
+        let buyback_amount = fee
+            .amount
+            .checked_mul_floor(buyback_config.share)
+            .unwrap_or(Uint128::zero());
+
+        if buyback_amount.is_zero() {
+            continue;
+        }
+
+        if fee.info == buyback_config.burn_asset {
+            let burned = Asset {
+                info: fee.info.clone(),
+                amount: buyback_amount,
             };
+
+            burn_msgs.push(burned.clone().into_burn_msg()?);
+            record_burn(deps.storage, epoch_id, burned)?;
+            fee.amount = fee.amount.saturating_sub(buyback_amount);
+            continue;
+        }
+
+        // if the swap can't be done, the fees are distributed as usual
+        if let AggregationSwap::Swap(swap_msg) = build_aggregation_swap_msg(
+            deps.as_ref(),
+            env,
+            config,
+            &aggregation_config,
+            &fee.info,
+            &buyback_config.burn_asset,
+            buyback_amount,
+        )? {
+            // reply always so a failing swap doesn't prevent the fees from being forwarded
+            swap_submsgs.push(SubMsg::reply_always(swap_msg, BUYBACK_REPLY_ID));
+            fee.amount = fee.amount.saturating_sub(buyback_amount);
         }
     }
 
+    fees.retain(|fee| !fee.amount.is_zero());
+
+    if !swap_submsgs.is_empty() {
+        TMP_BUYBACK.save(
+            deps.storage,
+            &PendingBuyback {
+                epoch_id,
+                balance_before: buyback_config.burn_asset.query_pool(
+                    &deps.querier,
+                    deps.api,
+                    env.contract.address.clone(),
+                )?,
+                burn_asset: buyback_config.burn_asset,
+                pending_swaps: swap_submsgs.len() as u32,
+            },
+        )?;
+    }
+
+    Ok((swap_submsgs, burn_msgs))
+}
+
+/// Burns the burn asset bought back by a buyback swap. If the swap failed, the fees are kept in the
+/// contract and forwarded on the next epoch.
+pub(crate) fn burn_bought_back_fees(
+    deps: DepsMut,
+    env: Env,
+    result: SubMsgResult,
+) -> Result<Response, ContractError> {
+    let mut pending_buyback = TMP_BUYBACK.load(deps.storage)?;
+    pending_buyback.pending_swaps = pending_buyback.pending_swaps.saturating_sub(1);
+
+    if pending_buyback.pending_swaps == 0 {
+        TMP_BUYBACK.remove(deps.storage);
+    } else {
+        TMP_BUYBACK.save(deps.storage, &pending_buyback)?;
+    }
+
+    if let SubMsgResult::Err(error) = result {
+        return Ok(Response::default().add_attributes(vec![
+            ("action", "buyback".to_string()),
+            ("failed_swap", error),
+        ]));
+    }
+
+    let balance =
+        pending_buyback
+            .burn_asset
+            .query_pool(&deps.querier, deps.api, env.contract.address)?;
+    let burned = Asset {
+        info: pending_buyback.burn_asset,
+        amount: balance.saturating_sub(pending_buyback.balance_before),
+    };
+
+    if burned.amount.is_zero() {
+        return Ok(Response::default().add_attribute("action", "buyback"));
+    }
+
+    record_burn(deps.storage, pending_buyback.epoch_id, burned.clone())?;
+
     Ok(Response::default()
-        .add_attribute("action", "aggregate_fees")
+        .add_attributes(vec![
+            ("action", "buyback".to_string()),
+            ("burned", burned.to_string()),
+        ])
+        .add_message(burned.into_burn_msg()?))
+}
+
+/// The maximum amount of contracts the factories return per page.
+const MAX_FEE_COLLECTION_LIMIT: u32 = 30u32;
+
+/// Collects and aggregates the fees of the next page of vaults, pools and trios, advancing the fee
+/// collection cursors. Factories that are not set in the config are skipped.
+pub fn sweep_fees(
+    mut deps: DepsMut,
+    env: Env,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit
+        .unwrap_or(MAX_FEE_COLLECTION_LIMIT)
+        .min(MAX_FEE_COLLECTION_LIMIT);
+
+    let mut collect_fees_messages: Vec<CosmosMsg> = Vec::new();
+    let mut aggregate_fees_messages: Vec<CosmosMsg> = Vec::new();
+
+    // the swept fees are forwarded on the next epoch, so they are reported along with it
+    let pending_epoch_id = get_pending_epoch_id(&deps, &config)?;
+
+    if !config.vault_factory.as_str().is_empty() {
+        let mut cursor = read_vaults_fee_collection_cursor(deps.storage)?;
+        let page = advance_fee_collection_cursor(&deps, &config.vault_factory, &mut cursor, limit)?;
+        VAULTS_FEE_COLLECTION_CURSOR.save(deps.storage, &cursor)?;
+
+        if let Some(epoch_id) = pending_epoch_id {
+            snapshot_contract_fees(&mut deps, &config.vault_factory, &page, epoch_id)?;
+        }
+
+        let (collect_fees_msg, aggregate_fees_msg) =
+            build_fee_collection_msgs(&env, &config.vault_factory, page)?;
+        collect_fees_messages.push(collect_fees_msg);
+        aggregate_fees_messages.push(aggregate_fees_msg);
+    }
+
+    if !config.pool_factory.as_str().is_empty() {
+        let mut cursor = read_pools_fee_collection_cursor(deps.storage)?;
+        let page = advance_fee_collection_cursor(&deps, &config.pool_factory, &mut cursor, limit)?;
+        POOLS_FEE_COLLECTION_CURSOR.save(deps.storage, &cursor)?;
+
+        if let Some(epoch_id) = pending_epoch_id {
+            snapshot_contract_fees(&mut deps, &config.pool_factory, &page, epoch_id)?;
+        }
+
+        let (collect_fees_msg, aggregate_fees_msg) =
+            build_fee_collection_msgs(&env, &config.pool_factory, page)?;
+        collect_fees_messages.push(collect_fees_msg);
+        aggregate_fees_messages.push(aggregate_fees_msg);
+
+        // the trios are created by the pool factory as well
+        let mut cursor = read_trios_fee_collection_cursor(deps.storage)?;
+        let page = advance_fee_collection_cursor(&deps, &config.pool_factory, &mut cursor, limit)?;
+        TRIOS_FEE_COLLECTION_CURSOR.save(deps.storage, &cursor)?;
+
+        if let Some(epoch_id) = pending_epoch_id {
+            snapshot_contract_fees(&mut deps, &config.pool_factory, &page, epoch_id)?;
+        }
+
+        let (collect_fees_msg, aggregate_fees_msg) =
+            build_fee_collection_msgs(&env, &config.pool_factory, page)?;
+        collect_fees_messages.push(collect_fees_msg);
+        aggregate_fees_messages.push(aggregate_fees_msg);
+    }
+
+    // the fees are aggregated once they have been collected from all the contracts in the batch
+    Ok(Response::default()
+        .add_attribute("action", "sweep_fees")
+        .add_attribute("limit", limit.to_string())
+        .add_messages(collect_fees_messages)
         .add_messages(aggregate_fees_messages))
 }
 
+/// Moves the given fee collection cursor past the next page of the factory's children, returning
+/// the page the fees should be collected and aggregated for. When the last page is reached, the
+/// cursor starts over from the first contract.
+fn advance_fee_collection_cursor(
+    deps: &DepsMut,
+    factory: &Addr,
+    cursor: &mut FeeCollectionCursor,
+    limit: u32,
+) -> StdResult<FactoryType> {
+    let (page, contracts, next_page) = match cursor.next_page.clone() {
+        FactoryType::Vault { start_after, .. } => {
+            let response: VaultsResponse =
+                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                    contract_addr: factory.to_string(),
+                    msg: to_json_binary(
+                        &white_whale_std::vault_network::vault_factory::QueryMsg::Vaults {
+                            start_after: start_after.clone(),
+                            limit: Some(limit),
+                        },
+                    )?,
+                }))?;
+
+            let next_start_after = if response.vaults.len() < limit as usize {
+                None
+            } else {
+                response
+                    .vaults
+                    .last()
+                    .map(|vault_info| vault_info.asset_info_reference.clone())
+            };
+
+            (
+                FactoryType::Vault {
+                    start_after,
+                    limit: Some(limit),
+                },
+                response.vaults.len(),
+                FactoryType::Vault {
+                    start_after: next_start_after,
+                    limit: None,
+                },
+            )
+        }
+        FactoryType::Pool {
+            start_after,
+            start_after_variant,
+            ..
+        } => {
+            let response: PairsResponse =
+                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                    contract_addr: factory.to_string(),
+                    msg: to_json_binary(&QueryMsg::Pairs {
+                        start_after: start_after.clone(),
+                        start_after_variant: start_after_variant.clone(),
+                        limit: Some(limit),
+                    })?,
+                }))?;
+
+            // the pairs are paginated by pair, so the next page starts after the variant of the
+            // last pair
+            let (next_start_after, next_start_after_variant) =
+                if response.pairs.len() < limit as usize {
+                    (None, None)
+                } else {
+                    (
+                        response.pairs.last().map(|pair| pair.asset_infos.clone()),
+                        response.last_pair_variant,
+                    )
+                };
+
+            (
+                FactoryType::Pool {
+                    start_after,
+                    start_after_variant,
+                    limit: Some(limit),
+                },
+                response.pairs.len(),
+                FactoryType::Pool {
+                    start_after: next_start_after,
+                    start_after_variant: next_start_after_variant,
+                    limit: None,
+                },
+            )
+        }
+        FactoryType::Trio { start_after, .. } => {
+            let response: TriosResponse =
+                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                    contract_addr: factory.to_string(),
+                    msg: to_json_binary(&QueryMsg::Trios {
+                        start_after: start_after.clone(),
+                        limit: Some(limit),
+                    })?,
+                }))?;
+
+            let next_start_after = if response.trios.len() < limit as usize {
+                None
+            } else {
+                response.trios.last().map(|trio| trio.asset_infos.clone())
+            };
+
+            (
+                FactoryType::Trio {
+                    start_after,
+                    limit: Some(limit),
+                },
+                response.trios.len(),
+                FactoryType::Trio {
+                    start_after: next_start_after,
+                    limit: None,
+                },
+            )
+        }
+    };
+
+    if contracts < limit as usize {
+        // the last page was reached, start over on the next sweep
+        cursor.swept_contracts = 0u64;
+        cursor.completed_sweeps = cursor.completed_sweeps.saturating_add(1u64);
+    } else {
+        cursor.swept_contracts = cursor.swept_contracts.saturating_add(contracts as u64);
+    }
+    cursor.next_page = next_page;
+
+    Ok(page)
+}
+
+/// Builds the messages for the fee collector to collect and aggregate the fees of the given page
+/// of the factory's children.
+fn build_fee_collection_msgs(
+    env: &Env,
+    factory: &Addr,
+    page: FactoryType,
+) -> StdResult<(CosmosMsg, CosmosMsg)> {
+    let collect_fees_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        funds: vec![],
+        msg: to_json_binary(&ExecuteMsg::CollectFees {
+            collect_fees_for: FeesFor::Factory {
+                factory_addr: factory.to_string(),
+                factory_type: page.clone(),
+            },
+        })?,
+    });
+
+    let aggregate_fees_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        funds: vec![],
+        msg: to_json_binary(&ExecuteMsg::AggregateFees {
+            aggregate_fees_for: FeesFor::Factory {
+                factory_addr: factory.to_string(),
+                factory_type: page,
+            },
+        })?,
+    });
+
+    Ok((collect_fees_msg, aggregate_fees_msg))
+}
+
+/// Gets the epoch the fees collected now will be forwarded on, i.e. the one after the current epoch
+/// of the fee distributor. Returns [None] if the fee distributor is not set yet.
+fn get_pending_epoch_id(deps: &DepsMut, config: &Config) -> StdResult<Option<u64>> {
+    if config.fee_distributor.as_str().is_empty() {
+        return Ok(None);
+    }
+
+    let epoch_response: white_whale_std::fee_distributor::EpochResponse =
+        deps.querier.query_wasm_smart(
+            config.fee_distributor.to_string(),
+            &white_whale_std::fee_distributor::QueryMsg::CurrentEpoch {},
+        )?;
+
+    Ok(Some(epoch_response.epoch.id.u64().saturating_add(1u64)))
+}
+
+/// Stores the fees the contracts in the given page of the factory's children have accrued, which are
+/// the fees about to be collected for the given epoch. The fees are added up with the ones already
+/// collected from the same contract for the epoch, i.e. by the sweeps in between epochs. Contracts
+/// without fees are skipped.
+fn snapshot_contract_fees(
+    deps: &mut DepsMut,
+    factory: &Addr,
+    page: &FactoryType,
+    epoch_id: u64,
+) -> StdResult<()> {
+    for contract in query_factory_contracts(&deps.as_ref(), factory, page.clone())? {
+        let fees: Vec<Asset> = query_contract_fees(&deps.as_ref(), &contract, false)?
+            .into_iter()
+            .filter(|fee| !fee.amount.is_zero())
+            .collect();
+
+        if fees.is_empty() {
+            continue;
+        }
+
+        let fees = match CONTRACT_FEES_HISTORY
+            .may_load(deps.storage, (contract.address.clone(), epoch_id))?
+        {
+            Some(collected_fees) => aggregate_assets(collected_fees, fees)?,
+            None => fees,
+        };
+
+        CONTRACT_FEES_HISTORY.save(deps.storage, (contract.address.clone(), epoch_id), &fees)?;
+        EPOCH_CONTRACT_FEES.save(
+            deps.storage,
+            (epoch_id, contract.address.clone()),
+            &ContractFees { contract, fees },
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Forwards the fees to the fee distributor.
 pub fn forward_fees(
-    deps: DepsMut,
+    mut deps: DepsMut,
     info: MessageInfo,
     env: Env,
     epoch: Epoch,
@@ -373,90 +1249,92 @@ pub fn forward_fees(
         return Err(ContractError::Unauthorized {});
     }
 
-    let mut messages = vec![];
+    // collect and aggregate the fees for the next batch of vaults, pools and trios, continuing from where
+    // the fee collection left off. Keepers can sweep the rest of them in between epochs.
+    let mut vaults_cursor = read_vaults_fee_collection_cursor(deps.storage)?;
+    let vaults_page = advance_fee_collection_cursor(
+        &deps,
+        &config.vault_factory,
+        &mut vaults_cursor,
+        MAX_FEE_COLLECTION_LIMIT,
+    )?;
+    VAULTS_FEE_COLLECTION_CURSOR.save(deps.storage, &vaults_cursor)?;
 
-    // trigger fee collection
-    let vaults_fee_collection_msg = SubMsg {
-        id: FEES_COLLECTION_REPLY_ID,
-        msg: CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            funds: vec![],
-            msg: to_json_binary(&ExecuteMsg::CollectFees {
-                collect_fees_for: FeesFor::Factory {
-                    factory_addr: config.vault_factory.to_string(),
-                    factory_type: FactoryType::Vault {
-                        start_after: None,
-                        limit: Some(30u32),
-                    },
-                },
-            })?,
-        }),
-        gas_limit: None,
-        reply_on: ReplyOn::Never,
-    };
+    let mut pools_cursor = read_pools_fee_collection_cursor(deps.storage)?;
+    let pools_page = advance_fee_collection_cursor(
+        &deps,
+        &config.pool_factory,
+        &mut pools_cursor,
+        MAX_FEE_COLLECTION_LIMIT,
+    )?;
+    POOLS_FEE_COLLECTION_CURSOR.save(deps.storage, &pools_cursor)?;
 
-    let pools_fee_collection_msg = SubMsg {
-        id: FEES_COLLECTION_REPLY_ID,
-        msg: CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            funds: vec![],
-            msg: to_json_binary(&ExecuteMsg::CollectFees {
-                collect_fees_for: FeesFor::Factory {
-                    factory_addr: config.pool_factory.to_string(),
-                    factory_type: FactoryType::Pool {
-                        start_after: None,
-                        limit: Some(30u32),
-                    },
-                },
-            })?,
-        }),
-        gas_limit: None,
-        reply_on: ReplyOn::Never,
-    };
+    let mut trios_cursor = read_trios_fee_collection_cursor(deps.storage)?;
+    let trios_page = advance_fee_collection_cursor(
+        &deps,
+        &config.pool_factory,
+        &mut trios_cursor,
+        MAX_FEE_COLLECTION_LIMIT,
+    )?;
+    TRIOS_FEE_COLLECTION_CURSOR.save(deps.storage, &trios_cursor)?;
 
-    // trigger fee aggregation
-    let vaults_fee_aggregation_msg = SubMsg {
-        id: FEES_AGGREGATION_REPLY_ID,
-        msg: CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            funds: vec![],
-            msg: to_json_binary(&ExecuteMsg::AggregateFees {
-                aggregate_fees_for: FeesFor::Factory {
-                    factory_addr: config.vault_factory.to_string(),
-                    factory_type: FactoryType::Vault {
-                        start_after: None,
-                        limit: Some(30u32),
-                    },
-                },
-            })?,
-        }),
-        gas_limit: None,
-        reply_on: ReplyOn::Never,
-    };
+    // snapshot the fees about to be collected from each contract, for reporting
+    snapshot_contract_fees(
+        &mut deps,
+        &config.vault_factory,
+        &vaults_page,
+        epoch.id.u64(),
+    )?;
+    snapshot_contract_fees(&mut deps, &config.pool_factory, &pools_page, epoch.id.u64())?;
+    snapshot_contract_fees(&mut deps, &config.pool_factory, &trios_page, epoch.id.u64())?;
 
-    let pools_fee_aggregation_msg = SubMsg {
-        id: FEES_AGGREGATION_REPLY_ID,
-        msg: CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            funds: vec![],
-            msg: to_json_binary(&ExecuteMsg::AggregateFees {
-                aggregate_fees_for: FeesFor::Factory {
-                    factory_addr: config.pool_factory.to_string(),
-                    factory_type: FactoryType::Pool {
-                        start_after: None,
-                        limit: Some(30u32),
-                    },
-                },
-            })?,
-        }),
-        gas_limit: None,
-        reply_on: ReplyOn::Success,
-    };
+    let (vaults_fee_collection_msg, vaults_fee_aggregation_msg) =
+        build_fee_collection_msgs(&env, &config.vault_factory, vaults_page)?;
+    let (pools_fee_collection_msg, pools_fee_aggregation_msg) =
+        build_fee_collection_msgs(&env, &config.pool_factory, pools_page)?;
+    let (trios_fee_collection_msg, trios_fee_aggregation_msg) =
+        build_fee_collection_msgs(&env, &config.pool_factory, trios_page)?;
 
-    messages.push(vaults_fee_collection_msg);
-    messages.push(pools_fee_collection_msg);
-    messages.push(vaults_fee_aggregation_msg);
-    messages.push(pools_fee_aggregation_msg);
+    let messages = vec![
+        // trigger fee collection
+        SubMsg {
+            id: FEES_COLLECTION_REPLY_ID,
+            msg: vaults_fee_collection_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never,
+        },
+        SubMsg {
+            id: FEES_COLLECTION_REPLY_ID,
+            msg: pools_fee_collection_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never,
+        },
+        SubMsg {
+            id: FEES_COLLECTION_REPLY_ID,
+            msg: trios_fee_collection_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never,
+        },
+        // trigger fee aggregation
+        SubMsg {
+            id: FEES_AGGREGATION_REPLY_ID,
+            msg: vaults_fee_aggregation_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never,
+        },
+        SubMsg {
+            id: FEES_AGGREGATION_REPLY_ID,
+            msg: pools_fee_aggregation_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Never,
+        },
+        SubMsg {
+            id: FEES_AGGREGATION_REPLY_ID,
+            msg: trios_fee_aggregation_msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Success,
+        },
+    ];
 
     // saving the epoch and the asset info to forward the fees as in temp storage
     TMP_EPOCH.save(deps.storage, &epoch)?;
@@ -9,12 +9,17 @@ use semver::Version;
 
 use white_whale_std::fee_collector::{
     Config, ExecuteMsg, ForwardFeesResponse, InstantiateMsg, MigrateMsg, QueryMsg,
+    TakeRateRecipient, TakeRateRecipientHistory,
 };
+use white_whale_std::ownership::query_ownership_proposal;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, ToCoins};
 
 use crate::error::ContractError;
-use crate::queries::query_distribution_asset;
-use crate::state::{CONFIG, TAKE_RATE_HISTORY, TMP_EPOCH};
+use crate::queries::query_distribution_assets;
+use crate::state::{
+    read_take_rate_splits, EpochFeeSummary, CONFIG, EPOCH_FEE_SUMMARY, TAKE_RATE_HISTORY,
+    TAKE_RATE_RECIPIENTS_HISTORY, TMP_CARRIED_OVER_ASSETS, TMP_EPOCH, TMP_SKIPPED_ASSETS,
+};
 use crate::ContractError::MigrateInvalidVersion;
 use crate::{commands, migrations, queries};
 
@@ -22,6 +27,7 @@ const CONTRACT_NAME: &str = "white_whale-fee_collector";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub(crate) const FEES_COLLECTION_REPLY_ID: u64 = 1u64;
 pub(crate) const FEES_AGGREGATION_REPLY_ID: u64 = 2u64;
+pub(crate) const BUYBACK_REPLY_ID: u64 = 3u64;
 
 #[entry_point]
 pub fn instantiate(
@@ -51,86 +57,157 @@ pub fn instantiate(
 }
 
 #[entry_point]
-pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+pub fn reply(mut deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.id == FEES_AGGREGATION_REPLY_ID {
         let mut epoch = TMP_EPOCH
             .may_load(deps.storage)?
             .ok_or(ContractError::CannotReadEpoch {})?;
 
-        let asset_info = query_distribution_asset(deps.as_ref())?;
+        let distribution_assets = query_distribution_assets(deps.as_ref())?;
+        let config: Config = CONFIG.load(deps.storage)?;
+
+        // check if the take rate is active
+        let take_rate_splits = read_take_rate_splits(deps.storage, &config)?;
+        let is_take_rate_active = config.is_take_rate_active
+            && config.take_rate != Decimal::zero()
+            && !take_rate_splits.is_empty();
+
+        let mut fees = vec![];
+        let mut aggregated_fees = vec![];
+        let mut take_rate_fees = vec![];
 
-        let mut token_balance: Uint128 = match asset_info.clone() {
-            AssetInfo::Token { .. } => {
-                return Err(ContractError::InvalidContractsFeeAggregation {})
+        for distribution_asset in distribution_assets {
+            let asset_info = distribution_asset.asset_info;
+
+            let mut token_balance: Uint128 = match asset_info.clone() {
+                AssetInfo::Token { .. } => {
+                    return Err(ContractError::InvalidContractsFeeAggregation {})
+                }
+                AssetInfo::NativeToken { denom } => {
+                    let balance_response: BalanceResponse =
+                        deps.querier.query(&QueryRequest::Bank(BankQuery::Balance {
+                            address: env.contract.address.to_string(),
+                            denom,
+                        }))?;
+                    balance_response.amount.amount
+                }
+            };
+
+            if !token_balance.is_zero() {
+                aggregated_fees.push(Asset {
+                    info: asset_info.clone(),
+                    amount: token_balance,
+                });
             }
-            AssetInfo::NativeToken { denom } => {
-                let balance_response: BalanceResponse =
-                    deps.querier.query(&QueryRequest::Bank(BankQuery::Balance {
-                        address: env.contract.address.to_string(),
-                        denom,
-                    }))?;
-                balance_response.amount.amount
+
+            if is_take_rate_active {
+                // take rate fee from token_balance and send it to dao
+                // in case there's an error in the calculation, default to zero so the operation doesn't fail
+                let take_rate_fee = token_balance
+                    .checked_mul_floor(config.take_rate)
+                    .unwrap_or(Uint128::zero());
+                token_balance = token_balance.saturating_sub(take_rate_fee);
+
+                if !take_rate_fee.is_zero() {
+                    take_rate_fees.push(coin(take_rate_fee.u128(), asset_info.to_string()));
+                }
             }
-        };
 
-        let mut messages = vec![];
+            // if not zero, it means there were fees aggregated
+            if !token_balance.is_zero() {
+                fees.push(Asset {
+                    info: asset_info,
+                    amount: token_balance,
+                });
+            }
+        }
 
-        let config: Config = CONFIG.load(deps.storage)?;
-        // check if the take rate is active
-        if config.is_take_rate_active
-            && config.take_rate != Decimal::zero()
-            && !config.take_rate_dao_address.to_string().is_empty()
-        {
-            // take rate fee from token_balance and send it to dao
-            // in case there's an error in the calculation, default to zero so the operation doesn't fail
-            let take_rate_fee = token_balance
-                .checked_mul_floor(config.take_rate)
-                .unwrap_or(Uint128::zero());
-            token_balance = token_balance.saturating_sub(take_rate_fee);
-
-            if !take_rate_fee.is_zero() {
-                messages.push(CosmosMsg::Bank(BankMsg::Send {
-                    to_address: config.take_rate_dao_address.to_string(),
-                    amount: vec![Asset {
-                        info: asset_info.clone(),
-                        amount: take_rate_fee,
-                    }]
-                    .to_coins()?,
-                }));
-
-                TAKE_RATE_HISTORY.save(
+        // take the buyback share out of the fees, and burn it
+        let (buyback_submsgs, mut messages) =
+            commands::buyback_fees(&mut deps, &env, &config, epoch.id.u64(), &mut fees)?;
+
+        if !take_rate_fees.is_empty() {
+            // coins need to be sorted by denom to be sent
+            take_rate_fees.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+            for (index, (recipient, take_rate)) in
+                commands::split_take_rate(&take_rate_fees, &take_rate_splits)?
+                    .into_iter()
+                    .enumerate()
+            {
+                if take_rate.is_empty() {
+                    continue;
+                }
+
+                messages.push(match recipient.clone() {
+                    TakeRateRecipient::Address { address } => CosmosMsg::Bank(BankMsg::Send {
+                        to_address: address,
+                        amount: take_rate.clone(),
+                    }),
+                    TakeRateRecipient::Burn {} => CosmosMsg::Bank(BankMsg::Burn {
+                        amount: take_rate.clone(),
+                    }),
+                });
+
+                TAKE_RATE_RECIPIENTS_HISTORY.save(
                     deps.storage,
-                    epoch.id.u64(),
-                    &coin(take_rate_fee.u128(), asset_info.to_string()),
+                    (epoch.id.u64(), index as u32),
+                    &TakeRateRecipientHistory {
+                        recipient,
+                        take_rate,
+                    },
                 )?;
             }
-        }
 
-        // if not zero, it means there were fees aggregated
-        if !token_balance.is_zero() {
-            let fees = vec![Asset {
-                info: asset_info,
-                amount: token_balance,
-            }];
+            TAKE_RATE_HISTORY.save(deps.storage, epoch.id.u64(), &take_rate_fees)?;
+        }
 
+        if !fees.is_empty() {
             epoch.total = fees.clone();
             epoch.available = fees.clone();
 
+            let mut coins = fees.to_coins()?;
+            coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
             // send tokens to fee distributor
-            let config = CONFIG.load(deps.storage)?;
             messages.push(CosmosMsg::Bank(BankMsg::Send {
                 to_address: config.fee_distributor.to_string(),
-                amount: fees.to_coins()?,
+                amount: coins,
             }));
         }
 
+        EPOCH_FEE_SUMMARY.save(
+            deps.storage,
+            epoch.id.u64(),
+            &EpochFeeSummary {
+                aggregated_fees,
+                forwarded_fees: fees,
+            },
+        )?;
+
         TMP_EPOCH.remove(deps.storage);
 
+        let skipped_assets = TMP_SKIPPED_ASSETS
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        TMP_SKIPPED_ASSETS.remove(deps.storage);
+        let carried_over_assets = TMP_CARRIED_OVER_ASSETS
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+        TMP_CARRIED_OVER_ASSETS.remove(deps.storage);
+
         Ok(Response::default()
             .add_attribute("action", "reply")
             .add_attribute("new_epoch", epoch.to_string())
+            .add_submessages(buyback_submsgs)
             .add_messages(messages)
-            .set_data(to_json_binary(&ForwardFeesResponse { epoch })?))
+            .set_data(to_json_binary(&ForwardFeesResponse {
+                epoch,
+                skipped_assets,
+                carried_over_assets,
+            })?))
+    } else if msg.id == BUYBACK_REPLY_ID {
+        commands::burn_bought_back_fees(deps, env, msg.result)
     } else {
         Err(ContractError::UnknownReplyId(msg.id))
     }
@@ -148,7 +225,6 @@ pub fn execute(
             commands::collect_fees(deps, collect_fees_for)
         }
         ExecuteMsg::UpdateConfig {
-            owner,
             pool_router,
             fee_distributor,
             pool_factory,
@@ -159,7 +235,6 @@ pub fn execute(
         } => commands::update_config(
             deps,
             info,
-            owner,
             pool_router,
             fee_distributor,
             pool_factory,
@@ -172,6 +247,31 @@ pub fn execute(
             commands::aggregate_fees(deps, env, aggregate_fees_for)
         }
         ExecuteMsg::ForwardFees { epoch, .. } => commands::forward_fees(deps, info, env, epoch),
+        ExecuteMsg::SweepFees { limit } => commands::sweep_fees(deps, env, limit),
+        ExecuteMsg::UpdateTakeRateSplits { splits } => {
+            commands::update_take_rate_splits(deps, info, splits)
+        }
+        ExecuteMsg::UpdateBuybackConfig { buyback_config } => {
+            commands::update_buyback_config(deps, info, buyback_config)
+        }
+        ExecuteMsg::UpdateAggregationConfig {
+            max_spread,
+            max_price_deviation,
+            reference_price_ttl,
+            reference_prices,
+        } => commands::update_aggregation_config(
+            deps,
+            env,
+            info,
+            max_spread,
+            max_price_deviation,
+            reference_price_ttl,
+            reference_prices,
+        ),
+        ExecuteMsg::UpdateAggregationLimits { limits } => {
+            commands::update_aggregation_limits(deps, info, limits)
+        }
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
     }
 }
 
@@ -179,6 +279,7 @@ pub fn execute(
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&queries::query_config(deps)?),
+        QueryMsg::OwnershipProposal {} => to_json_binary(&query_ownership_proposal(deps.storage)?),
         QueryMsg::Fees {
             query_fees_for,
             all_time,
@@ -190,6 +291,41 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::TakeRateHistory { epoch_id } => {
             to_json_binary(&queries::query_take_rate_history(deps, epoch_id)?)
         }
+        QueryMsg::AggregationConfig {} => to_json_binary(&queries::query_aggregation_config(deps)?),
+        QueryMsg::ReferencePrices {} => to_json_binary(&queries::query_reference_prices(deps)?),
+        QueryMsg::AggregationLimits {} => to_json_binary(&queries::query_aggregation_limits(deps)?),
+        QueryMsg::FeeCollectionProgress {} => {
+            to_json_binary(&queries::query_fee_collection_progress(deps)?)
+        }
+        QueryMsg::TakeRateSplits {} => to_json_binary(&queries::query_take_rate_splits(deps)?),
+        QueryMsg::TakeRateRecipientsHistory { epoch_id } => to_json_binary(
+            &queries::query_take_rate_recipients_history(deps, epoch_id)?,
+        ),
+        QueryMsg::BuybackConfig {} => to_json_binary(&queries::query_buyback_config(deps)?),
+        QueryMsg::BurnHistory { epoch_id } => {
+            to_json_binary(&queries::query_burn_history(deps, epoch_id)?)
+        }
+        QueryMsg::TotalBurned {} => to_json_binary(&queries::query_total_burned(deps)?),
+        QueryMsg::EpochFeeReport {
+            epoch_id,
+            start_after,
+            limit,
+        } => to_json_binary(&queries::query_epoch_fee_report(
+            deps,
+            epoch_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PoolFeeHistory {
+            pool,
+            start_after,
+            limit,
+        } => to_json_binary(&queries::query_pool_fee_history(
+            deps,
+            pool,
+            start_after,
+            limit,
+        )?),
     }
 }
 
@@ -214,6 +350,10 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
         migrations::migrate_to_v120(deps.branch())?;
     }
 
+    if storage_version < Version::parse("1.3.0")? {
+        migrations::migrate_to_v130(deps.branch())?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError};
 use cw_utils::ParseReplyError;
 use semver::Version;
 use thiserror::Error;
@@ -8,6 +8,9 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
     #[error("Semver parsing error: {0}")]
     SemVer(String),
 
@@ -34,6 +37,21 @@ pub enum ContractError {
 
     #[error("The provided take rate is invalid, try a lower number.")]
     InvalidTakeRate {},
+
+    #[error("The provided max spread is invalid, try a lower number.")]
+    InvalidMaxSpread {},
+
+    #[error("The provided max price deviation is invalid, try a lower number.")]
+    InvalidMaxPriceDeviation {},
+
+    #[error("The provided reference price is invalid, it must be positive and for two different assets.")]
+    InvalidReferencePrice {},
+
+    #[error("The provided take rate splits are invalid, the shares must be positive, add up to 1 and have no duplicated recipients.")]
+    InvalidTakeRateSplits {},
+
+    #[error("The provided buyback share is invalid, it must be greater than 0 and at most 1.")]
+    InvalidBuybackShare {},
 }
 
 impl From<semver::Error> for ContractError {
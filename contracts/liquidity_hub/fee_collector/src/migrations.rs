@@ -1,10 +1,14 @@
 #![cfg(not(tarpaulin_include))]
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, DepsMut, StdError};
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Coin, Decimal, DepsMut, Order, StdError, StdResult};
+use cw_storage_plus::{Item, Map};
 use white_whale_std::fee_collector::Config;
 
-use crate::state::CONFIG;
+use crate::state::{
+    read_aggregation_config, read_pools_fee_collection_cursor, read_trios_fee_collection_cursor,
+    read_vaults_fee_collection_cursor, AGGREGATION_CONFIG, CONFIG, POOLS_FEE_COLLECTION_CURSOR,
+    TAKE_RATE_HISTORY, TRIOS_FEE_COLLECTION_CURSOR, VAULTS_FEE_COLLECTION_CURSOR,
+};
 
 /// Migrates state from pre v1.2.0, which includes the take rate, the take rate dao address and the
 /// feature flag for the take rate
@@ -36,3 +40,30 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// Migrates state to v1.3.0, which stores the take rate history as a list of coins, as fees can be
+/// distributed in multiple assets, and initializes the fee collection cursors and the aggregation
+/// config with their defaults
+pub fn migrate_to_v130(deps: DepsMut) -> Result<(), StdError> {
+    const TAKE_RATE_HISTORY_PRE_V130: Map<u64, Coin> = Map::new("take_rate_history");
+
+    let take_rate_history = TAKE_RATE_HISTORY_PRE_V130
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, Coin)>>>()?;
+
+    for (epoch_id, take_rate) in take_rate_history {
+        TAKE_RATE_HISTORY.save(deps.storage, epoch_id, &vec![take_rate])?;
+    }
+
+    let vaults_cursor = read_vaults_fee_collection_cursor(deps.storage)?;
+    VAULTS_FEE_COLLECTION_CURSOR.save(deps.storage, &vaults_cursor)?;
+    let pools_cursor = read_pools_fee_collection_cursor(deps.storage)?;
+    POOLS_FEE_COLLECTION_CURSOR.save(deps.storage, &pools_cursor)?;
+    let trios_cursor = read_trios_fee_collection_cursor(deps.storage)?;
+    TRIOS_FEE_COLLECTION_CURSOR.save(deps.storage, &trios_cursor)?;
+
+    let aggregation_config = read_aggregation_config(deps.storage)?;
+    AGGREGATION_CONFIG.save(deps.storage, &aggregation_config)?;
+
+    Ok(())
+}
@@ -1,14 +1,32 @@
-use cosmwasm_std::{to_json_binary, Addr, Coin, Deps, QueryRequest, StdResult, Uint64, WasmQuery};
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, Deps, Order, QueryRequest, StdResult, Uint64, WasmQuery,
+};
+use cw_storage_plus::Bound;
 
-use white_whale_std::fee_collector::{Config, ContractType, FactoryType, FeesFor};
+use white_whale_std::fee_collector::{
+    AggregationConfig, AggregationLimit, BurnRecord, BuybackConfig, Config, Contract, ContractFees,
+    ContractType, EpochFeeReportResponse, EpochFees, FactoryType, FeeCollectionProgressResponse,
+    FeesFor, PoolFeeHistoryResponse, ReferencePriceRecord, TakeRateRecipientHistory, TakeRateSplit,
+};
+use white_whale_std::fee_distributor::DistributionAsset;
 use white_whale_std::pool_network;
-use white_whale_std::pool_network::asset::{Asset, AssetInfo};
-use white_whale_std::pool_network::factory::PairsResponse;
+use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::pool_network::factory::{PairsResponse, TriosResponse};
 use white_whale_std::pool_network::pair::ProtocolFeesResponse as ProtocolPairFeesResponse;
+use white_whale_std::pool_network::trio::ProtocolFeesResponse as ProtocolTrioFeesResponse;
 use white_whale_std::vault_network::vault::ProtocolFeesResponse as ProtocolVaultFeesResponse;
 use white_whale_std::vault_network::vault_factory::VaultsResponse;
 
-use crate::state::{CONFIG, TAKE_RATE_HISTORY};
+use crate::state::{
+    read_aggregation_config, read_pools_fee_collection_cursor, read_take_rate_splits,
+    read_trios_fee_collection_cursor, read_vaults_fee_collection_cursor, AGGREGATION_LIMITS,
+    BURN_HISTORY, BUYBACK_CONFIG, CONFIG, CONTRACT_FEES_HISTORY, EPOCH_CONTRACT_FEES,
+    EPOCH_FEE_SUMMARY, REFERENCE_PRICES, TAKE_RATE_HISTORY, TAKE_RATE_RECIPIENTS_HISTORY,
+    TOTAL_BURNED,
+};
+
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
 
 /// Queries the [Config], which contains the owner address
 pub fn query_config(deps: Deps) -> StdResult<Config> {
@@ -17,11 +35,74 @@ pub fn query_config(deps: Deps) -> StdResult<Config> {
 }
 
 /// Queries the take rate for the given epoch id
-pub fn query_take_rate_history(deps: Deps, epoch_id: Uint64) -> StdResult<Coin> {
+pub fn query_take_rate_history(deps: Deps, epoch_id: Uint64) -> StdResult<Vec<Coin>> {
     let take_rate = TAKE_RATE_HISTORY.load(deps.storage, epoch_id.u64())?;
     Ok(take_rate)
 }
 
+/// Queries the recipients the take rate is split among
+pub fn query_take_rate_splits(deps: Deps) -> StdResult<Vec<TakeRateSplit>> {
+    let config = CONFIG.load(deps.storage)?;
+    read_take_rate_splits(deps.storage, &config)
+}
+
+/// Queries how much of the take rate each recipient got for the given epoch id
+pub fn query_take_rate_recipients_history(
+    deps: Deps,
+    epoch_id: Uint64,
+) -> StdResult<Vec<TakeRateRecipientHistory>> {
+    TAKE_RATE_RECIPIENTS_HISTORY
+        .prefix(epoch_id.u64())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, history)| history))
+        .collect()
+}
+
+/// Queries the buyback settings, if the buyback is on
+pub fn query_buyback_config(deps: Deps) -> StdResult<Option<BuybackConfig>> {
+    BUYBACK_CONFIG.may_load(deps.storage)
+}
+
+/// Queries the assets burned by the buyback at the given epoch id
+pub fn query_burn_history(deps: Deps, epoch_id: Uint64) -> StdResult<BurnRecord> {
+    BURN_HISTORY.load(deps.storage, epoch_id.u64())
+}
+
+/// Queries the assets burned by the buyback since it was first turned on
+pub fn query_total_burned(deps: Deps) -> StdResult<Vec<Asset>> {
+    Ok(TOTAL_BURNED.may_load(deps.storage)?.unwrap_or_default())
+}
+
+/// Queries the settings used to protect the fee aggregation swaps
+pub fn query_aggregation_config(deps: Deps) -> StdResult<AggregationConfig> {
+    read_aggregation_config(deps.storage)
+}
+
+/// Queries the reference prices the aggregation swaps are checked against
+pub fn query_reference_prices(deps: Deps) -> StdResult<Vec<ReferencePriceRecord>> {
+    REFERENCE_PRICES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| Ok(item?.1))
+        .collect()
+}
+
+/// Queries the maximum amounts of the assets that can be aggregated at once
+pub fn query_aggregation_limits(deps: Deps) -> StdResult<Vec<AggregationLimit>> {
+    AGGREGATION_LIMITS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, limit)| limit))
+        .collect()
+}
+
+/// Queries the progress of the fee collection over the vaults and pools
+pub fn query_fee_collection_progress(deps: Deps) -> StdResult<FeeCollectionProgressResponse> {
+    Ok(FeeCollectionProgressResponse {
+        vaults: read_vaults_fee_collection_cursor(deps.storage)?,
+        pools: read_pools_fee_collection_cursor(deps.storage)?,
+        trios: read_trios_fee_collection_cursor(deps.storage)?,
+    })
+}
+
 /// Queries the fees in [Asset] for contracts or Factories defined by [FeesFor]
 pub fn query_fees(deps: Deps, query_fees_for: FeesFor, all_time: bool) -> StdResult<Vec<Asset>> {
     let mut fees: Vec<Asset> = Vec::new();
@@ -31,20 +112,8 @@ pub fn query_fees(deps: Deps, query_fees_for: FeesFor, all_time: bool) -> StdRes
             contracts.dedup_by(|a, b| a.address == b.address);
 
             for contract in contracts {
-                match contract.contract_type {
-                    ContractType::Pool {} => {
-                        let mut pair_fee =
-                            query_fees_for_pair(&deps, contract.address.clone(), all_time)?;
-
-                        fees.append(&mut pair_fee);
-                    }
-                    ContractType::Vault {} => {
-                        let vault_fee =
-                            query_fees_for_vault(&deps, contract.address.clone(), all_time)?;
-
-                        fees.push(vault_fee);
-                    }
-                }
+                let mut contract_fees = query_contract_fees(&deps, &contract, all_time)?;
+                fees.append(&mut contract_fees);
             }
         }
         FeesFor::Factory {
@@ -106,6 +175,39 @@ fn query_fees_for_pair(deps: &Deps, pair: String, all_time: bool) -> StdResult<V
     Ok(fees)
 }
 
+/// Queries the fees for a given trio
+fn query_fees_for_trio(deps: &Deps, trio: String, all_time: bool) -> StdResult<Vec<Asset>> {
+    let fees = deps
+        .querier
+        .query::<ProtocolTrioFeesResponse>(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: trio,
+            msg: to_json_binary(&pool_network::trio::QueryMsg::ProtocolFees {
+                all_time: Some(all_time),
+                asset_id: None,
+            })?,
+        }))?
+        .fees;
+
+    Ok(fees)
+}
+
+/// Queries the fees for a given pool, trio or vault
+pub(crate) fn query_contract_fees(
+    deps: &Deps,
+    contract: &Contract,
+    all_time: bool,
+) -> StdResult<Vec<Asset>> {
+    match contract.contract_type {
+        ContractType::Pool {} => query_fees_for_pair(deps, contract.address.clone(), all_time),
+        ContractType::Vault {} => Ok(vec![query_fees_for_vault(
+            deps,
+            contract.address.clone(),
+            all_time,
+        )?]),
+        ContractType::Trio {} => query_fees_for_trio(deps, contract.address.clone(), all_time),
+    }
+}
+
 /// Queries the fees collected by the children of the given factory
 fn query_fees_for_factory(
     deps: &Deps,
@@ -115,7 +217,21 @@ fn query_fees_for_factory(
 ) -> StdResult<Vec<Asset>> {
     let mut fees: Vec<Asset> = Vec::new();
 
-    match factory_type {
+    for contract in query_factory_contracts(deps, factory, factory_type)? {
+        let mut contract_fees = query_contract_fees(deps, &contract, all_time)?;
+        fees.append(&mut contract_fees);
+    }
+
+    Ok(fees)
+}
+
+/// Queries the children of the given factory, for the page defined by [FactoryType]
+pub(crate) fn query_factory_contracts(
+    deps: &Deps,
+    factory: &Addr,
+    factory_type: FactoryType,
+) -> StdResult<Vec<Contract>> {
+    let contracts = match factory_type {
         FactoryType::Vault { start_after, limit } => {
             let response: VaultsResponse =
                 deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
@@ -128,33 +244,124 @@ fn query_fees_for_factory(
                     )?,
                 }))?;
 
-            for vault_info in response.vaults {
-                let vault_fee = query_fees_for_vault(deps, vault_info.vault, all_time)?;
-                fees.push(vault_fee);
-            }
+            response
+                .vaults
+                .into_iter()
+                .map(|vault_info| Contract {
+                    address: vault_info.vault,
+                    contract_type: ContractType::Vault {},
+                })
+                .collect()
         }
-        FactoryType::Pool { start_after, limit } => {
+        FactoryType::Pool {
+            start_after,
+            start_after_variant,
+            limit,
+        } => {
             let response: PairsResponse =
                 deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
                     contract_addr: factory.to_string(),
                     msg: to_json_binary(&pool_network::factory::QueryMsg::Pairs {
                         start_after,
+                        start_after_variant,
                         limit,
                     })?,
                 }))?;
 
-            for pair in response.pairs {
-                let mut pair_fees = query_fees_for_pair(deps, pair.contract_addr, all_time)?;
-                fees.append(&mut pair_fees);
-            }
+            response
+                .pairs
+                .into_iter()
+                .map(|pair| Contract {
+                    address: pair.contract_addr,
+                    contract_type: ContractType::Pool {},
+                })
+                .collect()
         }
-    }
+        FactoryType::Trio { start_after, limit } => {
+            let response: TriosResponse =
+                deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+                    contract_addr: factory.to_string(),
+                    msg: to_json_binary(&pool_network::factory::QueryMsg::Trios {
+                        start_after,
+                        limit,
+                    })?,
+                }))?;
 
-    Ok(fees)
+            response
+                .trios
+                .into_iter()
+                .map(|trio| Contract {
+                    address: trio.contract_addr,
+                    contract_type: ContractType::Trio {},
+                })
+                .collect()
+        }
+    };
+
+    Ok(contracts)
+}
+
+/// Queries the fees collected from each contract when the fees were forwarded for the given epoch
+pub fn query_epoch_fee_report(
+    deps: Deps,
+    epoch_id: Uint64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<EpochFeeReportResponse> {
+    let summary = EPOCH_FEE_SUMMARY.load(deps.storage, epoch_id.u64())?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let contract_fees = EPOCH_CONTRACT_FEES
+        .prefix(epoch_id.u64())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, contract_fees)| contract_fees))
+        .collect::<StdResult<Vec<ContractFees>>>()?;
+
+    Ok(EpochFeeReportResponse {
+        epoch_id,
+        aggregated_fees: summary.aggregated_fees,
+        forwarded_fees: summary.forwarded_fees,
+        take_rate: TAKE_RATE_HISTORY
+            .may_load(deps.storage, epoch_id.u64())?
+            .unwrap_or_default(),
+        burned: BURN_HISTORY
+            .may_load(deps.storage, epoch_id.u64())?
+            .map(|record| record.burned)
+            .unwrap_or_default(),
+        contract_fees,
+    })
+}
+
+/// Queries the fees collected from the given pool, trio or vault on each epoch
+pub fn query_pool_fee_history(
+    deps: Deps,
+    pool: String,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> StdResult<PoolFeeHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|epoch_id| Bound::exclusive(epoch_id.u64()));
+
+    let history = CONTRACT_FEES_HISTORY
+        .prefix(pool.clone())
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            item.map(|(epoch_id, fees)| EpochFees {
+                epoch_id: Uint64::new(epoch_id),
+                fees,
+            })
+        })
+        .collect::<StdResult<Vec<EpochFees>>>()?;
+
+    Ok(PoolFeeHistoryResponse { pool, history })
 }
 
-/// Queries the fee collector to get the distribution asset
-pub(crate) fn query_distribution_asset(deps: Deps) -> StdResult<AssetInfo> {
+/// Queries the fee distributor to get the distribution assets
+pub(crate) fn query_distribution_assets(deps: Deps) -> StdResult<Vec<DistributionAsset>> {
     let config: Config = CONFIG.load(deps.storage)?;
 
     let fee_distributor_config: white_whale_std::fee_distributor::Config =
@@ -163,5 +370,5 @@ pub(crate) fn query_distribution_asset(deps: Deps) -> StdResult<AssetInfo> {
             msg: to_json_binary(&white_whale_std::fee_distributor::QueryMsg::Config {})?,
         }))?;
 
-    Ok(fee_distributor_config.distribution_asset)
+    Ok(fee_distributor_config.distribution_assets)
 }
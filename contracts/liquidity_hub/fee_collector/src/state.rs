@@ -1,15 +1,179 @@
-use cosmwasm_std::{Coin, DepsMut, Order, StdResult};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Decimal, DepsMut, Order, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
-use white_whale_std::fee_collector::Config;
+use white_whale_std::fee_collector::{
+    AggregationConfig, AggregationLimit, BurnRecord, BuybackConfig, Config, ContractFees,
+    FactoryType, FeeCollectionCursor, ReferencePriceRecord, SkippedAsset, TakeRateRecipient,
+    TakeRateRecipientHistory, TakeRateSplit,
+};
 use white_whale_std::fee_distributor::Epoch;
-use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::pool_network::asset::{aggregate_assets, Asset, AssetInfo};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const TMP_ASSET_INFOS: Map<String, AssetInfo> = Map::new("tmp_asset_infos");
 pub const TMP_EPOCH: Item<Epoch> = Item::new("tmp_epoch");
 
-// The take rate history is a map containing how much was the take rate at a given epoch
-pub const TAKE_RATE_HISTORY: Map<u64, Coin> = Map::new("take_rate_history");
+// The take rate history is a map containing how much was the take rate at a given epoch, for each
+// of the distribution assets
+pub const TAKE_RATE_HISTORY: Map<u64, Vec<Coin>> = Map::new("take_rate_history");
+// The recipients the take rate is split among
+pub const TAKE_RATE_SPLITS: Item<Vec<TakeRateSplit>> = Item::new("take_rate_splits");
+// How much of the take rate each recipient got at a given epoch, keyed by (epoch id, index of the
+// recipient's split), so the recipients can't collide with each other
+pub const TAKE_RATE_RECIPIENTS_HISTORY: Map<(u64, u32), TakeRateRecipientHistory> =
+    Map::new("take_rate_recipients_history");
+
+/// Reads the recipients the take rate is split among. If no splits were set, the whole take rate
+/// goes to the take rate dao address, if any.
+pub fn read_take_rate_splits(
+    storage: &dyn Storage,
+    config: &Config,
+) -> StdResult<Vec<TakeRateSplit>> {
+    let take_rate_splits = TAKE_RATE_SPLITS.may_load(storage)?.unwrap_or_default();
+
+    if !take_rate_splits.is_empty() || config.take_rate_dao_address.as_str().is_empty() {
+        return Ok(take_rate_splits);
+    }
+
+    Ok(vec![TakeRateSplit {
+        recipient: TakeRateRecipient::Address {
+            address: config.take_rate_dao_address.to_string(),
+        },
+        share: Decimal::one(),
+    }])
+}
+
+pub const AGGREGATION_CONFIG: Item<AggregationConfig> = Item::new("aggregation_config");
+// The aggregation swaps skipped, and the amounts carried over, while the fees are being forwarded
+pub const TMP_SKIPPED_ASSETS: Item<Vec<SkippedAsset>> = Item::new("tmp_skipped_assets");
+pub const TMP_CARRIED_OVER_ASSETS: Item<Vec<Asset>> = Item::new("tmp_carried_over_assets");
+// The maximum amount of an asset that can be aggregated at once, keyed by the asset
+pub const AGGREGATION_LIMITS: Map<String, AggregationLimit> = Map::new("aggregation_limits");
+// The reference prices the aggregation swaps are checked against, keyed by (offer asset, ask asset)
+pub const REFERENCE_PRICES: Map<(String, String), ReferencePriceRecord> =
+    Map::new("reference_prices");
+
+// The buyback settings. If not set, the buyback is off
+pub const BUYBACK_CONFIG: Item<BuybackConfig> = Item::new("buyback_config");
+// The buyback swaps that are in flight while the fees are being forwarded
+pub const TMP_BUYBACK: Item<PendingBuyback> = Item::new("tmp_buyback");
+// The assets burned by the buyback at a given epoch
+pub const BURN_HISTORY: Map<u64, BurnRecord> = Map::new("burn_history");
+// The assets burned by the buyback since it was first turned on
+pub const TOTAL_BURNED: Item<Vec<Asset>> = Item::new("total_burned");
+
+/// The buyback swaps that are being performed when forwarding the fees of an epoch.
+#[cw_serde]
+pub struct PendingBuyback {
+    /// The epoch the fees are being forwarded for.
+    pub epoch_id: u64,
+    /// The asset the fees are swapped to.
+    pub burn_asset: AssetInfo,
+    /// The balance of the burn asset before the swaps, so only the bought amount is burned.
+    pub balance_before: Uint128,
+    /// The number of swaps whose replies are still pending.
+    pub pending_swaps: u32,
+}
+
+/// Records the given asset as burned at the given epoch, updating the cumulative burns.
+pub fn record_burn(storage: &mut dyn Storage, epoch_id: u64, burned: Asset) -> StdResult<()> {
+    let total_burned = aggregate_assets(
+        TOTAL_BURNED.may_load(storage)?.unwrap_or_default(),
+        vec![burned.clone()],
+    )?;
+    TOTAL_BURNED.save(storage, &total_burned)?;
+
+    let burned_at_epoch = BURN_HISTORY
+        .may_load(storage, epoch_id)?
+        .map(|record| record.burned)
+        .unwrap_or_default();
+
+    BURN_HISTORY.save(
+        storage,
+        epoch_id,
+        &BurnRecord {
+            burned: aggregate_assets(burned_at_epoch, vec![burned])?,
+            cumulative_burned: total_burned,
+        },
+    )
+}
+
+// The fees collected from each contract when forwarding the fees, keyed by (epoch id, contract)
+pub const EPOCH_CONTRACT_FEES: Map<(u64, String), ContractFees> = Map::new("epoch_contract_fees");
+// The fees collected from each contract when forwarding the fees, keyed by (contract, epoch id)
+pub const CONTRACT_FEES_HISTORY: Map<(String, u64), Vec<Asset>> = Map::new("contract_fees_history");
+// The fees aggregated and forwarded at a given epoch
+pub const EPOCH_FEE_SUMMARY: Map<u64, EpochFeeSummary> = Map::new("epoch_fee_summary");
+
+/// The fees aggregated and forwarded to the fee distributor at a given epoch.
+#[cw_serde]
+pub struct EpochFeeSummary {
+    /// The fees aggregated into the distribution assets, before the take rate and the buyback.
+    pub aggregated_fees: Vec<Asset>,
+    /// The fees forwarded to the fee distributor.
+    pub forwarded_fees: Vec<Asset>,
+}
+
+/// Reads the aggregation config, using the default settings if it was never set.
+pub fn read_aggregation_config(storage: &dyn Storage) -> StdResult<AggregationConfig> {
+    Ok(AGGREGATION_CONFIG.may_load(storage)?.unwrap_or_default())
+}
+
+/// Reads the maximum amount of the given asset that can be aggregated at once, if any.
+pub fn read_aggregation_limit(
+    storage: &dyn Storage,
+    asset_key: String,
+) -> StdResult<Option<Uint128>> {
+    Ok(AGGREGATION_LIMITS
+        .may_load(storage, asset_key)?
+        .and_then(|limit| limit.max_amount))
+}
+
+// The cursors keep track of where the fee collection over the factories' children left off, so it
+// can be resumed in the next transaction
+pub const VAULTS_FEE_COLLECTION_CURSOR: Item<FeeCollectionCursor> =
+    Item::new("vaults_fee_collection_cursor");
+pub const POOLS_FEE_COLLECTION_CURSOR: Item<FeeCollectionCursor> =
+    Item::new("pools_fee_collection_cursor");
+pub const TRIOS_FEE_COLLECTION_CURSOR: Item<FeeCollectionCursor> =
+    Item::new("trios_fee_collection_cursor");
+
+/// Reads the fee collection cursor for the vaults, starting from the first vault if there's none.
+pub fn read_vaults_fee_collection_cursor(storage: &dyn Storage) -> StdResult<FeeCollectionCursor> {
+    Ok(VAULTS_FEE_COLLECTION_CURSOR
+        .may_load(storage)?
+        .unwrap_or_else(|| {
+            FeeCollectionCursor::new(FactoryType::Vault {
+                start_after: None,
+                limit: None,
+            })
+        }))
+}
+
+/// Reads the fee collection cursor for the pools, starting from the first pool if there's none.
+pub fn read_pools_fee_collection_cursor(storage: &dyn Storage) -> StdResult<FeeCollectionCursor> {
+    Ok(POOLS_FEE_COLLECTION_CURSOR
+        .may_load(storage)?
+        .unwrap_or_else(|| {
+            FeeCollectionCursor::new(FactoryType::Pool {
+                start_after: None,
+                start_after_variant: None,
+                limit: None,
+            })
+        }))
+}
+
+/// Reads the fee collection cursor for the trios, starting from the first trio if there's none.
+pub fn read_trios_fee_collection_cursor(storage: &dyn Storage) -> StdResult<FeeCollectionCursor> {
+    Ok(TRIOS_FEE_COLLECTION_CURSOR
+        .may_load(storage)?
+        .unwrap_or_else(|| {
+            FeeCollectionCursor::new(FactoryType::Trio {
+                start_after: None,
+                limit: None,
+            })
+        }))
+}
 
 pub fn store_temporal_asset_info(deps: DepsMut, asset_info: AssetInfo) -> StdResult<()> {
     let key = asset_info
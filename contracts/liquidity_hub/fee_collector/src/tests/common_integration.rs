@@ -1,10 +1,16 @@
 use cosmwasm_std::testing::mock_info;
-use cosmwasm_std::{Addr, Coin, MessageInfo, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, MessageInfo, Uint128};
 use cw_multi_test::{App, AppBuilder, BankKeeper, ContractWrapper, Executor};
+use white_whale_std::fee_collector::{ExecuteMsg, ReferencePrice};
+use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::pool_network::router;
+use white_whale_std::pool_network::router::SwapOperation;
 
 use crate::contract::{execute, instantiate, migrate, query, reply};
 
-use super::dummy_contract::create_dummy_flash_loan_contract;
+use super::dummy_contract::{
+    create_dummy_fee_distributor_contract, create_dummy_flash_loan_contract,
+};
 
 /// Mocks the App
 pub fn mock_app() -> App {
@@ -175,6 +181,13 @@ pub fn store_dummy_flash_loan_contract(app: &mut App) -> u64 {
     app.store_code(contract)
 }
 
+/// Stores a dummy fee distributor that only answers the `Config` query
+pub fn store_dummy_fee_distributor_code(app: &mut App) -> u64 {
+    let contract = Box::new(create_dummy_fee_distributor_contract());
+
+    app.store_code(contract)
+}
+
 pub fn increase_allowance(app: &mut App, sender: Addr, contract_addr: Addr, spender: Addr) {
     app.execute_contract(
         sender,
@@ -188,3 +201,84 @@ pub fn increase_allowance(app: &mut App, sender: Addr, contract_addr: Addr, spen
     )
     .unwrap();
 }
+
+/// Sets the aggregation config of the fee collector, along with the reference price of each given
+/// pair of assets, taken from the router simulation of the fee collector's balance of the offer
+/// asset. Pairs with no balance to aggregate are left out.
+pub fn set_reference_prices(
+    app: &mut App,
+    sender: Addr,
+    fee_collector: Addr,
+    pool_router: Addr,
+    asset_pairs: Vec<(AssetInfo, AssetInfo)>,
+) {
+    let mut reference_prices = vec![];
+
+    for (offer_asset_info, ask_asset_info) in asset_pairs {
+        let balance = match offer_asset_info.clone() {
+            AssetInfo::Token { contract_addr } => {
+                let balance_res: cw20::BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        contract_addr,
+                        &cw20::Cw20QueryMsg::Balance {
+                            address: fee_collector.to_string(),
+                        },
+                    )
+                    .unwrap();
+                balance_res.balance
+            }
+            AssetInfo::NativeToken { denom } => {
+                app.wrap()
+                    .query_balance(fee_collector.to_string(), denom)
+                    .unwrap()
+                    .amount
+            }
+        };
+
+        if balance.is_zero() {
+            continue;
+        }
+
+        let operations: Vec<SwapOperation> = app
+            .wrap()
+            .query_wasm_smart(
+                pool_router.clone(),
+                &router::QueryMsg::SwapRoute {
+                    offer_asset_info: offer_asset_info.clone(),
+                    ask_asset_info: ask_asset_info.clone(),
+                },
+            )
+            .unwrap();
+
+        let simulation: router::SimulateSwapOperationsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                pool_router.clone(),
+                &router::QueryMsg::SimulateSwapOperations {
+                    offer_amount: balance,
+                    operations,
+                },
+            )
+            .unwrap();
+
+        reference_prices.push(ReferencePrice {
+            offer_asset_info,
+            ask_asset_info,
+            price: Decimal::from_ratio(simulation.amount, balance),
+        });
+    }
+
+    app.execute_contract(
+        sender,
+        fee_collector,
+        &ExecuteMsg::UpdateAggregationConfig {
+            max_spread: Decimal::percent(50u64),
+            max_price_deviation: Decimal::percent(50u64),
+            reference_price_ttl: 86_400u64,
+            reference_prices,
+        },
+        &[],
+    )
+    .unwrap();
+}
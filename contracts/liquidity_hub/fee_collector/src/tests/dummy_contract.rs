@@ -1,5 +1,6 @@
-use cosmwasm_std::{to_json_binary, Addr, BankMsg, Coin, Response};
+use cosmwasm_std::{to_json_binary, Addr, BankMsg, Coin, Response, StdError};
 use cw_multi_test::ContractWrapper;
+use cw_storage_plus::Item;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -31,3 +32,41 @@ pub fn create_dummy_flash_loan_contract(
         |_deps, _env, _query| Ok(to_json_binary::<Vec<Coin>>(&vec![]).unwrap()),
     )
 }
+
+/// Creates a fee distributor that only answers the `Config` query, so the fee collector can be
+/// tested aggregating into cw20 distribution assets the actual fee distributor doesn't accept.
+pub fn create_dummy_fee_distributor_contract() -> ContractWrapper<
+    ExecuteMsg,
+    white_whale_std::fee_distributor::InstantiateMsg,
+    white_whale_std::fee_distributor::QueryMsg,
+    StdError,
+    StdError,
+    StdError,
+> {
+    ContractWrapper::new(
+        |_deps, _env, _info, _msg| Ok(Response::new()),
+        |deps, _env, info, msg| {
+            DUMMY_FEE_DISTRIBUTOR_CONFIG.save(
+                deps.storage,
+                &white_whale_std::fee_distributor::Config {
+                    owner: info.sender,
+                    bonding_contract_addr: Addr::unchecked(msg.bonding_contract_addr),
+                    fee_collector_addr: Addr::unchecked(msg.fee_collector_addr),
+                    grace_period: msg.grace_period,
+                    epoch_config: msg.epoch_config,
+                    distribution_assets: msg.distribution_assets,
+                },
+            )?;
+            Ok(Response::new())
+        },
+        |deps, _env, msg| match msg {
+            white_whale_std::fee_distributor::QueryMsg::Config {} => {
+                to_json_binary(&DUMMY_FEE_DISTRIBUTOR_CONFIG.load(deps.storage)?)
+            }
+            _ => Err(StdError::generic_err("unsupported query")),
+        },
+    )
+}
+
+const DUMMY_FEE_DISTRIBUTOR_CONFIG: Item<white_whale_std::fee_distributor::Config> =
+    Item::new("config");
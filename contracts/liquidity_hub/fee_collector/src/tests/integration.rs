@@ -10,13 +10,18 @@ use cw_multi_test::Executor;
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 use white_whale_std::fee::{Fee, VaultFee};
 use white_whale_std::fee_collector::ExecuteMsg::{
-    AggregateFees, CollectFees, ForwardFees, UpdateConfig,
+    AggregateFees, CollectFees, ForwardFees, SweepFees, UpdateAggregationLimits,
+    UpdateBuybackConfig, UpdateConfig, UpdateTakeRateSplits,
 };
 use white_whale_std::fee_collector::{
-    Contract, ContractType, FactoryType, FeesFor, InstantiateMsg, QueryMsg,
+    AggregationLimit, BurnRecord, BuybackConfig, Contract, ContractType, EpochFeeReportResponse,
+    FactoryType, FeeCollectionCursor, FeeCollectionProgressResponse, FeesFor, InstantiateMsg,
+    PoolFeeHistoryResponse, QueryMsg, TakeRateRecipient, TakeRateRecipientHistory, TakeRateSplit,
 };
 use white_whale_std::fee_distributor::ExecuteMsg::NewEpoch;
-use white_whale_std::fee_distributor::{ClaimableEpochsResponse, Epoch, EpochResponse};
+use white_whale_std::fee_distributor::{
+    ClaimableEpochsResponse, DistributionAsset, Epoch, EpochResponse,
+};
 use white_whale_std::pool_network::asset::AssetInfo::NativeToken;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairType};
 use white_whale_std::pool_network::factory::ExecuteMsg::{AddNativeTokenDecimals, CreatePair};
@@ -28,10 +33,11 @@ use white_whale_std::whale_lair::BondingWeightResponse;
 use white_whale_std::{pool_network, vault_network};
 
 use crate::tests::common_integration::{
-    increase_allowance, mock_app, mock_app_with_balance, mock_creator,
-    store_dummy_flash_loan_contract, store_fee_collector_code, store_fee_distributor_code,
-    store_pair_code, store_pool_factory_code, store_pool_router_code, store_token_code,
-    store_trio_code, store_vault_code, store_vault_factory_code, store_whale_lair_code,
+    increase_allowance, mock_app, mock_app_with_balance, mock_creator, set_reference_prices,
+    store_dummy_fee_distributor_code, store_dummy_flash_loan_contract, store_fee_collector_code,
+    store_fee_distributor_code, store_pair_code, store_pool_factory_code, store_pool_router_code,
+    store_token_code, store_trio_code, store_vault_code, store_vault_factory_code,
+    store_whale_lair_code,
 };
 use crate::ContractError;
 
@@ -43,7 +49,7 @@ fn collect_all_factories_cw20_fees_successfully() {
     let creator = mock_creator();
 
     let fee_collector_id = store_fee_collector_code(&mut app);
-    let fee_distributor_id = store_fee_distributor_code(&mut app);
+    let fee_distributor_id = store_dummy_fee_distributor_code(&mut app);
     let pool_factory_id = store_pool_factory_code(&mut app);
     let pool_router_id = store_pool_router_code(&mut app);
     let pair_id = store_pair_code(&mut app);
@@ -97,7 +103,6 @@ fn collect_all_factories_cw20_fees_successfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: None,
             pool_factory: None,
@@ -391,6 +396,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_variant: None,
                     limit: Some(u32::try_from(TOKEN_AMOUNT).unwrap()),
                 },
             },
@@ -420,7 +426,10 @@ fn collect_all_factories_cw20_fees_successfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: ask_asset.clone(),
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: ask_asset.clone(),
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -432,7 +441,6 @@ fn collect_all_factories_cw20_fees_successfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &white_whale_std::fee_collector::ExecuteMsg::UpdateConfig {
-            owner: None,
             pool_router: None,
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: None,
@@ -518,6 +526,24 @@ fn collect_all_factories_cw20_fees_successfully() {
         .unwrap();
     }
 
+    // Set the reference prices the aggregation swaps are checked against
+    set_reference_prices(
+        &mut app,
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        pool_router_address.clone(),
+        (1..TOKEN_AMOUNT)
+            .map(|i| {
+                (
+                    AssetInfo::Token {
+                        contract_addr: cw20_tokens[i as usize].to_string(),
+                    },
+                    ask_asset.clone(),
+                )
+            })
+            .collect(),
+    );
+
     // Aggregate fees
     app.execute_contract(
         creator.sender,
@@ -527,6 +553,7 @@ fn collect_all_factories_cw20_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_variant: None,
                     limit: Some(u32::try_from(TOKEN_AMOUNT).unwrap()),
                 },
             },
@@ -1030,7 +1057,7 @@ fn collect_pools_native_fees_successfully() {
     let mut app = mock_app_with_balance(balances);
 
     let fee_collector_id = store_fee_collector_code(&mut app);
-    let fee_distributor_id = store_fee_distributor_code(&mut app);
+    let fee_distributor_id = store_dummy_fee_distributor_code(&mut app);
     let pool_factory_id = store_pool_factory_code(&mut app);
     let pool_router_id = store_pool_router_code(&mut app);
     let pair_id = store_pair_code(&mut app);
@@ -1106,7 +1133,6 @@ fn collect_pools_native_fees_successfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: None,
             pool_factory: None,
@@ -1263,6 +1289,7 @@ fn collect_pools_native_fees_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_variant: None,
                         limit: None,
                     },
                 },
@@ -1371,6 +1398,7 @@ fn collect_pools_native_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_variant: None,
                     limit: None,
                 },
             },
@@ -1389,6 +1417,7 @@ fn collect_pools_native_fees_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_variant: None,
                         limit: None,
                     },
                 },
@@ -1415,7 +1444,10 @@ fn collect_pools_native_fees_successfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: ask_asset.clone(),
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: ask_asset.clone(),
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -1427,7 +1459,6 @@ fn collect_pools_native_fees_successfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &white_whale_std::fee_collector::ExecuteMsg::UpdateConfig {
-            owner: None,
             pool_router: None,
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: None,
@@ -1508,6 +1539,7 @@ fn collect_pools_native_fees_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: None,
+                        start_after_variant: None,
                         limit: None,
                     },
                 },
@@ -1579,12 +1611,40 @@ fn collect_pools_native_fees_successfully() {
 
     app.execute_contract(
         creator.sender.clone(),
-        pool_router_address,
+        pool_router_address.clone(),
         &pool_network::router::ExecuteMsg::AddSwapRoutes { swap_routes },
         &[],
     )
     .unwrap();
 
+    // Set the reference prices the aggregation swaps are checked against
+    let mut asset_pairs: Vec<(AssetInfo, AssetInfo)> = cw20_tokens
+        .iter()
+        .filter(|cw20_token| cw20_token.to_string() != ask_asset.to_string())
+        .map(|cw20_token| {
+            (
+                AssetInfo::Token {
+                    contract_addr: cw20_token.to_string(),
+                },
+                ask_asset.clone(),
+            )
+        })
+        .collect();
+    asset_pairs.push((
+        AssetInfo::NativeToken {
+            denom: "native".to_string(),
+        },
+        ask_asset.clone(),
+    ));
+
+    set_reference_prices(
+        &mut app,
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        pool_router_address,
+        asset_pairs,
+    );
+
     // Aggregate fees
     app.execute_contract(
         creator.sender,
@@ -1594,6 +1654,7 @@ fn collect_pools_native_fees_successfully() {
                 factory_addr: pool_factory_address.to_string(),
                 factory_type: FactoryType::Pool {
                     start_after: None,
+                    start_after_variant: None,
                     limit: Some(u32::try_from(TOKEN_AMOUNT).unwrap()),
                 },
             },
@@ -1940,6 +2001,7 @@ fn collect_fees_with_pagination_successfully() {
                 &pool_factory_address,
                 &pool_network::factory::QueryMsg::Pairs {
                     start_after: start_after.clone(),
+                    start_after_variant: None,
                     limit: Some(u32::try_from(TOKEN_AMOUNT / 2).unwrap()),
                 },
             )
@@ -1953,6 +2015,7 @@ fn collect_fees_with_pagination_successfully() {
                     factory_addr: pool_factory_address.to_string(),
                     factory_type: FactoryType::Pool {
                         start_after: start_after.clone(),
+                        start_after_variant: None,
                         limit: Some(u32::try_from(TOKEN_AMOUNT / 2).unwrap()),
                     },
                 },
@@ -2371,9 +2434,12 @@ fn aggregate_fees_for_vault() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uatom".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uatom".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -2385,7 +2451,6 @@ fn aggregate_fees_for_vault() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &white_whale_std::fee_collector::ExecuteMsg::UpdateConfig {
-            owner: None,
             pool_router: None,
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: None,
@@ -2511,7 +2576,6 @@ fn aggregate_fees_for_vault() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: None,
             pool_factory: None,
@@ -2765,21 +2829,28 @@ fn aggregate_fees_for_vault() {
     let ask_asset = AssetInfo::NativeToken {
         denom: "uatom".to_string(),
     };
-    app.execute_contract(
-        creator.sender.clone(),
-        fee_collector_address.clone(),
-        &AggregateFees {
-            aggregate_fees_for: FeesFor::Factory {
-                factory_addr: vault_factory_address.to_string(),
-                factory_type: FactoryType::Vault {
-                    start_after: None,
-                    limit: None,
+    let res = app
+        .execute_contract(
+            creator.sender.clone(),
+            fee_collector_address.clone(),
+            &AggregateFees {
+                aggregate_fees_for: FeesFor::Factory {
+                    factory_addr: vault_factory_address.to_string(),
+                    factory_type: FactoryType::Vault {
+                        start_after: None,
+                        limit: None,
+                    },
                 },
             },
-        },
-        &[],
-    )
-    .unwrap();
+            &[],
+        )
+        .unwrap();
+
+    // the skipped asset is reported
+    assert!(res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "skipped_asset" && attr.value == "ujuno:no_swap_route")));
 
     // verify the fees collected were not aggregated
     let mut ask_asset_original_balance = Uint128::zero();
@@ -2895,12 +2966,162 @@ fn aggregate_fees_for_vault() {
 
     app.execute_contract(
         creator.sender.clone(),
-        pool_router_address,
+        pool_router_address.clone(),
         &pool_network::router::ExecuteMsg::AddSwapRoutes { swap_routes },
         &[],
     )
     .unwrap();
 
+    let ujuno_balance = app
+        .wrap()
+        .query_balance(fee_collector_address.clone().to_string(), "ujuno")
+        .unwrap()
+        .amount;
+
+    // The swap is skipped when its price is too far below the reference price set by the owner
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &white_whale_std::fee_collector::ExecuteMsg::UpdateAggregationConfig {
+            max_spread: Decimal::percent(50u64),
+            max_price_deviation: Decimal::percent(10u64),
+            reference_price_ttl: 86_400u64,
+            reference_prices: vec![white_whale_std::fee_collector::ReferencePrice {
+                offer_asset_info: AssetInfo::NativeToken {
+                    denom: "ujuno".to_string(),
+                },
+                ask_asset_info: ask_asset.clone(),
+                price: Decimal::percent(1_000u64),
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res = app
+        .execute_contract(
+            creator.sender.clone(),
+            fee_collector_address.clone(),
+            &AggregateFees {
+                aggregate_fees_for: FeesFor::Factory {
+                    factory_addr: vault_factory_address.to_string(),
+                    factory_type: FactoryType::Vault {
+                        start_after: None,
+                        limit: None,
+                    },
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+    assert!(res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "skipped_asset" && attr.value == "ujuno:price_deviation")));
+
+    let balance_res = app
+        .wrap()
+        .query_balance(fee_collector_address.clone().to_string(), "ujuno")
+        .unwrap();
+    assert_eq!(balance_res.amount, ujuno_balance);
+
+    let ujuno_pair = (
+        AssetInfo::NativeToken {
+            denom: "ujuno".to_string(),
+        },
+        ask_asset.clone(),
+    );
+    set_reference_prices(
+        &mut app,
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        pool_router_address.clone(),
+        vec![ujuno_pair.clone()],
+    );
+
+    // Limit the amount of ujuno that can be aggregated at once, so half of it is carried over
+    let ujuno_limit = ujuno_balance.multiply_ratio(1u128, 2u128);
+
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &UpdateAggregationLimits {
+            limits: vec![AggregationLimit {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "ujuno".to_string(),
+                },
+                max_amount: Some(ujuno_limit),
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res = app
+        .execute_contract(
+            creator.sender.clone(),
+            fee_collector_address.clone(),
+            &AggregateFees {
+                aggregate_fees_for: FeesFor::Factory {
+                    factory_addr: vault_factory_address.to_string(),
+                    factory_type: FactoryType::Vault {
+                        start_after: None,
+                        limit: None,
+                    },
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+    let carried_over = ujuno_balance - ujuno_limit;
+    assert!(res
+        .events
+        .iter()
+        .any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "carried_over_asset"
+                && attr.value == format!("{carried_over}ujuno"))));
+
+    let balance_res = app
+        .wrap()
+        .query_balance(fee_collector_address.clone().to_string(), "ujuno")
+        .unwrap();
+    assert_eq!(balance_res.amount, carried_over);
+
+    // Remove the limit, so the rest is aggregated
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &UpdateAggregationLimits {
+            limits: vec![AggregationLimit {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "ujuno".to_string(),
+                },
+                max_amount: None,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let aggregation_limits: Vec<AggregationLimit> = app
+        .wrap()
+        .query_wasm_smart(&fee_collector_address, &QueryMsg::AggregationLimits {})
+        .unwrap();
+    assert!(aggregation_limits.is_empty());
+
+    // Refresh the reference price, as the previous swap moved the pool
+    set_reference_prices(
+        &mut app,
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        pool_router_address,
+        vec![ujuno_pair],
+    );
+
     // Aggregate fees
     app.execute_contract(
         creator.sender,
@@ -3133,9 +3354,12 @@ fn collect_and_distribute_fees_successfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -3149,9 +3373,9 @@ fn collect_and_distribute_fees_successfully() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -3162,7 +3386,6 @@ fn collect_and_distribute_fees_successfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -3553,9 +3776,12 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -3569,9 +3795,9 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -3582,7 +3808,6 @@ fn collect_and_dist_fees_where_one_bonder_is_increasing_weight_no_claims_until_e
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -4529,9 +4754,12 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -4545,9 +4773,9 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -4557,7 +4785,6 @@ fn collect_and_distribute_fees_with_expiring_epoch_successfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -5106,9 +5333,12 @@ fn create_epoch_unsuccessfully() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -5121,7 +5351,6 @@ fn create_epoch_unsuccessfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -5391,9 +5620,12 @@ fn aggregate_fees_unsuccessfully() {
                     duration: Uint64::new(86400000000000),
                     genesis_epoch: Default::default(),
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -5406,7 +5638,6 @@ fn aggregate_fees_unsuccessfully() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: None,
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: None,
@@ -5462,9 +5693,12 @@ fn forward_fees_unsuccessfully() {
             fee_collector_address.clone(),
             &ForwardFees {
                 epoch: Default::default(),
-                forward_fees_as: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                forward_fees_as: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
         )
@@ -5617,9 +5851,12 @@ fn decrease_grace_period_fee_distributor() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -5632,7 +5869,6 @@ fn decrease_grace_period_fee_distributor() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -5873,11 +6109,10 @@ fn decrease_grace_period_fee_distributor() {
             creator.sender.clone(),
             fee_distributor_address.clone(),
             &white_whale_std::fee_distributor::ExecuteMsg::UpdateConfig {
-                owner: None,
                 bonding_contract_addr: None,
                 fee_collector_addr: None,
                 grace_period: Some(Uint64::one()),
-                distribution_asset: None,
+                distribution_assets: None,
                 epoch_config: None,
             },
             &[],
@@ -6042,9 +6277,12 @@ fn users_cannot_claim_rewards_from_past_epochs() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -6058,9 +6296,9 @@ fn users_cannot_claim_rewards_from_past_epochs() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -6070,7 +6308,6 @@ fn users_cannot_claim_rewards_from_past_epochs() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -6612,9 +6849,12 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -6628,9 +6868,9 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -6640,7 +6880,6 @@ fn user_can_claim_even_when_his_weight_increases_for_past_epochs() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -7274,9 +7513,12 @@ fn user_weight_accounts_for_unbondings() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -7290,9 +7532,9 @@ fn user_weight_accounts_for_unbondings() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -7302,7 +7544,6 @@ fn user_weight_accounts_for_unbondings() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -8133,9 +8374,12 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -8149,9 +8393,9 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -8161,7 +8405,6 @@ fn users_can_claim_even_when_global_index_was_taken_after_epoch_was_created() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -8658,9 +8901,12 @@ fn collect_distribute_with_unbonders() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -8674,9 +8920,9 @@ fn collect_distribute_with_unbonders() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -8687,7 +8933,6 @@ fn collect_distribute_with_unbonders() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -9427,9 +9672,12 @@ fn test_take_rate_dao() {
                     duration: Uint64::new(86_400_000_000_000u64), // a day
                     genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "fee_distributor",
@@ -9443,9 +9691,9 @@ fn test_take_rate_dao() {
         whale_lair_address.clone(),
         &white_whale_std::whale_lair::ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         },
         &[],
     )
@@ -9456,7 +9704,6 @@ fn test_take_rate_dao() {
         creator.sender.clone(),
         fee_collector_address.clone(),
         &UpdateConfig {
-            owner: None,
             pool_router: Some(pool_router_address.to_string()),
             fee_distributor: Some(fee_distributor_address.to_string()),
             pool_factory: Some(pool_factory_address.to_string()),
@@ -9697,7 +9944,7 @@ fn test_take_rate_dao() {
     assert_eq!(uwhale_balance_on_dao, Uint128::new(506u128));
 
     // check the take rate query
-    let take_rate: Coin = app
+    let take_rate: Vec<Coin> = app
         .wrap()
         .query_wasm_smart(
             fee_collector_address.clone(),
@@ -9707,11 +9954,14 @@ fn test_take_rate_dao() {
         )
         .unwrap();
 
-    assert_eq!(take_rate, coin(Uint128::new(506u128).u128(), "uwhale"));
+    assert_eq!(
+        take_rate,
+        vec![coin(Uint128::new(506u128).u128(), "uwhale")]
+    );
 
     // this query should err cuz there's no take rate history for epoch 10
     app.wrap()
-        .query_wasm_smart::<Coin>(
+        .query_wasm_smart::<Vec<Coin>>(
             fee_collector_address.clone(),
             &QueryMsg::TakeRateHistory {
                 epoch_id: Uint64::new(10),
@@ -9804,4 +10054,1112 @@ fn test_take_rate_dao() {
         .amount;
 
     assert_eq!(uwhale_balance_on_dao, Uint128::new(506u128 + 550u128));
+
+    // split the take rate among the dao, an insurance fund and burning
+    let insurance_fund = Addr::unchecked("insurance_fund");
+    let take_rate_splits = vec![
+        TakeRateSplit {
+            recipient: TakeRateRecipient::Address {
+                address: dao_mock.to_string(),
+            },
+            share: Decimal::percent(50u64),
+        },
+        TakeRateSplit {
+            recipient: TakeRateRecipient::Address {
+                address: insurance_fund.to_string(),
+            },
+            share: Decimal::percent(25u64),
+        },
+        TakeRateSplit {
+            recipient: TakeRateRecipient::Burn {},
+            share: Decimal::percent(25u64),
+        },
+    ];
+
+    // shares not adding up to 1 should fail
+    let err = app
+        .execute_contract(
+            creator.sender.clone(),
+            fee_collector_address.clone(),
+            &UpdateTakeRateSplits {
+                splits: take_rate_splits[..2].to_vec(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidTakeRateSplits {}
+    );
+
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &UpdateTakeRateSplits {
+            splits: take_rate_splits.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let splits: Vec<TakeRateSplit> = app
+        .wrap()
+        .query_wasm_smart(fee_collector_address.clone(), &QueryMsg::TakeRateSplits {})
+        .unwrap();
+    assert_eq!(splits, take_rate_splits);
+
+    app.execute_contract(
+        creator.sender.clone(),
+        pair_tokens[0].clone(),
+        &pool_network::pair::ExecuteMsg::Swap {
+            offer_asset: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "usdc".to_string(),
+                },
+                amount: Uint128::new(25_000u128),
+            },
+            belief_price: None,
+            max_spread: Some(Decimal::percent(30u64)),
+            to: None,
+        },
+        &[Coin {
+            denom: "usdc".to_string(),
+            amount: Uint128::new(25_000u128),
+        }],
+    )
+    .unwrap();
+
+    // advance the time to one day after the second epoch was created
+    app.set_block(BlockInfo {
+        height: 123456790u64,
+        time: Timestamp::from_nanos(1678975200_000000000u64),
+        chain_id: "".to_string(),
+    });
+
+    // Create EPOCH 3
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_distributor_address.clone(),
+        &NewEpoch {},
+        &[],
+    )
+    .unwrap();
+
+    let take_rate: Vec<Coin> = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::TakeRateHistory {
+                epoch_id: Uint64::new(3u64),
+            },
+        )
+        .unwrap();
+    assert_eq!(take_rate.len(), 1);
+
+    let take_rate_amount = take_rate[0].amount;
+    let dao_take_rate = take_rate_amount.mul_floor(Decimal::percent(50u64));
+    let insurance_take_rate = take_rate_amount.mul_floor(Decimal::percent(25u64));
+    let burnt_take_rate = take_rate_amount - dao_take_rate - insurance_take_rate;
+
+    let uwhale_balance_on_dao = app
+        .wrap()
+        .query_balance(dao_mock.clone(), "uwhale")
+        .unwrap()
+        .amount;
+    assert_eq!(
+        uwhale_balance_on_dao,
+        Uint128::new(506u128 + 550u128) + dao_take_rate
+    );
+
+    let uwhale_balance_on_insurance_fund = app
+        .wrap()
+        .query_balance(insurance_fund.clone(), "uwhale")
+        .unwrap()
+        .amount;
+    assert_eq!(uwhale_balance_on_insurance_fund, insurance_take_rate);
+
+    // the share of the take rate that is burned doesn't stay in the fee collector
+    let uwhale_balance_on_fee_collector = app
+        .wrap()
+        .query_balance(fee_collector_address.clone(), "uwhale")
+        .unwrap()
+        .amount;
+    assert_eq!(uwhale_balance_on_fee_collector, Uint128::zero());
+
+    let recipients_history: Vec<TakeRateRecipientHistory> = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::TakeRateRecipientsHistory {
+                epoch_id: Uint64::new(3u64),
+            },
+        )
+        .unwrap();
+
+    // in the order of the splits
+    assert_eq!(
+        recipients_history,
+        vec![
+            TakeRateRecipientHistory {
+                recipient: TakeRateRecipient::Address {
+                    address: dao_mock.to_string(),
+                },
+                take_rate: vec![coin(dao_take_rate.u128(), "uwhale")],
+            },
+            TakeRateRecipientHistory {
+                recipient: TakeRateRecipient::Address {
+                    address: insurance_fund.to_string(),
+                },
+                take_rate: vec![coin(insurance_take_rate.u128(), "uwhale")],
+            },
+            TakeRateRecipientHistory {
+                recipient: TakeRateRecipient::Burn {},
+                take_rate: vec![coin(burnt_take_rate.u128(), "uwhale")],
+            },
+        ]
+    );
+
+    // turn the buyback on, burning 20% of the fees forwarded to the fee distributor. As the fees
+    // are aggregated to whale already, they are burned without swapping them
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &UpdateBuybackConfig {
+            buyback_config: Some(BuybackConfig {
+                burn_asset: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                share: Decimal::percent(20u64),
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        creator.sender.clone(),
+        pair_tokens[0].clone(),
+        &pool_network::pair::ExecuteMsg::Swap {
+            offer_asset: Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "usdc".to_string(),
+                },
+                amount: Uint128::new(100_000u128),
+            },
+            belief_price: None,
+            max_spread: Some(Decimal::percent(30u64)),
+            to: None,
+        },
+        &[Coin {
+            denom: "usdc".to_string(),
+            amount: Uint128::new(100_000u128),
+        }],
+    )
+    .unwrap();
+
+    // advance the time to one day after the third epoch was created
+    app.set_block(BlockInfo {
+        height: 123456791u64,
+        time: Timestamp::from_nanos(1679061600_000000000u64),
+        chain_id: "".to_string(),
+    });
+
+    // Create EPOCH 4
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_distributor_address.clone(),
+        &NewEpoch {},
+        &[],
+    )
+    .unwrap();
+
+    let new_epoch_res: EpochResponse = app
+        .wrap()
+        .query_wasm_smart(
+            fee_distributor_address.clone(),
+            &white_whale_std::fee_distributor::QueryMsg::CurrentEpoch {},
+        )
+        .unwrap();
+    assert_eq!(new_epoch_res.epoch.id, Uint64::new(4u64));
+
+    let burn_record: BurnRecord = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::BurnHistory {
+                epoch_id: Uint64::new(4u64),
+            },
+        )
+        .unwrap();
+    assert_eq!(burn_record.burned.len(), 1);
+    assert_eq!(burn_record.burned, burn_record.cumulative_burned);
+
+    let burned = burn_record.burned[0].amount;
+    assert!(!burned.is_zero());
+
+    // the fees that were not burned went to the fee distributor. The epoch also holds what was
+    // left on the expiring epoch, which is not part of the forwarded fees
+    let expired_epoch_res: EpochResponse = app
+        .wrap()
+        .query_wasm_smart(
+            fee_distributor_address.clone(),
+            &white_whale_std::fee_distributor::QueryMsg::Epoch {
+                id: Uint64::new(3u64),
+            },
+        )
+        .unwrap();
+    assert!(expired_epoch_res.epoch.available.is_empty());
+
+    let forwarded_fees =
+        new_epoch_res.epoch.total[0].amount - expired_epoch_res.epoch.total[0].amount;
+    assert_eq!(
+        burned,
+        (forwarded_fees + burned).mul_floor(Decimal::percent(20u64))
+    );
+
+    // the buyback and the share of the take rate that is burned don't stay in the fee collector
+    let recipients_history: Vec<TakeRateRecipientHistory> = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::TakeRateRecipientsHistory {
+                epoch_id: Uint64::new(4u64),
+            },
+        )
+        .unwrap();
+    let burnt_take_rate = recipients_history
+        .iter()
+        .find(|history| history.recipient == TakeRateRecipient::Burn {})
+        .unwrap()
+        .take_rate[0]
+        .amount;
+
+    assert!(!burnt_take_rate.is_zero());
+
+    let uwhale_balance_on_fee_collector = app
+        .wrap()
+        .query_balance(fee_collector_address.clone(), "uwhale")
+        .unwrap()
+        .amount;
+    assert_eq!(uwhale_balance_on_fee_collector, Uint128::zero());
+
+    let total_burned: Vec<Asset> = app
+        .wrap()
+        .query_wasm_smart(fee_collector_address.clone(), &QueryMsg::TotalBurned {})
+        .unwrap();
+    assert_eq!(total_burned, burn_record.cumulative_burned);
+
+    // the fee report for the epoch accounts for where the aggregated fees went
+    let fee_report: EpochFeeReportResponse = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::EpochFeeReport {
+                epoch_id: Uint64::new(4u64),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(fee_report.forwarded_fees[0].amount, forwarded_fees);
+    assert_eq!(fee_report.burned, burn_record.burned);
+    assert_eq!(
+        fee_report.aggregated_fees[0].amount,
+        forwarded_fees + burned + fee_report.take_rate[0].amount
+    );
+
+    // only the first pool had swaps
+    assert_eq!(fee_report.contract_fees.len(), 1usize);
+    assert_eq!(
+        fee_report.contract_fees[0].contract,
+        Contract {
+            address: pair_tokens[0].to_string(),
+            contract_type: ContractType::Pool {},
+        }
+    );
+
+    // the first pool had fees collected on every epoch
+    let pool_fee_history: PoolFeeHistoryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::PoolFeeHistory {
+                pool: pair_tokens[0].to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(
+        pool_fee_history
+            .history
+            .iter()
+            .map(|epoch_fees| epoch_fees.epoch_id.u64())
+            .collect::<Vec<u64>>(),
+        vec![1u64, 2u64, 3u64, 4u64]
+    );
+    assert_eq!(
+        pool_fee_history.history[3].fees,
+        fee_report.contract_fees[0].fees
+    );
+
+    let pool_fee_history: PoolFeeHistoryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            fee_collector_address.clone(),
+            &QueryMsg::PoolFeeHistory {
+                pool: pair_tokens[0].to_string(),
+                start_after: Some(Uint64::new(2u64)),
+                limit: Some(1u32),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(pool_fee_history.history.len(), 1usize);
+    assert_eq!(pool_fee_history.history[0].epoch_id, Uint64::new(3u64));
+}
+
+#[test]
+fn sweep_fees_with_cursor_successfully() {
+    const TOKEN_AMOUNT: usize = 10;
+
+    let creator = mock_creator();
+    let balances = vec![(
+        creator.clone().sender,
+        coins(1_000_000_000u128, "native".to_string()),
+    )];
+
+    let mut app = mock_app_with_balance(balances);
+
+    let fee_collector_id = store_fee_collector_code(&mut app);
+    let pool_factory_id = store_pool_factory_code(&mut app);
+    let pair_id = store_pair_code(&mut app);
+    let trio_id = store_trio_code(&mut app);
+    let token_id = store_token_code(&mut app);
+
+    let fee_collector_address = app
+        .instantiate_contract(
+            fee_collector_id,
+            creator.clone().sender,
+            &InstantiateMsg {},
+            &[],
+            "fee_collector",
+            None,
+        )
+        .unwrap();
+
+    #[cfg(feature = "osmosis")]
+    let osmosis_fee_collector_address = app
+        .instantiate_contract(
+            fee_collector_id,
+            creator.clone().sender,
+            &InstantiateMsg {},
+            &[],
+            "osmosis_fee_collector",
+            None,
+        )
+        .unwrap();
+
+    #[cfg(not(feature = "osmosis"))]
+    let instantiate_msg = pool_network::factory::InstantiateMsg {
+        pair_code_id: pair_id,
+        trio_code_id: trio_id,
+        token_code_id: token_id,
+        fee_collector_addr: fee_collector_address.to_string(),
+    };
+
+    #[cfg(feature = "osmosis")]
+    let instantiate_msg = pool_network::factory::InstantiateMsg {
+        pair_code_id: pair_id,
+        trio_code_id: trio_id,
+        token_code_id: token_id,
+        fee_collector_addr: fee_collector_address.to_string(),
+        osmosis_fee_collector_addr: osmosis_fee_collector_address.to_string(),
+    };
+
+    let pool_factory_address = app
+        .instantiate_contract(
+            pool_factory_id,
+            creator.clone().sender,
+            &instantiate_msg,
+            &[],
+            "fee_collector",
+            None,
+        )
+        .unwrap();
+
+    // add native token to the factory
+    app.execute_contract(
+        creator.sender.clone(),
+        pool_factory_address.clone(),
+        &AddNativeTokenDecimals {
+            denom: "native".to_string(),
+            decimals: 6,
+        },
+        &[Coin {
+            denom: "native".to_string(),
+            amount: Uint128::new(1u128),
+        }],
+    )
+    .unwrap();
+
+    // Create few tokens to create pools with
+    let mut cw20_tokens: Vec<Addr> = Vec::new();
+    for i in 0..TOKEN_AMOUNT {
+        let token_address = app
+            .instantiate_contract(
+                token_id,
+                creator.clone().sender,
+                &pool_network::token::InstantiateMsg {
+                    name: format!("token{}", i),
+                    symbol: "token".to_string(),
+                    decimals: 6,
+                    initial_balances: vec![Cw20Coin {
+                        address: creator.clone().sender.to_string(),
+                        amount: Uint128::new(1_000_000_000_000u128),
+                    }],
+                    mint: Some(MinterResponse {
+                        minter: creator.clone().sender.to_string(),
+                        cap: None,
+                    }),
+                },
+                &[],
+                "cw20 token",
+                None,
+            )
+            .unwrap();
+
+        cw20_tokens.push(token_address);
+    }
+
+    // Create few pools
+    let mut pair_tokens: Vec<Addr> = Vec::new();
+    for cw20_token in cw20_tokens.clone() {
+        let res = app
+            .execute_contract(
+                creator.sender.clone(),
+                pool_factory_address.clone(),
+                &CreatePair {
+                    asset_infos: [
+                        AssetInfo::NativeToken {
+                            denom: "native".to_string(),
+                        },
+                        AssetInfo::Token {
+                            contract_addr: cw20_token.to_string(),
+                        },
+                    ],
+                    pool_fees: PoolFee {
+                        protocol_fee: Fee {
+                            share: Decimal::percent(5u64),
+                        },
+                        swap_fee: Fee {
+                            share: Decimal::percent(7u64),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                    },
+                    pair_type: PairType::ConstantProduct,
+                    token_factory_lp: false,
+                },
+                &[],
+            )
+            .unwrap();
+
+        pair_tokens.push(Addr::unchecked(
+            res.events
+                .last()
+                .unwrap()
+                .attributes
+                .clone()
+                .get(1)
+                .unwrap()
+                .clone()
+                .value,
+        ));
+    }
+
+    // Increase allowance for the tokens on the pools
+    for (i, cw20_token) in cw20_tokens.clone().iter().enumerate() {
+        increase_allowance(
+            &mut app,
+            creator.sender.clone(),
+            cw20_token.clone(),
+            pair_tokens[i].clone(),
+        );
+    }
+
+    // Provide liquidity into pools
+    for (i, cw20_token) in cw20_tokens.clone().iter().enumerate() {
+        app.execute_contract(
+            creator.sender.clone(),
+            pair_tokens[i].clone(),
+            &pool_network::pair::ExecuteMsg::ProvideLiquidity {
+                assets: [
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "native".to_string(),
+                        },
+                        amount: Uint128::new(500_000u128),
+                    },
+                    Asset {
+                        info: AssetInfo::Token {
+                            contract_addr: cw20_token.to_string(),
+                        },
+                        amount: Uint128::new(500_000u128),
+                    },
+                ],
+                slippage_tolerance: None,
+                receiver: None,
+            },
+            &[Coin {
+                denom: "native".to_string(),
+                amount: Uint128::new(500_000u128),
+            }],
+        )
+        .unwrap();
+    }
+
+    // Perform some swaps
+    let mut assets_collected: HashMap<String, Asset> = HashMap::new();
+    for (i, cw20_token) in cw20_tokens.clone().iter().enumerate() {
+        // swap native -> cw20
+        app.execute_contract(
+            creator.sender.clone(),
+            pair_tokens[i].clone(),
+            &pool_network::pair::ExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "native".to_string(),
+                    },
+                    amount: Uint128::new(200_000u128),
+                },
+                belief_price: None,
+                max_spread: Some(Decimal::percent(40u64)),
+                to: None,
+            },
+            &[Coin {
+                denom: "native".to_string(),
+                amount: Uint128::new(200_000u128),
+            }],
+        )
+        .unwrap();
+
+        // swap cw20 -> native
+        app.execute_contract(
+            creator.sender.clone(),
+            cw20_token.clone(),
+            &Cw20ExecuteMsg::Send {
+                contract: pair_tokens[i].to_string(),
+                amount: Uint128::new(200_000u128),
+                msg: to_json_binary(&pool_network::pair::Cw20HookMsg::Swap {
+                    belief_price: None,
+                    max_spread: Some(Decimal::percent(40u64)),
+                    to: None,
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        // Verify the fees are being collected
+        let query_protocol_fees_res: ProtocolFeesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &pair_tokens[i],
+                &pool_network::pair::QueryMsg::ProtocolFees {
+                    asset_id: None,
+                    all_time: None,
+                },
+            )
+            .unwrap();
+
+        for asset in query_protocol_fees_res.fees {
+            assert!(asset.amount > Uint128::zero());
+            accumulate_fee(&mut assets_collected, &asset);
+        }
+    }
+
+    // assert the assets collected are the native token + the tokens created
+    assert_eq!(assets_collected.len(), TOKEN_AMOUNT + 1);
+
+    // Make sure the fee collector's balance for the assets in which fees were collected is zero
+    for (asset_id, _) in assets_collected.clone() {
+        if asset_id == "native" {
+            let balance_res = app
+                .wrap()
+                .query_balance(fee_collector_address.clone().to_string(), "native")
+                .unwrap();
+            assert_eq!(balance_res.amount, Uint128::zero());
+        } else {
+            let balance_res: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &asset_id,
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: fee_collector_address.clone().to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance_res.balance, Uint128::zero());
+        }
+    }
+
+    let fee_distributor_id = store_fee_distributor_code(&mut app);
+    let fee_distributor_address = app
+        .instantiate_contract(
+            fee_distributor_id,
+            creator.clone().sender,
+            &white_whale_std::fee_distributor::InstantiateMsg {
+                bonding_contract_addr: "whale_lair".to_string(),
+                fee_collector_addr: fee_collector_address.clone().to_string(),
+                grace_period: Uint64::new(1),
+                epoch_config: EpochConfig {
+                    duration: Uint64::new(86_400_000_000_000u64), // a day
+                    genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
+                },
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "native".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
+            },
+            &[],
+            "fee_distributor",
+            None,
+        )
+        .unwrap();
+
+    let pool_router_id = store_pool_router_code(&mut app);
+    let pool_router_address = app
+        .instantiate_contract(
+            pool_router_id,
+            creator.clone().sender,
+            &pool_network::router::InstantiateMsg {
+                terraswap_factory: pool_factory_address.to_string(),
+            },
+            &[],
+            "pool_router",
+            None,
+        )
+        .unwrap();
+
+    // the vault factory is not set, so only the pools are swept
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &UpdateConfig {
+            pool_router: Some(pool_router_address.to_string()),
+            fee_distributor: Some(fee_distributor_address.to_string()),
+            pool_factory: Some(pool_factory_address.to_string()),
+            vault_factory: None,
+            take_rate: None,
+            take_rate_dao_address: None,
+            is_take_rate_active: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let progress: FeeCollectionProgressResponse = app
+        .wrap()
+        .query_wasm_smart(&fee_collector_address, &QueryMsg::FeeCollectionProgress {})
+        .unwrap();
+    assert_eq!(
+        progress.pools,
+        FeeCollectionCursor::new(FactoryType::Pool {
+            start_after: None,
+            start_after_variant: None,
+            limit: None,
+        })
+    );
+
+    // there are 10 pools in this test, and the sweeps go through 4 of them at a time
+    let pairs_response: PairsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &pool_factory_address,
+            &pool_network::factory::QueryMsg::Pairs {
+                start_after: None,
+                start_after_variant: None,
+                limit: Some(u32::try_from(TOKEN_AMOUNT).unwrap()),
+            },
+        )
+        .unwrap();
+
+    for (i, swept_pairs) in [4usize, 8usize].iter().enumerate() {
+        app.execute_contract(
+            creator.sender.clone(),
+            fee_collector_address.clone(),
+            &SweepFees { limit: Some(4u32) },
+            &[],
+        )
+        .unwrap();
+
+        let progress: FeeCollectionProgressResponse = app
+            .wrap()
+            .query_wasm_smart(&fee_collector_address, &QueryMsg::FeeCollectionProgress {})
+            .unwrap();
+        assert_eq!(
+            progress.pools,
+            FeeCollectionCursor {
+                next_page: FactoryType::Pool {
+                    start_after: Some(pairs_response.pairs[swept_pairs - 1].asset_infos.clone()),
+                    // all the pools are the same variant
+                    start_after_variant: pairs_response.last_pair_variant.clone(),
+                    limit: None,
+                },
+                swept_contracts: *swept_pairs as u64,
+                completed_sweeps: 0u64,
+            },
+            "wrong progress after sweep {}",
+            i
+        );
+    }
+
+    // the last two pools are swept, so the cursor starts over
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &SweepFees { limit: Some(4u32) },
+        &[],
+    )
+    .unwrap();
+
+    let progress: FeeCollectionProgressResponse = app
+        .wrap()
+        .query_wasm_smart(&fee_collector_address, &QueryMsg::FeeCollectionProgress {})
+        .unwrap();
+    assert_eq!(
+        progress.pools,
+        FeeCollectionCursor {
+            next_page: FactoryType::Pool {
+                start_after: None,
+                start_after_variant: None,
+                limit: None,
+            },
+            swept_contracts: 0u64,
+            completed_sweeps: 1u64,
+        }
+    );
+    assert_eq!(
+        progress.vaults,
+        FeeCollectionCursor::new(FactoryType::Vault {
+            start_after: None,
+            limit: None,
+        })
+    );
+    // there are no trios, so every sweep goes through all of them
+    assert_eq!(
+        progress.trios,
+        FeeCollectionCursor {
+            next_page: FactoryType::Trio {
+                start_after: None,
+                limit: None,
+            },
+            swept_contracts: 0u64,
+            completed_sweeps: 3u64,
+        }
+    );
+
+    // Make sure the fee collector's balance for the assets in which fees were collected increased,
+    // and matches the amount the pool reported to have collected. There are no swap routes set on the
+    // router, so the tokens are not aggregated and stay in the fee collector
+    for (asset_id, asset) in assets_collected.clone() {
+        if asset_id == "native" {
+            let balance_res = app
+                .wrap()
+                .query_balance(fee_collector_address.clone().to_string(), "native")
+                .unwrap();
+            assert_eq!(balance_res.amount, asset.amount);
+        } else {
+            let balance_res: BalanceResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    &asset_id,
+                    &cw20::Cw20QueryMsg::Balance {
+                        address: fee_collector_address.clone().to_string(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(balance_res.balance, asset.amount);
+        }
+    }
+
+    // the swept fees are reported on the epoch they will be forwarded on, the one after the current
+    // epoch of the fee distributor
+    for pair_token in pair_tokens.clone() {
+        let pool_fee_history: PoolFeeHistoryResponse = app
+            .wrap()
+            .query_wasm_smart(
+                fee_collector_address.clone(),
+                &QueryMsg::PoolFeeHistory {
+                    pool: pair_token.to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(pool_fee_history.history.len(), 1usize);
+        assert_eq!(pool_fee_history.history[0].epoch_id, Uint64::one());
+        assert!(!pool_fee_history.history[0].fees.is_empty());
+    }
+
+    // Make sure protocol fees in all the pools are zero, as they have been collected
+    for pair_token in pair_tokens {
+        let protocol_fees_res: ProtocolFeesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &pair_token.clone(),
+                &pool_network::pair::QueryMsg::ProtocolFees {
+                    asset_id: None,
+                    all_time: None,
+                },
+            )
+            .unwrap();
+
+        for fee in protocol_fees_res.fees {
+            assert_eq!(fee.amount, Uint128::zero());
+        }
+    }
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn collect_trio_fees_successfully() {
+    let creator = mock_creator();
+    let denoms = ["uusdc", "uusdt", "uwhale"];
+    let balances = vec![(
+        creator.clone().sender,
+        denoms
+            .iter()
+            .map(|denom| coin(1_000_000_000_000u128, denom.to_string()))
+            .collect(),
+    )];
+
+    let mut app = mock_app_with_balance(balances);
+
+    let fee_collector_id = store_fee_collector_code(&mut app);
+    let pool_factory_id = store_pool_factory_code(&mut app);
+    let pair_id = store_pair_code(&mut app);
+    let trio_id = store_trio_code(&mut app);
+    let token_id = store_token_code(&mut app);
+
+    let fee_collector_address = app
+        .instantiate_contract(
+            fee_collector_id,
+            creator.clone().sender,
+            &InstantiateMsg {},
+            &[],
+            "fee_collector",
+            None,
+        )
+        .unwrap();
+
+    let pool_factory_address = app
+        .instantiate_contract(
+            pool_factory_id,
+            creator.clone().sender,
+            &pool_network::factory::InstantiateMsg {
+                pair_code_id: pair_id,
+                trio_code_id: trio_id,
+                token_code_id: token_id,
+                fee_collector_addr: fee_collector_address.to_string(),
+            },
+            &[],
+            "pool_factory",
+            None,
+        )
+        .unwrap();
+
+    // add native tokens to the factory
+    for denom in denoms {
+        app.execute_contract(
+            creator.sender.clone(),
+            pool_factory_address.clone(),
+            &AddNativeTokenDecimals {
+                denom: denom.to_string(),
+                decimals: 6,
+            },
+            &[coin(1u128, denom)],
+        )
+        .unwrap();
+    }
+
+    let asset_infos = denoms.map(|denom| AssetInfo::NativeToken {
+        denom: denom.to_string(),
+    });
+
+    app.execute_contract(
+        creator.sender.clone(),
+        pool_factory_address.clone(),
+        &pool_network::factory::ExecuteMsg::CreateTrio {
+            asset_infos: asset_infos.clone(),
+            pool_fees: pool_network::trio::PoolFee {
+                protocol_fee: Fee {
+                    share: Decimal::percent(5u64),
+                },
+                swap_fee: Fee {
+                    share: Decimal::percent(7u64),
+                },
+                burn_fee: Fee {
+                    share: Decimal::zero(),
+                },
+            },
+            amp_factor: 100u64,
+            token_factory_lp: false,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let trio_info: pool_network::asset::TrioInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &pool_factory_address,
+            &pool_network::factory::QueryMsg::Trio {
+                asset_infos: asset_infos.clone(),
+            },
+        )
+        .unwrap();
+    let trio_address = Addr::unchecked(trio_info.contract_addr);
+
+    app.execute_contract(
+        creator.sender.clone(),
+        trio_address.clone(),
+        &pool_network::trio::ExecuteMsg::ProvideLiquidity {
+            assets: asset_infos.clone().map(|info| Asset {
+                info,
+                amount: Uint128::new(1_000_000_000u128),
+            }),
+            slippage_tolerance: None,
+            receiver: None,
+        },
+        &denoms.map(|denom| coin(1_000_000_000u128, denom)),
+    )
+    .unwrap();
+
+    let trio_factory_type = FactoryType::Trio {
+        start_after: None,
+        limit: None,
+    };
+
+    // no fees were accrued yet
+    let fees: Vec<Asset> = app
+        .wrap()
+        .query_wasm_smart(
+            &fee_collector_address,
+            &QueryMsg::Fees {
+                query_fees_for: FeesFor::Factory {
+                    factory_addr: pool_factory_address.to_string(),
+                    factory_type: trio_factory_type.clone(),
+                },
+                all_time: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(fees.len(), 3usize);
+    assert!(fees.iter().all(|fee| fee.amount.is_zero()));
+
+    // swap whale -> usdc, the protocol fee is taken in usdc
+    app.execute_contract(
+        creator.sender.clone(),
+        trio_address.clone(),
+        &pool_network::trio::ExecuteMsg::Swap {
+            offer_asset: Asset {
+                info: asset_infos[2].clone(),
+                amount: Uint128::new(10_000_000u128),
+            },
+            ask_asset: asset_infos[0].clone(),
+            belief_price: None,
+            max_spread: Some(Decimal::percent(50u64)),
+            to: None,
+        },
+        &[coin(10_000_000u128, "uwhale")],
+    )
+    .unwrap();
+
+    let fees: Vec<Asset> = app
+        .wrap()
+        .query_wasm_smart(
+            &fee_collector_address,
+            &QueryMsg::Fees {
+                query_fees_for: FeesFor::Factory {
+                    factory_addr: pool_factory_address.to_string(),
+                    factory_type: trio_factory_type.clone(),
+                },
+                all_time: None,
+            },
+        )
+        .unwrap();
+
+    // querying the trio directly returns the same fees
+    let trio_fees: Vec<Asset> = app
+        .wrap()
+        .query_wasm_smart(
+            &fee_collector_address,
+            &QueryMsg::Fees {
+                query_fees_for: FeesFor::Contracts {
+                    contracts: vec![Contract {
+                        address: trio_address.to_string(),
+                        contract_type: ContractType::Trio {},
+                    }],
+                },
+                all_time: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(fees, trio_fees);
+
+    let usdc_fee = fees
+        .iter()
+        .find(|fee| fee.info == asset_infos[0])
+        .unwrap()
+        .amount;
+    assert!(!usdc_fee.is_zero());
+
+    // collect the fees from the trios created by the factory
+    app.execute_contract(
+        creator.sender.clone(),
+        fee_collector_address.clone(),
+        &CollectFees {
+            collect_fees_for: FeesFor::Factory {
+                factory_addr: pool_factory_address.to_string(),
+                factory_type: trio_factory_type.clone(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    for fee in fees {
+        let balance = app
+            .wrap()
+            .query_balance(&fee_collector_address, fee.info.to_string())
+            .unwrap()
+            .amount;
+        assert_eq!(balance, fee.amount);
+    }
+
+    // the fees were collected, so the trio has no fees left
+    let fees: Vec<Asset> = app
+        .wrap()
+        .query_wasm_smart(
+            &fee_collector_address,
+            &QueryMsg::Fees {
+                query_fees_for: FeesFor::Factory {
+                    factory_addr: pool_factory_address.to_string(),
+                    factory_type: trio_factory_type,
+                },
+                all_time: None,
+            },
+        )
+        .unwrap();
+    assert!(fees.iter().all(|fee| fee.amount.is_zero()));
 }
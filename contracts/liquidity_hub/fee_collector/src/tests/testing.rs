@@ -7,8 +7,18 @@ use std::str::FromStr;
 use crate::contract::{execute, instantiate, migrate, query};
 use white_whale_std::pool_network::mock_querier::mock_dependencies;
 
+use crate::state::{
+    AGGREGATION_CONFIG, POOLS_FEE_COLLECTION_CURSOR, TRIOS_FEE_COLLECTION_CURSOR,
+    VAULTS_FEE_COLLECTION_CURSOR,
+};
 use crate::ContractError;
-use white_whale_std::fee_collector::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use white_whale_std::fee_collector::{
+    AggregationConfig, BuybackConfig, Config, ExecuteMsg, FactoryType, FeeCollectionCursor,
+    InstantiateMsg, MigrateMsg, QueryMsg, ReferencePrice, ReferencePriceRecord, TakeRateRecipient,
+    TakeRateSplit,
+};
+use white_whale_std::ownership::{OwnershipAction, OwnershipProposal};
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
 pub fn mock_instantiation(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
     let msg = InstantiateMsg {};
@@ -40,7 +50,6 @@ fn test_update_config_successfully() {
     assert_eq!(config_res.owner, Addr::unchecked("owner"));
 
     let msg = ExecuteMsg::UpdateConfig {
-        owner: Some("new_owner".to_string()),
         pool_router: Some("new_router".to_string()),
         fee_distributor: None,
         pool_factory: None,
@@ -54,7 +63,7 @@ fn test_update_config_successfully() {
 
     let query_res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
     let config_res: Config = from_json(&query_res).unwrap();
-    assert_eq!(config_res.owner, Addr::unchecked("new_owner"));
+    assert_eq!(config_res.owner, Addr::unchecked("owner"));
     assert_eq!(config_res.pool_router, Addr::unchecked("new_router"));
     assert_eq!(
         config_res.take_rate_dao_address,
@@ -75,7 +84,6 @@ fn test_update_config_unsuccessfully_unauthorized() {
     assert_eq!(config_res.owner, Addr::unchecked("owner"));
 
     let msg = ExecuteMsg::UpdateConfig {
-        owner: None,
         pool_router: None,
         fee_distributor: None,
         pool_factory: None,
@@ -95,8 +103,7 @@ fn test_update_config_unsuccessfully_unauthorized() {
 
     let info = mock_info("unauthorized", &[]);
     let msg = ExecuteMsg::UpdateConfig {
-        owner: Some("new_owner".to_string()),
-        pool_router: None,
+        pool_router: Some("new_router".to_string()),
         fee_distributor: None,
         pool_factory: None,
         vault_factory: None,
@@ -114,6 +121,195 @@ fn test_update_config_unsuccessfully_unauthorized() {
     }
 }
 
+#[test]
+fn test_update_ownership() {
+    let mut deps = mock_dependencies(&[]);
+    let info = mock_info("owner", &[]);
+    mock_instantiation(deps.as_mut(), info.clone()).unwrap();
+
+    // only the owner can propose a new owner
+    let msg = ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+        new_owner: "new_owner".to_string(),
+        expiry: Some(100),
+    });
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("unauthorized", &[]),
+        msg.clone(),
+    )
+    .unwrap_err();
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::OwnershipProposal {}).unwrap();
+    let proposal: Option<OwnershipProposal> = from_json(&query_res).unwrap();
+    assert_eq!(
+        proposal,
+        Some(OwnershipProposal {
+            proposed_owner: Addr::unchecked("new_owner"),
+            expires_at: mock_env().block.time.plus_seconds(100),
+        })
+    );
+
+    // the proposal can't be accepted once expired
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("new_owner", &[]),
+        ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+    )
+    .unwrap_err();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_owner", &[]),
+        ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+    )
+    .unwrap();
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config_res: Config = from_json(&query_res).unwrap();
+    assert_eq!(config_res.owner, Addr::unchecked("new_owner"));
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::OwnershipProposal {}).unwrap();
+    let proposal: Option<OwnershipProposal> = from_json(&query_res).unwrap();
+    assert_eq!(proposal, None);
+}
+
+#[test]
+fn test_update_aggregation_config() {
+    let mut deps = mock_dependencies(&[]);
+    let info = mock_info("owner", &[]);
+    mock_instantiation(deps.as_mut(), info.clone()).unwrap();
+
+    // the default settings are used until they are set
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::AggregationConfig {}).unwrap();
+    let aggregation_config: AggregationConfig = from_json(&query_res).unwrap();
+    assert_eq!(aggregation_config, AggregationConfig::default());
+
+    let reference_price = ReferencePrice {
+        offer_asset_info: AssetInfo::NativeToken {
+            denom: "ujuno".to_string(),
+        },
+        ask_asset_info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        price: Decimal::percent(150u64),
+    };
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("unauthorized", &[]),
+        ExecuteMsg::UpdateAggregationConfig {
+            max_spread: Decimal::percent(1u64),
+            max_price_deviation: Decimal::percent(5u64),
+            reference_price_ttl: 86_400u64,
+            reference_prices: vec![reference_price.clone()],
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::Unauthorized"),
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateAggregationConfig {
+            max_spread: Decimal::one(),
+            max_price_deviation: Decimal::percent(5u64),
+            reference_price_ttl: 86_400u64,
+            reference_prices: vec![],
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::InvalidMaxSpread"),
+        Err(ContractError::InvalidMaxSpread {}) => (),
+        _ => panic!("should return ContractError::InvalidMaxSpread"),
+    }
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateAggregationConfig {
+            max_spread: Decimal::percent(1u64),
+            max_price_deviation: Decimal::one(),
+            reference_price_ttl: 86_400u64,
+            reference_prices: vec![],
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::InvalidMaxPriceDeviation"),
+        Err(ContractError::InvalidMaxPriceDeviation {}) => (),
+        _ => panic!("should return ContractError::InvalidMaxPriceDeviation"),
+    }
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateAggregationConfig {
+            max_spread: Decimal::percent(1u64),
+            max_price_deviation: Decimal::percent(5u64),
+            reference_price_ttl: 86_400u64,
+            reference_prices: vec![ReferencePrice {
+                price: Decimal::zero(),
+                ..reference_price.clone()
+            }],
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::InvalidReferencePrice"),
+        Err(ContractError::InvalidReferencePrice {}) => (),
+        _ => panic!("should return ContractError::InvalidReferencePrice"),
+    }
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateAggregationConfig {
+            max_spread: Decimal::percent(1u64),
+            max_price_deviation: Decimal::percent(5u64),
+            reference_price_ttl: 86_400u64,
+            reference_prices: vec![reference_price.clone()],
+        },
+    )
+    .unwrap();
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::AggregationConfig {}).unwrap();
+    let aggregation_config: AggregationConfig = from_json(&query_res).unwrap();
+    assert_eq!(
+        aggregation_config,
+        AggregationConfig {
+            max_spread: Decimal::percent(1u64),
+            max_price_deviation: Decimal::percent(5u64),
+            reference_price_ttl: 86_400u64,
+        }
+    );
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::ReferencePrices {}).unwrap();
+    let reference_prices: Vec<ReferencePriceRecord> = from_json(&query_res).unwrap();
+    assert_eq!(
+        reference_prices,
+        vec![ReferencePriceRecord {
+            reference_price,
+            updated_at: mock_env().block.time.seconds(),
+        }]
+    );
+}
+
 #[test]
 fn test_migration() {
     let mut deps = mock_dependencies(&[]);
@@ -156,3 +352,266 @@ fn test_migration() {
         _ => panic!("should return ContractError::Std"),
     }
 }
+
+#[test]
+fn test_migration_to_v130() {
+    let mut deps = mock_dependencies(&[]);
+    let info = mock_info("owner", &[]);
+    mock_instantiation(deps.as_mut(), info).unwrap();
+
+    set_contract_version(
+        &mut deps.storage,
+        "white_whale-fee_collector".to_string(),
+        "1.2.0",
+    )
+    .unwrap();
+
+    assert!(AGGREGATION_CONFIG
+        .may_load(&deps.storage)
+        .unwrap()
+        .is_none());
+    assert!(POOLS_FEE_COLLECTION_CURSOR
+        .may_load(&deps.storage)
+        .unwrap()
+        .is_none());
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    assert_eq!(
+        AGGREGATION_CONFIG.load(&deps.storage).unwrap(),
+        AggregationConfig::default()
+    );
+    assert_eq!(
+        VAULTS_FEE_COLLECTION_CURSOR.load(&deps.storage).unwrap(),
+        FeeCollectionCursor::new(FactoryType::Vault {
+            start_after: None,
+            limit: None,
+        })
+    );
+    assert_eq!(
+        POOLS_FEE_COLLECTION_CURSOR.load(&deps.storage).unwrap(),
+        FeeCollectionCursor::new(FactoryType::Pool {
+            start_after: None,
+            start_after_variant: None,
+            limit: None,
+        })
+    );
+    assert_eq!(
+        TRIOS_FEE_COLLECTION_CURSOR.load(&deps.storage).unwrap(),
+        FeeCollectionCursor::new(FactoryType::Trio {
+            start_after: None,
+            limit: None,
+        })
+    );
+    assert_eq!(
+        get_contract_version(&deps.storage).unwrap().version,
+        env!("CARGO_PKG_VERSION").to_string()
+    );
+}
+
+#[test]
+fn test_update_take_rate_splits() {
+    let mut deps = mock_dependencies(&[]);
+    let info = mock_info("owner", &[]);
+    mock_instantiation(deps.as_mut(), info.clone()).unwrap();
+
+    // no splits and no take rate dao address, nobody gets the take rate
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::TakeRateSplits {}).unwrap();
+    let splits: Vec<TakeRateSplit> = from_json(&query_res).unwrap();
+    assert!(splits.is_empty());
+
+    let splits = vec![
+        TakeRateSplit {
+            recipient: TakeRateRecipient::Address {
+                address: "dao".to_string(),
+            },
+            share: Decimal::percent(60u64),
+        },
+        TakeRateSplit {
+            recipient: TakeRateRecipient::Burn {},
+            share: Decimal::percent(40u64),
+        },
+    ];
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("unauthorized", &[]),
+        ExecuteMsg::UpdateTakeRateSplits {
+            splits: splits.clone(),
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::Unauthorized"),
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // duplicated recipients are not allowed
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateTakeRateSplits {
+            splits: vec![
+                TakeRateSplit {
+                    recipient: TakeRateRecipient::Burn {},
+                    share: Decimal::percent(50u64),
+                },
+                TakeRateSplit {
+                    recipient: TakeRateRecipient::Burn {},
+                    share: Decimal::percent(50u64),
+                },
+            ],
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::InvalidTakeRateSplits"),
+        Err(ContractError::InvalidTakeRateSplits {}) => (),
+        _ => panic!("should return ContractError::InvalidTakeRateSplits"),
+    }
+
+    // zero shares are not allowed
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateTakeRateSplits {
+            splits: vec![
+                TakeRateSplit {
+                    recipient: TakeRateRecipient::Burn {},
+                    share: Decimal::one(),
+                },
+                TakeRateSplit {
+                    recipient: TakeRateRecipient::Address {
+                        address: "dao".to_string(),
+                    },
+                    share: Decimal::zero(),
+                },
+            ],
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::InvalidTakeRateSplits"),
+        Err(ContractError::InvalidTakeRateSplits {}) => (),
+        _ => panic!("should return ContractError::InvalidTakeRateSplits"),
+    }
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateTakeRateSplits {
+            splits: splits.clone(),
+        },
+    )
+    .unwrap();
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::TakeRateSplits {}).unwrap();
+    let stored_splits: Vec<TakeRateSplit> = from_json(&query_res).unwrap();
+    assert_eq!(stored_splits, splits);
+
+    // clearing the splits sends the whole take rate to the take rate dao address again
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateTakeRateSplits { splits: vec![] },
+    )
+    .unwrap();
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::TakeRateSplits {}).unwrap();
+    let stored_splits: Vec<TakeRateSplit> = from_json(&query_res).unwrap();
+    assert!(stored_splits.is_empty());
+}
+
+#[test]
+fn test_update_buyback_config() {
+    let mut deps = mock_dependencies(&[]);
+    let info = mock_info("owner", &[]);
+    mock_instantiation(deps.as_mut(), info.clone()).unwrap();
+
+    // the buyback is off by default
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::BuybackConfig {}).unwrap();
+    let buyback_config: Option<BuybackConfig> = from_json(&query_res).unwrap();
+    assert!(buyback_config.is_none());
+
+    let buyback_config = BuybackConfig {
+        burn_asset: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        share: Decimal::percent(10u64),
+    };
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("unauthorized", &[]),
+        ExecuteMsg::UpdateBuybackConfig {
+            buyback_config: Some(buyback_config.clone()),
+        },
+    );
+
+    match res {
+        Ok(_) => panic!("should return ContractError::Unauthorized"),
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    for share in [Decimal::zero(), Decimal::percent(101u64)] {
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ExecuteMsg::UpdateBuybackConfig {
+                buyback_config: Some(BuybackConfig {
+                    share,
+                    ..buyback_config.clone()
+                }),
+            },
+        );
+
+        match res {
+            Ok(_) => panic!("should return ContractError::InvalidBuybackShare"),
+            Err(ContractError::InvalidBuybackShare {}) => (),
+            _ => panic!("should return ContractError::InvalidBuybackShare"),
+        }
+    }
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::UpdateBuybackConfig {
+            buyback_config: Some(buyback_config.clone()),
+        },
+    )
+    .unwrap();
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::BuybackConfig {}).unwrap();
+    let stored_buyback_config: Option<BuybackConfig> = from_json(&query_res).unwrap();
+    assert_eq!(stored_buyback_config, Some(buyback_config));
+
+    // nothing was burned yet
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::TotalBurned {}).unwrap();
+    let total_burned: Vec<Asset> = from_json(&query_res).unwrap();
+    assert!(total_burned.is_empty());
+
+    // turn the buyback off
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info,
+        ExecuteMsg::UpdateBuybackConfig {
+            buyback_config: None,
+        },
+    )
+    .unwrap();
+
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::BuybackConfig {}).unwrap();
+    let buyback_config: Option<BuybackConfig> = from_json(&query_res).unwrap();
+    assert!(buyback_config.is_none());
+}
@@ -1,17 +1,20 @@
 use cosmwasm_std::{
-    to_json_binary, CosmosMsg, DepsMut, Env, MessageInfo, QueryRequest, ReplyOn, Response,
-    StdError, SubMsg, Timestamp, Uint64, WasmMsg, WasmQuery,
+    to_json_binary, CosmosMsg, DepsMut, Env, MessageInfo, ReplyOn, Response, SubMsg, Timestamp,
+    Uint64, WasmMsg,
 };
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 
-use white_whale_std::fee_distributor::Epoch;
+use white_whale_std::fee_distributor::{DistributionAsset, Epoch};
+use white_whale_std::ownership::{self, OwnershipAction};
 use white_whale_std::pool_network::asset;
-use white_whale_std::pool_network::asset::{Asset, AssetInfo};
-use white_whale_std::whale_lair::{BondingWeightResponse, QueryMsg};
 
 use crate::contract::EPOCH_CREATION_REPLY_ID;
-use crate::helpers::{validate_epoch_config, validate_grace_period};
-use crate::state::{get_current_epoch, query_claimable, CONFIG, EPOCHS, LAST_CLAIMED_EPOCH};
+use crate::helpers::{
+    get_epoch_rewards, validate_distribution_assets, validate_epoch_config, validate_grace_period,
+};
+use crate::state::{
+    get_current_epoch, query_claimable, CLAIM_HISTORY, CONFIG, EPOCHS, LAST_CLAIMED_EPOCH,
+};
 use crate::ContractError;
 
 /// Creates a new epoch, forwarding available tokens from epochs that are past the grace period.
@@ -61,7 +64,7 @@ pub fn create_new_epoch(deps: DepsMut, env: Env) -> Result<Response, ContractErr
                 contract_addr: config.fee_collector_addr.to_string(),
                 msg: to_json_binary(&white_whale_std::fee_collector::ExecuteMsg::ForwardFees {
                     epoch: new_epoch.clone(),
-                    forward_fees_as: config.distribution_asset,
+                    forward_fees_as: config.distribution_assets,
                 })?,
                 funds: vec![],
             }),
@@ -86,74 +89,42 @@ pub fn claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError
 
     let mut claimable_fees = vec![];
     for mut epoch in claimable_epochs.clone() {
-        let bonding_weight_response: BondingWeightResponse =
-            deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
-                contract_addr: config.bonding_contract_addr.to_string(),
-                msg: to_json_binary(&QueryMsg::Weight {
-                    address: info.sender.to_string(),
-                    timestamp: Some(epoch.start_time),
-                    global_index: Some(epoch.global_index.clone()),
-                })?,
-            }))?;
-
-        for fee in epoch.total.iter() {
-            let reward = fee
-                .amount
-                .checked_mul_floor(bonding_weight_response.share)?;
-
-            if reward.is_zero() {
-                // nothing to claim
-                continue;
-            }
-            // make sure the reward is sound
-            let _ = epoch
-                .available
-                .iter()
-                .find(|available_fee| available_fee.info == fee.info)
-                .map(|available_fee| {
-                    if reward > available_fee.amount {
-                        //todo maybe we can just skip this epoch and log something on the attributes instead
-                        // of returning an error and blocking the whole operation
-                        // this would "solve" the case when users unbond and then those who have not claimed
-                        // past epochs won't be able to do it as their rewards exceed the available claimable fees
-                        // cuz their weight increased in relation to the global weight
-                        return Err(ContractError::InvalidReward {});
-                    }
-                    Ok(())
-                })
-                .ok_or_else(|| StdError::generic_err("Invalid fee"))?;
-
-            // add the reward to the claimable fees
-            claimable_fees = asset::aggregate_assets(
-                claimable_fees,
-                vec![Asset {
-                    info: fee.info.clone(),
-                    amount: reward,
-                }],
-            )?;
+        let epoch_rewards = get_epoch_rewards(
+            deps.as_ref(),
+            &config.bonding_contract_addr,
+            &info.sender,
+            &epoch,
+        )?;
+
+        if epoch_rewards.is_empty() {
+            continue;
+        }
 
+        for reward in epoch_rewards.iter() {
             // modify the epoch to reflect the new available and claimed amount
             for available_fee in epoch.available.iter_mut() {
-                if available_fee.info == fee.info {
-                    available_fee.amount = available_fee.amount.checked_sub(reward)?;
+                if available_fee.info == reward.info {
+                    available_fee.amount = available_fee.amount.checked_sub(reward.amount)?;
                 }
             }
 
-            if epoch.claimed.is_empty() {
-                epoch.claimed = vec![Asset {
-                    info: fee.info.clone(),
-                    amount: reward,
-                }];
-            } else {
-                for claimed_fee in epoch.claimed.iter_mut() {
-                    if claimed_fee.info == fee.info {
-                        claimed_fee.amount = claimed_fee.amount.checked_add(reward)?;
-                    }
+            match epoch
+                .claimed
+                .iter_mut()
+                .find(|claimed_fee| claimed_fee.info == reward.info)
+            {
+                Some(claimed_fee) => {
+                    claimed_fee.amount = claimed_fee.amount.checked_add(reward.amount)?;
                 }
+                None => epoch.claimed.push(reward.clone()),
             }
-
-            EPOCHS.save(deps.storage, &epoch.id.to_be_bytes(), &epoch)?;
         }
+
+        EPOCHS.save(deps.storage, &epoch.id.to_be_bytes(), &epoch)?;
+
+        // add the rewards to the claimable fees and keep record of what the user claimed on this epoch
+        claimable_fees = asset::aggregate_assets(claimable_fees, epoch_rewards.clone())?;
+        CLAIM_HISTORY.save(deps.storage, (&info.sender, epoch.id.u64()), &epoch_rewards)?;
     }
 
     // update the last claimed epoch for the user
@@ -175,11 +146,10 @@ pub fn claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError
 pub fn update_config(
     deps: DepsMut,
     info: MessageInfo,
-    owner: Option<String>,
     bonding_contract_addr: Option<String>,
     fee_collector_addr: Option<String>,
     grace_period: Option<Uint64>,
-    distribution_asset: Option<AssetInfo>,
+    distribution_assets: Option<Vec<DistributionAsset>>,
     epoch_config: Option<EpochConfig>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
@@ -188,10 +158,6 @@ pub fn update_config(
         return Err(ContractError::Unauthorized {});
     }
 
-    if let Some(owner) = owner {
-        config.owner = deps.api.addr_validate(&owner)?;
-    }
-
     if let Some(bonding_contract_addr) = bonding_contract_addr {
         config.bonding_contract_addr = deps.api.addr_validate(&bonding_contract_addr)?;
     }
@@ -200,8 +166,9 @@ pub fn update_config(
         config.fee_collector_addr = deps.api.addr_validate(&fee_collector_addr)?;
     }
 
-    if let Some(distribution_asset) = distribution_asset {
-        config.distribution_asset = distribution_asset;
+    if let Some(distribution_assets) = distribution_assets {
+        validate_distribution_assets(&distribution_assets)?;
+        config.distribution_assets = distribution_assets;
     }
 
     if let Some(epoch_config) = epoch_config {
@@ -230,7 +197,34 @@ pub fn update_config(
         ),
         ("fee_collector_addr", config.fee_collector_addr.to_string()),
         ("grace_period", config.grace_period.to_string()),
-        ("distribution_asset", config.distribution_asset.to_string()),
+        (
+            "distribution_assets",
+            config
+                .distribution_assets
+                .iter()
+                .map(|distribution_asset| distribution_asset.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
         ("epoch_config", config.epoch_config.to_string()),
     ]))
 }
+
+/// Proposes, accepts or cancels a transfer of the ownership of the contract
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
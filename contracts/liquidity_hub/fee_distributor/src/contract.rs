@@ -8,7 +8,7 @@ use cw2::{get_contract_version, set_contract_version};
 use cw_utils::parse_reply_execute_data;
 
 use crate::error::ContractError;
-use crate::helpers::{validate_epoch_config, validate_grace_period};
+use crate::helpers::{validate_distribution_assets, validate_epoch_config, validate_grace_period};
 use crate::state::{get_expiring_epoch, CONFIG, EPOCHS};
 use crate::{commands, migrations, queries, state};
 use semver::Version;
@@ -16,6 +16,7 @@ use white_whale_std::fee_collector::ForwardFeesResponse;
 use white_whale_std::fee_distributor::{
     Config, Epoch, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
+use white_whale_std::ownership::query_ownership_proposal;
 use white_whale_std::pool_network::asset;
 use white_whale_std::whale_lair::GlobalIndex;
 use white_whale_std::whale_lair::QueryMsg as LairQueryMsg;
@@ -36,6 +37,7 @@ pub fn instantiate(
 
     validate_grace_period(&msg.grace_period)?;
     validate_epoch_config(&msg.epoch_config)?;
+    validate_distribution_assets(&msg.distribution_assets)?;
 
     let config = Config {
         owner: deps.api.addr_validate(info.sender.as_str())?,
@@ -43,7 +45,7 @@ pub fn instantiate(
         fee_collector_addr: deps.api.addr_validate(msg.fee_collector_addr.as_str())?,
         grace_period: msg.grace_period,
         epoch_config: msg.epoch_config,
-        distribution_asset: msg.distribution_asset,
+        distribution_assets: msg.distribution_assets,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -58,7 +60,15 @@ pub fn instantiate(
         .add_attribute("fee_collector_addr", config.fee_collector_addr.as_str())
         .add_attribute("grace_period", config.grace_period.to_string())
         .add_attribute("epoch_config", config.epoch_config.to_string())
-        .add_attribute("distribution_asset", config.distribution_asset.to_string()))
+        .add_attribute(
+            "distribution_assets",
+            config
+                .distribution_assets
+                .iter()
+                .map(|distribution_asset| distribution_asset.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -131,22 +141,21 @@ pub fn execute(
         ExecuteMsg::NewEpoch {} => commands::create_new_epoch(deps, env),
         ExecuteMsg::Claim {} => commands::claim(deps, info),
         ExecuteMsg::UpdateConfig {
-            owner,
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
-            distribution_asset,
+            distribution_assets,
             epoch_config,
         } => commands::update_config(
             deps,
             info,
-            owner,
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
-            distribution_asset,
+            distribution_assets,
             epoch_config,
         ),
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
     }
 }
 
@@ -161,6 +170,22 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             deps,
             &deps.api.addr_validate(&address)?,
         )?)?),
+        QueryMsg::ClaimHistory {
+            address,
+            start_after,
+            limit,
+        } => Ok(to_json_binary(&queries::query_claim_history(
+            deps,
+            address,
+            start_after,
+            limit,
+        )?)?),
+        QueryMsg::EstimatedRewards { address } => Ok(to_json_binary(
+            &queries::query_estimated_rewards(deps, address)?,
+        )?),
+        QueryMsg::OwnershipProposal {} => {
+            Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+        }
     }
 }
 
@@ -180,26 +205,28 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
         });
     }
 
-    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let mut fees_refund_messages = vec![];
 
     if storage_version < Version::parse("0.9.0")? {
         migrations::migrate_to_v090(deps.branch())?;
     }
 
     if storage_version == Version::parse("0.9.0")? {
-        let fees_refund_messages = migrations::migrate_to_v091(deps.branch())?;
-        return Ok(Response::default()
-            .add_messages(fees_refund_messages)
-            .add_attribute("action", "migrate"));
+        fees_refund_messages.extend(migrations::migrate_to_v091(deps.branch())?);
     }
 
     #[cfg(feature = "osmosis")]
     if storage_version == Version::parse("0.9.1")? {
-        let fees_refund_messages = migrations::migrate_to_v091_hotfix(deps.branch())?;
-        return Ok(Response::default()
-            .add_messages(fees_refund_messages)
-            .add_attribute("action", "migrate"));
+        fees_refund_messages.extend(migrations::migrate_to_v091_hotfix(deps.branch())?);
     }
 
-    Ok(Response::default())
+    if storage_version < Version::parse("0.10.0")? {
+        migrations::migrate_to_v0100(deps.branch())?;
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::default()
+        .add_messages(fees_refund_messages)
+        .add_attribute("action", "migrate"))
 }
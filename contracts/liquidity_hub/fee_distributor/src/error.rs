@@ -46,6 +46,9 @@ pub enum ContractError {
     #[error("Can't lower the grace period.")]
     GracePeriodDecrease {},
 
+    #[error("Invalid distribution assets. There must be at least one native asset, without duplicates, and the ratios must add up to 1.")]
+    InvalidDistributionAssets {},
+
     #[error("{0}")]
     DivideByZeroError(#[from] DivideByZeroError),
 
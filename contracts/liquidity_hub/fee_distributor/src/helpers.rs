@@ -1,7 +1,12 @@
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{
+    to_json_binary, Addr, Decimal, Deps, QueryRequest, StdError, StdResult, Uint64, WasmQuery,
+};
 
 use crate::ContractError;
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::fee_distributor::{DistributionAsset, Epoch};
+use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::whale_lair::{BondingWeightResponse, QueryMsg};
 
 const MAX_GRACE_PERIOD: u64 = 30u64;
 pub const DAY_IN_NANOSECONDS: u64 = 86_400_000_000_000u64;
@@ -23,3 +28,97 @@ pub fn validate_epoch_config(epoch_config: &EpochConfig) -> Result<(), ContractE
 
     Ok(())
 }
+
+/// Validates the distribution assets. There must be at least one, they must be native tokens, as
+/// the fee collector can only forward those, they can't be duplicated and their aggregation ratios
+/// must add up to 1.
+pub fn validate_distribution_assets(
+    distribution_assets: &[DistributionAsset],
+) -> Result<(), ContractError> {
+    if distribution_assets.is_empty() {
+        return Err(ContractError::InvalidDistributionAssets {});
+    }
+
+    let mut total_ratio = Decimal::zero();
+    for (i, distribution_asset) in distribution_assets.iter().enumerate() {
+        if distribution_asset.aggregation_ratio.is_zero()
+            || !distribution_asset.asset_info.is_native_token()
+            || distribution_assets[..i]
+                .iter()
+                .any(|other| other.asset_info == distribution_asset.asset_info)
+        {
+            return Err(ContractError::InvalidDistributionAssets {});
+        }
+
+        total_ratio = total_ratio.checked_add(distribution_asset.aggregation_ratio)?;
+    }
+
+    if total_ratio != Decimal::one() {
+        return Err(ContractError::InvalidDistributionAssets {});
+    }
+
+    Ok(())
+}
+
+/// Queries the bonding weight of the given address at the time the given [Epoch] was created.
+pub fn query_epoch_weight(
+    deps: Deps,
+    bonding_contract_addr: &Addr,
+    address: &Addr,
+    epoch: &Epoch,
+) -> StdResult<BondingWeightResponse> {
+    deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: bonding_contract_addr.to_string(),
+        msg: to_json_binary(&QueryMsg::Weight {
+            address: address.to_string(),
+            timestamp: Some(epoch.start_time),
+            global_index: Some(epoch.global_index.clone()),
+        })?,
+    }))
+}
+
+/// Computes the rewards the given address gets on the given [Epoch] based on its bonding weight.
+/// Used both when claiming and when estimating the rewards, so they always agree.
+pub fn get_epoch_rewards(
+    deps: Deps,
+    bonding_contract_addr: &Addr,
+    address: &Addr,
+    epoch: &Epoch,
+) -> Result<Vec<Asset>, ContractError> {
+    let bonding_weight_response = query_epoch_weight(deps, bonding_contract_addr, address, epoch)?;
+
+    let mut rewards = vec![];
+    for fee in epoch.total.iter() {
+        let reward = fee
+            .amount
+            .checked_mul_floor(bonding_weight_response.share)?;
+
+        if reward.is_zero() {
+            // nothing to claim
+            continue;
+        }
+
+        // make sure the reward is sound
+        let available_fee = epoch
+            .available
+            .iter()
+            .find(|available_fee| available_fee.info == fee.info)
+            .ok_or_else(|| StdError::generic_err("Invalid fee"))?;
+
+        if reward > available_fee.amount {
+            //todo maybe we can just skip this epoch and log something on the attributes instead
+            // of returning an error and blocking the whole operation
+            // this would "solve" the case when users unbond and then those who have not claimed
+            // past epochs won't be able to do it as their rewards exceed the available claimable fees
+            // cuz their weight increased in relation to the global weight
+            return Err(ContractError::InvalidReward {});
+        }
+
+        rewards.push(Asset {
+            info: fee.info.clone(),
+            amount: reward,
+        });
+    }
+
+    Ok(rewards)
+}
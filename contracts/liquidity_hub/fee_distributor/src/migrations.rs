@@ -2,18 +2,48 @@
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, CosmosMsg, DepsMut, Order, QueryRequest, StdError, StdResult, Timestamp,
-    Uint64, WasmQuery,
+    to_json_binary, Addr, CosmosMsg, Decimal, Deps, DepsMut, Order, QueryRequest, StdError,
+    StdResult, Timestamp, Uint64, WasmQuery,
 };
-use cw_storage_plus::Map;
+use cw_storage_plus::{Item, Map};
 
-use white_whale_std::fee_distributor::Epoch;
+use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::fee_distributor::{Config, DistributionAsset, Epoch};
 use white_whale_std::pool_network::asset;
-use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::whale_lair::GlobalIndex;
 use white_whale_std::whale_lair::QueryMsg as LairQueryMsg;
 
-use crate::state::{get_claimable_epochs, CONFIG, EPOCHS};
+use crate::state::{CONFIG, EPOCHS};
+
+/// The config as of v0.9.*, before the basket of distribution assets was added. The migrations
+/// prior to v0.10.0 run before the config is migrated, so they have to use this one.
+#[cw_serde]
+struct ConfigV09 {
+    pub owner: Addr,
+    pub bonding_contract_addr: Addr,
+    pub fee_collector_addr: Addr,
+    pub grace_period: Uint64,
+    pub epoch_config: EpochConfig,
+    pub distribution_asset: AssetInfo,
+}
+
+const CONFIG_V09: Item<ConfigV09> = Item::new("config");
+
+/// Returns the epochs within the grace period, as [crate::state::get_claimable_epochs] does, using
+/// the v0.9.* config.
+fn get_claimable_epochs_v09(deps: Deps) -> StdResult<Vec<Epoch>> {
+    let grace_period = CONFIG_V09.load(deps.storage)?.grace_period;
+
+    EPOCHS
+        .range(deps.storage, None, None, Order::Descending)
+        .take(grace_period.u64() as usize)
+        .map(|item| {
+            let (_, epoch) = item?;
+            Ok(epoch)
+        })
+        .collect()
+}
 
 /// Migrates state from the first iteration, v0.8.* to v0.9.0, which includes the global index in
 /// the Epoch. This was done to fix bonding issues.
@@ -43,7 +73,7 @@ pub fn migrate_to_v090(deps: DepsMut) -> Result<(), StdError> {
         })
         .collect::<StdResult<Vec<EpochV08>>>()?;
 
-    let bonding_contract_addr = CONFIG.load(deps.storage)?.bonding_contract_addr;
+    let bonding_contract_addr = CONFIG_V09.load(deps.storage)?.bonding_contract_addr;
     // Query the current global index
     let global_index: GlobalIndex = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
         contract_addr: bonding_contract_addr.to_string(),
@@ -69,17 +99,16 @@ pub fn migrate_to_v090(deps: DepsMut) -> Result<(), StdError> {
 
 /// Fixes the broken state for Epochs created prior to the v0.9.0 migration.
 pub fn migrate_to_v091(deps: DepsMut) -> Result<Vec<CosmosMsg>, StdError> {
-    let claimable_epochs = get_claimable_epochs(deps.as_ref())?;
+    let claimable_epochs = get_claimable_epochs_v09(deps.as_ref())?;
 
     // 14 June 2023 16:00:00 UTC
     let migration_timestamp = Timestamp::from_seconds(1686758400);
     let mut faulty_epochs = claimable_epochs
-        .epochs
         .into_iter()
         .filter(|epoch| epoch.start_time.seconds() < migration_timestamp.seconds())
         .collect::<Vec<Epoch>>();
 
-    let fee_collector_addr = CONFIG.load(deps.storage)?.fee_collector_addr;
+    let fee_collector_addr = CONFIG_V09.load(deps.storage)?.fee_collector_addr;
 
     // collect all available funds on faulty epochs and send them back to the fee collector, to be
     // redistributed on the next (new) epoch
@@ -108,15 +137,14 @@ pub fn migrate_to_v091(deps: DepsMut) -> Result<Vec<CosmosMsg>, StdError> {
 #[cfg(feature = "osmosis")]
 /// Fixes the broken state for the first epoch on osmosis.
 pub fn migrate_to_v091_hotfix(deps: DepsMut) -> Result<Vec<CosmosMsg>, StdError> {
-    let claimable_epochs = get_claimable_epochs(deps.as_ref())?;
+    let claimable_epochs = get_claimable_epochs_v09(deps.as_ref())?;
 
     let mut faulty_epoch = claimable_epochs
-        .epochs
         .into_iter()
         .find(|epoch| epoch.id == Uint64::from(1u64))
         .ok_or(StdError::generic_err("Epoch not found"))?;
 
-    let fee_collector_addr = CONFIG.load(deps.storage)?.fee_collector_addr;
+    let fee_collector_addr = CONFIG_V09.load(deps.storage)?.fee_collector_addr;
 
     // collect all available funds on faulty epochs and send them back to the fee collector, to be
     // redistributed on the next (new) epoch
@@ -138,3 +166,25 @@ pub fn migrate_to_v091_hotfix(deps: DepsMut) -> Result<Vec<CosmosMsg>, StdError>
 
     Ok(messages)
 }
+
+/// Migrates the config to v0.10.0, which replaces the single distribution asset with a basket of
+/// distribution assets. The existing distribution asset takes the whole ratio.
+pub fn migrate_to_v0100(deps: DepsMut) -> Result<(), StdError> {
+    let config_v09 = CONFIG_V09.load(deps.storage)?;
+
+    let config = Config {
+        owner: config_v09.owner,
+        bonding_contract_addr: config_v09.bonding_contract_addr,
+        fee_collector_addr: config_v09.fee_collector_addr,
+        grace_period: config_v09.grace_period,
+        epoch_config: config_v09.epoch_config,
+        distribution_assets: vec![DistributionAsset {
+            asset_info: config_v09.distribution_asset,
+            aggregation_ratio: Decimal::one(),
+        }],
+    };
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(())
+}
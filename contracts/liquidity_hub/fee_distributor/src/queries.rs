@@ -1,8 +1,75 @@
-use crate::state::CONFIG;
-use cosmwasm_std::{Deps, StdResult};
-use white_whale_std::fee_distributor::Config;
+use cosmwasm_std::{Deps, Order, StdError, StdResult, Uint64};
+use cw_storage_plus::Bound;
+
+use white_whale_std::fee_distributor::{
+    ClaimHistoryResponse, Config, EpochRewards, EstimatedRewardsResponse,
+};
+use white_whale_std::pool_network::asset;
+
+use crate::helpers::get_epoch_rewards;
+use crate::state::{query_claimable, CLAIM_HISTORY, CONFIG};
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
 
 /// Queries the [Config] of the contract
 pub fn query_config(deps: Deps) -> StdResult<Config> {
     CONFIG.load(deps.storage)
 }
+
+/// Queries the rewards claimed by the given address on each epoch.
+pub fn query_claim_history(
+    deps: Deps,
+    address: String,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> StdResult<ClaimHistoryResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|epoch_id| Bound::exclusive(epoch_id.u64()));
+
+    let claims = CLAIM_HISTORY
+        .prefix(&address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (epoch_id, rewards) = item?;
+            Ok(EpochRewards {
+                epoch_id: Uint64::new(epoch_id),
+                rewards,
+            })
+        })
+        .collect::<StdResult<Vec<EpochRewards>>>()?;
+
+    Ok(ClaimHistoryResponse { claims })
+}
+
+/// Estimates the rewards the given address would get by claiming now, using its bonding weight on
+/// each of the claimable epochs. Errors in the same cases claiming would, i.e. when the rewards
+/// exceed what is left to be claimed on an epoch.
+pub fn query_estimated_rewards(deps: Deps, address: String) -> StdResult<EstimatedRewardsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let claimable_epochs = query_claimable(deps, &address)?.epochs;
+
+    let mut total = vec![];
+    let mut epochs = vec![];
+    for epoch in claimable_epochs {
+        let rewards = get_epoch_rewards(deps, &config.bonding_contract_addr, &address, &epoch)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        if rewards.is_empty() {
+            continue;
+        }
+
+        total = asset::aggregate_assets(total, rewards.clone())?;
+        epochs.push(EpochRewards {
+            epoch_id: epoch.id,
+            rewards,
+        });
+    }
+
+    Ok(EstimatedRewardsResponse { total, epochs })
+}
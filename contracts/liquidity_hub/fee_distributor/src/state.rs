@@ -2,11 +2,14 @@ use cosmwasm_std::{to_json_binary, Addr, Deps, Order, QueryRequest, StdResult, U
 use cw_storage_plus::{Item, Map};
 
 use white_whale_std::fee_distributor::{ClaimableEpochsResponse, Config, Epoch, EpochResponse};
+use white_whale_std::pool_network::asset::Asset;
 use white_whale_std::whale_lair::{BondedResponse, QueryMsg};
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const LAST_CLAIMED_EPOCH: Map<&Addr, Uint64> = Map::new("last_claimed_epoch");
 pub const EPOCHS: Map<&[u8], Epoch> = Map::new("epochs");
+/// Rewards claimed by each address on each epoch, keyed by (address, epoch_id).
+pub const CLAIM_HISTORY: Map<(&Addr, u64), Vec<Asset>> = Map::new("claim_history");
 
 /// Returns the current epoch, which is the last on the EPOCHS map.
 pub fn get_current_epoch(deps: Deps) -> StdResult<EpochResponse> {
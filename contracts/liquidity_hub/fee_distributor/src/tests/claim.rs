@@ -1,8 +1,12 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{Addr, Decimal, StdError, Timestamp, Uint128, Uint64};
+
+use white_whale_std::fee_distributor::{Epoch, EpochRewards};
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
 use crate::tests::robot::TestingRobot;
 use crate::tests::test_helpers;
+use crate::ContractError;
 
 #[test]
 fn test_claimable_epochs() {
@@ -29,3 +33,198 @@ fn test_claimable_epochs() {
             }
         });
 }
+
+fn uwhale(amount: u128) -> Vec<Asset> {
+    vec![Asset {
+        info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        amount: Uint128::new(amount),
+    }]
+}
+
+/// Epochs with the given fees to be distributed, none of them claimed yet.
+fn unclaimed_epochs(fees: Vec<Vec<Asset>>) -> Vec<Epoch> {
+    fees.into_iter()
+        .enumerate()
+        .map(|(i, fees)| Epoch {
+            id: Uint64::new(i as u64 + 1),
+            start_time: Timestamp::from_seconds(1678726800 + i as u64 * 86_400),
+            total: fees.clone(),
+            available: fees,
+            claimed: vec![],
+            global_index: Default::default(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_claim_history() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let address = Addr::unchecked("address");
+
+    robot
+        .instantiate_default()
+        .with_bonding_share(Decimal::percent(50))
+        .add_epochs_to_state(unclaimed_epochs(vec![uwhale(1_000), uwhale(2_000)]))
+        .claim(address.clone(), |res| {
+            res.unwrap();
+        })
+        .query_claim_history(address.clone(), None, None, |res| {
+            let (_, response) = res.unwrap();
+
+            assert_eq!(
+                response.claims,
+                vec![
+                    EpochRewards {
+                        epoch_id: Uint64::new(1),
+                        rewards: uwhale(500),
+                    },
+                    EpochRewards {
+                        epoch_id: Uint64::new(2),
+                        rewards: uwhale(1_000),
+                    },
+                ]
+            );
+        })
+        .query_claim_history(address.clone(), Some(Uint64::new(1)), Some(1), |res| {
+            let (_, response) = res.unwrap();
+
+            assert_eq!(
+                response.claims,
+                vec![EpochRewards {
+                    epoch_id: Uint64::new(2),
+                    rewards: uwhale(1_000),
+                }]
+            );
+        })
+        .query_claim_history(Addr::unchecked("nobody"), None, None, |res| {
+            let (_, response) = res.unwrap();
+            assert!(response.claims.is_empty());
+        })
+        // there's nothing left to claim, so the history stays the same
+        .claim(address.clone(), |res| match res {
+            Err(ContractError::NothingToClaim {}) => {}
+            _ => panic!("should return ContractError::NothingToClaim"),
+        })
+        .query_claim_history(address, None, None, |res| {
+            let (_, response) = res.unwrap();
+            assert_eq!(response.claims.len(), 2);
+        });
+}
+
+#[test]
+fn test_estimated_rewards() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let address = Addr::unchecked("address");
+
+    robot
+        .instantiate_default()
+        .with_bonding_share(Decimal::percent(50))
+        .add_epochs_to_state(unclaimed_epochs(vec![uwhale(1_000), uwhale(2_000)]))
+        .query_estimated_rewards(address.clone(), |res| {
+            let (_, response) = res.unwrap();
+
+            assert_eq!(response.total, uwhale(1_500));
+            assert_eq!(
+                response.epochs,
+                vec![
+                    EpochRewards {
+                        epoch_id: Uint64::new(2),
+                        rewards: uwhale(1_000),
+                    },
+                    EpochRewards {
+                        epoch_id: Uint64::new(1),
+                        rewards: uwhale(500),
+                    },
+                ]
+            );
+        })
+        // the estimation matches what is claimed
+        .claim(address.clone(), |res| {
+            let response = res.unwrap();
+            assert_eq!(
+                response.messages[0].msg,
+                uwhale(1_500)[0]
+                    .clone()
+                    .into_msg(Addr::unchecked("address"))
+                    .unwrap()
+            );
+        })
+        .query_estimated_rewards(address, |res| {
+            let (_, response) = res.unwrap();
+            assert!(response.total.is_empty());
+            assert!(response.epochs.is_empty());
+        });
+}
+
+#[test]
+fn test_estimated_rewards_exceeding_available_fees() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let address = Addr::unchecked("address");
+
+    let mut epochs = unclaimed_epochs(vec![uwhale(1_000)]);
+    // most of the fees were already claimed, so the reward exceeds what is available
+    epochs[0].available = uwhale(100);
+
+    robot
+        .instantiate_default()
+        .with_bonding_share(Decimal::percent(50))
+        .add_epochs_to_state(epochs)
+        .query_estimated_rewards(address.clone(), |res| match res {
+            Err(err) => assert_eq!(
+                err,
+                StdError::generic_err(ContractError::InvalidReward {}.to_string())
+            ),
+            Ok(_) => panic!("should return ContractError::InvalidReward"),
+        })
+        .claim(address, |res| match res {
+            Err(ContractError::InvalidReward {}) => {}
+            _ => panic!("should return ContractError::InvalidReward"),
+        });
+}
+
+#[test]
+fn test_claim_multiple_distribution_assets() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+    let fees = |uwhale: u128, uusdc: u128| {
+        vec![
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(uwhale),
+            },
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uusdc".to_string(),
+                },
+                amount: Uint128::new(uusdc),
+            },
+        ]
+    };
+
+    robot
+        .instantiate_default()
+        .with_bonding_share(Decimal::percent(25))
+        .add_epochs_to_state(unclaimed_epochs(vec![fees(1_000, 400)]))
+        .claim(Addr::unchecked("alice"), |res| {
+            res.unwrap();
+        })
+        .query_epoch(Uint64::new(1), |res| {
+            let (_, epoch) = res.unwrap();
+
+            // every distribution asset is recorded as claimed, not only the first one
+            assert_eq!(epoch.claimed, fees(250, 100));
+            assert_eq!(epoch.available, fees(750, 300));
+        })
+        .claim(Addr::unchecked("bob"), |res| {
+            res.unwrap();
+        })
+        .query_epoch(Uint64::new(1), |res| {
+            let (_, epoch) = res.unwrap();
+
+            assert_eq!(epoch.claimed, fees(500, 200));
+            assert_eq!(epoch.available, fees(500, 200));
+        });
+}
@@ -1,9 +1,9 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{Timestamp, Uint64};
+use cosmwasm_std::{Decimal, Timestamp, Uint64};
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 
 use crate::ContractError;
-use white_whale_std::fee_distributor::Epoch;
+use white_whale_std::fee_distributor::{DistributionAsset, Epoch};
 use white_whale_std::pool_network::asset::AssetInfo;
 
 use crate::tests::robot::TestingRobot;
@@ -38,9 +38,12 @@ fn test_expiring_epoch() {
 fn test_create_genesis_epoch() {
     let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
     let grace_period = Uint64::new(2);
-    let distribution_asset = AssetInfo::NativeToken {
-        denom: "uwhale".to_string(),
-    };
+    let distribution_assets = vec![DistributionAsset {
+        asset_info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        aggregation_ratio: Decimal::one(),
+    }];
     let epoch_config = EpochConfig {
         duration: Uint64::new(86_400_000_000_000u64), // a day
         genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
@@ -55,7 +58,7 @@ fn test_create_genesis_epoch() {
             "fee_collector_addr".to_string(),
             grace_period,
             epoch_config.clone(),
-            distribution_asset.clone(),
+            distribution_assets.clone(),
         )
         .create_new_epoch(mock_info("owner", &[]), |res| {
             let err = res.unwrap_err();
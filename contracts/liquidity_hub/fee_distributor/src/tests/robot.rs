@@ -1,14 +1,20 @@
 #![allow(dead_code)]
 use cosmwasm_std::testing::{mock_info, MockApi, MockQuerier, MockStorage};
 use cosmwasm_std::{
-    from_json, Addr, Empty, Env, MessageInfo, OwnedDeps, Response, StdResult, Uint64,
+    from_json, to_json_binary, Addr, ContractResult, Decimal, Empty, Env, MessageInfo, OwnedDeps,
+    Response, StdResult, SystemResult, Uint128, Uint64, WasmQuery,
 };
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 
 use white_whale_std::fee_distributor::{
-    ClaimableEpochsResponse, Config, Epoch, EpochResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+    ClaimHistoryResponse, ClaimableEpochsResponse, Config, DistributionAsset, Epoch, EpochResponse,
+    EstimatedRewardsResponse, ExecuteMsg, InstantiateMsg, QueryMsg,
+};
+use white_whale_std::ownership::OwnershipAction;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::whale_lair::{
+    BondedResponse, BondingWeightResponse, QueryMsg as BondingQueryMsg,
 };
-use white_whale_std::pool_network::asset::AssetInfo;
 
 use crate::contract::{execute, instantiate, query};
 use crate::state::{get_expiring_epoch, EPOCHS, LAST_CLAIMED_EPOCH};
@@ -36,9 +42,12 @@ impl TestingRobot {
                 duration: Uint64::new(86_400_000_000_000u64), // a day
                 genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
             },
-            distribution_asset: AssetInfo::NativeToken {
-                denom: "uwhale".to_string(),
-            },
+            distribution_assets: vec![DistributionAsset {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                aggregation_ratio: Decimal::one(),
+            }],
         };
 
         instantiate(
@@ -59,14 +68,14 @@ impl TestingRobot {
         fee_collector_addr: String,
         grace_period: Uint64,
         epoch_config: EpochConfig,
-        distribution_asset: AssetInfo,
+        distribution_assets: Vec<DistributionAsset>,
     ) -> &mut Self {
         let msg = InstantiateMsg {
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
             epoch_config,
-            distribution_asset,
+            distribution_assets,
         };
 
         instantiate(self.owned_deps.as_mut(), self.env.clone(), info, msg).unwrap();
@@ -81,14 +90,14 @@ impl TestingRobot {
         fee_collector_addr: String,
         grace_period: Uint64,
         epoch_config: EpochConfig,
-        distribution_asset: AssetInfo,
+        distribution_assets: Vec<DistributionAsset>,
     ) -> &mut Self {
         let msg = InstantiateMsg {
             bonding_contract_addr,
             fee_collector_addr,
             grace_period,
             epoch_config,
-            distribution_asset,
+            distribution_assets,
         };
 
         instantiate(self.owned_deps.as_mut(), self.env.clone(), info, msg).unwrap_err();
@@ -121,6 +130,43 @@ impl TestingRobot {
         self
     }
 
+    /// Mocks the bonding contract, so every address has bonded and has the given share of the
+    /// global weight on every epoch.
+    pub(crate) fn with_bonding_share(&mut self, share: Decimal) -> &mut Self {
+        self.owned_deps
+            .querier
+            .update_wasm(move |query| match query {
+                WasmQuery::Smart { msg, .. } => {
+                    let response = match from_json(msg).unwrap() {
+                        BondingQueryMsg::Bonded { .. } => to_json_binary(&BondedResponse {
+                            total_bonded: Uint128::new(1_000u128),
+                            bonded_assets: vec![Asset {
+                                info: AssetInfo::NativeToken {
+                                    denom: "ampWHALE".to_string(),
+                                },
+                                amount: Uint128::new(1_000u128),
+                            }],
+                            first_bonded_epoch_id: Uint64::zero(),
+                        }),
+                        BondingQueryMsg::Weight {
+                            address, timestamp, ..
+                        } => to_json_binary(&BondingWeightResponse {
+                            address,
+                            weight: Uint128::new(1_000u128),
+                            global_weight: Uint128::new(1_000u128),
+                            share,
+                            timestamp: timestamp.unwrap_or_default(),
+                        }),
+                        _ => panic!("unexpected query to the bonding contract"),
+                    };
+                    SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+                }
+                _ => panic!("unexpected wasm query"),
+            });
+
+        self
+    }
+
     pub(crate) fn update_config(
         &mut self,
         info: MessageInfo,
@@ -128,11 +174,10 @@ impl TestingRobot {
         response: impl Fn(Result<Response, ContractError>),
     ) -> &mut Self {
         let msg = ExecuteMsg::UpdateConfig {
-            owner: Some(config.owner.to_string()),
             bonding_contract_addr: Some(config.bonding_contract_addr.to_string()),
             fee_collector_addr: Some(config.fee_collector_addr.to_string()),
             grace_period: Some(config.grace_period),
-            distribution_asset: Some(config.distribution_asset),
+            distribution_assets: Some(config.distribution_assets),
             epoch_config: Some(config.epoch_config),
         };
 
@@ -146,6 +191,37 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn update_ownership(
+        &mut self,
+        info: MessageInfo,
+        action: OwnershipAction,
+        response: impl Fn(Result<Response, ContractError>),
+    ) -> &mut Self {
+        response(execute(
+            self.owned_deps.as_mut(),
+            self.env.clone(),
+            info,
+            ExecuteMsg::UpdateOwnership(action),
+        ));
+
+        self
+    }
+
+    pub(crate) fn claim(
+        &mut self,
+        sender: Addr,
+        response: impl Fn(Result<Response, ContractError>),
+    ) -> &mut Self {
+        response(execute(
+            self.owned_deps.as_mut(),
+            self.env.clone(),
+            mock_info(sender.as_str(), &[]),
+            ExecuteMsg::Claim {},
+        ));
+
+        self
+    }
+
     pub(crate) fn create_new_epoch(
         &mut self,
         info: MessageInfo,
@@ -228,6 +304,51 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn query_claim_history(
+        &mut self,
+        address: Addr,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+        response: impl Fn(StdResult<(&mut Self, ClaimHistoryResponse)>),
+    ) -> &mut Self {
+        let query_res = query(
+            self.owned_deps.as_ref(),
+            self.env.clone(),
+            QueryMsg::ClaimHistory {
+                address: address.to_string(),
+                start_after,
+                limit,
+            },
+        )
+        .unwrap();
+        let res: ClaimHistoryResponse = from_json(&query_res).unwrap();
+
+        response(Ok((self, res)));
+
+        self
+    }
+
+    pub(crate) fn query_estimated_rewards(
+        &mut self,
+        address: Addr,
+        response: impl Fn(StdResult<(&mut Self, EstimatedRewardsResponse)>),
+    ) -> &mut Self {
+        let query_res = query(
+            self.owned_deps.as_ref(),
+            self.env.clone(),
+            QueryMsg::EstimatedRewards {
+                address: address.to_string(),
+            },
+        );
+
+        match query_res.and_then(|query_res| from_json::<EstimatedRewardsResponse>(&query_res)) {
+            Ok(res) => response(Ok((self, res))),
+            Err(err) => response(Err(err)),
+        }
+
+        self
+    }
+
     pub(crate) fn query_config(
         &mut self,
         response: impl Fn(StdResult<(&mut Self, Config)>),
@@ -1,20 +1,24 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{Addr, Uint64};
+use cosmwasm_std::{Addr, Decimal, Uint64};
 
-use white_whale_std::fee_distributor::Config;
+use white_whale_std::fee_distributor::{Config, DistributionAsset};
 
 use crate::tests::robot::TestingRobot;
 use crate::ContractError;
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::ownership::OwnershipAction;
 use white_whale_std::pool_network::asset::AssetInfo;
 
 #[test]
 fn instantiate_successfully() {
     let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
     let grace_period = Uint64::new(2);
-    let distribution_asset = AssetInfo::NativeToken {
-        denom: "uwhale".to_string(),
-    };
+    let distribution_assets = vec![DistributionAsset {
+        asset_info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        aggregation_ratio: Decimal::one(),
+    }];
     let epoch_config = EpochConfig {
         duration: Uint64::new(86_400_000_000_000u64), // a day
         genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
@@ -27,7 +31,7 @@ fn instantiate_successfully() {
             "fee_collector_addr".to_string(),
             grace_period,
             epoch_config.clone(),
-            distribution_asset.clone(),
+            distribution_assets.clone(),
         )
         .asset_config(Config {
             owner: Addr::unchecked("owner"),
@@ -35,7 +39,7 @@ fn instantiate_successfully() {
             bonding_contract_addr: Addr::unchecked("bonding_contract_addr"),
             fee_collector_addr: Addr::unchecked("fee_collector_addr"),
             epoch_config,
-            distribution_asset,
+            distribution_assets,
         });
 }
 
@@ -43,9 +47,12 @@ fn instantiate_successfully() {
 fn instantiate_unsuccessfully() {
     let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
     let invalid_grace_period = Uint64::zero();
-    let distribution_asset = AssetInfo::NativeToken {
-        denom: "uwhale".to_string(),
-    };
+    let distribution_assets = vec![DistributionAsset {
+        asset_info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        aggregation_ratio: Decimal::one(),
+    }];
     let epoch_config = EpochConfig {
         duration: Uint64::new(86_400_000_000_000u64), // a day
         genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
@@ -57,7 +64,7 @@ fn instantiate_unsuccessfully() {
         "fee_collector_addr".to_string(),
         invalid_grace_period,
         epoch_config.clone(),
-        distribution_asset.clone(),
+        distribution_assets.clone(),
     );
 
     let invalid_grace_period = Uint64::new(31);
@@ -67,7 +74,7 @@ fn instantiate_unsuccessfully() {
         "fee_collector_addr".to_string(),
         invalid_grace_period,
         epoch_config.clone(),
-        distribution_asset.clone(),
+        distribution_assets.clone(),
     );
 
     let invalid_epoch_duration = Uint64::new(3600u64);
@@ -80,7 +87,7 @@ fn instantiate_unsuccessfully() {
             duration: invalid_epoch_duration,                    // a day
             genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
         },
-        distribution_asset.clone(),
+        distribution_assets.clone(),
     );
 }
 
@@ -89,7 +96,7 @@ fn test_update_config() {
     let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
 
     let new_config = Config {
-        owner: Addr::unchecked("new_owner"),
+        owner: Addr::unchecked("owner"),
         bonding_contract_addr: Addr::unchecked("new_bonding_contract_addr"),
         fee_collector_addr: Addr::unchecked("new_fee_collector_addr"),
         grace_period: Uint64::new(3),
@@ -97,9 +104,20 @@ fn test_update_config() {
             duration: Uint64::new(86_400_000_000_000u64), // a day
             genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
         },
-        distribution_asset: AssetInfo::NativeToken {
-            denom: "uwhale".to_string(),
-        },
+        distribution_assets: vec![
+            DistributionAsset {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                aggregation_ratio: Decimal::percent(70),
+            },
+            DistributionAsset {
+                asset_info: AssetInfo::NativeToken {
+                    denom: "uusdc".to_string(),
+                },
+                aggregation_ratio: Decimal::percent(30),
+            },
+        ],
     };
 
     robot
@@ -149,6 +167,128 @@ fn test_update_config() {
                 _ => panic!("should have returned ContractError::GracePeriodDecrease"),
             },
         )
+        .update_config(
+            mock_info("owner", &[]),
+            Config {
+                distribution_assets: vec![],
+                ..new_config.clone()
+            },
+            |res| match res {
+                Ok(_) => panic!("should have returned ContractError::InvalidDistributionAssets"),
+                Err(ContractError::InvalidDistributionAssets {}) => (),
+                _ => panic!("should have returned ContractError::InvalidDistributionAssets"),
+            },
+        )
+        .update_config(
+            mock_info("owner", &[]),
+            Config {
+                distribution_assets: vec![
+                    DistributionAsset {
+                        asset_info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        aggregation_ratio: Decimal::percent(70),
+                    },
+                    DistributionAsset {
+                        asset_info: AssetInfo::NativeToken {
+                            denom: "uusdc".to_string(),
+                        },
+                        aggregation_ratio: Decimal::percent(20),
+                    },
+                ],
+                ..new_config.clone()
+            },
+            |res| match res {
+                Ok(_) => panic!("should have returned ContractError::InvalidDistributionAssets"),
+                Err(ContractError::InvalidDistributionAssets {}) => (),
+                _ => panic!("should have returned ContractError::InvalidDistributionAssets"),
+            },
+        )
+        .update_config(
+            mock_info("owner", &[]),
+            Config {
+                distribution_assets: vec![
+                    DistributionAsset {
+                        asset_info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        aggregation_ratio: Decimal::percent(50),
+                    },
+                    DistributionAsset {
+                        asset_info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        aggregation_ratio: Decimal::percent(50),
+                    },
+                ],
+                ..new_config.clone()
+            },
+            |res| match res {
+                Ok(_) => panic!("should have returned ContractError::InvalidDistributionAssets"),
+                Err(ContractError::InvalidDistributionAssets {}) => (),
+                _ => panic!("should have returned ContractError::InvalidDistributionAssets"),
+            },
+        )
+        // cw20 tokens can't be forwarded by the fee collector
+        .update_config(
+            mock_info("owner", &[]),
+            Config {
+                distribution_assets: vec![DistributionAsset {
+                    asset_info: AssetInfo::Token {
+                        contract_addr: "whale_token".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
+                ..new_config.clone()
+            },
+            |res| match res {
+                Ok(_) => panic!("should have returned ContractError::InvalidDistributionAssets"),
+                Err(ContractError::InvalidDistributionAssets {}) => (),
+                _ => panic!("should have returned ContractError::InvalidDistributionAssets"),
+            },
+        )
         .update_config(mock_info("owner", &[]), new_config.clone(), |_| {})
         .asset_config(new_config.clone());
 }
+
+#[test]
+fn test_update_ownership() {
+    let mut robot = TestingRobot::new(mock_dependencies(), mock_env());
+
+    robot
+        .instantiate_default()
+        .update_ownership(
+            mock_info("unauthorized", &[]),
+            OwnershipAction::ProposeNewOwner {
+                new_owner: "new_owner".to_string(),
+                expiry: None,
+            },
+            |res| {
+                res.unwrap_err();
+            },
+        )
+        .update_ownership(
+            mock_info("owner", &[]),
+            OwnershipAction::ProposeNewOwner {
+                new_owner: "new_owner".to_string(),
+                expiry: None,
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        // the owner doesn't change until the proposed owner accepts the ownership
+        .query_config(|res| {
+            assert_eq!(res.unwrap().1.owner, Addr::unchecked("owner"));
+        })
+        .update_ownership(
+            mock_info("new_owner", &[]),
+            OwnershipAction::AcceptOwnership {},
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_config(|res| {
+            assert_eq!(res.unwrap().1.owner, Addr::unchecked("new_owner"));
+        });
+}
@@ -0,0 +1,366 @@
+use cosmwasm_std::{BankMsg, DepsMut, Empty, Env, MessageInfo, Response, StdError, Uint128};
+
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
+use white_whale_std::gauges::{Gauge, GaugeVote, Vote};
+use white_whale_std::ownership;
+use white_whale_std::ownership::OwnershipAction;
+use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::whale_lair::BondingWeightResponse;
+
+use crate::helpers;
+use crate::state::{
+    BRIBES_CLAIMED, CONFIG, CURRENT_EPOCH, GAUGES, PENDING_FLOWS, TOTAL_VOTES, VOTES,
+};
+use crate::ContractError;
+
+/// Casts the vote of the sender for the current epoch, replacing the vote it previously cast in the
+/// same epoch.
+pub(crate) fn vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    votes: Vec<GaugeVote>,
+) -> Result<Response, ContractError> {
+    helpers::validate_votes(&votes)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let epoch_id = CURRENT_EPOCH.load(deps.storage)?;
+
+    let weight_response: BondingWeightResponse = deps.querier.query_wasm_smart(
+        config.whale_lair_addr.to_string(),
+        &white_whale_std::whale_lair::QueryMsg::Weight {
+            address: info.sender.to_string(),
+            timestamp: Some(env.block.time),
+            global_index: None,
+        },
+    )?;
+
+    if weight_response.weight.is_zero() {
+        return Err(ContractError::NoVotingPower {});
+    }
+
+    let mut total_votes = TOTAL_VOTES
+        .may_load(deps.storage, epoch_id)?
+        .unwrap_or_default();
+
+    // take the previous vote of the sender out of the gauges it voted for
+    if let Some(previous_vote) = VOTES.may_load(deps.storage, (epoch_id, &info.sender))? {
+        for gauge_vote in &previous_vote.votes {
+            let gauge_votes = helpers::get_gauge_votes(&previous_vote, gauge_vote);
+            let gauge_key = gauge_vote.lp_asset.to_raw(deps.api)?;
+
+            GAUGES.update::<_, StdError>(
+                deps.storage,
+                (epoch_id, gauge_key.as_bytes()),
+                |gauge| {
+                    let mut gauge = gauge.ok_or(StdError::not_found("gauge"))?;
+                    gauge.votes = gauge.votes.checked_sub(gauge_votes)?;
+                    Ok(gauge)
+                },
+            )?;
+
+            total_votes = total_votes.checked_sub(gauge_votes)?;
+        }
+    }
+
+    let vote = Vote {
+        voting_power: weight_response.weight,
+        votes,
+    };
+
+    for gauge_vote in &vote.votes {
+        let gauge_votes = helpers::get_gauge_votes(&vote, gauge_vote);
+        let gauge_key = gauge_vote.lp_asset.to_raw(deps.api)?;
+
+        let mut gauge = match GAUGES.may_load(deps.storage, (epoch_id, gauge_key.as_bytes()))? {
+            Some(gauge) => gauge,
+            None => Gauge {
+                lp_asset: gauge_vote.lp_asset.clone(),
+                incentive_addr: helpers::get_incentive_addr(
+                    deps.as_ref(),
+                    &config,
+                    &gauge_vote.lp_asset,
+                )?,
+                votes: Uint128::zero(),
+                bribes: vec![],
+                flow_error: None,
+            },
+        };
+
+        gauge.votes = gauge.votes.checked_add(gauge_votes)?;
+        GAUGES.save(deps.storage, (epoch_id, gauge_key.as_bytes()), &gauge)?;
+
+        total_votes = total_votes.checked_add(gauge_votes)?;
+    }
+
+    VOTES.save(deps.storage, (epoch_id, &info.sender), &vote)?;
+    TOTAL_VOTES.save(deps.storage, epoch_id, &total_votes)?;
+
+    let votes = vote
+        .votes
+        .iter()
+        .map(|gauge_vote| format!("{}:{}", gauge_vote.lp_asset, gauge_vote.weight))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "vote".to_string()),
+        ("voter", info.sender.to_string()),
+        ("epoch_id", epoch_id.to_string()),
+        ("voting_power", vote.voting_power.to_string()),
+        ("votes", votes),
+    ]))
+}
+
+/// Adds the funds sent along the message as a bribe for the voters of the given gauge.
+pub(crate) fn add_bribe(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_asset: AssetInfo,
+    epoch_id: Option<u64>,
+) -> Result<Response, ContractError> {
+    let bribes = info
+        .funds
+        .into_iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .collect::<Vec<_>>();
+
+    if bribes.is_empty() {
+        return Err(ContractError::EmptyBribe {});
+    }
+
+    let current_epoch = CURRENT_EPOCH.load(deps.storage)?;
+    let epoch_id = epoch_id.unwrap_or(current_epoch);
+
+    // bribes can only be added while the votes for the epoch are still being cast
+    if epoch_id < current_epoch {
+        return Err(ContractError::BribeEpochInPast {
+            epoch_id,
+            current_epoch,
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let gauge_key = lp_asset.to_raw(deps.api)?;
+
+    let mut gauge = match GAUGES.may_load(deps.storage, (epoch_id, gauge_key.as_bytes()))? {
+        Some(gauge) => gauge,
+        None => Gauge {
+            incentive_addr: helpers::get_incentive_addr(deps.as_ref(), &config, &lp_asset)?,
+            lp_asset: lp_asset.clone(),
+            votes: Uint128::zero(),
+            bribes: vec![],
+            flow_error: None,
+        },
+    };
+
+    gauge.bribes = helpers::aggregate_coins(gauge.bribes, bribes.clone())?;
+    GAUGES.save(deps.storage, (epoch_id, gauge_key.as_bytes()), &gauge)?;
+
+    let bribes = bribes
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "add_bribe".to_string()),
+        ("briber", info.sender.to_string()),
+        ("lp_asset", lp_asset.to_string()),
+        ("epoch_id", epoch_id.to_string()),
+        ("bribes", bribes),
+    ]))
+}
+
+/// Claims the bribes for the gauges the sender voted for in the given epoch.
+pub(crate) fn claim_bribes(
+    deps: DepsMut,
+    info: MessageInfo,
+    epoch_id: u64,
+) -> Result<Response, ContractError> {
+    let current_epoch = CURRENT_EPOCH.load(deps.storage)?;
+    if epoch_id >= current_epoch {
+        return Err(ContractError::EpochNotEnded { epoch_id });
+    }
+
+    if BRIBES_CLAIMED.has(deps.storage, (epoch_id, &info.sender)) {
+        return Err(ContractError::BribesAlreadyClaimed { epoch_id });
+    }
+
+    let bribes = helpers::get_claimable_bribes(deps.as_ref(), &info.sender, epoch_id)?;
+    if bribes.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    BRIBES_CLAIMED.save(deps.storage, (epoch_id, &info.sender), &Empty {})?;
+
+    let claimed = bribes
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok(Response::default()
+        .add_attributes(vec![
+            ("action", "claim_bribes".to_string()),
+            ("voter", info.sender.to_string()),
+            ("epoch_id", epoch_id.to_string()),
+            ("bribes", claimed),
+        ])
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: bribes,
+        }))
+}
+
+/// Tallies the votes of the epoch that ended and opens the flows for the voted gauges. Can only be
+/// called by the epoch manager.
+pub(crate) fn epoch_changed_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: EpochChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.epoch_manager_addr {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let ended_epoch = CURRENT_EPOCH.load(deps.storage)?;
+    let new_epoch = msg.current_epoch.id;
+
+    if new_epoch <= ended_epoch {
+        return Ok(Response::default().add_attributes(vec![
+            ("action", "epoch_changed_hook".to_string()),
+            ("epoch_id", new_epoch.to_string()),
+            ("result", "epoch already processed".to_string()),
+        ]));
+    }
+
+    CURRENT_EPOCH.save(deps.storage, &new_epoch)?;
+
+    let (messages, flow_gauges) =
+        helpers::get_gauge_flow_msgs(deps.as_ref(), &config, ended_epoch)?;
+    // the replies of the flows that fail to open point to the gauge in this list
+    PENDING_FLOWS.save(deps.storage, &(ended_epoch, flow_gauges))?;
+
+    let carried_over_bribes = helpers::carry_over_bribes(deps, ended_epoch, new_epoch)?;
+
+    let mut attributes = vec![
+        ("action", "epoch_changed_hook".to_string()),
+        ("ended_epoch", ended_epoch.to_string()),
+        ("epoch_id", new_epoch.to_string()),
+    ];
+
+    if !carried_over_bribes.is_empty() {
+        attributes.push(("carried_over_bribes", carried_over_bribes.join(", ")));
+    }
+
+    Ok(Response::default()
+        .add_attributes(attributes)
+        .add_submessages(messages))
+}
+
+/// Records the error of a flow that couldn't be opened on the gauge it was meant for. The emissions
+/// of the gauge are kept in the contract.
+pub(crate) fn record_failed_flow(
+    deps: DepsMut,
+    flow_index: u64,
+    error: String,
+) -> Result<Response, ContractError> {
+    let (epoch_id, flow_gauges) = PENDING_FLOWS.load(deps.storage)?;
+    let lp_asset = flow_gauges
+        .get(flow_index as usize)
+        .ok_or(StdError::not_found("pending flow"))?;
+    let gauge_key = lp_asset.to_raw(deps.api)?;
+
+    GAUGES.update::<_, StdError>(deps.storage, (epoch_id, gauge_key.as_bytes()), |gauge| {
+        let mut gauge = gauge.ok_or(StdError::not_found("gauge"))?;
+        gauge.flow_error = Some(error.clone());
+        Ok(gauge)
+    })?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "open_flow_failed".to_string()),
+        ("epoch_id", epoch_id.to_string()),
+        ("lp_asset", lp_asset.to_string()),
+        ("error", error),
+    ]))
+}
+
+/// Updates the configuration of the contract. Can only be called by the owner.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    whale_lair_addr: Option<String>,
+    incentive_factory_addr: Option<String>,
+    epoch_manager_addr: Option<String>,
+    emissions_per_epoch: Option<Uint128>,
+    flow_duration: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(whale_lair_addr) = whale_lair_addr {
+        config.whale_lair_addr = deps.api.addr_validate(&whale_lair_addr)?;
+    }
+
+    if let Some(incentive_factory_addr) = incentive_factory_addr {
+        config.incentive_factory_addr = deps.api.addr_validate(&incentive_factory_addr)?;
+    }
+
+    if let Some(epoch_manager_addr) = epoch_manager_addr {
+        config.epoch_manager_addr = deps.api.addr_validate(&epoch_manager_addr)?;
+    }
+
+    if let Some(emissions_per_epoch) = emissions_per_epoch {
+        config.emissions_per_epoch = emissions_per_epoch;
+    }
+
+    if let Some(flow_duration) = flow_duration {
+        if flow_duration == 0 {
+            return Err(ContractError::InvalidFlowDuration {});
+        }
+
+        config.flow_duration = flow_duration;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "update_config".to_string()),
+        ("owner", config.owner.to_string()),
+        ("whale_lair_addr", config.whale_lair_addr.to_string()),
+        (
+            "incentive_factory_addr",
+            config.incentive_factory_addr.to_string(),
+        ),
+        ("epoch_manager_addr", config.epoch_manager_addr.to_string()),
+        (
+            "emissions_per_epoch",
+            config.emissions_per_epoch.to_string(),
+        ),
+        ("flow_duration", config.flow_duration.to_string()),
+    ]))
+}
+
+/// Proposes, accepts or cancels a transfer of the ownership of the contract.
+pub(crate) fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,147 @@
+use cosmwasm_std::{entry_point, to_json_binary};
+use cosmwasm_std::{
+    Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsgResult,
+};
+use cw2::{get_contract_version, set_contract_version};
+use semver::Version;
+
+use white_whale_std::epoch_manager::epoch_manager::EpochResponse;
+use white_whale_std::gauges::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use white_whale_std::migrate_guards::check_contract_name;
+use white_whale_std::ownership::query_ownership_proposal;
+
+use crate::error::ContractError;
+use crate::state::{CONFIG, CURRENT_EPOCH};
+use crate::{commands, queries};
+
+// version info for migration info
+const CONTRACT_NAME: &str = "white_whale-gauges";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if msg.flow_duration == 0 {
+        return Err(ContractError::InvalidFlowDuration {});
+    }
+
+    let config = Config {
+        owner: info.sender,
+        whale_lair_addr: deps.api.addr_validate(&msg.whale_lair_addr)?,
+        incentive_factory_addr: deps.api.addr_validate(&msg.incentive_factory_addr)?,
+        epoch_manager_addr: deps.api.addr_validate(&msg.epoch_manager_addr)?,
+        emission_denom: msg.emission_denom,
+        emissions_per_epoch: msg.emissions_per_epoch,
+        flow_duration: msg.flow_duration,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    // votes are cast on the epoch the epoch manager is currently at
+    let epoch_response: EpochResponse = deps.querier.query_wasm_smart(
+        config.epoch_manager_addr.to_string(),
+        &white_whale_std::epoch_manager::epoch_manager::QueryMsg::CurrentEpoch {},
+    )?;
+    CURRENT_EPOCH.save(deps.storage, &epoch_response.epoch.id)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "instantiate".to_string()),
+        ("owner", config.owner.to_string()),
+        ("whale_lair_addr", config.whale_lair_addr.to_string()),
+        (
+            "incentive_factory_addr",
+            config.incentive_factory_addr.to_string(),
+        ),
+        ("epoch_manager_addr", config.epoch_manager_addr.to_string()),
+        ("emission_denom", config.emission_denom),
+        (
+            "emissions_per_epoch",
+            config.emissions_per_epoch.to_string(),
+        ),
+        ("flow_duration", config.flow_duration.to_string()),
+        ("current_epoch", epoch_response.epoch.id.to_string()),
+    ]))
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Vote { votes } => commands::vote(deps, env, info, votes),
+        ExecuteMsg::AddBribe { lp_asset, epoch_id } => {
+            commands::add_bribe(deps, info, lp_asset, epoch_id)
+        }
+        ExecuteMsg::ClaimBribes { epoch_id } => commands::claim_bribes(deps, info, epoch_id),
+        ExecuteMsg::EpochChangedHook(msg) => commands::epoch_changed_hook(deps, info, msg),
+        ExecuteMsg::UpdateConfig {
+            whale_lair_addr,
+            incentive_factory_addr,
+            epoch_manager_addr,
+            emissions_per_epoch,
+            flow_duration,
+        } => commands::update_config(
+            deps,
+            info,
+            whale_lair_addr,
+            incentive_factory_addr,
+            epoch_manager_addr,
+            emissions_per_epoch,
+            flow_duration,
+        ),
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
+    }
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    // only the flows opened when the epoch changes reply, and only on error
+    match msg.result {
+        SubMsgResult::Err(error) => commands::record_failed_flow(deps, msg.id, error),
+        SubMsgResult::Ok(_) => Ok(Response::default()),
+    }
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&queries::query_config(deps)?),
+        QueryMsg::CurrentEpoch {} => to_json_binary(&queries::query_current_epoch(deps)?),
+        QueryMsg::Gauges { epoch_id } => to_json_binary(&queries::query_gauges(deps, epoch_id)?),
+        QueryMsg::Vote { address, epoch_id } => {
+            to_json_binary(&queries::query_vote(deps, address, epoch_id)?)
+        }
+        QueryMsg::ClaimableBribes { address, epoch_id } => {
+            to_json_binary(&queries::query_claimable_bribes(deps, address, epoch_id)?)
+        }
+        QueryMsg::OwnershipProposal {} => to_json_binary(&query_ownership_proposal(deps.storage)?),
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+#[entry_point]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    check_contract_name(deps.storage, CONTRACT_NAME.to_string())?;
+
+    let version: Version = CONTRACT_VERSION.parse()?;
+    let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
+
+    if storage_version >= version {
+        return Err(ContractError::MigrateInvalidVersion {
+            current_version: storage_version,
+            new_version: version,
+        });
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::default())
+}
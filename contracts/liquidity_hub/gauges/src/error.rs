@@ -0,0 +1,57 @@
+use cosmwasm_std::{OverflowError, StdError};
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Semver parsing error: {0}")]
+    SemVer(String),
+
+    #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
+    MigrateInvalidVersion {
+        new_version: Version,
+        current_version: Version,
+    },
+
+    #[error("The flow duration must be at least one epoch")]
+    InvalidFlowDuration {},
+
+    #[error("Invalid votes: {reason}")]
+    InvalidVotes { reason: String },
+
+    #[error("The sender has no voting power")]
+    NoVotingPower {},
+
+    #[error("There's no incentive contract for the LP asset {lp_asset}")]
+    NoIncentive { lp_asset: String },
+
+    #[error("No funds were sent along with the bribe")]
+    EmptyBribe {},
+
+    #[error("Can't add a bribe for epoch {epoch_id}, the current epoch is {current_epoch}")]
+    BribeEpochInPast { epoch_id: u64, current_epoch: u64 },
+
+    #[error("The epoch {epoch_id} has not ended yet")]
+    EpochNotEnded { epoch_id: u64 },
+
+    #[error("The bribes for epoch {epoch_id} were already claimed")]
+    BribesAlreadyClaimed { epoch_id: u64 },
+
+    #[error("There are no bribes to claim")]
+    NothingToClaim {},
+}
+
+impl From<semver::Error> for ContractError {
+    fn from(err: semver::Error) -> Self {
+        Self::SemVer(err.to_string())
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, Decimal, Deps, DepsMut, Order, StdResult, SubMsg, Uint128, WasmMsg,
+};
+use serde::{Deserialize, Serialize};
+
+use white_whale_std::gauges::{Config, Gauge, GaugeVote, Vote};
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::pool_network::incentive_factory::IncentiveResponse;
+
+use crate::state::{BRIBES_CLAIMED, GAUGES, MIN_FLOW_AMOUNT, TOTAL_VOTES, VOTES};
+use crate::ContractError;
+
+/// The parts of the incentive factory config needed to open the flows of the gauges. Unknown fields
+/// are ignored, so changes to the incentive factory config don't affect the gauges.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct IncentiveFactoryConfig {
+    pub fee_distributor_addr: Addr,
+    pub create_flow_fee: Asset,
+}
+
+/// Validates the votes cast by a voter. The weights must be positive, add up to one at most, and
+/// each gauge can only be voted once.
+pub fn validate_votes(votes: &[GaugeVote]) -> Result<(), ContractError> {
+    if votes.is_empty() {
+        return Err(ContractError::InvalidVotes {
+            reason: "no gauges were voted".to_string(),
+        });
+    }
+
+    let mut total_weight = Decimal::zero();
+    let mut gauges = HashSet::new();
+
+    for vote in votes {
+        if vote.weight.is_zero() {
+            return Err(ContractError::InvalidVotes {
+                reason: format!("the weight for {} must be positive", vote.lp_asset),
+            });
+        }
+
+        if !gauges.insert(vote.lp_asset.to_string()) {
+            return Err(ContractError::InvalidVotes {
+                reason: format!("{} was voted more than once", vote.lp_asset),
+            });
+        }
+
+        total_weight = total_weight.checked_add(vote.weight)?;
+    }
+
+    if total_weight > Decimal::one() {
+        return Err(ContractError::InvalidVotes {
+            reason: format!("the weights add up to {total_weight}, which is more than one"),
+        });
+    }
+
+    Ok(())
+}
+
+/// Gets the votes a voter gave to a gauge, i.e. the share of its voting power given to it.
+pub fn get_gauge_votes(vote: &Vote, gauge_vote: &GaugeVote) -> Uint128 {
+    vote.voting_power.mul_floor(gauge_vote.weight)
+}
+
+/// Gets the incentive contract for the given LP asset from the incentive factory.
+pub fn get_incentive_addr(
+    deps: Deps,
+    config: &Config,
+    lp_asset: &AssetInfo,
+) -> Result<Addr, ContractError> {
+    let incentive: IncentiveResponse = deps.querier.query_wasm_smart(
+        config.incentive_factory_addr.to_string(),
+        &white_whale_std::pool_network::incentive_factory::QueryMsg::Incentive {
+            lp_asset: lp_asset.clone(),
+        },
+    )?;
+
+    incentive.ok_or(ContractError::NoIncentive {
+        lp_asset: lp_asset.to_string(),
+    })
+}
+
+/// Aggregates two lists of coins, adding up the amounts of the coins with the same denom.
+pub fn aggregate_coins(coins: Vec<Coin>, other_coins: Vec<Coin>) -> StdResult<Vec<Coin>> {
+    let mut aggregated_coins = coins;
+
+    for coin in other_coins {
+        if let Some(aggregated_coin) = aggregated_coins.iter_mut().find(|c| c.denom == coin.denom) {
+            aggregated_coin.amount = aggregated_coin.amount.checked_add(coin.amount)?;
+        } else {
+            aggregated_coins.push(coin);
+        }
+    }
+
+    Ok(aggregated_coins)
+}
+
+/// Gets the bribes the given address can claim for the given epoch. The bribes of a gauge are split
+/// across its voters proportionally to the votes they gave to it.
+pub fn get_claimable_bribes(
+    deps: Deps,
+    address: &Addr,
+    epoch_id: u64,
+) -> Result<Vec<Coin>, ContractError> {
+    if BRIBES_CLAIMED.has(deps.storage, (epoch_id, address)) {
+        return Ok(vec![]);
+    }
+
+    let Some(vote) = VOTES.may_load(deps.storage, (epoch_id, address))? else {
+        return Ok(vec![]);
+    };
+
+    let mut bribes = vec![];
+
+    for gauge_vote in &vote.votes {
+        let gauge_key = gauge_vote.lp_asset.to_raw(deps.api)?;
+        let gauge = GAUGES.load(deps.storage, (epoch_id, gauge_key.as_bytes()))?;
+
+        let gauge_votes = get_gauge_votes(&vote, gauge_vote);
+        if gauge.votes.is_zero() || gauge_votes.is_zero() {
+            continue;
+        }
+
+        let gauge_bribes = gauge
+            .bribes
+            .iter()
+            .map(|bribe| Coin {
+                denom: bribe.denom.clone(),
+                amount: bribe.amount.multiply_ratio(gauge_votes, gauge.votes),
+            })
+            .filter(|bribe| !bribe.amount.is_zero())
+            .collect();
+
+        bribes = aggregate_coins(bribes, gauge_bribes)?;
+    }
+
+    Ok(bribes)
+}
+
+/// Creates the messages to open the flows on the incentive contracts of the gauges voted in the
+/// given epoch, splitting the emissions proportionally to the votes each gauge received. Along with
+/// them, returns the LP assets of the gauges the flows are opened for, in the same order.
+///
+/// Each flow is sent as a submessage replying on error, with the index of its gauge as the reply
+/// id, so a single failing flow doesn't prevent the others from being opened. Gauges whose share of
+/// the emissions can't cover the flow fee and the minimum flow amount are skipped.
+pub fn get_gauge_flow_msgs(
+    deps: Deps,
+    config: &Config,
+    epoch_id: u64,
+) -> Result<(Vec<SubMsg>, Vec<AssetInfo>), ContractError> {
+    let total_votes = TOTAL_VOTES
+        .may_load(deps.storage, epoch_id)?
+        .unwrap_or_default();
+
+    if total_votes.is_zero() || config.emissions_per_epoch.is_zero() {
+        return Ok((vec![], vec![]));
+    }
+
+    let incentive_factory_config: IncentiveFactoryConfig = deps.querier.query_wasm_smart(
+        config.incentive_factory_addr.to_string(),
+        &white_whale_std::pool_network::incentive_factory::QueryMsg::Config {},
+    )?;
+
+    // the incentive contracts track epochs through the fee distributor. Flows start on the next one.
+    let epoch_response: white_whale_std::fee_distributor::EpochResponse =
+        deps.querier.query_wasm_smart(
+            incentive_factory_config.fee_distributor_addr.to_string(),
+            &white_whale_std::fee_distributor::QueryMsg::CurrentEpoch {},
+        )?;
+    let start_epoch = epoch_response.epoch.id.u64() + 1;
+    let end_epoch = start_epoch + config.flow_duration - 1;
+
+    let flow_fee = incentive_factory_config.create_flow_fee;
+
+    let gauges = GAUGES
+        .prefix(epoch_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut messages = vec![];
+    let mut flow_gauges = vec![];
+
+    for (_, gauge) in gauges {
+        if gauge.votes.is_zero() {
+            continue;
+        }
+
+        let amount = config
+            .emissions_per_epoch
+            .multiply_ratio(gauge.votes, total_votes);
+
+        let mut funds = vec![Coin {
+            denom: config.emission_denom.clone(),
+            amount,
+        }];
+
+        match &flow_fee.info {
+            // the incentive contract takes the fee out of the flow asset when they match
+            AssetInfo::NativeToken { denom } if denom == &config.emission_denom => {
+                if amount < flow_fee.amount.checked_add(MIN_FLOW_AMOUNT)? {
+                    continue;
+                }
+            }
+            AssetInfo::NativeToken { denom } => {
+                if amount < MIN_FLOW_AMOUNT {
+                    continue;
+                }
+
+                if !flow_fee.amount.is_zero() {
+                    funds.push(Coin {
+                        denom: denom.clone(),
+                        amount: flow_fee.amount,
+                    });
+                    funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+                }
+            }
+            AssetInfo::Token { contract_addr } => {
+                if amount < MIN_FLOW_AMOUNT {
+                    continue;
+                }
+
+                messages.push(SubMsg::new(WasmMsg::Execute {
+                    contract_addr: contract_addr.clone(),
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: gauge.incentive_addr.to_string(),
+                        amount: flow_fee.amount,
+                        expires: None,
+                    })?,
+                    funds: vec![],
+                }));
+            }
+        }
+
+        messages.push(SubMsg::reply_on_error(
+            WasmMsg::Execute {
+                contract_addr: gauge.incentive_addr.to_string(),
+                msg: to_json_binary(
+                    &white_whale_std::pool_network::incentive::ExecuteMsg::OpenFlow {
+                        start_epoch: Some(start_epoch),
+                        end_epoch: Some(end_epoch),
+                        curve: None,
+                        flow_asset: Asset {
+                            info: AssetInfo::NativeToken {
+                                denom: config.emission_denom.clone(),
+                            },
+                            amount,
+                        },
+                        flow_label: Some(format!("gauge epoch {epoch_id}")),
+                        vesting_duration: None,
+                    },
+                )?,
+                funds,
+            },
+            flow_gauges.len() as u64,
+        ));
+        flow_gauges.push(gauge.lp_asset);
+    }
+
+    Ok((messages, flow_gauges))
+}
+
+/// Carries the bribes of the gauges that got no votes in the ended epoch over to the new epoch, as
+/// there are no voters to split them across. Returns the carried over bribes of each gauge.
+pub fn carry_over_bribes(
+    deps: DepsMut,
+    ended_epoch: u64,
+    new_epoch: u64,
+) -> Result<Vec<String>, ContractError> {
+    let unvoted_gauges = GAUGES
+        .prefix(ended_epoch)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref().map_or(true, |(_, gauge)| {
+                gauge.votes.is_zero() && !gauge.bribes.is_empty()
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut carried_over_bribes = vec![];
+
+    for (gauge_key, mut gauge) in unvoted_gauges {
+        let bribes = std::mem::take(&mut gauge.bribes);
+        GAUGES.save(deps.storage, (ended_epoch, &gauge_key), &gauge)?;
+
+        let mut new_gauge = GAUGES
+            .may_load(deps.storage, (new_epoch, &gauge_key))?
+            .unwrap_or(Gauge {
+                votes: Uint128::zero(),
+                bribes: vec![],
+                flow_error: None,
+                ..gauge.clone()
+            });
+        new_gauge.bribes = aggregate_coins(new_gauge.bribes, bribes.clone())?;
+        GAUGES.save(deps.storage, (new_epoch, &gauge_key), &new_gauge)?;
+
+        carried_over_bribes.push(format!(
+            "{}:{}",
+            gauge.lp_asset,
+            bribes
+                .iter()
+                .map(|coin| coin.to_string())
+                .collect::<Vec<_>>()
+                .join("+")
+        ));
+    }
+
+    Ok(carried_over_bribes)
+}
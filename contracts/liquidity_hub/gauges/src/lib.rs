@@ -0,0 +1,11 @@
+pub use crate::error::ContractError;
+
+mod commands;
+pub mod contract;
+mod error;
+pub mod helpers;
+mod queries;
+pub mod state;
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,62 @@
+use cosmwasm_std::{Deps, Order, StdError, StdResult};
+
+use white_whale_std::gauges::{
+    ClaimableBribesResponse, Config, CurrentEpochResponse, GaugesResponse, VoteResponse,
+};
+
+use crate::helpers;
+use crate::state::{CONFIG, CURRENT_EPOCH, GAUGES, TOTAL_VOTES, VOTES};
+
+/// Queries the config of the contract.
+pub(crate) fn query_config(deps: Deps) -> StdResult<Config> {
+    CONFIG.load(deps.storage)
+}
+
+/// Queries the epoch in which votes are currently being cast.
+pub(crate) fn query_current_epoch(deps: Deps) -> StdResult<CurrentEpochResponse> {
+    Ok(CurrentEpochResponse {
+        epoch_id: CURRENT_EPOCH.load(deps.storage)?,
+    })
+}
+
+/// Queries the gauges voted or bribed in the given epoch.
+pub(crate) fn query_gauges(deps: Deps, epoch_id: u64) -> StdResult<GaugesResponse> {
+    let gauges = GAUGES
+        .prefix(epoch_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, gauge)| gauge))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(GaugesResponse {
+        epoch_id,
+        total_votes: TOTAL_VOTES
+            .may_load(deps.storage, epoch_id)?
+            .unwrap_or_default(),
+        gauges,
+    })
+}
+
+/// Queries the vote cast by the given address in the given epoch.
+pub(crate) fn query_vote(deps: Deps, address: String, epoch_id: u64) -> StdResult<VoteResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let vote = VOTES.may_load(deps.storage, (epoch_id, &address))?;
+
+    Ok(VoteResponse {
+        address,
+        epoch_id,
+        vote,
+    })
+}
+
+/// Queries the bribes the given address can claim for the given epoch.
+pub(crate) fn query_claimable_bribes(
+    deps: Deps,
+    address: String,
+    epoch_id: u64,
+) -> StdResult<ClaimableBribesResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let bribes = helpers::get_claimable_bribes(deps, &address, epoch_id)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(ClaimableBribesResponse { bribes })
+}
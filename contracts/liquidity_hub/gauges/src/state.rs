@@ -0,0 +1,23 @@
+use cosmwasm_std::{Addr, Empty, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use white_whale_std::gauges::{Config, Gauge, Vote};
+use white_whale_std::pool_network::asset::AssetInfo;
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// The epoch in which votes are currently being cast.
+pub const CURRENT_EPOCH: Item<u64> = Item::new("current_epoch");
+/// The votes cast in each epoch, keyed by (epoch_id, voter).
+pub const VOTES: Map<(u64, &Addr), Vote> = Map::new("votes");
+/// The gauges voted or bribed in each epoch, keyed by (epoch_id, raw lp_asset).
+pub const GAUGES: Map<(u64, &[u8]), Gauge> = Map::new("gauges");
+/// The total votes cast in each epoch.
+pub const TOTAL_VOTES: Map<u64, Uint128> = Map::new("total_votes");
+/// The epoch whose flows are being opened and the gauges of each flow, in the order the flows were
+/// sent to the incentive contracts. Used to find the gauge of a flow that failed to open.
+pub const PENDING_FLOWS: Item<(u64, Vec<AssetInfo>)> = Item::new("pending_flows");
+/// The voters that claimed their bribes for a given epoch, keyed by (epoch_id, voter).
+pub const BRIBES_CLAIMED: Map<(u64, &Addr), Empty> = Map::new("bribes_claimed");
+
+/// The minimum amount of a flow accepted by the incentive contracts.
+pub const MIN_FLOW_AMOUNT: Uint128 = Uint128::new(1_000u128);
@@ -0,0 +1,309 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{
+    coin, coins, from_json, to_json_binary, Attribute, BankMsg, CosmosMsg, Decimal, Reply, ReplyOn,
+    SubMsgResult, Timestamp, Uint128, WasmMsg,
+};
+
+use white_whale_std::epoch_manager::epoch_manager::EpochV2;
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
+use white_whale_std::gauges::{
+    ClaimableBribesResponse, CurrentEpochResponse, ExecuteMsg, GaugeVote, GaugesResponse, QueryMsg,
+};
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+
+use crate::contract::{execute, query, reply};
+use crate::tests::{
+    lp_asset, mock_instantiate, EMISSIONS_PER_EPOCH, FEE_DISTRIBUTOR_EPOCH, FLOW_DURATION,
+};
+use crate::ContractError;
+
+fn epoch_changed_hook(id: u64) -> ExecuteMsg {
+    ExecuteMsg::EpochChangedHook(EpochChangedHookMsg {
+        current_epoch: EpochV2 {
+            id,
+            start_time: Timestamp::from_seconds(id),
+        },
+    })
+}
+
+#[test]
+fn open_flows_and_claim_bribes_when_epoch_changes() {
+    let mut deps = mock_instantiate();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![GaugeVote {
+                lp_asset: lp_asset("lp_2"),
+                weight: Decimal::one(),
+            }],
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("bob", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![
+                GaugeVote {
+                    lp_asset: lp_asset("lp_1"),
+                    weight: Decimal::percent(50),
+                },
+                GaugeVote {
+                    lp_asset: lp_asset("lp_2"),
+                    weight: Decimal::percent(50),
+                },
+            ],
+        },
+    )
+    .unwrap();
+
+    // bribes can't be added for past epochs or without funds
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("briber", &[]),
+        ExecuteMsg::AddBribe {
+            lp_asset: lp_asset("lp_2"),
+            epoch_id: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::EmptyBribe {});
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("briber", &coins(1_000u128, "ubribe")),
+        ExecuteMsg::AddBribe {
+            lp_asset: lp_asset("lp_2"),
+            epoch_id: Some(0),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::BribeEpochInPast {
+            epoch_id: 0,
+            current_epoch: 1,
+        }
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("briber", &coins(1_000u128, "ubribe")),
+        ExecuteMsg::AddBribe {
+            lp_asset: lp_asset("lp_2"),
+            epoch_id: None,
+        },
+    )
+    .unwrap();
+
+    // bribes can't be claimed while the epoch is ongoing
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::ClaimBribes { epoch_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::EpochNotEnded { epoch_id: 1 });
+
+    // only the epoch manager can call the hook
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        epoch_changed_hook(2),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("epoch_manager", &[]),
+        epoch_changed_hook(2),
+    )
+    .unwrap();
+
+    // lp_1 got 150 votes and lp_2 250 votes out of 400
+    let flow_msg = |incentive: &str, amount: u128| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: incentive.to_string(),
+            msg: to_json_binary(
+                &white_whale_std::pool_network::incentive::ExecuteMsg::OpenFlow {
+                    start_epoch: Some(FEE_DISTRIBUTOR_EPOCH + 1),
+                    end_epoch: Some(FEE_DISTRIBUTOR_EPOCH + FLOW_DURATION),
+                    curve: None,
+                    flow_asset: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        amount: Uint128::new(amount),
+                    },
+                    flow_label: Some("gauge epoch 1".to_string()),
+                    vesting_duration: None,
+                },
+            )
+            .unwrap(),
+            funds: coins(amount, "uwhale"),
+        }
+        .into()
+    };
+
+    let messages = res
+        .messages
+        .into_iter()
+        .map(|sub_msg| sub_msg.msg)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            flow_msg("incentive_lp_1", EMISSIONS_PER_EPOCH * 150 / 400),
+            flow_msg("incentive_lp_2", EMISSIONS_PER_EPOCH * 250 / 400),
+        ]
+    );
+
+    let current_epoch: CurrentEpochResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::CurrentEpoch {}).unwrap()).unwrap();
+    assert_eq!(current_epoch.epoch_id, 2);
+
+    // calling the hook again for the same epoch doesn't open flows again
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("epoch_manager", &[]),
+        epoch_changed_hook(2),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+
+    // the lp_2 bribe is split between alice (100 votes) and bob (150 votes)
+    let claimable: ClaimableBribesResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ClaimableBribes {
+                address: "bob".to_string(),
+                epoch_id: 1,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(claimable.bribes, vec![coin(600u128, "ubribe")]);
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::ClaimBribes { epoch_id: 1 },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "alice".to_string(),
+            amount: coins(400u128, "ubribe"),
+        })
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::ClaimBribes { epoch_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::BribesAlreadyClaimed { epoch_id: 1 });
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("carol", &[]),
+        ExecuteMsg::ClaimBribes { epoch_id: 1 },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NothingToClaim {});
+}
+
+#[test]
+fn carry_over_unvoted_bribes_and_record_failed_flows() {
+    let mut deps = mock_instantiate();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![GaugeVote {
+                lp_asset: lp_asset("lp_1"),
+                weight: Decimal::one(),
+            }],
+        },
+    )
+    .unwrap();
+
+    // nobody votes for lp_2, so its bribe can't be split in epoch 1
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("briber", &coins(1_000u128, "ubribe")),
+        ExecuteMsg::AddBribe {
+            lp_asset: lp_asset("lp_2"),
+            epoch_id: None,
+        },
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("epoch_manager", &[]),
+        epoch_changed_hook(2),
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, 0);
+    assert_eq!(res.messages[0].reply_on, ReplyOn::Error);
+    assert!(res
+        .attributes
+        .contains(&Attribute::new("carried_over_bribes", "lp_2:1000ubribe")));
+
+    // the bribe was moved to epoch 2
+    let gauges: GaugesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Gauges { epoch_id: 1 }).unwrap())
+            .unwrap();
+    assert_eq!(gauges.gauges[1].lp_asset, lp_asset("lp_2"));
+    assert!(gauges.gauges[1].bribes.is_empty());
+
+    let gauges: GaugesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Gauges { epoch_id: 2 }).unwrap())
+            .unwrap();
+    assert_eq!(gauges.gauges.len(), 1);
+    assert_eq!(gauges.gauges[0].lp_asset, lp_asset("lp_2"));
+    assert_eq!(gauges.gauges[0].bribes, coins(1_000u128, "ubribe"));
+
+    // the flow for lp_1 fails to open, which is recorded on the gauge
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: 0,
+            result: SubMsgResult::Err("flow error".to_string()),
+        },
+    )
+    .unwrap();
+
+    let gauges: GaugesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Gauges { epoch_id: 1 }).unwrap())
+            .unwrap();
+    assert_eq!(gauges.gauges[0].lp_asset, lp_asset("lp_1"));
+    assert_eq!(gauges.gauges[0].flow_error, Some("flow error".to_string()));
+}
@@ -0,0 +1,121 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, ContractResult, OwnedDeps, QuerierResult, SystemError,
+    SystemResult, Timestamp, Uint128, Uint64, WasmQuery,
+};
+
+use white_whale_std::epoch_manager::epoch_manager::{EpochResponse, EpochV2};
+use white_whale_std::gauges::InstantiateMsg;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::whale_lair::BondingWeightResponse;
+
+use crate::contract::instantiate;
+use crate::helpers::IncentiveFactoryConfig;
+
+mod bribes;
+mod ownership;
+mod vote;
+
+pub(crate) const EMISSIONS_PER_EPOCH: u128 = 10_000u128;
+pub(crate) const FLOW_DURATION: u64 = 7u64;
+pub(crate) const FEE_DISTRIBUTOR_EPOCH: u64 = 10u64;
+
+pub(crate) fn lp_asset(denom: &str) -> AssetInfo {
+    AssetInfo::NativeToken {
+        denom: denom.to_string(),
+    }
+}
+
+/// Mocks the contracts the gauges contract talks to. alice has a voting power of 100 and bob of
+/// 300. Every LP asset has an incentive contract except "unknown_lp".
+fn mock_wasm_query(query: &WasmQuery) -> QuerierResult {
+    let WasmQuery::Smart { contract_addr, msg } = query else {
+        return SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "raw query".to_string(),
+        });
+    };
+
+    let response = match contract_addr.as_str() {
+        "whale_lair" => match from_json(msg).unwrap() {
+            white_whale_std::whale_lair::QueryMsg::Weight { address, .. } => {
+                let weight = match address.as_str() {
+                    "alice" => Uint128::new(100u128),
+                    "bob" => Uint128::new(300u128),
+                    _ => Uint128::zero(),
+                };
+
+                to_json_binary(&BondingWeightResponse {
+                    address,
+                    weight,
+                    global_weight: Uint128::new(400u128),
+                    share: Default::default(),
+                    timestamp: Timestamp::default(),
+                })
+            }
+            _ => panic!("unexpected whale lair query"),
+        },
+        "incentive_factory" => match from_json(msg).unwrap() {
+            white_whale_std::pool_network::incentive_factory::QueryMsg::Incentive { lp_asset } => {
+                let incentive = if lp_asset == self::lp_asset("unknown_lp") {
+                    None
+                } else {
+                    Some(Addr::unchecked(format!("incentive_{lp_asset}")))
+                };
+
+                to_json_binary(&incentive)
+            }
+            white_whale_std::pool_network::incentive_factory::QueryMsg::Config {} => {
+                to_json_binary(&IncentiveFactoryConfig {
+                    fee_distributor_addr: Addr::unchecked("fee_distributor"),
+                    create_flow_fee: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        amount: Uint128::new(1_000u128),
+                    },
+                })
+            }
+            _ => panic!("unexpected incentive factory query"),
+        },
+        "fee_distributor" => to_json_binary(&white_whale_std::fee_distributor::EpochResponse {
+            epoch: white_whale_std::fee_distributor::Epoch {
+                id: Uint64::new(FEE_DISTRIBUTOR_EPOCH),
+                ..Default::default()
+            },
+        }),
+        "epoch_manager" => to_json_binary(&EpochResponse {
+            epoch: EpochV2 {
+                id: 1,
+                start_time: Timestamp::from_seconds(1),
+            },
+        }),
+        _ => panic!("unexpected contract {contract_addr}"),
+    };
+
+    SystemResult::Ok(ContractResult::Ok(response.unwrap()))
+}
+
+/// Instantiates the gauges contract, with the votes being cast on epoch 1.
+pub(crate) fn mock_instantiate() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_wasm_query);
+
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            whale_lair_addr: "whale_lair".to_string(),
+            incentive_factory_addr: "incentive_factory".to_string(),
+            epoch_manager_addr: "epoch_manager".to_string(),
+            emission_denom: "uwhale".to_string(),
+            emissions_per_epoch: Uint128::new(EMISSIONS_PER_EPOCH),
+            flow_duration: FLOW_DURATION,
+        },
+    )
+    .unwrap();
+
+    deps
+}
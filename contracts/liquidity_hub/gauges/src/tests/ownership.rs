@@ -0,0 +1,63 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{from_json, Addr};
+
+use white_whale_std::gauges::{Config, ExecuteMsg, QueryMsg};
+use white_whale_std::ownership::{OwnershipAction, OwnershipProposal};
+
+use crate::contract::{execute, query};
+use crate::tests::mock_instantiate;
+
+#[test]
+fn transfer_ownership_in_two_steps() {
+    let mut deps = mock_instantiate();
+
+    // only the owner can propose a new owner
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+            new_owner: "alice".to_string(),
+            expiry: None,
+        }),
+    );
+    assert!(res.is_err());
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+            new_owner: "alice".to_string(),
+            expiry: None,
+        }),
+    )
+    .unwrap();
+
+    let proposal: Option<OwnershipProposal> =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::OwnershipProposal {}).unwrap())
+            .unwrap();
+    assert_eq!(proposal.unwrap().proposed_owner, Addr::unchecked("alice"));
+
+    // the owner doesn't change until the proposed owner accepts
+    let config: Config =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.owner, Addr::unchecked("owner"));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+    )
+    .unwrap();
+
+    let config: Config =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.owner, Addr::unchecked("alice"));
+
+    let proposal: Option<OwnershipProposal> =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::OwnershipProposal {}).unwrap())
+            .unwrap();
+    assert!(proposal.is_none());
+}
@@ -0,0 +1,197 @@
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{from_json, Addr, Decimal, Uint128};
+
+use white_whale_std::gauges::{ExecuteMsg, GaugeVote, GaugesResponse, QueryMsg, VoteResponse};
+
+use crate::contract::{execute, query};
+use crate::tests::{lp_asset, mock_instantiate};
+use crate::ContractError;
+
+#[test]
+fn cast_and_replace_votes() {
+    let mut deps = mock_instantiate();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![
+                GaugeVote {
+                    lp_asset: lp_asset("lp_1"),
+                    weight: Decimal::percent(50),
+                },
+                GaugeVote {
+                    lp_asset: lp_asset("lp_2"),
+                    weight: Decimal::percent(50),
+                },
+            ],
+        },
+    )
+    .unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("bob", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![GaugeVote {
+                lp_asset: lp_asset("lp_1"),
+                weight: Decimal::one(),
+            }],
+        },
+    )
+    .unwrap();
+
+    let gauges: GaugesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Gauges { epoch_id: 1 }).unwrap())
+            .unwrap();
+
+    assert_eq!(gauges.total_votes, Uint128::new(400u128));
+    assert_eq!(gauges.gauges.len(), 2);
+    assert_eq!(gauges.gauges[0].lp_asset, lp_asset("lp_1"));
+    assert_eq!(
+        gauges.gauges[0].incentive_addr,
+        Addr::unchecked("incentive_lp_1")
+    );
+    assert_eq!(gauges.gauges[0].votes, Uint128::new(350u128));
+    assert_eq!(gauges.gauges[1].lp_asset, lp_asset("lp_2"));
+    assert_eq!(gauges.gauges[1].votes, Uint128::new(50u128));
+
+    // alice moves all of her votes to lp_2
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![GaugeVote {
+                lp_asset: lp_asset("lp_2"),
+                weight: Decimal::one(),
+            }],
+        },
+    )
+    .unwrap();
+
+    let gauges: GaugesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Gauges { epoch_id: 1 }).unwrap())
+            .unwrap();
+
+    assert_eq!(gauges.total_votes, Uint128::new(400u128));
+    assert_eq!(gauges.gauges[0].votes, Uint128::new(300u128));
+    assert_eq!(gauges.gauges[1].votes, Uint128::new(100u128));
+
+    let vote: VoteResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Vote {
+                address: "alice".to_string(),
+                epoch_id: 1,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let vote = vote.vote.unwrap();
+    assert_eq!(vote.voting_power, Uint128::new(100u128));
+    assert_eq!(
+        vote.votes,
+        vec![GaugeVote {
+            lp_asset: lp_asset("lp_2"),
+            weight: Decimal::one(),
+        }]
+    );
+}
+
+#[test]
+fn cant_cast_invalid_votes() {
+    let mut deps = mock_instantiate();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![
+                GaugeVote {
+                    lp_asset: lp_asset("lp_1"),
+                    weight: Decimal::percent(60),
+                },
+                GaugeVote {
+                    lp_asset: lp_asset("lp_2"),
+                    weight: Decimal::percent(50),
+                },
+            ],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidVotes { .. }));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![
+                GaugeVote {
+                    lp_asset: lp_asset("lp_1"),
+                    weight: Decimal::percent(20),
+                },
+                GaugeVote {
+                    lp_asset: lp_asset("lp_1"),
+                    weight: Decimal::percent(20),
+                },
+            ],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidVotes { .. }));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![GaugeVote {
+                lp_asset: lp_asset("lp_1"),
+                weight: Decimal::zero(),
+            }],
+        },
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidVotes { .. }));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("carol", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![GaugeVote {
+                lp_asset: lp_asset("lp_1"),
+                weight: Decimal::one(),
+            }],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("alice", &[]),
+        ExecuteMsg::Vote {
+            votes: vec![GaugeVote {
+                lp_asset: lp_asset("unknown_lp"),
+                weight: Decimal::one(),
+            }],
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NoIncentive {
+            lp_asset: "unknown_lp".to_string()
+        }
+    );
+}
@@ -1,23 +1,21 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult, SubMsg,
-    WasmMsg,
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
 };
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
 
-use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::ownership::query_ownership_proposal;
 use white_whale_std::pool_network::frontend_helper::{
-    Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, TempState,
+    Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
-use white_whale_std::traits::OptionDecimal;
 
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
-use crate::reply;
 use crate::reply::deposit_pair::DEPOSIT_PAIR_REPLY_ID;
-use crate::state::{CONFIG, TEMP_STATE};
+use crate::state::CONFIG;
+use crate::{execute, reply};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "white_whale-frontend-helper";
@@ -55,108 +53,49 @@ pub fn execute(
             assets,
             slippage_tolerance,
             unbonding_duration,
-        } => {
-            TEMP_STATE.save(
-                deps.storage,
-                &TempState {
-                    unbonding_duration,
-                    receiver: info.sender.clone(),
-                    pair_addr: deps.api.addr_validate(&pair_address)?,
-                },
-            )?;
-
-            let transfer_token_msgs = assets
-                .iter()
-                .filter_map(|asset| match asset.info.clone() {
-                    AssetInfo::NativeToken { .. } => None,
-                    AssetInfo::Token { contract_addr } => Some((asset.amount, contract_addr)),
-                })
-                .map(|(token_amount, token_contract_addr)| {
-                    // ensure that we have this token amount
-                    let allowance: cw20::AllowanceResponse = deps.querier.query_wasm_smart(
-                        token_contract_addr.clone(),
-                        &cw20::Cw20QueryMsg::Allowance {
-                            owner: info.sender.clone().into_string(),
-                            spender: env.contract.address.clone().into_string(),
-                        },
-                    )?;
-
-                    if allowance.allowance != token_amount {
-                        return Err(ContractError::MissingToken {
-                            asset: Asset {
-                                info: AssetInfo::Token {
-                                    contract_addr: token_contract_addr,
-                                },
-                                amount: token_amount,
-                            },
-                            current_allowance: allowance.allowance,
-                        });
-                    }
-
-                    Ok::<_, ContractError>(vec![
-                        WasmMsg::Execute {
-                            contract_addr: token_contract_addr.clone(),
-                            msg: to_json_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
-                                owner: info.sender.clone().into_string(),
-                                recipient: env.contract.address.clone().into_string(),
-                                amount: token_amount,
-                            })?,
-                            funds: vec![],
-                        },
-                        WasmMsg::Execute {
-                            contract_addr: token_contract_addr,
-                            msg: to_json_binary(&cw20::Cw20ExecuteMsg::IncreaseAllowance {
-                                spender: pair_address.clone(),
-                                amount: token_amount,
-                                expires: None,
-                            })?,
-                            funds: vec![],
-                        },
-                    ])
-                })
-                .collect::<Result<Vec<_>, _>>()?
-                .concat();
-
-            // send request to deposit
-            Ok(Response::default()
-                .add_attributes(vec![
-                    ("action", "deposit".to_string()),
-                    ("pair_address", pair_address.clone()),
-                    ("unbonding_duration", unbonding_duration.to_string()),
-                    ("slippage_tolerance", slippage_tolerance.to_string()),
-                ])
-                .add_messages(transfer_token_msgs)
-                .add_submessage(SubMsg {
-                    id: DEPOSIT_PAIR_REPLY_ID,
-                    reply_on: cosmwasm_std::ReplyOn::Always,
-                    gas_limit: None,
-                    msg: WasmMsg::Execute {
-                        contract_addr: pair_address,
-                        msg: to_json_binary(
-                            &white_whale_std::pool_network::pair::ExecuteMsg::ProvideLiquidity {
-                                assets,
-                                slippage_tolerance,
-                                receiver: None,
-                            },
-                        )?,
-                        funds: info.funds,
-                    }
-                    .into(),
-                }))
-        }
+        } => execute::deposit::deposit_pair(
+            deps,
+            env,
+            info,
+            pair_address,
+            assets,
+            slippage_tolerance,
+            unbonding_duration,
+        ),
+        ExecuteMsg::DepositTrio {
+            trio_address,
+            assets,
+            slippage_tolerance,
+            unbonding_duration,
+        } => execute::deposit::deposit_trio(
+            deps,
+            env,
+            info,
+            trio_address,
+            assets,
+            slippage_tolerance,
+            unbonding_duration,
+        ),
+        ExecuteMsg::DepositVault {
+            vault_address,
+            asset,
+            unbonding_duration,
+        } => execute::deposit::deposit_vault(
+            deps,
+            env,
+            info,
+            vault_address,
+            asset,
+            unbonding_duration,
+        ),
         ExecuteMsg::UpdateConfig {
             incentive_factory_addr,
-            owner,
         } => {
             let mut config = CONFIG.load(deps.storage)?;
             if config.owner != info.sender {
                 return Err(ContractError::Unauthorized {});
             }
 
-            if let Some(owner) = owner {
-                config.owner = deps.api.addr_validate(&owner)?;
-            }
-
             if let Some(incentive_factory_addr) = incentive_factory_addr {
                 config.incentive_factory_addr = deps.api.addr_validate(&incentive_factory_addr)?;
             }
@@ -165,13 +104,15 @@ pub fn execute(
 
             Ok(Response::default().add_attributes(vec![
                 ("action", "update_config".to_string()),
-                ("owner", config.owner.to_string()),
                 (
                     "incentive_factory_addr",
                     config.incentive_factory_addr.to_string(),
                 ),
             ]))
         }
+        ExecuteMsg::UpdateOwnership(action) => {
+            execute::update_ownership::update_ownership(deps, env, info, action)
+        }
     }
 }
 
@@ -188,6 +129,9 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => Ok(to_json_binary(&CONFIG.load(deps.storage)?)?),
+        QueryMsg::OwnershipProposal {} => {
+            Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+        }
     }
 }
 
@@ -23,7 +23,7 @@ pub enum ContractError {
     #[error("Sender is not authorized to invoke functions on the frontend helper")]
     Unauthorized {},
 
-    #[error("Pair had no incentive associated with it")]
+    #[error("Pool had no incentive associated with it")]
     MissingIncentive { pair_address: String },
 
     #[error("Unknown reply id {id}")]
@@ -0,0 +1,232 @@
+use cosmwasm_std::{to_json_binary, Decimal, DepsMut, Env, MessageInfo, Response, SubMsg, WasmMsg};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::pool_network::frontend_helper::{DepositPoolType, TempState};
+use white_whale_std::traits::OptionDecimal;
+
+use crate::error::ContractError;
+use crate::reply::deposit_pair::DEPOSIT_PAIR_REPLY_ID;
+use crate::state::TEMP_STATE;
+
+/// Deposits assets into a pair pool, opening or expanding an incentive position with the LP
+/// tokens once the deposit is done.
+pub fn deposit_pair(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pair_address: String,
+    assets: [Asset; 2],
+    slippage_tolerance: Option<Decimal>,
+    unbonding_duration: u64,
+) -> Result<Response, ContractError> {
+    save_temp_state(
+        &mut deps,
+        &info,
+        &pair_address,
+        DepositPoolType::Pair,
+        unbonding_duration,
+    )?;
+
+    let transfer_token_msgs = get_transfer_token_msgs(&deps, &env, &info, &assets, &pair_address)?;
+
+    // send request to deposit
+    Ok(Response::default()
+        .add_attributes(vec![
+            ("action", "deposit".to_string()),
+            ("pair_address", pair_address.clone()),
+            ("unbonding_duration", unbonding_duration.to_string()),
+            ("slippage_tolerance", slippage_tolerance.to_string()),
+        ])
+        .add_messages(transfer_token_msgs)
+        .add_submessage(deposit_submsg(
+            pair_address,
+            to_json_binary(
+                &white_whale_std::pool_network::pair::ExecuteMsg::ProvideLiquidity {
+                    assets,
+                    slippage_tolerance,
+                    receiver: None,
+                },
+            )?,
+            info,
+        )))
+}
+
+/// Deposits assets into a trio (3pool), opening or expanding an incentive position with the LP
+/// tokens once the deposit is done.
+pub fn deposit_trio(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trio_address: String,
+    assets: [Asset; 3],
+    slippage_tolerance: Option<Decimal>,
+    unbonding_duration: u64,
+) -> Result<Response, ContractError> {
+    save_temp_state(
+        &mut deps,
+        &info,
+        &trio_address,
+        DepositPoolType::Trio,
+        unbonding_duration,
+    )?;
+
+    let transfer_token_msgs = get_transfer_token_msgs(&deps, &env, &info, &assets, &trio_address)?;
+
+    Ok(Response::default()
+        .add_attributes(vec![
+            ("action", "deposit_trio".to_string()),
+            ("trio_address", trio_address.clone()),
+            ("unbonding_duration", unbonding_duration.to_string()),
+            ("slippage_tolerance", slippage_tolerance.to_string()),
+        ])
+        .add_messages(transfer_token_msgs)
+        .add_submessage(deposit_submsg(
+            trio_address,
+            to_json_binary(
+                &white_whale_std::pool_network::trio::ExecuteMsg::ProvideLiquidity {
+                    assets,
+                    slippage_tolerance,
+                    receiver: None,
+                },
+            )?,
+            info,
+        )))
+}
+
+/// Deposits an asset into a vault, opening or expanding an incentive position with the LP tokens
+/// once the deposit is done.
+pub fn deposit_vault(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    vault_address: String,
+    asset: Asset,
+    unbonding_duration: u64,
+) -> Result<Response, ContractError> {
+    save_temp_state(
+        &mut deps,
+        &info,
+        &vault_address,
+        DepositPoolType::Vault,
+        unbonding_duration,
+    )?;
+
+    let transfer_token_msgs =
+        get_transfer_token_msgs(&deps, &env, &info, &[asset.clone()], &vault_address)?;
+
+    Ok(Response::default()
+        .add_attributes(vec![
+            ("action", "deposit_vault".to_string()),
+            ("vault_address", vault_address.clone()),
+            ("asset", asset.to_string()),
+            ("unbonding_duration", unbonding_duration.to_string()),
+        ])
+        .add_messages(transfer_token_msgs)
+        .add_submessage(deposit_submsg(
+            vault_address,
+            to_json_binary(
+                &white_whale_std::vault_network::vault::ExecuteMsg::Deposit {
+                    amount: asset.amount,
+                },
+            )?,
+            info,
+        )))
+}
+
+/// Stores the information needed to open the incentive position once the deposit is done.
+fn save_temp_state(
+    deps: &mut DepsMut,
+    info: &MessageInfo,
+    pool_address: &str,
+    pool_type: DepositPoolType,
+    unbonding_duration: u64,
+) -> Result<(), ContractError> {
+    TEMP_STATE.save(
+        deps.storage,
+        &TempState {
+            unbonding_duration,
+            receiver: info.sender.clone(),
+            pool_addr: deps.api.addr_validate(pool_address)?,
+            pool_type,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Builds the messages to transfer the cw20 tokens to be deposited from the sender to the frontend
+/// helper, and to allow the pool to spend them.
+fn get_transfer_token_msgs(
+    deps: &DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    assets: &[Asset],
+    pool_address: &str,
+) -> Result<Vec<WasmMsg>, ContractError> {
+    Ok(assets
+        .iter()
+        .filter_map(|asset| match asset.info.clone() {
+            AssetInfo::NativeToken { .. } => None,
+            AssetInfo::Token { contract_addr } => Some((asset.amount, contract_addr)),
+        })
+        .map(|(token_amount, token_contract_addr)| {
+            // ensure that we have this token amount
+            let allowance: cw20::AllowanceResponse = deps.querier.query_wasm_smart(
+                token_contract_addr.clone(),
+                &cw20::Cw20QueryMsg::Allowance {
+                    owner: info.sender.clone().into_string(),
+                    spender: env.contract.address.clone().into_string(),
+                },
+            )?;
+
+            if allowance.allowance != token_amount {
+                return Err(ContractError::MissingToken {
+                    asset: Asset {
+                        info: AssetInfo::Token {
+                            contract_addr: token_contract_addr,
+                        },
+                        amount: token_amount,
+                    },
+                    current_allowance: allowance.allowance,
+                });
+            }
+
+            Ok::<_, ContractError>(vec![
+                WasmMsg::Execute {
+                    contract_addr: token_contract_addr.clone(),
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
+                        owner: info.sender.clone().into_string(),
+                        recipient: env.contract.address.clone().into_string(),
+                        amount: token_amount,
+                    })?,
+                    funds: vec![],
+                },
+                WasmMsg::Execute {
+                    contract_addr: token_contract_addr,
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: pool_address.to_string(),
+                        amount: token_amount,
+                        expires: None,
+                    })?,
+                    funds: vec![],
+                },
+            ])
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .concat())
+}
+
+/// Builds the deposit submessage to the pool, which replies to open the incentive position.
+fn deposit_submsg(pool_address: String, msg: cosmwasm_std::Binary, info: MessageInfo) -> SubMsg {
+    SubMsg {
+        id: DEPOSIT_PAIR_REPLY_ID,
+        reply_on: cosmwasm_std::ReplyOn::Always,
+        gas_limit: None,
+        msg: WasmMsg::Execute {
+            contract_addr: pool_address,
+            msg,
+            funds: info.funds,
+        }
+        .into(),
+    }
+}
@@ -0,0 +1,26 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use white_whale_std::ownership::{self, OwnershipAction};
+
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+/// Proposes, accepts or cancels a transfer of the ownership of the frontend helper. Once the
+/// ownership is accepted, the new owner is stored in the config.
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
@@ -1,6 +1,8 @@
 use cosmwasm_std::{to_json_binary, DepsMut, Env, Reply, Response, WasmMsg};
 use white_whale_std::pool_network::{
-    asset::AssetInfo, frontend_helper::TempState, incentive::QueryPosition,
+    asset::AssetInfo,
+    frontend_helper::{DepositPoolType, TempState},
+    incentive::QueryPosition,
 };
 
 use crate::{
@@ -8,10 +10,10 @@ use crate::{
     state::{CONFIG, TEMP_STATE},
 };
 
-/// The reply ID for submessages after depositing to the pair contract.
+/// The reply ID for submessages after depositing to the pair, trio or vault contract.
 pub const DEPOSIT_PAIR_REPLY_ID: u64 = 1;
 
-/// Triggered after a new deposit is made to a pair.
+/// Triggered after a new deposit is made to a pair, trio or vault.
 ///
 /// Triggered to allow us to register the new contract in state.
 pub fn deposit_pair(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
@@ -22,29 +24,55 @@ pub fn deposit_pair(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, Con
     let TempState {
         unbonding_duration,
         receiver,
-        pair_addr,
+        pool_addr,
+        pool_type,
     } = TEMP_STATE.load(deps.storage)?;
 
     // now perform the incentive position creation
     let config = CONFIG.load(deps.storage)?;
 
-    let pair_info: white_whale_std::pool_network::asset::PairInfo = deps.querier.query_wasm_smart(
-        pair_addr.clone(),
-        &white_whale_std::pool_network::pair::QueryMsg::Pair {},
-    )?;
+    let lp_asset = match pool_type {
+        DepositPoolType::Pair => {
+            let pair_info: white_whale_std::pool_network::asset::PairInfo =
+                deps.querier.query_wasm_smart(
+                    pool_addr.clone(),
+                    &white_whale_std::pool_network::pair::QueryMsg::Pair {},
+                )?;
+
+            pair_info.liquidity_token
+        }
+        DepositPoolType::Trio => {
+            let trio_info: white_whale_std::pool_network::asset::TrioInfo =
+                deps.querier.query_wasm_smart(
+                    pool_addr.clone(),
+                    &white_whale_std::pool_network::trio::QueryMsg::Trio {},
+                )?;
+
+            trio_info.liquidity_token
+        }
+        DepositPoolType::Vault => {
+            let vault_config: white_whale_std::vault_network::vault::Config =
+                deps.querier.query_wasm_smart(
+                    pool_addr.clone(),
+                    &white_whale_std::vault_network::vault::QueryMsg::Config {},
+                )?;
+
+            vault_config.lp_asset
+        }
+    };
 
     let incentive_address: white_whale_std::pool_network::incentive_factory::IncentiveResponse =
         deps.querier.query_wasm_smart(
             config.incentive_factory_addr,
             &white_whale_std::pool_network::incentive_factory::QueryMsg::Incentive {
-                lp_asset: pair_info.liquidity_token.clone(),
+                lp_asset: lp_asset.clone(),
             },
         )?;
     // return an error if there was no incentive address
     let incentive_address = incentive_address.map_or_else(
         || {
             Err(ContractError::MissingIncentive {
-                pair_address: pair_addr.to_string(),
+                pair_address: pool_addr.to_string(),
             })
         },
         Ok,
@@ -53,7 +81,7 @@ pub fn deposit_pair(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, Con
     // compute current LP token amount
     let mut messages = vec![];
     let mut funds = vec![];
-    let lp_amount = match pair_info.liquidity_token {
+    let lp_amount = match lp_asset {
         AssetInfo::NativeToken { denom } => {
             // ask the bank module
             let balance = deps.querier.query_balance(env.contract.address, denom)?;
@@ -110,7 +138,8 @@ pub fn deposit_pair(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, Con
     Ok(Response::default()
         .add_attributes(vec![
             ("action", "deposit_pair_reply"),
-            ("pair_address", pair_addr.to_string().as_str()),
+            ("pool_address", pool_addr.to_string().as_str()),
+            ("pool_type", pool_type.to_string().as_str()),
             ("lp_amount", &lp_amount.to_string()),
             ("unbonding_duration", &unbonding_duration.to_string()),
             ("receiver", receiver.as_ref()),
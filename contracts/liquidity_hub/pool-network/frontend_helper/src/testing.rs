@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod tests {
     use crate::error::ContractError;
-    use cosmwasm_std::{coin, coins, to_json_binary, Addr, Uint128, WasmMsg};
+    use cosmwasm_std::{coin, coins, to_json_binary, Addr, Decimal, Uint128, WasmMsg};
     use cw_multi_test::Executor;
+    use white_whale_std::fee::{Fee, VaultFee};
+    use white_whale_std::ownership::OwnershipAction;
     use white_whale_std::pool_network::asset::{Asset, AssetInfo};
     use white_whale_std::pool_network::frontend_helper::ConfigResponse;
     use white_whale_std::pool_network::incentive::{PositionsResponse, QueryPosition};
@@ -13,6 +15,7 @@ mod tests {
     use crate::tests::{
         mock_app::mock_app_with_balance,
         mock_instantiate::{app_mock_instantiate, AppInstantiateResponse},
+        store_code::{store_cw20_token_code, store_vault},
     };
 
     #[test]
@@ -199,6 +202,155 @@ mod tests {
         }
     }
 
+    #[test]
+    fn can_deposit_vault() {
+        let mut app =
+            mock_app_with_balance(vec![(mock_creator().sender, vec![coin(10_000, "uwhale")])]);
+
+        let AppInstantiateResponse {
+            frontend_helper,
+            incentive_factory,
+            ..
+        } = app_mock_instantiate(
+            &mut app,
+            [
+                AssetInfo::NativeToken {
+                    denom: "token_a".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "token_b".to_string(),
+                },
+            ],
+        );
+
+        let token_id = store_cw20_token_code(&mut app);
+        let vault_id = store_vault(&mut app);
+
+        let vault_address = app
+            .instantiate_contract(
+                vault_id,
+                mock_admin().sender,
+                &white_whale_std::vault_network::vault::InstantiateMsg {
+                    owner: mock_admin().sender.into_string(),
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    token_id,
+                    vault_fees: VaultFee {
+                        protocol_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                        flash_loan_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                        burn_fee: Fee {
+                            share: Decimal::zero(),
+                        },
+                    },
+                    fee_collector_addr: "fee_collector_addr".to_string(),
+                    token_factory_lp: false,
+                },
+                &[],
+                "mock vault",
+                None,
+            )
+            .unwrap();
+
+        let vault_lp_token = app
+            .wrap()
+            .query_wasm_smart::<white_whale_std::vault_network::vault::Config>(
+                vault_address.clone(),
+                &white_whale_std::vault_network::vault::QueryMsg::Config {},
+            )
+            .unwrap()
+            .lp_asset;
+
+        // depositing without an incentive for the vault LP token fails
+        let err = app
+            .execute_contract(
+                mock_creator().sender,
+                frontend_helper.clone(),
+                &white_whale_std::pool_network::frontend_helper::ExecuteMsg::DepositVault {
+                    vault_address: vault_address.clone().into_string(),
+                    asset: Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        amount: Uint128::new(5_000),
+                    },
+                    unbonding_duration: 86400,
+                },
+                &[coin(5_000, "uwhale")],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("Pool had no incentive"));
+
+        app.execute_contract(
+            mock_admin().sender,
+            incentive_factory.clone(),
+            &white_whale_std::pool_network::incentive_factory::ExecuteMsg::CreateIncentive {
+                lp_asset: vault_lp_token.clone(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            mock_creator().sender,
+            frontend_helper,
+            &white_whale_std::pool_network::frontend_helper::ExecuteMsg::DepositVault {
+                vault_address: vault_address.into_string(),
+                asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    amount: Uint128::new(5_000),
+                },
+                unbonding_duration: 86400,
+            },
+            &[coin(5_000, "uwhale")],
+        )
+        .unwrap();
+
+        let incentive_addr: IncentiveResponse = app
+            .wrap()
+            .query_wasm_smart(
+                incentive_factory,
+                &white_whale_std::pool_network::incentive_factory::QueryMsg::Incentive {
+                    lp_asset: vault_lp_token,
+                },
+            )
+            .unwrap();
+
+        let positions_response: PositionsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                incentive_addr.unwrap(),
+                &white_whale_std::pool_network::incentive::QueryMsg::Positions {
+                    address: mock_creator().sender.to_string(),
+                },
+            )
+            .unwrap();
+
+        let positions: Vec<QueryPosition> = positions_response.positions;
+        assert_eq!(positions.len(), 1);
+
+        match positions.first().unwrap() {
+            QueryPosition::OpenPosition {
+                amount,
+                unbonding_duration,
+                ..
+            } => {
+                assert_eq!(amount, &Uint128::new(4_000)); // 5_000 - 1000 that is kept by the vault as min lp mint (first deposit ever)
+                assert_eq!(unbonding_duration, &86400);
+            }
+            QueryPosition::ClosedPosition { .. } => panic!("position should be open"),
+        }
+    }
+
     //write test to update the config of the contract
     #[test]
     fn update_config() {
@@ -234,7 +386,6 @@ mod tests {
             frontend_helper.clone(),
             &white_whale_std::pool_network::frontend_helper::ExecuteMsg::UpdateConfig {
                 incentive_factory_addr: Some("new_factory".to_string()),
-                owner: Some("new_owner".to_string()),
             },
             &vec![],
         );
@@ -251,7 +402,6 @@ mod tests {
             frontend_helper.clone(),
             &white_whale_std::pool_network::frontend_helper::ExecuteMsg::UpdateConfig {
                 incentive_factory_addr: Some("new_factory".to_string()),
-                owner: Some("new_owner".to_string()),
             },
             &vec![],
         )
@@ -260,12 +410,12 @@ mod tests {
         let new_config: ConfigResponse = app
             .wrap()
             .query_wasm_smart(
-                frontend_helper,
+                frontend_helper.clone(),
                 &white_whale_std::pool_network::frontend_helper::QueryMsg::Config {},
             )
             .unwrap();
 
-        assert_eq!(new_config.owner, Addr::unchecked("new_owner"));
+        assert_eq!(new_config.owner, mock_admin().sender);
         assert_ne!(
             new_config.incentive_factory_addr,
             config.incentive_factory_addr
@@ -274,5 +424,37 @@ mod tests {
             new_config.incentive_factory_addr,
             Addr::unchecked("new_factory")
         );
+
+        // the ownership is transferred in two steps
+        app.execute_contract(
+            mock_admin().sender,
+            frontend_helper.clone(),
+            &white_whale_std::pool_network::frontend_helper::ExecuteMsg::UpdateOwnership(
+                OwnershipAction::ProposeNewOwner {
+                    new_owner: mock_alice().sender.into_string(),
+                    expiry: None,
+                },
+            ),
+            &vec![],
+        )
+        .unwrap();
+        app.execute_contract(
+            mock_alice().sender,
+            frontend_helper.clone(),
+            &white_whale_std::pool_network::frontend_helper::ExecuteMsg::UpdateOwnership(
+                OwnershipAction::AcceptOwnership {},
+            ),
+            &vec![],
+        )
+        .unwrap();
+
+        let new_config: ConfigResponse = app
+            .wrap()
+            .query_wasm_smart(
+                frontend_helper,
+                &white_whale_std::pool_network::frontend_helper::QueryMsg::Config {},
+            )
+            .unwrap();
+        assert_eq!(new_config.owner, mock_alice().sender);
     }
 }
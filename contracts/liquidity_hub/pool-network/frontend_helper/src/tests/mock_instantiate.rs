@@ -158,10 +158,13 @@ pub fn app_mock_instantiate(app: &mut App, pool_assets: [AssetInfo; 2]) -> AppIn
                 fee_collector_addr: "fee_collector".to_string(),
                 incentive_code_id: incentive_id,
                 max_concurrent_flows: 7,
+                reserved_flow_slots: 0,
                 max_flow_epoch_buffer: 100,
                 max_unbonding_duration: 100000,
+                emergency_exit_penalty: Decimal::zero(),
                 min_unbonding_duration: 86400,
                 fee_distributor_addr: fee_distributor.to_string(),
+                epoch_manager_addr: None,
             },
             &[],
             "mock incentive factory",
@@ -72,6 +72,19 @@ pub fn store_pair(app: &mut App) -> u64 {
     app.store_code(contract)
 }
 
+pub fn store_vault(app: &mut App) -> u64 {
+    let contract = Box::new(
+        ContractWrapper::new(
+            vault::contract::execute,
+            vault::contract::instantiate,
+            vault::contract::query,
+        )
+        .with_reply(vault::reply::reply),
+    );
+
+    app.store_code(contract)
+}
+
 pub fn fee_distributor_mock_contract(app: &mut App) -> u64 {
     let contract = Box::new(ContractWrapper::new(
         fee_distributor_mock::contract::execute,
@@ -3,10 +3,12 @@ use cosmwasm_std::{
     Uint256, WasmMsg,
 };
 
-use white_whale_std::pool_network::asset::AssetInfo;
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
-use crate::helpers::{get_flow_asset_amount_at_epoch, get_flow_current_end_epoch};
-use crate::state::{EpochId, ADDRESS_WEIGHT_HISTORY, GLOBAL_WEIGHT_SNAPSHOT, LAST_CLAIMED_EPOCH};
+use crate::helpers::get_flow_emission_at_epoch;
+use crate::state::{
+    EpochId, ADDRESS_WEIGHT_HISTORY, GLOBAL_WEIGHT_SNAPSHOT, LAST_CLAIMED_EPOCH, VESTINGS,
+};
 use crate::{error::ContractError, helpers, state::FLOWS};
 
 //todo abstract code in this function as most of it is also used in get_rewards.rs
@@ -79,6 +81,8 @@ pub fn claim(deps: &mut DepsMut, info: &MessageInfo) -> Result<Vec<CosmosMsg>, C
         };
 
         let mut epoch_count = 0;
+        // the rewards of flows with a vesting duration are added to the vesting ledger instead
+        let mut vesting_reward = Uint128::zero();
 
         // calculate the total reward for this flow, from the first claimable epoch to the current epoch
         for epoch_id in first_claimable_epoch..=current_epoch {
@@ -117,14 +121,9 @@ pub fn claim(deps: &mut DepsMut, info: &MessageInfo) -> Result<Vec<CosmosMsg>, C
                 previous_emission
             };
 
-            // use the flow asset amount at the current epoch considering flow expansions
-            let flow_asset_amount = get_flow_asset_amount_at_epoch(flow, epoch_id);
-            let flow_expanded_end_epoch = get_flow_current_end_epoch(flow, epoch_id);
-
-            // emission = (total_tokens_for_epoch_considering_expansion - emitted_tokens_at_epoch) / (flow_start + flow_duration - epoch) = (total_tokens - emitted_tokens_at_epoch) / (flow_end - epoch)
-            let emission_per_epoch = flow_asset_amount
-                .saturating_sub(emitted_tokens)
-                .checked_div(Uint128::from(flow_expanded_end_epoch - epoch_id))?;
+            // calculate the emission for this epoch according to the flow's curve, considering
+            // flow expansions
+            let emission_per_epoch = get_flow_emission_at_epoch(flow, epoch_id, emitted_tokens)?;
 
             // record the emitted tokens for this epoch if it hasn't been recorded before.
             // emitted tokens for this epoch is the total emitted tokens in previous epoch + the ones
@@ -188,6 +187,11 @@ pub fn claim(deps: &mut DepsMut, info: &MessageInfo) -> Result<Vec<CosmosMsg>, C
             // increase the amount of tokens claimed on this flow
             flow.claimed_amount = flow.claimed_amount.checked_add(user_reward_at_epoch)?;
 
+            if flow.vesting_duration.is_some() {
+                vesting_reward = vesting_reward.checked_add(user_reward_at_epoch)?;
+                continue;
+            }
+
             // construct transfer message for user
             match &flow.flow_asset.info {
                 AssetInfo::NativeToken { denom } => messages.push(
@@ -214,6 +218,21 @@ pub fn claim(deps: &mut DepsMut, info: &MessageInfo) -> Result<Vec<CosmosMsg>, C
             }
         }
 
+        if let Some(vesting_duration) = flow.vesting_duration {
+            if !vesting_reward.is_zero() {
+                helpers::add_vesting(
+                    deps.storage,
+                    &address,
+                    Asset {
+                        info: flow.flow_asset.info.clone(),
+                        amount: vesting_reward,
+                    },
+                    current_epoch,
+                    vesting_duration,
+                )?;
+            }
+        }
+
         // save current flow state
         FLOWS.save(deps.storage, (flow.start_epoch, flow.flow_id), flow)?;
     }
@@ -235,3 +254,46 @@ pub fn claim(deps: &mut DepsMut, info: &MessageInfo) -> Result<Vec<CosmosMsg>, C
 
     Ok(messages)
 }
+
+/// Performs the claim of the vested flow rewards, returning all the [`CosmosMsg`]'s to run. Fully
+/// vested rewards are removed from the vesting ledger.
+pub fn claim_vested(
+    deps: &mut DepsMut,
+    info: &MessageInfo,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let current_epoch = helpers::get_current_epoch(deps.as_ref())?;
+    let vestings = VESTINGS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut remaining_vestings = vec![];
+
+    for mut vesting in vestings {
+        let claimable_amount = helpers::get_vested_amount(&vesting, current_epoch)?
+            .checked_sub(vesting.claimed_amount)?;
+
+        if !claimable_amount.is_zero() {
+            vesting.claimed_amount = vesting.claimed_amount.checked_add(claimable_amount)?;
+            messages.push(
+                Asset {
+                    info: vesting.asset.info.clone(),
+                    amount: claimable_amount,
+                }
+                .into_msg(info.sender.clone())?,
+            );
+        }
+
+        if vesting.claimed_amount < vesting.asset.amount {
+            remaining_vestings.push(vesting);
+        }
+    }
+
+    if remaining_vestings.is_empty() {
+        VESTINGS.remove(deps.storage, &info.sender);
+    } else {
+        VESTINGS.save(deps.storage, &info.sender, &remaining_vestings)?;
+    }
+
+    Ok(messages)
+}
@@ -15,7 +15,7 @@ use white_whale_std::pool_network::asset::AssetInfo;
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::state::{CONFIG, FLOW_COUNTER, GLOBAL_WEIGHT};
-use crate::{execute, migrations, queries};
+use crate::{execute, helpers, migrations, queries};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "white_whale-incentive";
@@ -77,14 +77,18 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    helpers::assert_not_paused(deps.as_ref(), &msg)?;
+
     match msg {
         ExecuteMsg::TakeGlobalWeightSnapshot {} => execute::take_global_weight_snapshot(deps),
+        ExecuteMsg::EpochChangedHook(msg) => execute::epoch_changed_hook(deps, info, msg),
         ExecuteMsg::OpenFlow {
             start_epoch,
             end_epoch,
             curve,
             flow_asset,
             flow_label,
+            vesting_duration,
         } => execute::open_flow(
             deps,
             env,
@@ -94,6 +98,7 @@ pub fn execute(
             curve,
             flow_asset,
             flow_label,
+            vesting_duration,
         ),
         ExecuteMsg::CloseFlow { flow_identifier } => {
             execute::close_flow(deps, info, flow_identifier)
@@ -108,11 +113,21 @@ pub fn execute(
             unbonding_duration,
             receiver,
         } => execute::expand_position(deps, env, info, amount, unbonding_duration, receiver),
-        ExecuteMsg::ClosePosition { unbonding_duration } => {
-            execute::close_position(deps, env, info, unbonding_duration)
+        ExecuteMsg::ClosePosition {
+            unbonding_duration,
+            amount,
+        } => execute::close_position(deps, env, info, unbonding_duration, amount),
+        ExecuteMsg::PerpetualLockPosition { unbonding_duration } => {
+            execute::perpetual_lock_position(deps, info, unbonding_duration)
+        }
+        ExecuteMsg::RolloverPositions { unbonding_duration } => {
+            execute::rollover_positions(deps, env, info, unbonding_duration)
         }
         ExecuteMsg::Withdraw {} => execute::withdraw(deps, env, info),
+        ExecuteMsg::EmergencyExit {} => execute::emergency_exit(deps, env, info),
         ExecuteMsg::Claim {} => execute::claim(deps, info),
+        ExecuteMsg::ClaimVested {} => execute::claim_vested(deps, info),
+        ExecuteMsg::ClaimFor { address } => execute::claim_for(deps, env, info, address),
         ExecuteMsg::ExpandFlow {
             flow_identifier,
             end_epoch,
@@ -154,6 +169,15 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
         QueryMsg::CurrentEpochRewardsShare { address } => Ok(to_json_binary(
             &queries::get_rewards_share(deps, deps.api.addr_validate(&address)?)?,
         )?),
+        QueryMsg::ExpectedEmissions {
+            address,
+            new_position,
+        } => Ok(to_json_binary(&queries::get_expected_emissions(
+            deps,
+            address,
+            new_position,
+        )?)?),
+        QueryMsg::Vesting { address } => Ok(to_json_binary(&queries::get_vesting(deps, address)?)?),
     }
 }
 
@@ -176,6 +200,10 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
         migrations::migrate_to_v106(deps.branch())?;
     }
 
+    if storage_version < Version::parse("1.1.0")? {
+        migrations::migrate_to_v110(deps.branch())?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
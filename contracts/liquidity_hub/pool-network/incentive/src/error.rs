@@ -153,6 +153,30 @@ pub enum ContractError {
 
     #[error("The flow has already ended, can't be expanded")]
     FlowAlreadyEnded {},
+
+    #[error("The position with unbonding_duration of {unbonding_duration} is already perpetually locked")]
+    PositionAlreadyPerpetual { unbonding_duration: u64 },
+
+    #[error("There are no closed positions that finished unbonding to roll over")]
+    NothingToRollover {},
+
+    #[error("Can't close {amount} from a position of {position_amount}")]
+    InvalidCloseAmount {
+        amount: Uint128,
+        position_amount: Uint128,
+    },
+
+    #[error("There are no closed positions to exit")]
+    NoClosedPositions {},
+
+    #[error("The flow curve is invalid: {reason}")]
+    InvalidCurve { reason: String },
+
+    #[error("Either an address or a new position must be specified")]
+    MissingAddressOrPosition {},
+
+    #[error("The {feature} are paused across the incentives of the factory")]
+    FeaturePaused { feature: String },
 }
 
 impl From<semver::Error> for ContractError {
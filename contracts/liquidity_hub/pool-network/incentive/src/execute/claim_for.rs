@@ -0,0 +1,46 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use crate::error::ContractError;
+use crate::helpers;
+use crate::state::{CONFIG, GLOBAL_WEIGHT_SNAPSHOT};
+
+/// Claims the rewards and the vested rewards, and withdraws the closed positions of the given
+/// address on behalf of the incentive factory, which batches claims across incentive contracts.
+pub fn claim_for(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.factory_address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let address_info = MessageInfo {
+        sender: deps.api.addr_validate(&address)?,
+        funds: vec![],
+    };
+
+    // rewards can't be claimed until the global weight snapshot for the current epoch is taken,
+    // in which case only the closed positions are withdrawn
+    let current_epoch = helpers::get_current_epoch(deps.as_ref())?;
+    let mut messages = if GLOBAL_WEIGHT_SNAPSHOT.has(deps.storage, current_epoch) {
+        match crate::claim::claim(&mut deps, &address_info) {
+            Ok(messages) => messages,
+            Err(ContractError::NothingToClaim {}) => vec![],
+            Err(err) => return Err(err),
+        }
+    } else {
+        vec![]
+    };
+
+    messages.extend(crate::claim::claim_vested(&mut deps, &address_info)?);
+
+    let withdraw_response = super::withdraw(deps.branch(), env, address_info)?;
+
+    Ok(Response::default()
+        .add_attributes(vec![("action", "claim_for"), ("address", address.as_str())])
+        .add_messages(messages)
+        .add_submessages(withdraw_response.messages))
+}
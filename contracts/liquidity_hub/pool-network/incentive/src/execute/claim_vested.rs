@@ -0,0 +1,16 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+use crate::error::ContractError;
+
+/// Claims the vested flow rewards for the user.
+pub fn claim_vested(mut deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let messages = crate::claim::claim_vested(&mut deps, &info)?;
+
+    if messages.is_empty() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    Ok(Response::default()
+        .add_attributes(vec![("action", "claim_vested")])
+        .add_messages(messages))
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, Uint128};
 
 use white_whale_std::pool_network::incentive::{ClosedPosition, OpenPosition};
 
@@ -7,16 +7,18 @@ use crate::state::ADDRESS_WEIGHT_HISTORY;
 use crate::{
     error::ContractError,
     helpers,
-    state::{ADDRESS_WEIGHT, CLOSED_POSITIONS, GLOBAL_WEIGHT, OPEN_POSITIONS},
+    state::{ADDRESS_WEIGHT, CLOSED_POSITIONS, GLOBAL_WEIGHT, OPEN_POSITIONS, PERPETUAL_POSITIONS},
     weight::calculate_weight,
 };
 
-/// Closes the position for the user with the given unbonding_duration.
+/// Closes the position for the user with the given unbonding_duration. If an amount is given, only
+/// that amount is closed and the rest of the position is left open.
 pub fn close_position(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     unbonding_duration: u64,
+    amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     //query and check if the user has pending rewards
     let rewards_query_result = get_rewards(deps.as_ref(), info.sender.clone().into_string());
@@ -38,6 +40,23 @@ pub fn close_position(
         .ok_or(ContractError::NonExistentPosition { unbonding_duration })?;
     let to_close_position = &open_positions[to_close_index];
 
+    let close_amount = amount.unwrap_or(to_close_position.amount);
+    if close_amount.is_zero() || close_amount > to_close_position.amount {
+        return Err(ContractError::InvalidCloseAmount {
+            amount: close_amount,
+            position_amount: to_close_position.amount,
+        });
+    }
+    let is_partial_close = close_amount < to_close_position.amount;
+
+    // perpetually locked positions unbond over, and have the weight of, the maximum unbonding
+    // duration they were locked with. The rest of a partially closed position stays locked.
+    let weight_duration =
+        helpers::get_position_weight_duration(deps.storage, &info.sender, unbonding_duration)?;
+    if !is_partial_close {
+        PERPETUAL_POSITIONS.remove(deps.storage, (&info.sender, unbonding_duration));
+    }
+
     // move to a closed position
     CLOSED_POSITIONS.update::<_, ContractError>(
         deps.storage,
@@ -46,12 +65,12 @@ pub fn close_position(
             let mut closed_positions = closed_positions.unwrap_or_default();
 
             closed_positions.push(ClosedPosition {
-                amount: to_close_position.amount,
+                amount: close_amount,
                 unbonding_timestamp: env
                     .block
                     .time
                     .seconds()
-                    .checked_add(to_close_position.unbonding_duration)
+                    .checked_add(weight_duration)
                     .ok_or(ContractError::OverflowTimestamp)?,
             });
 
@@ -60,9 +79,15 @@ pub fn close_position(
     )?;
 
     // reduce weight
-    // we reduce the weight to be equivalent to 1*amount, so we subtract by (weight - amount)
-    // this should always be a valid operation as calculate_weight will return >= amount
-    let weight_to_reduce = calculate_weight(unbonding_duration, to_close_position.amount)?;
+    // a partial close takes the closed share of the position's weight out, leaving the weight of the
+    // remaining amount so that closing the rest of the position later takes its whole weight out
+    let position_weight = calculate_weight(weight_duration, to_close_position.amount)?;
+    let weight_to_reduce = if is_partial_close {
+        let remaining_amount = to_close_position.amount.checked_sub(close_amount)?;
+        position_weight.checked_sub(calculate_weight(weight_duration, remaining_amount)?)?
+    } else {
+        position_weight
+    };
 
     // reduce the global weight
     GLOBAL_WEIGHT.update::<_, StdError>(deps.storage, |global_weight| {
@@ -85,10 +110,19 @@ pub fn close_position(
         |_| Ok(user_weight),
     )?;
 
-    // remove closed position from open positions map
-    let closing_position: OpenPosition = open_positions[to_close_index].clone();
-
-    open_positions.remove(to_close_index);
+    // remove closed position from open positions map, or reduce it if it was partially closed
+    let closing_position = OpenPosition {
+        amount: close_amount,
+        unbonding_duration,
+    };
+
+    if is_partial_close {
+        open_positions[to_close_index].amount = open_positions[to_close_index]
+            .amount
+            .checked_sub(close_amount)?;
+    } else {
+        open_positions.remove(to_close_index);
+    }
     OPEN_POSITIONS.save(deps.storage, info.sender, &open_positions)?;
 
     Ok(Response::default().add_attributes(vec![
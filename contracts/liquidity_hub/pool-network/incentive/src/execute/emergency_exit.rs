@@ -0,0 +1,83 @@
+use cosmwasm_std::{CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+use white_whale_std::pool_network::asset::Asset;
+
+use crate::{
+    error::ContractError,
+    helpers,
+    state::{CLOSED_POSITIONS, CONFIG},
+};
+
+/// Withdraws the LP tokens from all the closed positions of the sender, without waiting for them to
+/// finish unbonding. The penalty taken from the positions that are still unbonding is sent to the
+/// fee collector.
+pub fn emergency_exit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let closed_positions = CLOSED_POSITIONS
+        .may_load(deps.storage, info.sender.clone())?
+        .unwrap_or_default();
+
+    if closed_positions.is_empty() {
+        return Err(ContractError::NoClosedPositions {});
+    }
+
+    let incentive_factory_config = helpers::get_incentive_factory_config(deps.as_ref())?;
+
+    let mut return_token_count = Uint128::zero();
+    let mut penalty_token_count = Uint128::zero();
+
+    for position in closed_positions {
+        // the penalty scales with the time left to unbond, capped at the maximum unbonding duration
+        let time_left = position
+            .unbonding_timestamp
+            .saturating_sub(env.block.time.seconds())
+            .min(incentive_factory_config.max_unbonding_duration);
+
+        let penalty = if time_left == 0 {
+            Uint128::zero()
+        } else {
+            position
+                .amount
+                .mul_floor(incentive_factory_config.emergency_exit_penalty)
+                .multiply_ratio(time_left, incentive_factory_config.max_unbonding_duration)
+        };
+
+        penalty_token_count = penalty_token_count.checked_add(penalty)?;
+        return_token_count =
+            return_token_count.checked_add(position.amount.checked_sub(penalty)?)?;
+    }
+
+    CLOSED_POSITIONS.remove(deps.storage, info.sender.clone());
+
+    let config = CONFIG.load(deps.storage)?;
+    let return_asset = Asset {
+        info: config.lp_asset.clone(),
+        amount: return_token_count,
+    };
+    let penalty_asset = Asset {
+        info: config.lp_asset,
+        amount: penalty_token_count,
+    };
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !return_asset.amount.is_zero() {
+        messages.push(return_asset.clone().into_msg(info.sender)?);
+    }
+    if !penalty_asset.amount.is_zero() {
+        messages.push(
+            penalty_asset
+                .clone()
+                .into_msg(incentive_factory_config.fee_collector_addr)?,
+        );
+    }
+
+    Ok(Response::default()
+        .add_attributes(vec![
+            ("action", "emergency_exit".to_string()),
+            ("return_asset", return_asset.to_string()),
+            ("penalty_asset", penalty_asset.to_string()),
+        ])
+        .add_messages(messages))
+}
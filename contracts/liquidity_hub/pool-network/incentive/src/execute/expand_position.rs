@@ -62,8 +62,11 @@ pub fn expand_position(
         },
     )?;
 
-    // add the weight to the global weight and the user's weight
-    let weight = calculate_weight(unbonding_duration, amount)?;
+    // add the weight to the global weight and the user's weight. Perpetually locked positions use
+    // the maximum unbonding duration they were locked with.
+    let weight_duration =
+        helpers::get_position_weight_duration(deps.storage, &receiver.sender, unbonding_duration)?;
+    let weight = calculate_weight(weight_duration, amount)?;
     GLOBAL_WEIGHT.update::<_, StdError>(deps.storage, |global_weight| {
         Ok(global_weight.checked_add(weight)?)
     })?;
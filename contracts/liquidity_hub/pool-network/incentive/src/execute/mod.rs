@@ -1,19 +1,29 @@
 mod claim;
+mod claim_for;
+mod claim_vested;
 mod close_flow;
 mod close_position;
+mod emergency_exit;
 mod expand_flow;
 mod expand_position;
 mod open_flow;
 mod open_position;
+mod perpetual_lock_position;
+mod rollover_positions;
 mod snapshot;
 mod withdraw;
 
 pub use claim::claim;
+pub use claim_for::claim_for;
+pub use claim_vested::claim_vested;
 pub use close_flow::close_flow;
 pub use close_position::close_position;
+pub use emergency_exit::emergency_exit;
 pub use expand_flow::expand_flow;
 pub use expand_position::expand_position;
 pub use open_flow::open_flow;
 pub use open_position::open_position;
-pub use snapshot::take_global_weight_snapshot;
+pub use perpetual_lock_position::perpetual_lock_position;
+pub use rollover_positions::rollover_positions;
+pub use snapshot::{epoch_changed_hook, take_global_weight_snapshot};
 pub use withdraw::withdraw;
@@ -32,6 +32,7 @@ pub fn open_flow(
     curve: Option<Curve>,
     mut flow_asset: Asset,
     flow_label: Option<String>,
+    vesting_duration: Option<u64>,
 ) -> Result<Response, ContractError> {
     // check the user is not trying to create an empty flow
     if flow_asset.amount < MIN_FLOW_AMOUNT {
@@ -44,25 +45,39 @@ pub fn open_flow(
 
     let incentive_factory_config: white_whale_std::pool_network::incentive_factory::ConfigResponse =
         deps.querier.query_wasm_smart(
-            config.factory_address.into_string(),
+            config.factory_address.clone().into_string(),
             &white_whale_std::pool_network::incentive_factory::QueryMsg::Config {},
         )?;
 
+    // allowlisted flow creators get a discount on the flow fee and can fill the reserved flow slots
+    let flow_creator: white_whale_std::pool_network::incentive_factory::FlowCreatorResponse =
+        deps.querier.query_wasm_smart(
+            config.factory_address.into_string(),
+            &white_whale_std::pool_network::incentive_factory::QueryMsg::FlowCreator {
+                address: info.sender.clone().into_string(),
+            },
+        )?;
+
     let mut messages: Vec<CosmosMsg> = vec![];
 
-    let flow_fee = incentive_factory_config.create_flow_fee;
+    let mut flow_fee = incentive_factory_config.create_flow_fee;
+    flow_fee.amount -= flow_fee.amount.mul_floor(flow_creator.fee_discount);
     // check the fee to create a flow is being paid
     match flow_fee.info.clone() {
         AssetInfo::NativeToken {
             denom: flow_fee_denom,
         } => {
             // fee should be included inside message info
-            let paid_amount = info
+            let paid_amount = match info
                 .funds
                 .iter()
                 .find(|token| token.denom == flow_fee_denom)
-                .ok_or(ContractError::FlowFeeMissing)?
-                .amount;
+            {
+                Some(token) => token.amount,
+                // the fee can be fully waived for allowlisted flow creators
+                None if flow_fee.amount.is_zero() => Uint128::zero(),
+                None => return Err(ContractError::FlowFeeMissing),
+            };
 
             // check if the user intends to open a flow with the same asset used to pay for the flow_fee
             match flow_asset.info.clone() {
@@ -118,19 +133,21 @@ pub fn open_flow(
             }
 
             // send fee to fee collector
-            messages.push(
-                BankMsg::Send {
-                    to_address: incentive_factory_config
-                        .fee_collector_addr
-                        .clone()
-                        .into_string(),
-                    amount: vec![Coin {
-                        amount: flow_fee.amount,
-                        denom: flow_fee_denom,
-                    }],
-                }
-                .into(),
-            );
+            if !flow_fee.amount.is_zero() {
+                messages.push(
+                    BankMsg::Send {
+                        to_address: incentive_factory_config
+                            .fee_collector_addr
+                            .clone()
+                            .into_string(),
+                        amount: vec![Coin {
+                            amount: flow_fee.amount,
+                            denom: flow_fee_denom,
+                        }],
+                    }
+                    .into(),
+                );
+            }
         }
         AssetInfo::Token {
             contract_addr: flow_fee_contract_addr,
@@ -174,18 +191,20 @@ pub fn open_flow(
             }
 
             // send fee to fee collector
-            messages.push(
-                WasmMsg::Execute {
-                    contract_addr: flow_fee_contract_addr,
-                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
-                        owner: info.sender.clone().into_string(),
-                        recipient: incentive_factory_config.fee_collector_addr.into_string(),
-                        amount: flow_fee.amount,
-                    })?,
-                    funds: vec![],
-                }
-                .into(),
-            );
+            if !flow_fee.amount.is_zero() {
+                messages.push(
+                    WasmMsg::Execute {
+                        contract_addr: flow_fee_contract_addr,
+                        msg: to_json_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
+                            owner: info.sender.clone().into_string(),
+                            recipient: incentive_factory_config.fee_collector_addr.into_string(),
+                            amount: flow_fee.amount,
+                        })?,
+                        funds: vec![],
+                    }
+                    .into(),
+                );
+            }
         }
     }
 
@@ -198,9 +217,18 @@ pub fn open_flow(
             .len(),
     )
     .map_err(|_| StdError::generic_err("Failed to parse flow count"))?;
-    if flows >= incentive_factory_config.max_concurrent_flows {
+    // the reserved flow slots can only be filled by allowlisted flow creators
+    let max_concurrent_flows = if flow_creator.allowlisted {
+        incentive_factory_config.max_concurrent_flows
+    } else {
+        incentive_factory_config
+            .max_concurrent_flows
+            .saturating_sub(incentive_factory_config.reserved_flow_slots)
+    };
+
+    if flows >= max_concurrent_flows {
         return Err(ContractError::TooManyFlows {
-            maximum: incentive_factory_config.max_concurrent_flows,
+            maximum: max_concurrent_flows,
         });
     }
 
@@ -353,6 +381,10 @@ pub fn open_flow(
         FLOW_COUNTER.update::<_, StdError>(deps.storage, |current_id| Ok(current_id + 1u64))?;
 
     let curve = curve.unwrap_or(Curve::Linear);
+    helpers::validate_curve(&curve, start_epoch, end_epoch)?;
+
+    // a vesting duration of zero epochs means the rewards are paid out when claimed
+    let vesting_duration = vesting_duration.filter(|vesting_duration| *vesting_duration > 0);
 
     FLOWS.save(
         deps.storage,
@@ -368,6 +400,7 @@ pub fn open_flow(
             end_epoch,
             emitted_tokens: HashMap::new(),
             asset_history: Default::default(),
+            vesting_duration,
         },
     )?;
 
@@ -388,6 +421,10 @@ pub fn open_flow(
         ("curve", curve.to_string()),
     ]);
 
+    if let Some(vesting_duration) = vesting_duration {
+        attributes.push(("vesting_duration", vesting_duration.to_string()));
+    }
+
     Ok(Response::default()
         .add_attributes(attributes)
         .add_messages(messages))
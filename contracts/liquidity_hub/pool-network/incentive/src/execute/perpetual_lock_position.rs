@@ -0,0 +1,48 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+
+use crate::{
+    error::ContractError,
+    helpers,
+    state::{OPEN_POSITIONS, PERPETUAL_POSITIONS},
+    weight::calculate_weight,
+};
+
+/// Perpetually locks the position for the user with the given unbonding_duration, giving it the
+/// maximum weight until it is closed.
+pub fn perpetual_lock_position(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    unbonding_duration: u64,
+) -> Result<Response, ContractError> {
+    let position = OPEN_POSITIONS
+        .may_load(deps.storage, info.sender.clone())?
+        .unwrap_or_default()
+        .into_iter()
+        .find(|position| position.unbonding_duration == unbonding_duration)
+        .ok_or(ContractError::NonExistentPosition { unbonding_duration })?;
+
+    if PERPETUAL_POSITIONS.has(deps.storage, (&info.sender, unbonding_duration)) {
+        return Err(ContractError::PositionAlreadyPerpetual { unbonding_duration });
+    }
+
+    let max_unbonding_duration =
+        helpers::get_incentive_factory_config(deps.as_ref())?.max_unbonding_duration;
+
+    PERPETUAL_POSITIONS.save(
+        deps.storage,
+        (&info.sender, unbonding_duration),
+        &max_unbonding_duration,
+    )?;
+
+    // add the weight difference between the maximum weight and the current weight of the position
+    let weight = calculate_weight(max_unbonding_duration, position.amount)?
+        .saturating_sub(calculate_weight(unbonding_duration, position.amount)?);
+    let user_weight = helpers::increase_address_weight(&mut deps, &info.sender, weight)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "perpetual_lock_position".to_string()),
+        ("position", position.to_string()),
+        ("weight_duration", max_unbonding_duration.to_string()),
+        ("user_weight", user_weight.to_string()),
+    ]))
+}
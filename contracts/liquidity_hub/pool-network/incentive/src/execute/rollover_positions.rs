@@ -0,0 +1,87 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, Uint128};
+
+use white_whale_std::pool_network::incentive::OpenPosition;
+
+use crate::{
+    error::ContractError,
+    helpers,
+    state::{CLOSED_POSITIONS, OPEN_POSITIONS},
+    weight::calculate_weight,
+};
+
+/// Rolls the closed positions that finished unbonding back into the open position with the given
+/// unbonding_duration, creating the position if it doesn't exist.
+pub fn rollover_positions(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    unbonding_duration: u64,
+) -> Result<Response, ContractError> {
+    // validate unbonding duration
+    let incentive_factory_config = helpers::get_incentive_factory_config(deps.as_ref())?;
+
+    if unbonding_duration < incentive_factory_config.min_unbonding_duration
+        || unbonding_duration > incentive_factory_config.max_unbonding_duration
+    {
+        return Err(ContractError::InvalidUnbondingDuration {
+            min: incentive_factory_config.min_unbonding_duration,
+            max: incentive_factory_config.max_unbonding_duration,
+            specified: unbonding_duration,
+        });
+    }
+
+    // take the closed positions that finished unbonding
+    let mut rollover_amount = Uint128::zero();
+
+    CLOSED_POSITIONS.update::<_, ContractError>(
+        deps.storage,
+        info.sender.clone(),
+        |closed_positions| {
+            let mut closed_positions = closed_positions.unwrap_or_default();
+
+            for i in (0..closed_positions.len()).rev() {
+                if env.block.time.seconds() >= closed_positions[i].unbonding_timestamp {
+                    rollover_amount = rollover_amount.checked_add(closed_positions[i].amount)?;
+                    closed_positions.remove(i);
+                }
+            }
+
+            Ok(closed_positions)
+        },
+    )?;
+
+    if rollover_amount.is_zero() {
+        return Err(ContractError::NothingToRollover {});
+    }
+
+    // roll the amount into the open position, creating it if it doesn't exist
+    OPEN_POSITIONS.update::<_, StdError>(deps.storage, info.sender.clone(), |positions| {
+        let mut positions = positions.unwrap_or_default();
+
+        match positions
+            .iter_mut()
+            .find(|position| position.unbonding_duration == unbonding_duration)
+        {
+            Some(position) => position.amount = position.amount.checked_add(rollover_amount)?,
+            None => positions.push(OpenPosition {
+                amount: rollover_amount,
+                unbonding_duration,
+            }),
+        }
+
+        Ok(positions)
+    })?;
+
+    // closed positions don't have weight, so the whole weight for the rolled amount is added
+    let weight_duration =
+        helpers::get_position_weight_duration(deps.storage, &info.sender, unbonding_duration)?;
+    let weight = calculate_weight(weight_duration, rollover_amount)?;
+    let user_weight = helpers::increase_address_weight(&mut deps, &info.sender, weight)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "rollover_positions".to_string()),
+        ("amount", rollover_amount.to_string()),
+        ("unbonding_duration", unbonding_duration.to_string()),
+        ("user_weight", user_weight.to_string()),
+    ]))
+}
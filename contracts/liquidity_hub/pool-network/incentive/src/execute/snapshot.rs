@@ -1,4 +1,6 @@
-use cosmwasm_std::{DepsMut, Response, StdError, Uint128};
+use cosmwasm_std::{DepsMut, MessageInfo, Response, StdError, Uint128};
+
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
 
 use crate::error::ContractError;
 use crate::helpers;
@@ -15,8 +17,14 @@ pub fn take_global_weight_snapshot(deps: DepsMut) -> Result<Response, ContractEr
         });
     }
 
-    // take the snapshot
+    save_global_weight_snapshot(deps, current_epoch)
+}
 
+/// Saves the current global weight as the snapshot for the given epoch.
+fn save_global_weight_snapshot(
+    deps: DepsMut,
+    current_epoch: u64,
+) -> Result<Response, ContractError> {
     let current_global_weight = GLOBAL_WEIGHT
         .may_load(deps.storage)?
         .unwrap_or(Uint128::zero());
@@ -30,3 +38,38 @@ pub fn take_global_weight_snapshot(deps: DepsMut) -> Result<Response, ContractEr
         ("current_global_weight", current_global_weight.to_string()),
     ]))
 }
+
+/// Takes the global weight snapshot when the epoch manager creates a new epoch. The snapshot is
+/// keyed by the current epoch of the fee distributor, which the rewards are claimed against, rather
+/// than by the epoch sent by the epoch manager. Doesn't fail if the snapshot was already taken, i.e.
+/// manually.
+pub fn epoch_changed_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: EpochChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let epoch_manager_addr =
+        helpers::get_incentive_factory_config(deps.as_ref())?.epoch_manager_addr;
+
+    if epoch_manager_addr != Some(info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let current_epoch = helpers::get_current_epoch(deps.as_ref())?;
+
+    if GLOBAL_WEIGHT_SNAPSHOT.has(deps.storage, current_epoch) {
+        return Ok(Response::default().add_attributes(vec![
+            ("action", "epoch_changed_hook".to_string()),
+            ("epoch", current_epoch.to_string()),
+            ("epoch_manager_epoch", msg.current_epoch.id.to_string()),
+            ("result", "snapshot already taken".to_string()),
+        ]));
+    }
+
+    Ok(
+        save_global_weight_snapshot(deps, current_epoch)?.add_attributes(vec![
+            ("hook", "epoch_changed_hook".to_string()),
+            ("epoch_manager_epoch", msg.current_epoch.id.to_string()),
+        ]),
+    )
+}
@@ -7,7 +7,7 @@ use crate::{
 };
 
 /// Withdraws LP tokens from the contract.
-pub fn withdraw(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn withdraw(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     // counter of how many LP tokens we must return to use and the weight to remove
     let mut return_token_count = Uint128::zero();
 
@@ -22,13 +22,13 @@ pub fn withdraw(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response,
 
                 // if unbonding timestamp is in the past, it's possible to withdraw
                 // unlocking LPs
-                //if env.block.time.seconds() > position.unbonding_timestamp {
-                // add return tokens to sum
-                return_token_count = return_token_count.checked_add(position.amount)?;
+                if env.block.time.seconds() >= position.unbonding_timestamp {
+                    // add return tokens to sum
+                    return_token_count = return_token_count.checked_add(position.amount)?;
 
-                // remove position
-                closed_positions.remove(i);
-                //}
+                    // remove position
+                    closed_positions.remove(i);
+                }
             }
 
             Ok(closed_positions)
@@ -1,9 +1,14 @@
-use cosmwasm_std::{Addr, Deps, DepsMut, Order, StdError, StdResult, Uint128};
+use cosmwasm_std::{Addr, Decimal, Deps, DepsMut, Order, StdError, StdResult, Storage, Uint128};
 
-use white_whale_std::pool_network::incentive::Flow;
+use white_whale_std::pause::query_paused_features;
+use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::pool_network::incentive::{Curve, EpochEmission, ExecuteMsg, Flow, Vesting};
 
 use crate::error::ContractError;
-use crate::state::{EpochId, ADDRESS_WEIGHT_HISTORY, CONFIG, FLOWS};
+use crate::state::{
+    EpochId, ADDRESS_WEIGHT, ADDRESS_WEIGHT_HISTORY, CONFIG, FLOWS, GLOBAL_WEIGHT,
+    PERPETUAL_POSITIONS, VESTINGS,
+};
 
 /// Gets the current epoch from the fee distributor contract.
 pub fn get_current_epoch(deps: Deps) -> Result<u64, ContractError> {
@@ -17,6 +22,92 @@ pub fn get_current_epoch(deps: Deps) -> Result<u64, ContractError> {
     Ok(epoch_response.epoch.id.u64())
 }
 
+/// Checks that the feature the message relies on is not paused across the incentives of the
+/// factory, i.e. deposits for opening, expanding or rolling over positions and withdrawals for
+/// closing, withdrawing or emergency exiting them and for claiming on behalf of the factory.
+pub fn assert_not_paused(deps: Deps, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    let is_deposit = match msg {
+        ExecuteMsg::OpenPosition { .. }
+        | ExecuteMsg::ExpandPosition { .. }
+        | ExecuteMsg::RolloverPositions { .. } => true,
+        ExecuteMsg::ClosePosition { .. }
+        | ExecuteMsg::Withdraw {}
+        | ExecuteMsg::EmergencyExit {}
+        | ExecuteMsg::ClaimFor { .. } => false,
+        _ => return Ok(()),
+    };
+
+    let config = CONFIG.load(deps.storage)?;
+    let paused_features = query_paused_features(&deps.querier, config.factory_address)?;
+
+    if is_deposit && paused_features.deposits {
+        return Err(ContractError::FeaturePaused {
+            feature: "deposits".to_string(),
+        });
+    }
+
+    if !is_deposit && paused_features.withdrawals {
+        return Err(ContractError::FeaturePaused {
+            feature: "withdrawals".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Gets the incentive factory config.
+pub fn get_incentive_factory_config(
+    deps: Deps,
+) -> Result<white_whale_std::pool_network::incentive_factory::ConfigResponse, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(deps.querier.query_wasm_smart(
+        config.factory_address.into_string(),
+        &white_whale_std::pool_network::incentive_factory::QueryMsg::Config {},
+    )?)
+}
+
+/// Gets the unbonding duration used to compute the weight of the position with the given
+/// unbonding duration. Perpetually locked positions use the maximum unbonding duration at the time
+/// they were locked.
+pub fn get_position_weight_duration(
+    storage: &dyn Storage,
+    address: &Addr,
+    unbonding_duration: u64,
+) -> StdResult<u64> {
+    Ok(PERPETUAL_POSITIONS
+        .may_load(storage, (address, unbonding_duration))?
+        .unwrap_or(unbonding_duration))
+}
+
+/// Increases the weight of the given address and the global weight, recording the new address
+/// weight in the history for the next epoch.
+pub fn increase_address_weight(
+    deps: &mut DepsMut,
+    address: &Addr,
+    weight: Uint128,
+) -> Result<Uint128, ContractError> {
+    GLOBAL_WEIGHT.update::<_, StdError>(deps.storage, |global_weight| {
+        Ok(global_weight.checked_add(weight)?)
+    })?;
+
+    let mut user_weight = ADDRESS_WEIGHT
+        .may_load(deps.storage, address.clone())?
+        .unwrap_or_default();
+    user_weight = user_weight.checked_add(weight)?;
+    ADDRESS_WEIGHT.save(deps.storage, address.clone(), &user_weight)?;
+
+    let current_epoch = get_current_epoch(deps.as_ref())?;
+
+    ADDRESS_WEIGHT_HISTORY.update::<_, StdError>(
+        deps.storage,
+        (address, current_epoch + 1u64),
+        |_| Ok(user_weight),
+    )?;
+
+    Ok(user_weight)
+}
+
 /// Gets the flows that are available for the current epoch, i.e. those flows that started either on
 /// the epoch provided or before it.
 pub fn get_available_flows(deps: Deps, epoch: &u64) -> Result<Vec<Flow>, ContractError> {
@@ -92,7 +183,224 @@ pub fn get_flow_current_end_epoch(flow: &Flow, epoch: u64) -> u64 {
     end_epoch
 }
 
+/// Validates the given curve for a flow running from `start_epoch` until `end_epoch`.
+pub fn validate_curve(
+    curve: &Curve,
+    start_epoch: u64,
+    end_epoch: u64,
+) -> Result<(), ContractError> {
+    match curve {
+        Curve::Linear => Ok(()),
+        Curve::ExponentialDecay { decay_factor } => {
+            if decay_factor.is_zero() || *decay_factor >= Decimal::one() {
+                return Err(ContractError::InvalidCurve {
+                    reason: "the decay factor must be between 0 and 1".to_string(),
+                });
+            }
+
+            Ok(())
+        }
+        Curve::Step { steps } => {
+            if steps.len() as u64 > MAX_CURVE_ENTRIES {
+                return Err(ContractError::InvalidCurve {
+                    reason: format!("the curve can't have more than {MAX_CURVE_ENTRIES} steps"),
+                });
+            }
+
+            if steps.first().map(|step| step.epoch_offset) != Some(0u64) {
+                return Err(ContractError::InvalidCurve {
+                    reason: "the first step must start at offset 0".to_string(),
+                });
+            }
+
+            if steps
+                .windows(2)
+                .any(|pair| pair[0].epoch_offset >= pair[1].epoch_offset)
+            {
+                return Err(ContractError::InvalidCurve {
+                    reason: "the step offsets must be strictly ascending".to_string(),
+                });
+            }
+
+            if steps.iter().any(|step| step.weight.is_zero()) {
+                return Err(ContractError::InvalidCurve {
+                    reason: "the step weights can't be zero".to_string(),
+                });
+            }
+
+            Ok(())
+        }
+        Curve::Custom { weights } => {
+            if weights.is_empty() || weights.len() as u64 > end_epoch.saturating_sub(start_epoch) {
+                return Err(ContractError::InvalidCurve {
+                    reason: "the emission table must have between 1 and the flow duration entries"
+                        .to_string(),
+                });
+            }
+
+            if weights.len() as u64 > MAX_CURVE_ENTRIES {
+                return Err(ContractError::InvalidCurve {
+                    reason: format!(
+                        "the emission table can't have more than {MAX_CURVE_ENTRIES} entries"
+                    ),
+                });
+            }
+
+            if weights.last().map_or(true, |weight| weight.is_zero()) {
+                return Err(ContractError::InvalidCurve {
+                    reason: "the last weight of the emission table can't be zero".to_string(),
+                });
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Gets the weight of the given curve at the given epoch offset, relative to the flow's start epoch.
+fn get_curve_weight(curve: &Curve, epoch_offset: u64) -> Result<Decimal, ContractError> {
+    Ok(match curve {
+        Curve::Linear => Decimal::one(),
+        Curve::ExponentialDecay { decay_factor } => {
+            decay_factor.checked_pow(epoch_offset.min(u32::MAX as u64) as u32)?
+        }
+        Curve::Step { steps } => steps
+            .iter()
+            .rev()
+            .find(|step| step.epoch_offset <= epoch_offset)
+            .map(|step| step.weight)
+            .unwrap_or_default(),
+        Curve::Custom { weights } => weights
+            .get(epoch_offset as usize)
+            .or(weights.last())
+            .copied()
+            .unwrap_or_default(),
+    })
+}
+
+/// Gets the sum of the weights of the given curve for the epoch offsets in `[start_offset, end_offset)`,
+/// relative to the flow's start epoch. The sum is computed without iterating over the epochs, so
+/// the cost doesn't grow with the flow duration.
+fn get_curve_weight_sum(
+    curve: &Curve,
+    start_offset: u64,
+    end_offset: u64,
+) -> Result<Decimal, ContractError> {
+    if start_offset >= end_offset {
+        return Ok(Decimal::zero());
+    }
+
+    Ok(match curve {
+        Curve::Linear => Decimal::from_ratio(end_offset - start_offset, 1u64),
+        Curve::ExponentialDecay { decay_factor } => {
+            // geometric series: r^start * (1 - r^n) / (1 - r)
+            let epochs = (end_offset - start_offset).min(u32::MAX as u64) as u32;
+            let series = Decimal::one()
+                .checked_sub(decay_factor.checked_pow(epochs)?)?
+                .checked_div(Decimal::one().checked_sub(*decay_factor)?)?;
+            get_curve_weight(curve, start_offset)?.checked_mul(series)?
+        }
+        Curve::Step { steps } => {
+            let mut sum = Decimal::zero();
+            for (i, step) in steps.iter().enumerate() {
+                let step_end = steps.get(i + 1).map_or(u64::MAX, |next| next.epoch_offset);
+                let overlap = step_end
+                    .min(end_offset)
+                    .saturating_sub(step.epoch_offset.max(start_offset));
+                sum = sum.checked_add(
+                    step.weight
+                        .checked_mul(Decimal::from_ratio(overlap, 1u64))?,
+                )?;
+            }
+            sum
+        }
+        Curve::Custom { weights } => {
+            let table_len = weights.len() as u64;
+            let mut sum = Decimal::zero();
+            for weight in weights
+                .iter()
+                .take(end_offset.min(table_len) as usize)
+                .skip(start_offset as usize)
+            {
+                sum = sum.checked_add(*weight)?;
+            }
+
+            // epochs beyond the table use the last weight
+            let tail_epochs = end_offset.saturating_sub(start_offset.max(table_len));
+            let last_weight = weights.last().copied().unwrap_or_default();
+            sum.checked_add(last_weight.checked_mul(Decimal::from_ratio(tail_epochs, 1u64))?)?
+        }
+    })
+}
+
+/// Gets the amount of the flow asset emitted at the given epoch, according to the flow's curve and
+/// taking into account flow expansions. `emitted_tokens` is the amount emitted until the previous epoch.
+pub fn get_flow_emission_at_epoch(
+    flow: &Flow,
+    epoch: u64,
+    emitted_tokens: Uint128,
+) -> Result<Uint128, ContractError> {
+    // use the flow asset amount at the current epoch considering flow expansions
+    let flow_asset_amount = get_flow_asset_amount_at_epoch(flow, epoch);
+    let flow_expanded_end_epoch = get_flow_current_end_epoch(flow, epoch);
+    let remaining_tokens = flow_asset_amount.saturating_sub(emitted_tokens);
+
+    // the linear curve has the same weight for every epoch, no need to add the weights up
+    let mut remaining_weight = Decimal::zero();
+    if flow.curve != Curve::Linear {
+        remaining_weight = get_curve_weight_sum(
+            &flow.curve,
+            epoch.saturating_sub(flow.start_epoch),
+            flow_expanded_end_epoch.saturating_sub(flow.start_epoch),
+        )?;
+    }
+
+    if remaining_weight.is_zero() {
+        // emission = (total_tokens - emitted_tokens_at_epoch) / (flow_start + flow_duration - epoch) = (total_tokens - emitted_tokens_at_epoch) / (flow_end - epoch)
+        return Ok(remaining_tokens.checked_div(Uint128::from(flow_expanded_end_epoch - epoch))?);
+    }
+
+    // emission = (total_tokens - emitted_tokens_at_epoch) * weight_at_epoch / sum(weights from epoch until flow_end)
+    let weight = get_curve_weight(&flow.curve, epoch.saturating_sub(flow.start_epoch))?;
+    Ok(remaining_tokens.multiply_ratio(weight.atomics(), remaining_weight.atomics()))
+}
+
+/// Projects the emissions of the flow for each of its epochs, returning the ones within the given
+/// range of epochs. The emissions already recorded for the flow are used when available.
+pub fn get_flow_emission_schedule(
+    flow: &Flow,
+    start_epoch: Option<u64>,
+    end_epoch: Option<u64>,
+) -> Result<Vec<EpochEmission>, ContractError> {
+    let flow_end_epoch = get_flow_end_epoch(flow);
+    let start_range = start_epoch.unwrap_or(flow.start_epoch);
+    let end_range = end_epoch
+        .unwrap_or(flow_end_epoch)
+        .min(start_range.saturating_add(MAX_EPOCH_LIMIT));
+
+    let mut emission_schedule = vec![];
+    let mut emitted_tokens = Uint128::zero();
+
+    for epoch_id in flow.start_epoch..flow_end_epoch.min(end_range.saturating_add(1u64)) {
+        let emission = get_flow_emission_at_epoch(flow, epoch_id, emitted_tokens)?;
+
+        // prefer the recorded emissions, as those are the ones used when claiming
+        emitted_tokens = match flow.emitted_tokens.get(&epoch_id) {
+            Some(recorded_emitted_tokens) => *recorded_emitted_tokens,
+            None => emitted_tokens.checked_add(emission)?,
+        };
+
+        if epoch_id >= start_range {
+            emission_schedule.push(EpochEmission { epoch_id, emission });
+        }
+    }
+
+    Ok(emission_schedule)
+}
+
 pub const MAX_EPOCH_LIMIT: u64 = 100;
+/// Maximum number of steps of a [Curve::Step] and of entries of a [Curve::Custom] emission table.
+pub const MAX_CURVE_ENTRIES: u64 = 100;
 
 /// Gets a [Flow] filtering the asset history and emitted tokens to the given range of epochs.
 pub fn get_filtered_flow(
@@ -120,3 +428,55 @@ pub fn get_filtered_flow(
 
     Ok(flow)
 }
+
+/// Adds the given flow rewards to the vesting ledger of the address, vesting linearly from the
+/// current epoch over `vesting_duration` epochs. Rewards of the same asset vesting over the same
+/// epochs are aggregated.
+pub fn add_vesting(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    asset: Asset,
+    current_epoch: EpochId,
+    vesting_duration: u64,
+) -> Result<(), ContractError> {
+    let end_epoch = current_epoch
+        .checked_add(vesting_duration)
+        .ok_or(ContractError::InvalidEndEpoch {})?;
+
+    let mut vestings = VESTINGS.may_load(storage, address)?.unwrap_or_default();
+
+    match vestings.iter_mut().find(|vesting| {
+        vesting.asset.info == asset.info
+            && vesting.start_epoch == current_epoch
+            && vesting.end_epoch == end_epoch
+    }) {
+        Some(vesting) => {
+            vesting.asset.amount = vesting.asset.amount.checked_add(asset.amount)?;
+        }
+        None => vestings.push(Vesting {
+            asset,
+            claimed_amount: Uint128::zero(),
+            start_epoch: current_epoch,
+            end_epoch,
+        }),
+    }
+
+    VESTINGS.save(storage, address, &vestings)?;
+
+    Ok(())
+}
+
+/// Gets the amount of the given vesting rewards that has vested by the given epoch, including the
+/// amount that was already claimed.
+pub fn get_vested_amount(vesting: &Vesting, epoch_id: EpochId) -> StdResult<Uint128> {
+    if epoch_id >= vesting.end_epoch {
+        return Ok(vesting.asset.amount);
+    }
+
+    let elapsed_epochs = epoch_id.saturating_sub(vesting.start_epoch);
+
+    Ok(vesting.asset.amount.multiply_ratio(
+        elapsed_epochs,
+        vesting.end_epoch.saturating_sub(vesting.start_epoch),
+    ))
+}
@@ -13,6 +13,23 @@ use white_whale_std::pool_network::incentive::{Curve, Flow};
 
 use crate::state::{EpochId, FlowId, FLOWS};
 
+/// The flow as of v1.0.8, before the vesting duration was added.
+#[cw_serde]
+struct FlowV108 {
+    pub flow_id: u64,
+    pub flow_label: Option<String>,
+    pub flow_creator: Addr,
+    pub flow_asset: Asset,
+    pub claimed_amount: Uint128,
+    pub curve: Curve,
+    pub start_epoch: u64,
+    pub end_epoch: u64,
+    pub emitted_tokens: HashMap<u64, Uint128>,
+    pub asset_history: BTreeMap<u64, (Uint128, u64)>,
+}
+
+const FLOWS_V108: Map<(EpochId, FlowId), FlowV108> = Map::new("flows");
+
 /// Migrates to version 1.0.6, which introduces the [Flow] field asset_history.
 pub(crate) fn migrate_to_v106(deps: DepsMut) -> Result<(), StdError> {
     #[cw_serde]
@@ -47,7 +64,7 @@ pub(crate) fn migrate_to_v106(deps: DepsMut) -> Result<(), StdError> {
 
     // add the asset_history field to all available flows
     for f in flows.iter() {
-        let flow = Flow {
+        let flow = FlowV108 {
             flow_id: f.clone().flow_id,
             flow_label: None, //new field
             flow_creator: f.clone().flow_creator,
@@ -60,7 +77,36 @@ pub(crate) fn migrate_to_v106(deps: DepsMut) -> Result<(), StdError> {
             asset_history: BTreeMap::new(), //new field
         };
 
-        FLOWS.save(deps.storage, (f.start_epoch, f.flow_id), &flow)?;
+        FLOWS_V108.save(deps.storage, (f.start_epoch, f.flow_id), &flow)?;
+    }
+
+    Ok(())
+}
+
+/// Migrates to version 1.1.0, which introduces the [Flow] field vesting_duration. Existing flows
+/// don't vest their rewards.
+pub(crate) fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
+    let flows = FLOWS_V108
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, flow)| flow))
+        .collect::<StdResult<Vec<FlowV108>>>()?;
+
+    for f in flows {
+        let flow = Flow {
+            flow_id: f.flow_id,
+            flow_label: f.flow_label,
+            flow_creator: f.flow_creator,
+            flow_asset: f.flow_asset,
+            claimed_amount: f.claimed_amount,
+            curve: f.curve,
+            start_epoch: f.start_epoch,
+            end_epoch: f.end_epoch,
+            emitted_tokens: f.emitted_tokens,
+            asset_history: f.asset_history,
+            vesting_duration: None, //new field
+        };
+
+        FLOWS.save(deps.storage, (flow.start_epoch, flow.flow_id), &flow)?;
     }
 
     Ok(())
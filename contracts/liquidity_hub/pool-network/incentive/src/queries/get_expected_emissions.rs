@@ -0,0 +1,98 @@
+use cosmwasm_std::{Decimal256, Deps, Uint128, Uint256};
+
+use white_whale_std::pool_network::asset::{aggregate_assets, Asset};
+use white_whale_std::pool_network::incentive::{
+    ExpectedEmissionsResponse, FlowExpectedEmission, NewPosition,
+};
+
+use crate::error::ContractError;
+use crate::helpers;
+use crate::state::{ADDRESS_WEIGHT, GLOBAL_WEIGHT};
+use crate::weight::calculate_weight;
+
+/// Gets the emissions expected on the next epoch for the given address and/or new position, based
+/// on the open flows and the current weights. Returns an [ExpectedEmissionsResponse] struct.
+pub fn get_expected_emissions(
+    deps: Deps,
+    address: Option<String>,
+    new_position: Option<NewPosition>,
+) -> Result<ExpectedEmissionsResponse, ContractError> {
+    if address.is_none() && new_position.is_none() {
+        return Err(ContractError::MissingAddressOrPosition {});
+    }
+
+    // weights updated in the current epoch are considered from the next one
+    let epoch_id = helpers::get_current_epoch(deps)? + 1u64;
+
+    let mut weight = match address {
+        Some(address) => ADDRESS_WEIGHT
+            .may_load(deps.storage, deps.api.addr_validate(&address)?)?
+            .unwrap_or_default(),
+        None => Uint128::zero(),
+    };
+    let mut global_weight = GLOBAL_WEIGHT.may_load(deps.storage)?.unwrap_or_default();
+
+    if let Some(new_position) = new_position {
+        let incentive_factory_config = helpers::get_incentive_factory_config(deps)?;
+
+        if new_position.unbonding_duration < incentive_factory_config.min_unbonding_duration
+            || new_position.unbonding_duration > incentive_factory_config.max_unbonding_duration
+        {
+            return Err(ContractError::InvalidUnbondingDuration {
+                min: incentive_factory_config.min_unbonding_duration,
+                max: incentive_factory_config.max_unbonding_duration,
+                specified: new_position.unbonding_duration,
+            });
+        }
+
+        let position_weight =
+            calculate_weight(new_position.unbonding_duration, new_position.amount)?;
+        weight = weight.checked_add(position_weight)?;
+        global_weight = global_weight.checked_add(position_weight)?;
+    }
+
+    let share = if global_weight.is_zero() {
+        Decimal256::zero()
+    } else {
+        Decimal256::from_ratio(weight, global_weight)
+    };
+
+    let mut flows = vec![];
+    let mut emissions = vec![];
+
+    for flow in helpers::get_available_flows(deps, &epoch_id)? {
+        let Some(epoch_emission) =
+            helpers::get_flow_emission_schedule(&flow, Some(epoch_id), Some(epoch_id))?
+                .into_iter()
+                .next()
+        else {
+            // the flow doesn't emit on the epoch, i.e. it has ended
+            continue;
+        };
+
+        let expected_emission = Asset {
+            info: flow.flow_asset.info.clone(),
+            amount: (Uint256::from_uint128(epoch_emission.emission) * share).try_into()?,
+        };
+
+        emissions = aggregate_assets(emissions, vec![expected_emission.clone()])?;
+        flows.push(FlowExpectedEmission {
+            flow_id: flow.flow_id,
+            flow_label: flow.flow_label,
+            flow_emission: Asset {
+                info: flow.flow_asset.info,
+                amount: epoch_emission.emission,
+            },
+            expected_emission,
+        });
+    }
+
+    Ok(ExpectedEmissionsResponse {
+        epoch_id,
+        weight,
+        global_weight,
+        share,
+        flows,
+        emissions,
+    })
+}
@@ -1,17 +1,18 @@
-use cosmwasm_std::{Deps, Order, StdError, StdResult};
+use cosmwasm_std::{Deps, Order, StdResult};
 
 use white_whale_std::pool_network::incentive::{Flow, FlowIdentifier, FlowResponse};
 
-use crate::helpers::get_filtered_flow;
+use crate::error::ContractError;
+use crate::helpers::{get_filtered_flow, get_flow_emission_schedule};
 use crate::state::FLOWS;
 
-/// Gets a flow given the [FlowIdentifier].
+/// Gets a flow given the [FlowIdentifier], along with its projected emission schedule.
 pub fn get_flow(
     deps: Deps,
     flow_identifier: FlowIdentifier,
     start_epoch: Option<u64>,
     end_epoch: Option<u64>,
-) -> Result<Option<FlowResponse>, StdError> {
+) -> Result<Option<FlowResponse>, ContractError> {
     FLOWS
         .range(deps.storage, None, None, Order::Ascending)
         .collect::<StdResult<Vec<(_, Flow)>>>()?
@@ -21,8 +22,11 @@ pub fn get_flow(
             FlowIdentifier::Label(label) => flow.flow_label.as_ref() == Some(label),
         })
         .map(|(_, flow)| {
-            get_filtered_flow(flow, start_epoch, end_epoch).map(|filtered_flow| FlowResponse {
-                flow: Some(filtered_flow),
+            let emission_schedule = get_flow_emission_schedule(&flow, start_epoch, end_epoch)?;
+
+            Ok(FlowResponse {
+                flow: Some(get_filtered_flow(flow, start_epoch, end_epoch)?),
+                emission_schedule,
             })
         })
         .transpose()
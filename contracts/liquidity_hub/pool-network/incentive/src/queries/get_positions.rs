@@ -3,6 +3,7 @@ use white_whale_std::pool_network::incentive::{PositionsResponse, QueryPosition}
 
 use crate::{
     error::ContractError,
+    helpers::get_position_weight_duration,
     state::{CLOSED_POSITIONS, OPEN_POSITIONS},
     weight::calculate_weight,
 };
@@ -20,10 +21,13 @@ pub fn get_positions(
         .unwrap_or_default()
         .into_iter()
         .map(|position| {
+            let weight_duration =
+                get_position_weight_duration(deps.storage, &address, position.unbonding_duration)?;
+
             Ok(QueryPosition::OpenPosition {
                 amount: position.amount,
                 unbonding_duration: position.unbonding_duration,
-                weight: calculate_weight(position.unbonding_duration, position.amount)?,
+                weight: calculate_weight(weight_duration, position.amount)?,
             })
         })
         .collect::<Result<Vec<_>, ContractError>>()?;
@@ -4,7 +4,7 @@ use white_whale_std::pool_network::{asset::Asset, incentive::RewardsResponse};
 
 use crate::error::ContractError;
 use crate::helpers;
-use crate::helpers::{get_flow_asset_amount_at_epoch, get_flow_current_end_epoch};
+use crate::helpers::get_flow_emission_at_epoch;
 use crate::state::{EpochId, ADDRESS_WEIGHT_HISTORY, GLOBAL_WEIGHT_SNAPSHOT, LAST_CLAIMED_EPOCH};
 
 #[allow(unused_assignments)]
@@ -101,14 +101,9 @@ pub fn get_rewards(deps: Deps, address: String) -> Result<RewardsResponse, Contr
                 previous_emission
             };
 
-            // use the flow asset amount at the current epoch considering flow expansions
-            let flow_asset_amount = get_flow_asset_amount_at_epoch(flow, epoch_id);
-            let flow_expanded_end_epoch = get_flow_current_end_epoch(flow, epoch_id);
-
-            // emission = (total_tokens - emitted_tokens_at_epoch) / (flow_start + flow_duration - epoch) = (total_tokens - emitted_tokens_at_epoch) / (flow_end - epoch)
-            let emission_per_epoch = flow_asset_amount
-                .saturating_sub(emitted_tokens)
-                .checked_div(Uint128::from(flow_expanded_end_epoch - epoch_id))?;
+            // calculate the emission for this epoch according to the flow's curve, considering
+            // flow expansions
+            let emission_per_epoch = get_flow_emission_at_epoch(flow, epoch_id, emitted_tokens)?;
 
             // record the emitted tokens for this epoch if it hasn't been recorded before.
             // emitted tokens for this epoch is the total emitted tokens in previous epoch + the ones
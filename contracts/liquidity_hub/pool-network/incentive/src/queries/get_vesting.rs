@@ -0,0 +1,41 @@
+use cosmwasm_std::Deps;
+use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::pool_network::incentive::VestingResponse;
+
+use crate::{error::ContractError, helpers, state::VESTINGS};
+
+/// Gets the flow rewards vesting for the given address, and the amount that can be claimed.
+pub fn get_vesting(deps: Deps, address: String) -> Result<VestingResponse, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+    let current_epoch = helpers::get_current_epoch(deps)?;
+
+    let vestings = VESTINGS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+
+    let mut claimable: Vec<Asset> = vec![];
+    for vesting in &vestings {
+        let claimable_amount = helpers::get_vested_amount(vesting, current_epoch)?
+            .checked_sub(vesting.claimed_amount)?;
+
+        if claimable_amount.is_zero() {
+            continue;
+        }
+
+        match claimable
+            .iter_mut()
+            .find(|asset| asset.info == vesting.asset.info)
+        {
+            Some(asset) => asset.amount = asset.amount.checked_add(claimable_amount)?,
+            None => claimable.push(Asset {
+                info: vesting.asset.info.clone(),
+                amount: claimable_amount,
+            }),
+        }
+    }
+
+    Ok(VestingResponse {
+        vestings,
+        claimable,
+    })
+}
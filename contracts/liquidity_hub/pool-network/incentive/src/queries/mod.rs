@@ -1,15 +1,19 @@
 mod get_config;
+mod get_expected_emissions;
 mod get_flow;
 mod get_flows;
 mod get_global_weight;
 mod get_positions;
 mod get_rewards;
 mod get_rewards_share;
+mod get_vesting;
 
 pub use get_config::get_config;
+pub use get_expected_emissions::get_expected_emissions;
 pub use get_flow::get_flow;
 pub use get_flows::get_flows;
 pub use get_global_weight::get_global_weight;
 pub use get_positions::get_positions;
 pub use get_rewards::get_rewards;
 pub use get_rewards_share::get_rewards_share;
+pub use get_vesting::get_vesting;
@@ -1,6 +1,8 @@
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, Map};
-use white_whale_std::pool_network::incentive::{ClosedPosition, Config, Flow, OpenPosition};
+use white_whale_std::pool_network::incentive::{
+    ClosedPosition, Config, Flow, OpenPosition, Vesting,
+};
 
 /// The configuration for the incentive contract.
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -13,6 +15,10 @@ pub const FLOWS: Map<(EpochId, FlowId), Flow> = Map::new("flows");
 
 /// All open positions that users have.
 pub const OPEN_POSITIONS: Map<Addr, Vec<OpenPosition>> = Map::new("open_positions");
+/// Open positions that were perpetually locked. Key is (address, unbonding_duration), value is the
+/// unbonding duration used to compute the weight of the position, i.e. the maximum unbonding
+/// duration at the time the position was locked.
+pub const PERPETUAL_POSITIONS: Map<(&Addr, u64), u64> = Map::new("perpetual_positions");
 /// All closed positions that users have.
 pub const CLOSED_POSITIONS: Map<Addr, Vec<ClosedPosition>> = Map::new("closed_positions");
 
@@ -31,6 +37,8 @@ pub const ADDRESS_WEIGHT_HISTORY: Map<(&Addr, EpochId), Uint128> =
     Map::new("address_weight_snapshot");
 /// The last epoch an address claimed rewards
 pub const LAST_CLAIMED_EPOCH: Map<&Addr, EpochId> = Map::new("last_claimed_epoch");
+/// The flow rewards claimed by an address that are still vesting
+pub const VESTINGS: Map<&Addr, Vec<Vesting>> = Map::new("vestings");
 
 pub type EpochId = u64;
 pub type FlowId = u64;
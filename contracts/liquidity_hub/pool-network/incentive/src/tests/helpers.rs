@@ -1,11 +1,118 @@
 use std::collections::{BTreeMap, HashMap};
 
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Uint128};
 
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
-use white_whale_std::pool_network::incentive::{Curve, Flow};
+use white_whale_std::pool_network::incentive::{Curve, CurveStep, Flow};
 
-use crate::helpers::{get_filtered_flow, get_flow_asset_amount_at_epoch};
+use crate::error::ContractError;
+use crate::helpers::{
+    get_filtered_flow, get_flow_asset_amount_at_epoch, get_flow_emission_at_epoch, validate_curve,
+    MAX_CURVE_ENTRIES,
+};
+
+fn flow_with_curve(curve: Curve, amount: u128, start_epoch: u64, end_epoch: u64) -> Flow {
+    Flow {
+        flow_id: 1,
+        flow_label: None,
+        flow_creator: Addr::unchecked("creator"),
+        flow_asset: Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            amount: Uint128::from(amount),
+        },
+        claimed_amount: Uint128::zero(),
+        curve,
+        start_epoch,
+        end_epoch,
+        emitted_tokens: HashMap::new(),
+        asset_history: BTreeMap::new(),
+        vesting_duration: None,
+    }
+}
+
+#[test]
+fn get_flow_emission_at_epoch_with_curves() {
+    // weights 2, 2, 1, 1
+    let flow = flow_with_curve(
+        Curve::Step {
+            steps: vec![
+                CurveStep {
+                    epoch_offset: 0,
+                    weight: Decimal::percent(200),
+                },
+                CurveStep {
+                    epoch_offset: 2,
+                    weight: Decimal::one(),
+                },
+            ],
+        },
+        600,
+        10,
+        14,
+    );
+    assert_eq!(
+        get_flow_emission_at_epoch(&flow, 10, Uint128::zero()).unwrap(),
+        Uint128::new(200)
+    );
+    assert_eq!(
+        get_flow_emission_at_epoch(&flow, 12, Uint128::new(400)).unwrap(),
+        Uint128::new(100)
+    );
+
+    // weights 3, 1, and the last weight for the epochs beyond the table
+    let flow = flow_with_curve(
+        Curve::Custom {
+            weights: vec![Decimal::percent(300), Decimal::one()],
+        },
+        600,
+        10,
+        14,
+    );
+    assert_eq!(
+        get_flow_emission_at_epoch(&flow, 10, Uint128::zero()).unwrap(),
+        Uint128::new(300)
+    );
+    assert_eq!(
+        get_flow_emission_at_epoch(&flow, 12, Uint128::new(400)).unwrap(),
+        Uint128::new(100)
+    );
+
+    // the weights of a long flow are added up without iterating over its epochs
+    let flow = flow_with_curve(
+        Curve::ExponentialDecay {
+            decay_factor: Decimal::percent(50),
+        },
+        1_000,
+        0,
+        1_000_000,
+    );
+    assert_eq!(
+        get_flow_emission_at_epoch(&flow, 0, Uint128::zero()).unwrap(),
+        Uint128::new(500)
+    );
+}
+
+#[test]
+fn validate_curve_caps_entries() {
+    let steps = (0..=MAX_CURVE_ENTRIES)
+        .map(|epoch_offset| CurveStep {
+            epoch_offset,
+            weight: Decimal::one(),
+        })
+        .collect();
+    match validate_curve(&Curve::Step { steps }, 0, 1_000) {
+        Err(ContractError::InvalidCurve { .. }) => (),
+        _ => panic!("should return ContractError::InvalidCurve"),
+    }
+
+    let weights = vec![Decimal::one(); MAX_CURVE_ENTRIES as usize + 1];
+    match validate_curve(&Curve::Custom { weights }, 0, 1_000) {
+        Err(ContractError::InvalidCurve { .. }) => (),
+        _ => panic!("should return ContractError::InvalidCurve"),
+    }
+}
 
 #[test]
 fn test_get_flow_asset_amount_at_epoch_with_expansion() {
@@ -29,6 +136,7 @@ fn test_get_flow_asset_amount_at_epoch_with_expansion() {
         end_epoch: 100,
         emitted_tokens: HashMap::new(),
         asset_history,
+        vesting_duration: None,
     };
 
     // Before any change
@@ -88,6 +196,7 @@ fn test_get_flow_asset_amount_at_epoch_without_expansion() {
         end_epoch: 100,
         emitted_tokens: HashMap::new(),
         asset_history,
+        vesting_duration: None,
     };
 
     // Before any change
@@ -162,6 +271,7 @@ fn get_filtered_flow_cases() {
                 ),
             )
         })),
+        vesting_duration: None,
     };
 
     assert!(flow.asset_history.get(&104).is_some());
@@ -1,15 +1,20 @@
 use std::cell::RefCell;
 use std::collections::{BTreeMap, HashMap};
 
-use cosmwasm_std::{coin, coins, Addr, Decimal256, Timestamp, Uint128};
+use cosmwasm_std::{coin, coins, Addr, Decimal, Decimal256, Timestamp, Uint128};
+use cw_multi_test::AppResponse;
 
+use white_whale_std::pause::PausedFeatures;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::pool_network::incentive;
-use white_whale_std::pool_network::incentive::{Curve, Flow, FlowIdentifier, RewardsShareResponse};
-use white_whale_std::pool_network::incentive_factory::IncentivesContract;
+use white_whale_std::pool_network::incentive::{
+    Curve, CurveStep, EpochEmission, Flow, FlowIdentifier, NewPosition, RewardsShareResponse,
+};
+use white_whale_std::pool_network::incentive_factory::{FlowCreator, IncentivesContract};
 
 use crate::error::ContractError;
 use crate::tests::suite::TestingSuite;
+use crate::weight::calculate_weight;
 
 #[test]
 fn instantiate_incentive_factory_successful() {
@@ -330,6 +335,7 @@ fn try_open_more_flows_than_allowed() {
                 end_epoch: 10u64,
                 emitted_tokens: Default::default(),
                 asset_history: Default::default(),
+                vesting_duration: None,
             }
         );
         assert_eq!(
@@ -350,6 +356,7 @@ fn try_open_more_flows_than_allowed() {
                 end_epoch: 10u64,
                 emitted_tokens: Default::default(),
                 asset_history: Default::default(),
+                vesting_duration: None,
             }
         );
     });
@@ -686,6 +693,7 @@ fn open_flow_with_fee_native_token_and_flow_same_native_token() {
                         end_epoch: 19u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -927,6 +935,7 @@ fn open_flow_with_fee_native_token_and_flow_different_native_token() {
                         end_epoch: 10u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -1156,6 +1165,7 @@ fn open_flow_with_fee_native_token_and_flow_cw20_token() {
                         end_epoch: 10u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -1342,6 +1352,7 @@ fn open_flow_with_fee_cw20_token_and_flow_same_cw20_token() {
                         end_epoch: 10u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -1573,6 +1584,7 @@ fn open_flow_with_fee_cw20_token_and_flow_different_cw20_token() {
                         end_epoch: 10u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -1840,6 +1852,7 @@ fn open_flow_with_fee_cw20_token_and_flow_native_token() {
                         end_epoch: 10u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -1950,6 +1963,7 @@ fn close_native_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
             assert_eq!(
@@ -1970,6 +1984,7 @@ fn close_native_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
         })
@@ -2046,6 +2061,7 @@ fn close_native_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
         })
@@ -2154,6 +2170,7 @@ fn close_native_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
         });
@@ -2264,6 +2281,7 @@ fn close_cw20_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
             assert_eq!(
@@ -2282,6 +2300,7 @@ fn close_cw20_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
         })
@@ -2350,6 +2369,7 @@ fn close_cw20_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
         })
@@ -2442,6 +2462,7 @@ fn close_cw20_token_flows() {
                     end_epoch: 10u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 }
             );
         });
@@ -4332,6 +4353,103 @@ fn take_global_weight_snapshot() {
     ;
 }
 
+#[test]
+fn take_global_weight_snapshot_through_epoch_changed_hook() {
+    let mut suite = TestingSuite::default_with_balances(vec![]);
+    let alice = suite.creator();
+    let epoch_manager = suite.senders[1].clone();
+    let carol = suite.senders[2].clone();
+
+    suite.instantiate_default_native_fee();
+
+    let incentive_asset = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), incentive_asset.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(incentive_asset.clone(), |result| {
+            let incentive = result.unwrap();
+            assert!(incentive.is_some());
+            *incentive_addr.borrow_mut() = incentive.unwrap();
+        })
+        .epoch_changed_hook(
+            epoch_manager.clone(),
+            incentive_addr.clone().into_inner(),
+            5u64,
+            |result| {
+                // there's no epoch manager set on the incentive factory yet
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::Unauthorized { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+                }
+            },
+        )
+        .update_epoch_manager(alice.clone(), epoch_manager.clone(), |result| {
+            result.unwrap();
+        })
+        .create_epochs_on_fee_distributor_without_snapshot_on_incentive(1u64)
+        .query_current_epoch(|result| {
+            assert_eq!(result.unwrap().epoch.id.u64(), 2u64);
+        })
+        .epoch_changed_hook(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            5u64,
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::Unauthorized { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+                }
+            },
+        )
+        // the snapshot is taken for the current epoch on the fee distributor, which the rewards
+        // are claimed against, not for the epoch in the hook message
+        .epoch_changed_hook(
+            epoch_manager.clone(),
+            incentive_addr.clone().into_inner(),
+            5u64,
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_incentive_global_weight(incentive_addr.clone().into_inner(), 2u64, |result| {
+            assert_eq!(result.unwrap().global_weight, Uint128::zero());
+        })
+        .query_incentive_global_weight(incentive_addr.clone().into_inner(), 5u64, |result| {
+            assert!(result.is_err());
+        })
+        // taking the snapshot manually fails as the hook already took it
+        .take_global_weight_snapshot(incentive_addr.clone().into_inner(), |result| {
+            let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+            match err {
+                ContractError::GlobalWeightSnapshotAlreadyExists { epoch } => assert_eq!(epoch, 2),
+                _ => panic!(
+                    "Wrong error type, should return ContractError::GlobalWeightSnapshotAlreadyExists"
+                ),
+            }
+        })
+        // the hook doesn't fail if the snapshot was already taken
+        .epoch_changed_hook(
+            epoch_manager.clone(),
+            incentive_addr.clone().into_inner(),
+            5u64,
+            |result| {
+                let res = result.unwrap();
+                assert!(res.events.iter().any(|event| event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.value == "snapshot already taken")));
+            },
+        );
+}
+
 #[test]
 fn open_expand_position_with_optional_receiver() {
     let mut suite = TestingSuite::default_with_balances(vec![
@@ -4910,6 +5028,7 @@ fn open_expand_flow_with_native_token() {
                     end_epoch: 19u64,
                     emitted_tokens: Default::default(),
                     asset_history: Default::default(),
+                    vesting_duration: None,
                 })
             );
         })
@@ -5068,6 +5187,7 @@ fn open_expand_flow_with_native_token() {
                     end_epoch: 19u64,
                     emitted_tokens: Default::default(),
                     asset_history: BTreeMap::from_iter(vec![(11, (Uint128::new(2_000u128), 19u64))]),
+                    vesting_duration: None,
                 })
             );
         })
@@ -5123,6 +5243,7 @@ fn open_expand_flow_with_native_token() {
                     end_epoch: 19u64,
                     emitted_tokens: Default::default(),
                     asset_history: BTreeMap::from_iter(vec![(11, (Uint128::new(3_000u128), 30u64))]),
+                    vesting_duration: None,
                 })
             );
         });
@@ -5214,6 +5335,7 @@ fn open_expand_flow_with_cw20_token() {
                         end_epoch: 19u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -5342,6 +5464,7 @@ fn open_expand_flow_with_cw20_token() {
                             11,
                             (Uint128::new(3_000u128), 19u64)
                         )]),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -5390,6 +5513,7 @@ fn open_expand_flow_with_cw20_token() {
                             11,
                             (Uint128::new(4_000u128), 30u64)
                         )]),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -5482,6 +5606,7 @@ fn fail_expand_ended_flow() {
                         end_epoch: 19u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     })
                 );
             },
@@ -5626,6 +5751,7 @@ fn open_expand_flow_with_default_values() {
                         end_epoch: 25u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -5692,6 +5818,7 @@ fn open_expand_flow_with_default_values() {
                         asset_history: vec![(12, (Uint128::new(2_000_000_000u128), 25u64))]
                             .into_iter()
                             .collect(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -5737,6 +5864,7 @@ fn open_expand_flow_with_default_values() {
                         asset_history: vec![(12, (Uint128::new(3_000_000_000u128), 25u64))]
                             .into_iter()
                             .collect(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -5786,6 +5914,7 @@ fn open_expand_flow_with_default_values() {
                         ]
                         .into_iter()
                         .collect(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -5836,6 +5965,7 @@ fn open_expand_flow_with_default_values() {
                         ]
                         .into_iter()
                         .collect(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -5989,6 +6119,7 @@ fn open_expand_flow_verify_rewards() {
                         end_epoch: 21u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -6107,6 +6238,7 @@ fn open_expand_flow_verify_rewards() {
                         asset_history: vec![(18, (Uint128::new(41_000u128), 35u64))]
                             .into_iter()
                             .collect(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -6257,6 +6389,7 @@ fn open_expand_flow_verify_rewards() {
                         ]
                         .into_iter()
                         .collect(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -6396,6 +6529,7 @@ fn open_expand_flow_over_expand_limit() {
         end_epoch: 0,
         emitted_tokens: Default::default(),
         asset_history: Default::default(),
+        vesting_duration: None,
     });
 
     suite
@@ -6495,6 +6629,7 @@ fn open_expand_flow_over_expand_limit() {
                         end_epoch: 21u64,
                         emitted_tokens: Default::default(),
                         asset_history: Default::default(),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -6588,6 +6723,7 @@ fn open_expand_flow_over_expand_limit() {
                             187,
                             (Uint128::new(13_005), 203u64)
                         )]),
+                        vesting_duration: None,
                     }
                 );
             },
@@ -6609,3 +6745,1422 @@ fn open_expand_flow_over_expand_limit() {
             },
         );
 }
+
+#[test]
+fn open_flows_with_curves_and_query_emission_schedule() {
+    let mut suite =
+        TestingSuite::default_with_balances(vec![coin(1_000_000_000u128, "uwhale".to_string())]);
+    let alice = suite.creator();
+    let carol = suite.senders[2].clone();
+
+    suite.instantiate_default_native_fee().create_lp_tokens();
+
+    let lp_address_1 = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), lp_address_1.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(lp_address_1.clone(), |result| {
+            let incentive = result.unwrap();
+            assert!(incentive.is_some());
+            *incentive_addr.borrow_mut() = incentive.unwrap();
+        });
+
+    let current_epoch = RefCell::new(0u64);
+    suite
+        .create_epochs_on_fee_distributor(9u64, vec![])
+        .query_current_epoch(|result| {
+            *current_epoch.borrow_mut() = result.unwrap().epoch.id.u64();
+        });
+
+    let flow_asset = Asset {
+        info: AssetInfo::NativeToken {
+            denom: "uwhale".to_string(),
+        },
+        amount: Uint128::new(2_000u128),
+    };
+
+    suite
+        .open_incentive_flow(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(current_epoch.clone().into_inner() + 4),
+            Some(Curve::ExponentialDecay {
+                decay_factor: Decimal::one(),
+            }),
+            flow_asset.clone(),
+            None,
+            &vec![coin(2_000u128, "uwhale".to_string())],
+            |result| {
+                // this should fail as the decay factor must be lower than 1
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCurve { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidCurve"),
+                }
+            },
+        )
+        .open_incentive_flow(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(current_epoch.clone().into_inner() + 4),
+            Some(Curve::Step {
+                steps: vec![CurveStep {
+                    epoch_offset: 1u64,
+                    weight: Decimal::one(),
+                }],
+            }),
+            flow_asset.clone(),
+            None,
+            &vec![coin(2_000u128, "uwhale".to_string())],
+            |result| {
+                // this should fail as the first step doesn't start at offset 0
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCurve { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidCurve"),
+                }
+            },
+        )
+        .open_incentive_flow(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(current_epoch.clone().into_inner() + 4),
+            Some(Curve::Custom {
+                weights: vec![Decimal::one(); 5],
+            }),
+            flow_asset.clone(),
+            None,
+            &vec![coin(2_000u128, "uwhale".to_string())],
+            |result| {
+                // this should fail as the emission table is longer than the flow
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCurve { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::InvalidCurve"),
+                }
+            },
+        )
+        .open_incentive_flow(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(current_epoch.clone().into_inner() + 4),
+            Some(Curve::ExponentialDecay {
+                decay_factor: Decimal::percent(50),
+            }),
+            flow_asset.clone(),
+            None,
+            &vec![coin(2_000u128, "uwhale".to_string())],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .open_incentive_flow(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(current_epoch.clone().into_inner() + 4),
+            Some(Curve::Custom {
+                weights: vec![Decimal::percent(300), Decimal::one()],
+            }),
+            flow_asset.clone(),
+            None,
+            &vec![coin(2_000u128, "uwhale".to_string())],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_flow(
+            incentive_addr.clone().into_inner(),
+            FlowIdentifier::Id(1u64),
+            |result| {
+                let flow_response = result.unwrap().unwrap();
+                // weights 1, 0.5, 0.25, 0.125
+                assert_eq!(
+                    flow_response.emission_schedule,
+                    vec![
+                        EpochEmission {
+                            epoch_id: 10u64,
+                            emission: Uint128::new(533u128),
+                        },
+                        EpochEmission {
+                            epoch_id: 11u64,
+                            emission: Uint128::new(266u128),
+                        },
+                        EpochEmission {
+                            epoch_id: 12u64,
+                            emission: Uint128::new(134u128),
+                        },
+                        EpochEmission {
+                            epoch_id: 13u64,
+                            emission: Uint128::new(67u128),
+                        },
+                    ]
+                );
+            },
+        )
+        .query_flow(
+            incentive_addr.clone().into_inner(),
+            FlowIdentifier::Id(2u64),
+            |result| {
+                let flow_response = result.unwrap().unwrap();
+                // weights 3, 1, 1, 1
+                assert_eq!(
+                    flow_response.emission_schedule,
+                    vec![
+                        EpochEmission {
+                            epoch_id: 10u64,
+                            emission: Uint128::new(500u128),
+                        },
+                        EpochEmission {
+                            epoch_id: 11u64,
+                            emission: Uint128::new(166u128),
+                        },
+                        EpochEmission {
+                            epoch_id: 12u64,
+                            emission: Uint128::new(167u128),
+                        },
+                        EpochEmission {
+                            epoch_id: 13u64,
+                            emission: Uint128::new(167u128),
+                        },
+                    ]
+                );
+            },
+        );
+}
+
+#[test]
+fn perpetual_lock_and_rollover_positions() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(100_000_000_000u128, "uwhale".to_string()),
+        coin(100_000_000_000u128, "ampWHALE".to_string()),
+    ]);
+    let alice = suite.creator();
+
+    suite.instantiate_default_native_fee();
+
+    let incentive_asset = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+
+    let incentive_asset_addr = suite.cw20_tokens.first().unwrap().clone();
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), incentive_asset.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(incentive_asset.clone(), |result| {
+            let incentive = result.unwrap();
+            assert!(incentive.is_some());
+            *incentive_addr.borrow_mut() = incentive.unwrap();
+        });
+
+    let time = Timestamp::from_seconds(1684766796u64);
+    suite.set_time(time);
+    suite.create_epochs_on_fee_distributor(10, vec![incentive_addr.clone().into_inner()]);
+
+    let amount = Uint128::new(1_000u128);
+    // the max unbonding duration set on the incentive factory is 3 days
+    let max_unbonding_duration = 259200u64;
+
+    suite
+        .increase_allowance(
+            alice.clone(),
+            incentive_asset_addr.clone(),
+            amount,
+            incentive_addr.clone().into_inner(),
+        )
+        .open_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            amount,
+            86400u64,
+            None,
+            vec![],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .perpetual_lock_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            172800u64,
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NonExistentPosition { .. } => {}
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::NonExistentPosition")
+                    }
+                }
+            },
+        )
+        .perpetual_lock_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            86400u64,
+            |result| {
+                result.unwrap();
+            },
+        )
+        .perpetual_lock_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            86400u64,
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::PositionAlreadyPerpetual { .. } => {}
+                    _ => panic!(
+                        "Wrong error type, should return ContractError::PositionAlreadyPerpetual"
+                    ),
+                }
+            },
+        )
+        .query_positions(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                // the perpetually locked position has the maximum weight
+                assert_eq!(
+                    result.unwrap().positions,
+                    vec![incentive::QueryPosition::OpenPosition {
+                        amount,
+                        unbonding_duration: 86400u64,
+                        weight: calculate_weight(max_unbonding_duration, amount).unwrap(),
+                    }]
+                );
+            },
+        )
+        .close_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            86400u64,
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_positions(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                // the perpetually locked position unbonds over the maximum unbonding duration
+                assert_eq!(
+                    result.unwrap().positions,
+                    vec![incentive::QueryPosition::ClosedPosition {
+                        amount,
+                        unbonding_timestamp: time.seconds() + max_unbonding_duration,
+                        weight: amount,
+                    }]
+                );
+            },
+        )
+        .rollover_incentive_positions(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            86400u64,
+            |result| {
+                // the position hasn't finished unbonding yet
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToRollover { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToRollover"),
+                }
+            },
+        );
+
+    suite
+        .set_time(time.plus_seconds(max_unbonding_duration))
+        .rollover_incentive_positions(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            86400u64,
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_positions(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                // the unbonded tokens are rolled into a new regular position
+                assert_eq!(
+                    result.unwrap().positions,
+                    vec![incentive::QueryPosition::OpenPosition {
+                        amount,
+                        unbonding_duration: 86400u64,
+                        weight: calculate_weight(86400u64, amount).unwrap(),
+                    }]
+                );
+            },
+        );
+}
+
+#[test]
+fn query_expected_emissions() {
+    let mut suite =
+        TestingSuite::default_with_balances(vec![coin(1_000_000_000u128, "uwhale".to_string())]);
+    let alice = suite.creator();
+    let carol = suite.senders[2].clone();
+
+    suite.instantiate_default_native_fee().create_lp_tokens();
+
+    let lp_address_1 = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+    let lp_address_1_addr = suite.cw20_tokens.first().unwrap().clone();
+
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), lp_address_1.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(lp_address_1.clone(), |result| {
+            let incentive = result.unwrap();
+            assert!(incentive.is_some());
+            *incentive_addr.borrow_mut() = incentive.unwrap();
+        });
+
+    let current_epoch = RefCell::new(0u64);
+    suite
+        .create_epochs_on_fee_distributor(9u64, vec![])
+        .query_current_epoch(|result| {
+            *current_epoch.borrow_mut() = result.unwrap().epoch.id.u64();
+        });
+
+    // 1_000 uwhale are left on the flow after paying the fee, emitting 250 per epoch
+    suite.open_incentive_flow(
+        carol.clone(),
+        incentive_addr.clone().into_inner(),
+        None,
+        Some(current_epoch.clone().into_inner() + 4),
+        None,
+        Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            amount: Uint128::new(2_000u128),
+        },
+        None,
+        &vec![coin(2_000u128, "uwhale".to_string())],
+        |result| {
+            result.unwrap();
+        },
+    );
+
+    let new_position = NewPosition {
+        amount: Uint128::new(1_000u128),
+        unbonding_duration: 86400u64,
+    };
+
+    suite
+        .query_expected_emissions(incentive_addr.clone().into_inner(), None, None, |result| {
+            // either an address or a new position must be given
+            assert!(result.is_err());
+        })
+        .query_expected_emissions(
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(NewPosition {
+                amount: Uint128::new(1_000u128),
+                unbonding_duration: 1u64,
+            }),
+            |result| {
+                // invalid unbonding duration
+                assert!(result.is_err());
+            },
+        )
+        .query_expected_emissions(
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(new_position.clone()),
+            |result| {
+                // the new position would be the only one, getting all the emissions
+                let response = result.unwrap();
+                assert_eq!(response.epoch_id, 11u64);
+                assert_eq!(response.share, Decimal256::one());
+                assert_eq!(response.flows.len(), 1);
+                assert_eq!(
+                    response.flows[0].flow_emission.amount,
+                    Uint128::new(250u128)
+                );
+                assert_eq!(
+                    response.emissions,
+                    vec![Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uwhale".to_string(),
+                        },
+                        amount: Uint128::new(250u128),
+                    }]
+                );
+            },
+        );
+
+    suite
+        .increase_allowance(
+            alice.clone(),
+            lp_address_1_addr,
+            Uint128::new(1_000u128),
+            incentive_addr.clone().into_inner(),
+        )
+        .open_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            Uint128::new(1_000u128),
+            86400u64,
+            None,
+            vec![],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_expected_emissions(
+            incentive_addr.clone().into_inner(),
+            Some(alice.clone()),
+            None,
+            |result| {
+                let response = result.unwrap();
+                assert_eq!(response.weight, response.global_weight);
+                assert_eq!(response.emissions[0].amount, Uint128::new(250u128));
+            },
+        )
+        .query_expected_emissions(
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(new_position.clone()),
+            |result| {
+                // the new position would have the same weight as alice's
+                let response = result.unwrap();
+                assert_eq!(response.share, Decimal256::percent(50));
+                assert_eq!(
+                    response.flows[0].expected_emission.amount,
+                    Uint128::new(125u128)
+                );
+            },
+        )
+        .query_expected_emissions(
+            incentive_addr.clone().into_inner(),
+            Some(alice.clone()),
+            Some(new_position),
+            |result| {
+                // alice adding a new position would keep getting all the emissions
+                let response = result.unwrap();
+                assert_eq!(response.share, Decimal256::one());
+                assert_eq!(response.emissions[0].amount, Uint128::new(250u128));
+            },
+        );
+}
+
+#[test]
+fn claim_all_across_incentives() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000u128, "uwhale".to_string()),
+        coin(1_000_000_000u128, "usdc".to_string()),
+        coin(1_000_000_000u128, "ampWHALE".to_string()),
+        coin(1_000_000_000u128, "bWHALE".to_string()),
+    ]);
+    let alice = suite.creator();
+    let carol = suite.senders[2].clone();
+
+    suite.instantiate_default_native_fee().create_lp_tokens();
+
+    let lp_assets = vec![
+        AssetInfo::NativeToken {
+            denom: "ampWHALE".to_string(),
+        },
+        AssetInfo::NativeToken {
+            denom: "bWHALE".to_string(),
+        },
+    ];
+    let incentive_addrs = RefCell::new(vec![]);
+
+    for lp_asset in &lp_assets {
+        suite
+            .create_incentive(alice.clone(), lp_asset.clone(), |result| {
+                result.unwrap();
+            })
+            .query_incentive(lp_asset.clone(), |result| {
+                incentive_addrs.borrow_mut().push(result.unwrap().unwrap());
+            });
+    }
+    let incentive_addrs = incentive_addrs.into_inner();
+
+    for (lp_asset, incentive_addr) in lp_assets.iter().zip(incentive_addrs.iter()) {
+        let AssetInfo::NativeToken { denom } = lp_asset else {
+            unreachable!()
+        };
+
+        suite.open_incentive_position(
+            carol.clone(),
+            incentive_addr.clone(),
+            Uint128::new(1_000u128),
+            86400u64,
+            None,
+            vec![coin(1_000u128, denom)],
+            |result| {
+                result.unwrap();
+            },
+        );
+    }
+
+    let time = Timestamp::from_seconds(1684766796u64);
+    suite.set_time(time);
+
+    let current_epoch = RefCell::new(0u64);
+    suite
+        .create_epochs_on_fee_distributor(10, incentive_addrs.clone())
+        .query_current_epoch(|result| {
+            *current_epoch.borrow_mut() = result.unwrap().epoch.id.u64();
+        });
+
+    for incentive_addr in &incentive_addrs {
+        suite.open_incentive_flow(
+            alice.clone(),
+            incentive_addr.clone(),
+            None,
+            Some(current_epoch.clone().into_inner() + 10),
+            None,
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "usdc".to_string(),
+                },
+                amount: Uint128::new(1_000_000u128),
+            },
+            None,
+            &vec![coin(1_000_000u128, "usdc"), coin(1_000u128, "uwhale")],
+            |result| {
+                result.unwrap();
+            },
+        );
+    }
+
+    let expected_rewards = RefCell::new(Uint128::zero());
+    let carol_usdc_funds = RefCell::new(Uint128::zero());
+
+    suite
+        .set_time(time.plus_seconds(86400u64))
+        .create_epochs_on_fee_distributor(4, incentive_addrs.clone());
+
+    for incentive_addr in &incentive_addrs {
+        suite.query_rewards(incentive_addr.clone(), carol.clone(), |result| {
+            let rewards = result.unwrap().rewards;
+            assert_eq!(rewards.len(), 1);
+            *expected_rewards.borrow_mut() += rewards[0].amount;
+        });
+    }
+    assert!(!expected_rewards.borrow().is_zero());
+
+    suite
+        .query_funds(
+            carol.clone(),
+            AssetInfo::NativeToken {
+                denom: "usdc".to_string(),
+            },
+            |result| {
+                *carol_usdc_funds.borrow_mut() = result;
+            },
+        )
+        .claim_for(
+            incentive_addrs[0].clone(),
+            carol.clone(),
+            carol.clone(),
+            |result| {
+                // only the incentive factory can claim on behalf of others
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::Unauthorized { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::Unauthorized"),
+                }
+            },
+        )
+        .claim_all(
+            carol.clone(),
+            Some(vec![AssetInfo::NativeToken {
+                denom: "usdc".to_string(),
+            }]),
+            |result| {
+                // there's no incentive contract for usdc
+                assert!(result.is_err());
+            },
+        )
+        .claim_all(alice.clone(), None, |result| {
+            // alice has no positions nor rewards anywhere
+            assert!(result.is_err());
+        })
+        .claim_all(carol.clone(), None, |result| {
+            result.unwrap();
+        })
+        .query_funds(
+            carol.clone(),
+            AssetInfo::NativeToken {
+                denom: "usdc".to_string(),
+            },
+            |result| {
+                assert_eq!(
+                    result,
+                    carol_usdc_funds.clone().into_inner() + expected_rewards.clone().into_inner()
+                );
+            },
+        );
+
+    for incentive_addr in &incentive_addrs {
+        suite.query_rewards(incentive_addr.clone(), carol.clone(), |result| {
+            assert!(result.unwrap().rewards.is_empty());
+        });
+    }
+
+    // claiming again is a no-op, as there's nothing left to claim
+    suite
+        .claim_all(carol.clone(), Some(lp_assets.clone()), |result| {
+            result.unwrap();
+        })
+        .query_funds(
+            carol.clone(),
+            AssetInfo::NativeToken {
+                denom: "usdc".to_string(),
+            },
+            |result| {
+                assert_eq!(
+                    result,
+                    carol_usdc_funds.clone().into_inner() + expected_rewards.clone().into_inner()
+                );
+            },
+        );
+}
+
+#[test]
+fn open_flows_with_allowlisted_flow_creators() {
+    let mut suite =
+        TestingSuite::default_with_balances(vec![coin(1_000_000_000u128, "uwhale".to_string())]);
+    let alice = suite.creator();
+    let bob = suite.senders[1].clone();
+    let carol = suite.senders[2].clone();
+
+    suite.instantiate_default_native_fee().create_lp_tokens();
+
+    let lp_address_1 = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+    let fee_collector_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), lp_address_1.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(lp_address_1.clone(), |result| {
+            *incentive_addr.borrow_mut() = result.unwrap().unwrap();
+        })
+        .query_incentive_factory_config(|result| {
+            *fee_collector_addr.borrow_mut() = result.unwrap().fee_collector_addr;
+        })
+        .update_flow_creators(
+            bob.clone(),
+            vec![FlowCreator {
+                address: bob.to_string(),
+                fee_discount: Decimal::percent(50),
+            }],
+            vec![],
+            |result| {
+                // only the owner can allowlist flow creators
+                result.unwrap_err();
+            },
+        )
+        .update_flow_creators(
+            alice.clone(),
+            vec![FlowCreator {
+                address: bob.to_string(),
+                fee_discount: Decimal::percent(50),
+            }],
+            vec![],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .update_reserved_flow_slots(alice.clone(), 8u64, |result| {
+            // can't reserve more slots than max_concurrent_flows
+            result.unwrap_err();
+        })
+        .update_reserved_flow_slots(alice.clone(), 2u64, |result| {
+            result.unwrap();
+        });
+
+    // carol is not allowlisted, so she can only fill 5 out of the 7 flow slots
+    for i in 1..=6 {
+        suite.open_incentive_flow(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(10u64),
+            None,
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(2_000u128),
+            },
+            None,
+            &vec![coin(2_000u128, "uwhale".to_string())],
+            |result| {
+                if i > 5 {
+                    let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                    match err {
+                        ContractError::TooManyFlows { maximum } => assert_eq!(maximum, 5u64),
+                        _ => panic!("Wrong error type, should return ContractError::TooManyFlows"),
+                    }
+                } else {
+                    result.unwrap();
+                }
+            },
+        );
+    }
+
+    suite
+        .query_funds(
+            fee_collector_addr.clone().into_inner(),
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            |funds| {
+                assert_eq!(funds, Uint128::new(5_000u128));
+            },
+        )
+        // bob is allowlisted, so he can fill the reserved slots paying half the fee
+        .open_incentive_flow(
+            bob.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(10u64),
+            None,
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(2_000u128),
+            },
+            None,
+            &vec![coin(2_000u128, "uwhale".to_string())],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_flow(
+            incentive_addr.clone().into_inner(),
+            FlowIdentifier::Id(6u64),
+            |result| {
+                let flow = result.unwrap().unwrap().flow.unwrap();
+                assert_eq!(flow.flow_creator, bob);
+                assert_eq!(flow.flow_asset.amount, Uint128::new(1_500u128));
+            },
+        )
+        .query_funds(
+            fee_collector_addr.clone().into_inner(),
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            |funds| {
+                assert_eq!(funds, Uint128::new(5_500u128));
+            },
+        )
+        // waive the fee entirely for bob
+        .update_flow_creators(
+            alice.clone(),
+            vec![FlowCreator {
+                address: bob.to_string(),
+                fee_discount: Decimal::one(),
+            }],
+            vec![],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .open_incentive_flow(
+            bob.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(10u64),
+            None,
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            None,
+            &vec![coin(1_000u128, "uwhale".to_string())],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_flow(
+            incentive_addr.clone().into_inner(),
+            FlowIdentifier::Id(7u64),
+            |result| {
+                let flow = result.unwrap().unwrap().flow.unwrap();
+                assert_eq!(flow.flow_asset.amount, Uint128::new(1_000u128));
+            },
+        )
+        .query_funds(
+            fee_collector_addr.clone().into_inner(),
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            |funds| {
+                assert_eq!(funds, Uint128::new(5_500u128));
+            },
+        )
+        // all the slots are taken now, allowlisted or not
+        .open_incentive_flow(
+            bob.clone(),
+            incentive_addr.clone().into_inner(),
+            None,
+            Some(10u64),
+            None,
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            None,
+            &vec![coin(1_000u128, "uwhale".to_string())],
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::TooManyFlows { maximum } => assert_eq!(maximum, 7u64),
+                    _ => panic!("Wrong error type, should return ContractError::TooManyFlows"),
+                }
+            },
+        );
+
+    // the owner can force-close a spam flow, refunding the creator
+    let carol_funds = RefCell::new(Uint128::zero());
+    suite
+        .query_funds(
+            carol.clone(),
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            |funds| {
+                *carol_funds.borrow_mut() = funds;
+            },
+        )
+        .close_incentive_flow(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            FlowIdentifier::Id(1u64),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_funds(
+            carol.clone(),
+            AssetInfo::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            |funds| {
+                assert_eq!(
+                    funds,
+                    carol_funds.clone().into_inner() + Uint128::new(1_000u128)
+                );
+            },
+        );
+}
+
+#[test]
+fn partially_close_and_emergency_exit_positions() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(100_000_000_000u128, "uwhale".to_string()),
+        coin(100_000_000_000u128, "ampWHALE".to_string()),
+    ]);
+    let alice = suite.creator();
+
+    suite.instantiate_default_native_fee();
+
+    let incentive_asset = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+
+    let incentive_asset_addr = suite.cw20_tokens.first().unwrap().clone();
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+    let fee_collector_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), incentive_asset.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(incentive_asset.clone(), |result| {
+            *incentive_addr.borrow_mut() = result.unwrap().unwrap();
+        })
+        .query_incentive_factory_config(|result| {
+            *fee_collector_addr.borrow_mut() = result.unwrap().fee_collector_addr;
+        });
+
+    let time = Timestamp::from_seconds(1684766796u64);
+    suite.set_time(time);
+    suite.create_epochs_on_fee_distributor(10, vec![incentive_addr.clone().into_inner()]);
+
+    let alice_funds = RefCell::new(Uint128::zero());
+
+    suite
+        .query_funds(alice.clone(), incentive_asset.clone(), |funds| {
+            *alice_funds.borrow_mut() = funds;
+        })
+        .increase_allowance(
+            alice.clone(),
+            incentive_asset_addr.clone(),
+            Uint128::new(1_000u128),
+            incentive_addr.clone().into_inner(),
+        )
+        .open_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            Uint128::new(1_000u128),
+            172800u64,
+            None,
+            vec![],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .partially_close_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            172800u64,
+            Uint128::new(2_000u128),
+            |result| {
+                // can't close more than the position has
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::InvalidCloseAmount { .. } => {}
+                    _ => {
+                        panic!("Wrong error type, should return ContractError::InvalidCloseAmount")
+                    }
+                }
+            },
+        )
+        .partially_close_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            172800u64,
+            Uint128::new(400u128),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_positions(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                assert_eq!(
+                    result.unwrap().positions,
+                    vec![
+                        incentive::QueryPosition::OpenPosition {
+                            amount: Uint128::new(600u128),
+                            unbonding_duration: 172800u64,
+                            weight: calculate_weight(172800u64, Uint128::new(600u128)).unwrap(),
+                        },
+                        incentive::QueryPosition::ClosedPosition {
+                            amount: Uint128::new(400u128),
+                            unbonding_timestamp: time.seconds() + 172800u64,
+                            weight: Uint128::new(400u128),
+                        },
+                    ]
+                );
+            },
+        )
+        // the closed share of the position's weight is taken out of the global weight
+        .create_epochs_on_fee_distributor(1, vec![incentive_addr.clone().into_inner()])
+        .query_incentive_global_weight(incentive_addr.clone().into_inner(), 12u64, |result| {
+            assert_eq!(
+                result.unwrap().global_weight,
+                calculate_weight(172800u64, Uint128::new(600u128)).unwrap()
+            );
+        })
+        // the closed position hasn't finished unbonding, so nothing is withdrawn
+        .withdraw(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_funds(alice.clone(), incentive_asset.clone(), |funds| {
+            assert_eq!(
+                funds,
+                alice_funds.clone().into_inner() - Uint128::new(1_000u128)
+            );
+        })
+        // exiting early takes 10% * 2 days left / 3 days max unbonding of the position, i.e. 26
+        .emergency_exit(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_funds(alice.clone(), incentive_asset.clone(), |funds| {
+            assert_eq!(
+                funds,
+                alice_funds.clone().into_inner() - Uint128::new(626u128)
+            );
+        })
+        .query_funds(
+            fee_collector_addr.clone().into_inner(),
+            incentive_asset.clone(),
+            |funds| {
+                assert_eq!(funds, Uint128::new(26u128));
+            },
+        )
+        .emergency_exit(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NoClosedPositions { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NoClosedPositions"),
+                }
+            },
+        )
+        .close_incentive_position(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            172800u64,
+            |result| {
+                result.unwrap();
+            },
+        );
+
+    // once unbonded, exiting doesn't take any penalty
+    suite
+        .set_time(time.plus_seconds(172800u64))
+        .emergency_exit(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_funds(alice.clone(), incentive_asset.clone(), |funds| {
+            assert_eq!(
+                funds,
+                alice_funds.clone().into_inner() - Uint128::new(26u128)
+            );
+        })
+        .query_funds(
+            fee_collector_addr.clone().into_inner(),
+            incentive_asset.clone(),
+            |funds| {
+                assert_eq!(funds, Uint128::new(26u128));
+            },
+        )
+        .query_positions(
+            incentive_addr.clone().into_inner(),
+            alice.clone(),
+            |result| {
+                assert!(result.unwrap().positions.is_empty());
+            },
+        )
+        // closing the rest of the position took the rest of its weight out
+        .create_epochs_on_fee_distributor(1, vec![incentive_addr.clone().into_inner()])
+        .query_incentive_global_weight(incentive_addr.clone().into_inner(), 13u64, |result| {
+            assert_eq!(result.unwrap().global_weight, Uint128::zero());
+        });
+}
+
+#[test]
+fn open_vesting_flow_and_claim_vested_rewards() {
+    let mut suite = TestingSuite::default_with_balances(vec![
+        coin(1_000_000_000u128, "uwhale".to_string()),
+        coin(1_000_000_000u128, "usdc".to_string()),
+    ]);
+    let alice = suite.creator();
+    let carol = suite.senders[2].clone();
+
+    suite.instantiate_default_native_fee();
+
+    let incentive_asset = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+    let incentive_asset_addr = suite.cw20_tokens.first().unwrap().clone();
+    let flow_asset = AssetInfo::NativeToken {
+        denom: "usdc".to_string(),
+    };
+
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+
+    suite
+        .create_incentive(alice.clone(), incentive_asset.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(incentive_asset.clone(), |result| {
+            *incentive_addr.borrow_mut() = result.unwrap().unwrap();
+        })
+        .increase_allowance(
+            carol.clone(),
+            incentive_asset_addr.clone(),
+            Uint128::new(1_000u128),
+            incentive_addr.clone().into_inner(),
+        )
+        .open_incentive_position(
+            carol.clone(),
+            incentive_addr.clone().into_inner(),
+            Uint128::new(1_000u128),
+            86400u64,
+            None,
+            vec![],
+            |result| {
+                result.unwrap();
+            },
+        );
+
+    let time = Timestamp::from_seconds(1684766796u64);
+    suite.set_time(time);
+    suite.create_epochs_on_fee_distributor(10, vec![incentive_addr.clone().into_inner()]);
+
+    let current_epoch = RefCell::new(0u64);
+    suite.query_current_epoch(|result| {
+        *current_epoch.borrow_mut() = result.unwrap().epoch.id.u64();
+    });
+
+    suite
+        .open_vesting_incentive_flow(
+            alice.clone(),
+            incentive_addr.clone().into_inner(),
+            Some(current_epoch.clone().into_inner() + 4),
+            Asset {
+                info: flow_asset.clone(),
+                amount: Uint128::new(1_000_000u128),
+            },
+            4u64,
+            &vec![coin(1_000_000u128, "usdc"), coin(1_000u128, "uwhale")],
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_flow(
+            incentive_addr.clone().into_inner(),
+            FlowIdentifier::Id(1u64),
+            |result| {
+                assert_eq!(
+                    result.unwrap().unwrap().flow.unwrap().vesting_duration,
+                    Some(4u64)
+                );
+            },
+        );
+
+    suite.set_time(time.plus_seconds(86400u64));
+    suite.create_epochs_on_fee_distributor(4, vec![incentive_addr.clone().into_inner()]);
+
+    let carol_funds = RefCell::new(Uint128::zero());
+    let rewards = RefCell::new(Uint128::zero());
+
+    suite
+        .query_funds(carol.clone(), flow_asset.clone(), |funds| {
+            *carol_funds.borrow_mut() = funds;
+        })
+        .query_rewards(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                *rewards.borrow_mut() = result.unwrap().rewards.first().unwrap().amount;
+            },
+        );
+
+    assert!(!rewards.clone().into_inner().is_zero());
+
+    suite.query_current_epoch(|result| {
+        *current_epoch.borrow_mut() = result.unwrap().epoch.id.u64();
+    });
+
+    suite
+        .claim_vested(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                // nothing has been claimed from the flow yet
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToClaim { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+                }
+            },
+        )
+        .claim(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                result.unwrap();
+            },
+        )
+        // the rewards are not transferred but start vesting
+        .query_funds(carol.clone(), flow_asset.clone(), |funds| {
+            assert_eq!(funds, carol_funds.clone().into_inner());
+        })
+        .query_vesting(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                let vesting_response = result.unwrap();
+                assert_eq!(
+                    vesting_response.vestings,
+                    vec![incentive::Vesting {
+                        asset: Asset {
+                            info: flow_asset.clone(),
+                            amount: rewards.clone().into_inner(),
+                        },
+                        claimed_amount: Uint128::zero(),
+                        start_epoch: current_epoch.clone().into_inner(),
+                        end_epoch: current_epoch.clone().into_inner() + 4,
+                    }]
+                );
+                assert!(vesting_response.claimable.is_empty());
+            },
+        )
+        .claim_vested(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                // nothing has vested yet
+                let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+                match err {
+                    ContractError::NothingToClaim { .. } => {}
+                    _ => panic!("Wrong error type, should return ContractError::NothingToClaim"),
+                }
+            },
+        );
+
+    // half of the vesting period goes by
+    suite.set_time(time.plus_seconds(172800u64));
+    suite.create_epochs_on_fee_distributor(2, vec![incentive_addr.clone().into_inner()]);
+
+    let half_vested = rewards.clone().into_inner().multiply_ratio(2u128, 4u128);
+
+    suite
+        .query_vesting(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                assert_eq!(
+                    result.unwrap().claimable,
+                    vec![Asset {
+                        info: flow_asset.clone(),
+                        amount: half_vested,
+                    }]
+                );
+            },
+        )
+        .claim_vested(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_funds(carol.clone(), flow_asset.clone(), |funds| {
+            assert_eq!(funds, carol_funds.clone().into_inner() + half_vested);
+        });
+
+    // the vesting period is over
+    suite.set_time(time.plus_seconds(259200u64));
+    suite.create_epochs_on_fee_distributor(2, vec![incentive_addr.clone().into_inner()]);
+
+    suite
+        .claim_vested(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                result.unwrap();
+            },
+        )
+        .query_funds(carol.clone(), flow_asset.clone(), |funds| {
+            assert_eq!(
+                funds,
+                carol_funds.clone().into_inner() + rewards.clone().into_inner()
+            );
+        })
+        .query_vesting(
+            incentive_addr.clone().into_inner(),
+            carol.clone(),
+            |result| {
+                let vesting_response = result.unwrap();
+                assert!(vesting_response.vestings.is_empty());
+                assert!(vesting_response.claimable.is_empty());
+            },
+        );
+}
+
+#[test]
+fn emergency_pause_withdrawals() {
+    let mut suite = TestingSuite::default_with_balances(vec![]);
+    let alice = suite.creator();
+    let bob = suite.senders[1].clone();
+
+    suite.instantiate_default_native_fee();
+
+    let incentive_asset = AssetInfo::Token {
+        contract_addr: suite.cw20_tokens.first().unwrap().to_string(),
+    };
+
+    let incentive_addr = RefCell::new(Addr::unchecked(""));
+
+    let assert_withdrawals_paused = |result: Result<AppResponse, anyhow::Error>| {
+        let err = result.unwrap_err().downcast::<ContractError>().unwrap();
+        match err {
+            ContractError::FeaturePaused { feature } => assert_eq!(feature, "withdrawals"),
+            _ => panic!("Wrong error type, should return ContractError::FeaturePaused"),
+        }
+    };
+
+    suite
+        .create_incentive(alice.clone(), incentive_asset.clone(), |result| {
+            result.unwrap();
+        })
+        .query_incentive(incentive_asset.clone(), |result| {
+            *incentive_addr.borrow_mut() = result.unwrap().unwrap();
+        })
+        .emergency_pause(
+            alice.clone(),
+            PausedFeatures {
+                withdrawals: true,
+                ..PausedFeatures::default()
+            },
+            |result| {
+                result.unwrap();
+            },
+        )
+        .close_incentive_position(
+            bob.clone(),
+            incentive_addr.clone().into_inner(),
+            86400u64,
+            assert_withdrawals_paused,
+        )
+        .withdraw(
+            incentive_addr.clone().into_inner(),
+            bob.clone(),
+            assert_withdrawals_paused,
+        )
+        .claim_for(
+            incentive_addr.clone().into_inner(),
+            bob.clone(),
+            bob.clone(),
+            assert_withdrawals_paused,
+        )
+        .claim_all(bob.clone(), Some(vec![incentive_asset.clone()]), |result| {
+            result.unwrap_err();
+        });
+}
@@ -1,6 +1,6 @@
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage},
-    to_json_binary, Addr, Env, OwnedDeps, Uint128, Uint64, WasmMsg,
+    to_json_binary, Addr, Decimal, Env, OwnedDeps, Uint128, Uint64, WasmMsg,
 };
 use cw20::Cw20Coin;
 use cw_multi_test::{App, Executor};
@@ -89,9 +89,12 @@ pub fn app_mock_instantiate(app: &mut App, lp_balance: Uint128) -> AppInstantiat
                     duration: Uint64::new(86400_000000000u64),
                     genesis_epoch: Uint64::new(1685458800_000000000u64),
                 },
-                distribution_asset: AssetInfo::NativeToken {
-                    denom: "uwhale".to_string(),
-                },
+                distribution_assets: vec![white_whale_std::fee_distributor::DistributionAsset {
+                    asset_info: AssetInfo::NativeToken {
+                        denom: "uwhale".to_string(),
+                    },
+                    aggregation_ratio: Decimal::one(),
+                }],
             },
             &[],
             "mock fee distributor",
@@ -113,10 +116,13 @@ pub fn app_mock_instantiate(app: &mut App, lp_balance: Uint128) -> AppInstantiat
                 fee_collector_addr: "fee_collector".to_string(),
                 incentive_code_id: incentive_id,
                 max_concurrent_flows: 7,
+                reserved_flow_slots: 0,
                 max_flow_epoch_buffer: 100,
                 max_unbonding_duration: 31556926,
+                emergency_exit_penalty: Decimal::zero(),
                 min_unbonding_duration: 86400,
                 fee_distributor_addr: fee_distributor.to_string(),
+                epoch_manager_addr: None,
             },
             &[],
             "mock incentive factory",
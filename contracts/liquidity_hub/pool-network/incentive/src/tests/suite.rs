@@ -1,15 +1,18 @@
-use cosmwasm_std::{Addr, Coin, StdResult, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Coin, Decimal, StdResult, Timestamp, Uint128};
 use cw20::{BalanceResponse, Cw20Coin, MinterResponse};
 use cw_multi_test::{App, AppBuilder, AppResponse, BankKeeper, Executor};
 
+use white_whale_std::epoch_manager::epoch_manager::EpochV2;
+use white_whale_std::epoch_manager::hooks::EpochChangedHookMsg;
 use white_whale_std::fee_distributor::EpochResponse;
+use white_whale_std::pause::PausedFeatures;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::pool_network::incentive::{
-    Curve, Flow, FlowIdentifier, FlowResponse, GlobalWeightResponse, PositionsResponse,
-    RewardsResponse, RewardsShareResponse,
+    Curve, ExpectedEmissionsResponse, Flow, FlowIdentifier, FlowResponse, GlobalWeightResponse,
+    NewPosition, PositionsResponse, RewardsResponse, RewardsShareResponse, VestingResponse,
 };
 use white_whale_std::pool_network::incentive_factory::{
-    IncentiveResponse, IncentivesResponse, InstantiateMsg,
+    FlowCreator, IncentiveResponse, IncentivesResponse, InstantiateMsg,
 };
 
 use crate::tests::suite_contracts::{
@@ -375,6 +378,34 @@ impl TestingSuite {
             curve,
             flow_asset,
             flow_label,
+            vesting_duration: None,
+        };
+
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, funds),
+        );
+
+        self
+    }
+
+    pub(crate) fn open_vesting_incentive_flow(
+        &mut self,
+        sender: Addr,
+        incentive_addr: Addr,
+        end_epoch: Option<u64>,
+        flow_asset: Asset,
+        vesting_duration: u64,
+        funds: &Vec<Coin>,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::OpenFlow {
+            start_epoch: None,
+            end_epoch,
+            curve: None,
+            flow_asset,
+            flow_label: None,
+            vesting_duration: Some(vesting_duration),
         };
 
         result(
@@ -436,6 +467,66 @@ impl TestingSuite {
     ) -> &mut Self {
         let msg = white_whale_std::pool_network::incentive::ExecuteMsg::ClosePosition {
             unbonding_duration,
+            amount: None,
+        };
+
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, &vec![]),
+        );
+
+        self
+    }
+
+    pub(crate) fn partially_close_incentive_position(
+        &mut self,
+        sender: Addr,
+        incentive_addr: Addr,
+        unbonding_duration: u64,
+        amount: Uint128,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::ClosePosition {
+            unbonding_duration,
+            amount: Some(amount),
+        };
+
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, &vec![]),
+        );
+
+        self
+    }
+
+    pub(crate) fn perpetual_lock_incentive_position(
+        &mut self,
+        sender: Addr,
+        incentive_addr: Addr,
+        unbonding_duration: u64,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::PerpetualLockPosition {
+            unbonding_duration,
+        };
+
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, &vec![]),
+        );
+
+        self
+    }
+
+    pub(crate) fn rollover_incentive_positions(
+        &mut self,
+        sender: Addr,
+        incentive_addr: Addr,
+        unbonding_duration: u64,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::RolloverPositions {
+            unbonding_duration,
         };
 
         result(
@@ -501,6 +592,196 @@ impl TestingSuite {
         self
     }
 
+    pub(crate) fn emergency_exit(
+        &mut self,
+        incentive_addr: Addr,
+        sender: Addr,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::EmergencyExit {};
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, &vec![]),
+        );
+
+        self
+    }
+
+    pub(crate) fn claim_vested(
+        &mut self,
+        incentive_addr: Addr,
+        sender: Addr,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::ClaimVested {};
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, &vec![]),
+        );
+
+        self
+    }
+
+    pub(crate) fn claim_for(
+        &mut self,
+        incentive_addr: Addr,
+        sender: Addr,
+        address: Addr,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::ClaimFor {
+            address: address.to_string(),
+        };
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, &vec![]),
+        );
+
+        self
+    }
+
+    pub(crate) fn claim_all(
+        &mut self,
+        sender: Addr,
+        lp_assets: Option<Vec<AssetInfo>>,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg =
+            white_whale_std::pool_network::incentive_factory::ExecuteMsg::ClaimAll { lp_assets };
+        result(self.app.execute_contract(
+            sender,
+            self.incentive_factory_addr.clone(),
+            &msg,
+            &vec![],
+        ));
+
+        self
+    }
+
+    pub(crate) fn update_flow_creators(
+        &mut self,
+        sender: Addr,
+        add: Vec<FlowCreator>,
+        remove: Vec<String>,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg =
+            white_whale_std::pool_network::incentive_factory::ExecuteMsg::UpdateFlowCreators {
+                add,
+                remove,
+            };
+        result(self.app.execute_contract(
+            sender,
+            self.incentive_factory_addr.clone(),
+            &msg,
+            &vec![],
+        ));
+
+        self
+    }
+
+    pub(crate) fn update_reserved_flow_slots(
+        &mut self,
+        sender: Addr,
+        reserved_flow_slots: u64,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive_factory::ExecuteMsg::UpdateConfig {
+            fee_collector_addr: None,
+            fee_distributor_addr: None,
+            create_flow_fee: None,
+            max_concurrent_flows: None,
+            reserved_flow_slots: Some(reserved_flow_slots),
+            incentive_code_id: None,
+            max_flow_start_time_buffer: None,
+            min_unbonding_duration: None,
+            max_unbonding_duration: None,
+            emergency_exit_penalty: None,
+            epoch_manager_addr: None,
+        };
+        result(self.app.execute_contract(
+            sender,
+            self.incentive_factory_addr.clone(),
+            &msg,
+            &vec![],
+        ));
+
+        self
+    }
+
+    pub(crate) fn update_epoch_manager(
+        &mut self,
+        sender: Addr,
+        epoch_manager_addr: Addr,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive_factory::ExecuteMsg::UpdateConfig {
+            fee_collector_addr: None,
+            fee_distributor_addr: None,
+            create_flow_fee: None,
+            max_concurrent_flows: None,
+            reserved_flow_slots: None,
+            incentive_code_id: None,
+            max_flow_start_time_buffer: None,
+            min_unbonding_duration: None,
+            max_unbonding_duration: None,
+            emergency_exit_penalty: None,
+            epoch_manager_addr: Some(epoch_manager_addr.to_string()),
+        };
+        result(self.app.execute_contract(
+            sender,
+            self.incentive_factory_addr.clone(),
+            &msg,
+            &vec![],
+        ));
+
+        self
+    }
+
+    pub(crate) fn emergency_pause(
+        &mut self,
+        sender: Addr,
+        paused_features: PausedFeatures,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive_factory::ExecuteMsg::EmergencyPause {
+            paused_features,
+        };
+
+        result(self.app.execute_contract(
+            sender,
+            self.incentive_factory_addr.clone(),
+            &msg,
+            &vec![],
+        ));
+
+        self
+    }
+
+    pub(crate) fn epoch_changed_hook(
+        &mut self,
+        sender: Addr,
+        incentive_addr: Addr,
+        epoch_id: u64,
+        result: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::pool_network::incentive::ExecuteMsg::EpochChangedHook(
+            EpochChangedHookMsg {
+                current_epoch: EpochV2 {
+                    id: epoch_id,
+                    start_time: self.get_time(),
+                },
+            },
+        );
+
+        result(
+            self.app
+                .execute_contract(sender, incentive_addr, &msg, &vec![]),
+        );
+
+        self
+    }
+
     pub(crate) fn create_epochs_on_fee_distributor(
         &mut self,
         epoch_amount: u64,
@@ -756,6 +1037,45 @@ impl TestingSuite {
         self
     }
 
+    pub(crate) fn query_vesting(
+        &mut self,
+        incentive_addr: Addr,
+        address: Addr,
+        result: impl Fn(StdResult<VestingResponse>),
+    ) -> &mut Self {
+        let vesting_response: StdResult<VestingResponse> = self.app.wrap().query_wasm_smart(
+            incentive_addr,
+            &white_whale_std::pool_network::incentive::QueryMsg::Vesting {
+                address: address.to_string(),
+            },
+        );
+
+        result(vesting_response);
+
+        self
+    }
+
+    pub(crate) fn query_expected_emissions(
+        &mut self,
+        incentive_addr: Addr,
+        address: Option<Addr>,
+        new_position: Option<NewPosition>,
+        result: impl Fn(StdResult<ExpectedEmissionsResponse>),
+    ) -> &mut Self {
+        let expected_emissions_response: StdResult<ExpectedEmissionsResponse> =
+            self.app.wrap().query_wasm_smart(
+                incentive_addr,
+                &white_whale_std::pool_network::incentive::QueryMsg::ExpectedEmissions {
+                    address: address.map(|address| address.to_string()),
+                    new_position,
+                },
+            );
+
+        result(expected_emissions_response);
+
+        self
+    }
+
     pub(crate) fn query_incentive_factory_config(
         &mut self,
         result: impl Fn(StdResult<white_whale_std::pool_network::incentive_factory::ConfigResponse>),
@@ -857,10 +1177,13 @@ fn instantiate_contract(
                 fee_distributor_addr,
                 create_flow_fee,
                 max_concurrent_flows,
+                reserved_flow_slots: 0,
                 incentive_code_id,
                 max_flow_epoch_buffer,
                 min_unbonding_duration,
                 max_unbonding_duration,
+                emergency_exit_penalty: Decimal::percent(10),
+                epoch_manager_addr: None,
             };
 
             let incentive_factory_id = suite.app.store_code(incentive_factory_contract());
@@ -1,11 +1,14 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
 };
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
 
+use white_whale_std::ownership::{
+    has_role, query_ownership_proposal, query_roles, update_role, Role,
+};
 use white_whale_std::pool_network::incentive_factory::{
     Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
@@ -14,7 +17,7 @@ use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::reply::create_incentive_reply::CREATE_INCENTIVE_REPLY_ID;
 use crate::state::CONFIG;
-use crate::{execute, queries, reply};
+use crate::{execute, migrations, queries, reply};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "white_whale-incentive_factory";
@@ -34,6 +37,13 @@ pub fn instantiate(
         return Err(ContractError::UnspecifiedConcurrentFlows);
     }
 
+    if msg.reserved_flow_slots > msg.max_concurrent_flows {
+        return Err(ContractError::InvalidReservedFlowSlots {
+            reserved_flow_slots: msg.reserved_flow_slots,
+            max_concurrent_flows: msg.max_concurrent_flows,
+        });
+    }
+
     if msg.max_unbonding_duration < msg.min_unbonding_duration {
         return Err(ContractError::InvalidUnbondingRange {
             min: msg.min_unbonding_duration,
@@ -41,16 +51,28 @@ pub fn instantiate(
         });
     }
 
+    if msg.emergency_exit_penalty > Decimal::one() {
+        return Err(ContractError::InvalidEmergencyExitPenalty {
+            emergency_exit_penalty: msg.emergency_exit_penalty,
+        });
+    }
+
     let config = Config {
         owner: deps.api.addr_validate(info.sender.as_str())?,
         fee_collector_addr: deps.api.addr_validate(msg.fee_collector_addr.as_str())?,
         fee_distributor_addr: deps.api.addr_validate(msg.fee_distributor_addr.as_str())?,
         create_flow_fee: msg.create_flow_fee,
         max_concurrent_flows: msg.max_concurrent_flows,
+        reserved_flow_slots: msg.reserved_flow_slots,
         incentive_code_id: msg.incentive_code_id,
         max_flow_epoch_buffer: msg.max_flow_epoch_buffer,
         min_unbonding_duration: msg.min_unbonding_duration,
         max_unbonding_duration: msg.max_unbonding_duration,
+        emergency_exit_penalty: msg.emergency_exit_penalty,
+        epoch_manager_addr: msg
+            .epoch_manager_addr
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -68,6 +90,10 @@ pub fn instantiate(
             "max_concurrent_flows",
             config.max_concurrent_flows.to_string(),
         ),
+        (
+            "reserved_flow_slots",
+            config.reserved_flow_slots.to_string(),
+        ),
         ("incentive_code_id", config.incentive_code_id.to_string()),
         (
             "max_flow_epoch_buffer",
@@ -81,6 +107,16 @@ pub fn instantiate(
             "max_unbonding_duration",
             config.max_unbonding_duration.to_string(),
         ),
+        (
+            "emergency_exit_penalty",
+            config.emergency_exit_penalty.to_string(),
+        ),
+        (
+            "epoch_manager_addr",
+            config
+                .epoch_manager_addr
+                .map_or_else(|| "None".to_string(), |addr| addr.to_string()),
+        ),
     ]))
 }
 
@@ -91,40 +127,68 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Only the owner can execute messages on the factory
+    // Only the owner can execute messages on the factory, except for claiming, the messages granted
+    // to the admin roles and the ownership transfer, which is checked when handled
     let config: Config = CONFIG.load(deps.storage)?;
-    if deps.api.addr_validate(info.sender.as_str())? != config.owner {
+    let authorized = deps.api.addr_validate(info.sender.as_str())? == config.owner
+        || match &msg {
+            ExecuteMsg::ClaimAll { .. } | ExecuteMsg::UpdateOwnership(_) => true,
+            ExecuteMsg::EmergencyPause { .. } => {
+                has_role(deps.storage, Role::Pauser, &info.sender)?
+            }
+            ExecuteMsg::MigrateIncentives { .. } => {
+                has_role(deps.storage, Role::Migrator, &info.sender)?
+            }
+            _ => false,
+        };
+    if !authorized {
         return Err(ContractError::Unauthorized {});
     }
 
     match msg {
         ExecuteMsg::CreateIncentive { lp_asset } => execute::create_incentive(deps, env, lp_asset),
         ExecuteMsg::UpdateConfig {
-            owner,
             fee_collector_addr,
             fee_distributor_addr,
             create_flow_fee,
             max_concurrent_flows,
+            reserved_flow_slots,
             incentive_code_id,
             max_flow_start_time_buffer,
             min_unbonding_duration,
             max_unbonding_duration,
+            emergency_exit_penalty,
+            epoch_manager_addr,
         } => execute::update_config(
             deps,
-            owner,
             fee_collector_addr,
             fee_distributor_addr,
             create_flow_fee,
             max_concurrent_flows,
+            reserved_flow_slots,
             incentive_code_id,
             max_flow_start_time_buffer,
             min_unbonding_duration,
             max_unbonding_duration,
+            emergency_exit_penalty,
+            epoch_manager_addr,
         ),
         ExecuteMsg::MigrateIncentives {
             incentive_address,
             code_id,
         } => execute::migrate_incentives(deps, incentive_address, code_id),
+        ExecuteMsg::UpdateFlowCreators { add, remove } => {
+            execute::update_flow_creators(deps, add, remove)
+        }
+        ExecuteMsg::RegisterEpochHooks { start_after, limit } => {
+            execute::register_epoch_hooks(deps, start_after, limit)
+        }
+        ExecuteMsg::ClaimAll { lp_assets } => execute::claim_all(deps, info, lp_assets),
+        ExecuteMsg::EmergencyPause { paused_features } => {
+            execute::emergency_pause(deps, info, paused_features)
+        }
+        ExecuteMsg::UpdateOwnership(action) => execute::update_ownership(deps, env, info, action),
+        ExecuteMsg::UpdateRole { role, address } => Ok(update_role(deps, role, address)?),
     }
 }
 
@@ -150,12 +214,21 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Incentives { start_after, limit } => {
             to_json_binary(&queries::get_incentives(deps, start_after, limit)?)
         }
+        QueryMsg::FlowCreator { address } => {
+            to_json_binary(&queries::get_flow_creator(deps, address)?)
+        }
+        QueryMsg::FlowCreators { start_after, limit } => {
+            to_json_binary(&queries::get_flow_creators(deps, start_after, limit)?)
+        }
+        QueryMsg::EmergencyPause {} => to_json_binary(&queries::get_emergency_pause(deps)?),
+        QueryMsg::OwnershipProposal {} => to_json_binary(&query_ownership_proposal(deps.storage)?),
+        QueryMsg::Roles {} => to_json_binary(&query_roles(deps.storage)?),
     }
 }
 
 #[cfg(not(tarpaulin_include))]
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let version: Version = CONTRACT_VERSION.parse()?;
     let storage_version: Version = get_contract_version(deps.storage)?.version.parse()?;
 
@@ -166,6 +239,22 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         });
     }
 
+    if storage_version < Version::parse("1.1.0")? {
+        migrations::migrate_to_v110(deps.branch())?;
+    }
+
+    if storage_version < Version::parse("1.2.0")? {
+        migrations::migrate_to_v120(deps.branch())?;
+    }
+
+    if storage_version < Version::parse("1.3.0")? {
+        migrations::migrate_to_v130(deps.branch())?;
+    }
+
+    if storage_version < Version::parse("1.5.0")? {
+        migrations::migrate_to_v150(deps.branch())?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default().add_attributes(vec![("action", "migrate".to_string())]))
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Decimal, StdError};
 use semver::Version;
 use thiserror::Error;
 use white_whale_std::pool_network::asset::AssetInfo;
@@ -20,6 +20,21 @@ pub enum ContractError {
     #[error("max_concurrent_flows cannot be set to zero")]
     UnspecifiedConcurrentFlows,
 
+    #[error("reserved_flow_slots ({reserved_flow_slots}) cannot be more than max_concurrent_flows ({max_concurrent_flows})")]
+    InvalidReservedFlowSlots {
+        reserved_flow_slots: u64,
+        max_concurrent_flows: u64,
+    },
+
+    #[error("The fee discount for the flow creator {address} must be between 0 and 1, got {fee_discount}")]
+    InvalidFeeDiscount {
+        address: String,
+        fee_discount: Decimal,
+    },
+
+    #[error("The emergency exit penalty must be between 0 and 1, got {emergency_exit_penalty}")]
+    InvalidEmergencyExitPenalty { emergency_exit_penalty: Decimal },
+
     #[error(
         "Attempt to create a duplicate incentive contract. Incentive already exists at {incentive}"
     )]
@@ -41,6 +56,18 @@ pub enum ContractError {
         /// The maximum unbonding time
         max: u64,
     },
+
+    #[error("There's no incentive contract for the LP asset {lp_asset}")]
+    NonExistentIncentive { lp_asset: AssetInfo },
+
+    #[error("There's nothing to claim on any incentive contract")]
+    NothingToClaim,
+
+    #[error("Can't claim on more than {max} incentive contracts at once")]
+    TooManyIncentivesToClaim { max: usize },
+
+    #[error("There's no epoch manager set on the incentive factory")]
+    EpochManagerNotSet,
 }
 
 impl From<semver::Error> for ContractError {
@@ -0,0 +1,310 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, DepsMut, MessageInfo, Order, Response, StdResult, WasmMsg,
+};
+use cw_storage_plus::Bound;
+
+use white_whale_std::pool_network::asset::AssetInfo;
+
+use crate::error::ContractError;
+use crate::helpers;
+use crate::state::INCENTIVE_MAPPINGS;
+
+/// The maximum number of incentive contracts that can be claimed on in a single transaction.
+pub const MAX_CLAIM_ALL_INCENTIVES: usize = 30;
+
+/// Claims the rewards and withdraws the closed positions of the sender across the incentive
+/// contracts of the given LP assets, or of all the LP assets if none are given, where the sender
+/// has positions or rewards. Incentive contracts that fail to be queried are skipped, so they don't
+/// prevent claiming on the others.
+pub fn claim_all(
+    deps: DepsMut,
+    info: MessageInfo,
+    lp_assets: Option<Vec<AssetInfo>>,
+) -> Result<Response, ContractError> {
+    let incentives = match lp_assets {
+        Some(lp_assets) => get_claimable_incentives(&deps, &info.sender, lp_assets)?,
+        None => find_claimable_incentives(&deps, &info.sender)?,
+    };
+
+    if incentives.is_empty() {
+        return Err(ContractError::NothingToClaim);
+    }
+
+    let messages = incentives
+        .iter()
+        .map(|incentive_addr| {
+            Ok(WasmMsg::Execute {
+                contract_addr: incentive_addr.to_string(),
+                msg: to_json_binary(
+                    &white_whale_std::pool_network::incentive::ExecuteMsg::ClaimFor {
+                        address: info.sender.to_string(),
+                    },
+                )?,
+                funds: vec![],
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(Response::default()
+        .add_attributes(vec![
+            ("action", "claim_all".to_string()),
+            ("address", info.sender.to_string()),
+            ("incentives", incentives.len().to_string()),
+        ])
+        .add_messages(messages))
+}
+
+/// Gets the incentive contracts of the given LP assets where the address has something to claim.
+fn get_claimable_incentives(
+    deps: &DepsMut,
+    address: &Addr,
+    lp_assets: Vec<AssetInfo>,
+) -> Result<Vec<Addr>, ContractError> {
+    if lp_assets.len() > MAX_CLAIM_ALL_INCENTIVES {
+        return Err(ContractError::TooManyIncentivesToClaim {
+            max: MAX_CLAIM_ALL_INCENTIVES,
+        });
+    }
+
+    let mut incentives = vec![];
+    for lp_asset in lp_assets {
+        let incentive_addr = INCENTIVE_MAPPINGS
+            .may_load(deps.storage, lp_asset.to_raw(deps.api)?.as_bytes())?
+            .ok_or(ContractError::NonExistentIncentive { lp_asset })?;
+
+        if incentives.contains(&incentive_addr) {
+            continue;
+        }
+
+        if let Ok(true) = helpers::has_positions_or_rewards(&deps.querier, &incentive_addr, address)
+        {
+            incentives.push(incentive_addr);
+        }
+    }
+
+    Ok(incentives)
+}
+
+/// Goes through the incentive contracts in pages, looking for the ones where the address has
+/// something to claim, until [MAX_CLAIM_ALL_INCENTIVES] are found or all of them were checked.
+fn find_claimable_incentives(deps: &DepsMut, address: &Addr) -> StdResult<Vec<Addr>> {
+    let mut incentives = vec![];
+    let mut start_after: Option<Vec<u8>> = None;
+
+    loop {
+        let page = INCENTIVE_MAPPINGS
+            .range(
+                deps.storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(MAX_CLAIM_ALL_INCENTIVES)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let Some((last_lp_reference, _)) = page.last() else {
+            return Ok(incentives);
+        };
+        start_after = Some(last_lp_reference.clone());
+
+        for (_, incentive_addr) in page {
+            if let Ok(true) =
+                helpers::has_positions_or_rewards(&deps.querier, &incentive_addr, address)
+            {
+                incentives.push(incentive_addr);
+
+                if incentives.len() == MAX_CLAIM_ALL_INCENTIVES {
+                    return Ok(incentives);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{
+        to_json_binary, Addr, ContractResult, CosmosMsg, SystemError, SystemResult, Uint128,
+        WasmMsg, WasmQuery,
+    };
+
+    use white_whale_std::pool_network::asset::AssetInfo;
+    use white_whale_std::pool_network::incentive::{PositionsResponse, QueryPosition};
+    use white_whale_std::pool_network::incentive_factory::ExecuteMsg::ClaimAll;
+
+    use crate::contract::{execute, instantiate};
+    use crate::error::ContractError;
+    use crate::state::INCENTIVE_MAPPINGS;
+    use crate::testing::mock_instantiate_msg;
+
+    use super::MAX_CLAIM_ALL_INCENTIVES;
+
+    fn lp_asset(id: usize) -> AssetInfo {
+        AssetInfo::NativeToken {
+            denom: format!("lp{id}"),
+        }
+    }
+
+    #[test]
+    fn claim_all_skips_failing_incentives() {
+        let mut deps = mock_dependencies();
+
+        let msg = mock_instantiate_msg();
+        instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        for id in 1..=2 {
+            let lp_reference = lp_asset(id).to_raw(&deps.api).unwrap();
+            INCENTIVE_MAPPINGS
+                .save(
+                    &mut deps.storage,
+                    lp_reference.as_bytes(),
+                    &Addr::unchecked(format!("incentive{id}")),
+                )
+                .unwrap();
+        }
+
+        // incentive1 answers with an open position, incentive2 fails to be queried
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == "incentive1" => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&PositionsResponse {
+                        timestamp: 0,
+                        positions: vec![QueryPosition::OpenPosition {
+                            amount: Uint128::new(1_000u128),
+                            unbonding_duration: 86400u64,
+                            weight: Uint128::new(1_000u128),
+                        }],
+                    })
+                    .unwrap(),
+                ))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "incentive2".to_string(),
+            }),
+        });
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            ClaimAll {
+                lp_assets: Some(vec![lp_asset(1), lp_asset(2), lp_asset(1)]),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1usize);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+                assert_eq!(contract_addr, "incentive1")
+            }
+            _ => panic!("should claim on incentive1"),
+        }
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            ClaimAll {
+                lp_assets: Some(vec![lp_asset(2)]),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::NothingToClaim => {}
+            _ => panic!("should return ContractError::NothingToClaim"),
+        }
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            ClaimAll {
+                lp_assets: Some((0..=MAX_CLAIM_ALL_INCENTIVES).map(lp_asset).collect()),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::TooManyIncentivesToClaim { .. } => {}
+            _ => panic!("should return ContractError::TooManyIncentivesToClaim"),
+        }
+    }
+
+    #[test]
+    fn claim_all_looks_up_the_incentives_in_pages() {
+        let mut deps = mock_dependencies();
+
+        let msg = mock_instantiate_msg();
+        instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        for id in 0..MAX_CLAIM_ALL_INCENTIVES + 5 {
+            let lp_reference = lp_asset(id).to_raw(&deps.api).unwrap();
+            INCENTIVE_MAPPINGS
+                .save(
+                    &mut deps.storage,
+                    lp_reference.as_bytes(),
+                    &Addr::unchecked(format!("incentive{id}")),
+                )
+                .unwrap();
+        }
+
+        // only incentive9 answers, which is the last one as the LP assets are sorted by denom
+        deps.querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == "incentive9" => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&PositionsResponse {
+                        timestamp: 0,
+                        positions: vec![QueryPosition::OpenPosition {
+                            amount: Uint128::new(1_000u128),
+                            unbonding_duration: 86400u64,
+                            weight: Uint128::new(1_000u128),
+                        }],
+                    })
+                    .unwrap(),
+                ))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "incentive".to_string(),
+            }),
+        });
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            ClaimAll { lp_assets: None },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1usize);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+                assert_eq!(contract_addr, "incentive9")
+            }
+            _ => panic!("should claim on incentive9"),
+        }
+
+        // all the incentives answer, so the claims are capped
+        deps.querier.update_wasm(|_| {
+            SystemResult::Ok(ContractResult::Ok(
+                to_json_binary(&PositionsResponse {
+                    timestamp: 0,
+                    positions: vec![QueryPosition::OpenPosition {
+                        amount: Uint128::new(1_000u128),
+                        unbonding_duration: 86400u64,
+                        weight: Uint128::new(1_000u128),
+                    }],
+                })
+                .unwrap(),
+            ))
+        });
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("alice", &[]),
+            ClaimAll { lp_assets: None },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), MAX_CLAIM_ALL_INCENTIVES);
+    }
+}
@@ -0,0 +1,117 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use white_whale_std::pause::PausedFeatures;
+
+use crate::{
+    error::ContractError,
+    state::{CONFIG, PAUSED_FEATURES},
+};
+
+/// Pauses features across all the incentive contracts. The pauser can only pause features, while
+/// the owner can also unpause them.
+pub fn emergency_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    paused_features: PausedFeatures,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let current_paused_features = PAUSED_FEATURES.may_load(deps.storage)?.unwrap_or_default();
+
+    if info.sender != config.owner && paused_features.unpauses(&current_paused_features) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    PAUSED_FEATURES.save(deps.storage, &paused_features)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "emergency_pause".to_string()),
+        ("deposits", paused_features.deposits.to_string()),
+        ("withdrawals", paused_features.withdrawals.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{from_json, Addr};
+    use white_whale_std::ownership::Role;
+    use white_whale_std::pause::{EmergencyPauseResponse, PausedFeatures};
+    use white_whale_std::pool_network::incentive_factory::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::error::ContractError;
+    use crate::testing::mock_instantiate_msg;
+
+    #[test]
+    fn emergency_pause() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            max_concurrent_flows: 5u64,
+            reserved_flow_slots: 2u64,
+            ..mock_instantiate_msg()
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            ExecuteMsg::UpdateRole {
+                role: Role::Pauser,
+                address: Some("pauser".to_string()),
+            },
+        )
+        .unwrap();
+
+        let withdrawals_paused = PausedFeatures {
+            withdrawals: true,
+            ..PausedFeatures::default()
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("pauser", &[]),
+            ExecuteMsg::EmergencyPause {
+                paused_features: withdrawals_paused.clone(),
+            },
+        )
+        .unwrap();
+
+        let emergency_pause: EmergencyPauseResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::EmergencyPause {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            emergency_pause,
+            EmergencyPauseResponse {
+                pauser: Some(Addr::unchecked("pauser")),
+                paused_features: withdrawals_paused,
+            }
+        );
+
+        // the pauser can't unpause withdrawals
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("pauser", &[]),
+            ExecuteMsg::EmergencyPause {
+                paused_features: PausedFeatures::default(),
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Unauthorized => {}
+            _ => panic!("should return ContractError::Unauthorized"),
+        }
+
+        // but the owner can
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            ExecuteMsg::EmergencyPause {
+                paused_features: PausedFeatures::default(),
+            },
+        )
+        .unwrap();
+    }
+}
@@ -57,35 +57,20 @@ mod tests {
     // create test to check the update_config function works properly
 
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{attr, Addr, Uint128};
+    use cosmwasm_std::{attr, Addr};
 
-    use white_whale_std::pool_network::asset::{Asset, AssetInfo};
     use white_whale_std::pool_network::incentive_factory::ExecuteMsg::MigrateIncentives;
-    use white_whale_std::pool_network::incentive_factory::InstantiateMsg;
 
     use crate::contract::{execute, instantiate};
     use crate::state::INCENTIVE_MAPPINGS;
+    use crate::testing::mock_instantiate_msg;
 
     #[test]
     fn migrate_single_incentive() {
         let mut deps = mock_dependencies();
         let info = mock_info("owner", &[]);
 
-        let msg = InstantiateMsg {
-            fee_collector_addr: "fee_collector_addr".to_string(),
-            fee_distributor_addr: "fee_distributor_addr".to_string(),
-            create_flow_fee: Asset {
-                info: AssetInfo::NativeToken {
-                    denom: "native-fee-token".to_string(),
-                },
-                amount: Uint128::one(),
-            },
-            max_concurrent_flows: 1u64,
-            incentive_code_id: 123,
-            max_flow_epoch_buffer: 3600u64,
-            min_unbonding_duration: 86400u64,
-            max_unbonding_duration: 259200u64,
-        };
+        let msg = mock_instantiate_msg();
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         for i in 0..3 {
@@ -122,21 +107,7 @@ mod tests {
         let mut deps = mock_dependencies();
         let info = mock_info("owner", &[]);
 
-        let msg = InstantiateMsg {
-            fee_collector_addr: "fee_collector_addr".to_string(),
-            fee_distributor_addr: "fee_distributor_addr".to_string(),
-            create_flow_fee: Asset {
-                info: AssetInfo::NativeToken {
-                    denom: "native-fee-token".to_string(),
-                },
-                amount: Uint128::one(),
-            },
-            max_concurrent_flows: 1u64,
-            incentive_code_id: 123,
-            max_flow_epoch_buffer: 3600u64,
-            min_unbonding_duration: 86400u64,
-            max_unbonding_duration: 259200u64,
-        };
+        let msg = mock_instantiate_msg();
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         for i in 0..3 {
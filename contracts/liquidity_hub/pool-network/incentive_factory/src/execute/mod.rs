@@ -1,7 +1,17 @@
+mod claim_all;
 mod create_incentive;
+mod emergency_pause;
 mod migrate_incentive;
+mod register_epoch_hooks;
 mod update_config;
+mod update_flow_creators;
+mod update_ownership;
 
+pub use claim_all::claim_all;
 pub use create_incentive::create_incentive;
+pub use emergency_pause::emergency_pause;
 pub use migrate_incentive::migrate_incentives;
+pub use register_epoch_hooks::register_epoch_hooks;
 pub use update_config::update_config;
+pub use update_flow_creators::update_flow_creators;
+pub use update_ownership::update_ownership;
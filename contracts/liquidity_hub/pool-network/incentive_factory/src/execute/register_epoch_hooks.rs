@@ -0,0 +1,164 @@
+use cosmwasm_std::{CosmosMsg, DepsMut, Response, StdResult};
+
+use white_whale_std::pool_network::asset::AssetInfo;
+
+use crate::error::ContractError;
+use crate::helpers::add_epoch_hook_msg;
+use crate::queries::get_incentives;
+use crate::state::CONFIG;
+
+/// Registers a page of the existing incentive contracts as hooks on the epoch manager.
+pub fn register_epoch_hooks(
+    deps: DepsMut,
+    start_after: Option<AssetInfo>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let epoch_manager_addr = CONFIG
+        .load(deps.storage)?
+        .epoch_manager_addr
+        .ok_or(ContractError::EpochManagerNotSet)?;
+
+    let incentives = get_incentives(deps.as_ref(), start_after, limit)?;
+    let messages = incentives
+        .iter()
+        .map(|incentive| add_epoch_hook_msg(&epoch_manager_addr, &incentive.incentive_address))
+        .collect::<StdResult<Vec<CosmosMsg>>>()?;
+
+    Ok(Response::default()
+        .add_messages(messages)
+        .add_attributes(vec![
+            ("action", "register_epoch_hooks".to_string()),
+            ("epoch_manager_addr", epoch_manager_addr.to_string()),
+            ("registered", incentives.len().to_string()),
+        ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{Addr, CosmosMsg, DepsMut};
+
+    use white_whale_std::pool_network::asset::AssetInfo;
+    use white_whale_std::pool_network::incentive_factory::ExecuteMsg::RegisterEpochHooks;
+    use white_whale_std::pool_network::incentive_factory::InstantiateMsg;
+
+    use crate::contract::{execute, instantiate};
+    use crate::error::ContractError;
+    use crate::helpers::add_epoch_hook_msg;
+    use crate::state::INCENTIVE_MAPPINGS;
+    use crate::testing::mock_instantiate_msg;
+
+    fn lp_asset(id: u64) -> AssetInfo {
+        AssetInfo::NativeToken {
+            denom: format!("lp{id}"),
+        }
+    }
+
+    fn mock_instantiation(mut deps: DepsMut, epoch_manager_addr: Option<String>) {
+        let msg = InstantiateMsg {
+            epoch_manager_addr,
+            ..mock_instantiate_msg()
+        };
+        instantiate(deps.branch(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        for id in 1..=3 {
+            INCENTIVE_MAPPINGS
+                .save(
+                    deps.storage,
+                    lp_asset(id).to_raw(deps.api).unwrap().as_bytes(),
+                    &Addr::unchecked(format!("incentive{id}")),
+                )
+                .unwrap();
+        }
+    }
+
+    fn hook_msgs(ids: Vec<u64>) -> Vec<CosmosMsg> {
+        ids.into_iter()
+            .map(|id| {
+                add_epoch_hook_msg(
+                    &Addr::unchecked("epoch_manager"),
+                    &Addr::unchecked(format!("incentive{id}")),
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cannot_register_epoch_hooks_without_epoch_manager() {
+        let mut deps = mock_dependencies();
+        mock_instantiation(deps.as_mut(), None);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            RegisterEpochHooks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::EpochManagerNotSet => {}
+            _ => panic!("should return ContractError::EpochManagerNotSet"),
+        }
+    }
+
+    #[test]
+    fn register_epoch_hooks_in_pages() {
+        let mut deps = mock_dependencies();
+        mock_instantiation(deps.as_mut(), Some("epoch_manager".to_string()));
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("unauthorized", &[]),
+            RegisterEpochHooks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Unauthorized => {}
+            _ => panic!("should return ContractError::Unauthorized"),
+        }
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            RegisterEpochHooks {
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|sub_msg| sub_msg.msg)
+                .collect::<Vec<_>>(),
+            hook_msgs(vec![1, 2])
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            RegisterEpochHooks {
+                start_after: Some(lp_asset(2)),
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages
+                .into_iter()
+                .map(|sub_msg| sub_msg.msg)
+                .collect::<Vec<_>>(),
+            hook_msgs(vec![3])
+        );
+    }
+}
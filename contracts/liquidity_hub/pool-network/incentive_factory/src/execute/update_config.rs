@@ -1,4 +1,4 @@
-use cosmwasm_std::{DepsMut, Response};
+use cosmwasm_std::{Decimal, DepsMut, Response};
 use white_whale_std::pool_network::asset::Asset;
 
 use crate::{error::ContractError, state::CONFIG};
@@ -6,22 +6,20 @@ use crate::{error::ContractError, state::CONFIG};
 #[allow(clippy::too_many_arguments)]
 pub fn update_config(
     deps: DepsMut,
-    owner: Option<String>,
     fee_collector_addr: Option<String>,
     fee_distributor_addr: Option<String>,
     create_flow_fee: Option<Asset>,
     max_concurrent_flows: Option<u64>,
+    reserved_flow_slots: Option<u64>,
     incentive_code_id: Option<u64>,
     max_flow_start_time_buffer: Option<u64>,
     min_unbonding_duration: Option<u64>,
     max_unbonding_duration: Option<u64>,
+    emergency_exit_penalty: Option<Decimal>,
+    epoch_manager_addr: Option<String>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
 
-    if let Some(owner) = owner {
-        config.owner = deps.api.addr_validate(&owner)?;
-    }
-
     if let Some(fee_collector_addr) = fee_collector_addr {
         config.fee_collector_addr = deps.api.addr_validate(&fee_collector_addr)?;
     }
@@ -42,6 +40,17 @@ pub fn update_config(
         config.max_concurrent_flows = max_concurrent_flows;
     }
 
+    if let Some(reserved_flow_slots) = reserved_flow_slots {
+        config.reserved_flow_slots = reserved_flow_slots;
+    }
+
+    if config.reserved_flow_slots > config.max_concurrent_flows {
+        return Err(ContractError::InvalidReservedFlowSlots {
+            reserved_flow_slots: config.reserved_flow_slots,
+            max_concurrent_flows: config.max_concurrent_flows,
+        });
+    }
+
     if let Some(incentive_code_id) = incentive_code_id {
         config.incentive_code_id = incentive_code_id;
     }
@@ -72,6 +81,21 @@ pub fn update_config(
         config.min_unbonding_duration = min_unbonding_duration;
     }
 
+    if let Some(emergency_exit_penalty) = emergency_exit_penalty {
+        if emergency_exit_penalty > Decimal::one() {
+            return Err(ContractError::InvalidEmergencyExitPenalty {
+                emergency_exit_penalty,
+            });
+        }
+
+        config.emergency_exit_penalty = emergency_exit_penalty;
+    }
+
+    // the existing incentive contracts are registered on the new epoch manager with RegisterEpochHooks
+    if let Some(epoch_manager_addr) = epoch_manager_addr {
+        config.epoch_manager_addr = Some(deps.api.addr_validate(&epoch_manager_addr)?);
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::default().add_attributes(vec![
@@ -87,6 +111,10 @@ pub fn update_config(
             "max_concurrent_flows",
             config.max_concurrent_flows.to_string(),
         ),
+        (
+            "reserved_flow_slots",
+            config.reserved_flow_slots.to_string(),
+        ),
         ("incentive_code_id", config.incentive_code_id.to_string()),
         (
             "max_flow_start_time_buffer",
@@ -100,6 +128,16 @@ pub fn update_config(
             "max_unbonding_duration",
             config.max_unbonding_duration.to_string(),
         ),
+        (
+            "emergency_exit_penalty",
+            config.emergency_exit_penalty.to_string(),
+        ),
+        (
+            "epoch_manager_addr",
+            config
+                .epoch_manager_addr
+                .map_or_else(|| "None".to_string(), |addr| addr.to_string()),
+        ),
     ]))
 }
 
@@ -109,32 +147,19 @@ mod tests {
 
     use crate::contract::{execute, instantiate, query};
     use crate::error::ContractError;
+    use crate::testing::mock_instantiate_msg;
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{from_json, Addr, Uint128};
+    use cosmwasm_std::{from_json, Addr, Decimal, Uint128};
     use white_whale_std::pool_network::asset::{Asset, AssetInfo};
     use white_whale_std::pool_network::incentive_factory::ExecuteMsg::UpdateConfig;
-    use white_whale_std::pool_network::incentive_factory::{Config, InstantiateMsg, QueryMsg};
+    use white_whale_std::pool_network::incentive_factory::{Config, QueryMsg};
 
     #[test]
     fn update_config_successfully() {
         let mut deps = mock_dependencies();
         let info = mock_info("owner", &[]);
 
-        let msg = InstantiateMsg {
-            fee_collector_addr: "fee_collector_addr".to_string(),
-            fee_distributor_addr: "fee_distributor_addr".to_string(),
-            create_flow_fee: Asset {
-                info: AssetInfo::NativeToken {
-                    denom: "native-fee-token".to_string(),
-                },
-                amount: Uint128::one(),
-            },
-            max_concurrent_flows: 1u64,
-            incentive_code_id: 123,
-            max_flow_epoch_buffer: 3600u64,
-            min_unbonding_duration: 86400u64,
-            max_unbonding_duration: 259200u64,
-        };
+        let msg = mock_instantiate_msg();
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         let config: Config =
@@ -153,15 +178,17 @@ mod tests {
                     amount: Uint128::one()
                 },
                 max_concurrent_flows: 1u64,
+                reserved_flow_slots: 0u64,
                 incentive_code_id: 123,
                 max_flow_epoch_buffer: 3600u64,
                 min_unbonding_duration: 86400u64,
                 max_unbonding_duration: 259200u64,
+                emergency_exit_penalty: Decimal::zero(),
+                epoch_manager_addr: None,
             }
         );
 
         let msg = UpdateConfig {
-            owner: Some("new_owner".to_string()),
             fee_collector_addr: Some("new_fee_collector_addr".to_string()),
             fee_distributor_addr: Some("new_fee_distributor_addr".to_string()),
             create_flow_fee: Some(Asset {
@@ -171,10 +198,13 @@ mod tests {
                 amount: Uint128::new(1000u128),
             }),
             max_concurrent_flows: Some(10u64),
+            reserved_flow_slots: Some(2u64),
             incentive_code_id: Some(456u64),
             max_flow_start_time_buffer: Some(60u64),
             min_unbonding_duration: Some(1000u64),
             max_unbonding_duration: Some(86400u64),
+            emergency_exit_penalty: Some(Decimal::percent(10)),
+            epoch_manager_addr: Some("epoch_manager_addr".to_string()),
         };
 
         let info = mock_info("owner", &[]);
@@ -186,7 +216,7 @@ mod tests {
         assert_eq!(
             config,
             Config {
-                owner: Addr::unchecked("new_owner"),
+                owner: Addr::unchecked("owner"),
                 fee_collector_addr: Addr::unchecked("new_fee_collector_addr"),
                 fee_distributor_addr: Addr::unchecked("new_fee_distributor_addr"),
                 create_flow_fee: Asset {
@@ -196,10 +226,13 @@ mod tests {
                     amount: Uint128::new(1000u128)
                 },
                 max_concurrent_flows: 10u64,
+                reserved_flow_slots: 2u64,
                 incentive_code_id: 456u64,
                 max_flow_epoch_buffer: 60u64,
                 min_unbonding_duration: 1000u64,
                 max_unbonding_duration: 86400u64,
+                emergency_exit_penalty: Decimal::percent(10),
+                epoch_manager_addr: Some(Addr::unchecked("epoch_manager_addr")),
             }
         );
     }
@@ -209,34 +242,22 @@ mod tests {
         let mut deps = mock_dependencies();
         let info = mock_info("owner", &[]);
 
-        let msg = InstantiateMsg {
-            fee_collector_addr: "fee_collector_addr".to_string(),
-            fee_distributor_addr: "fee_distributor_addr".to_string(),
-            create_flow_fee: Asset {
-                info: AssetInfo::NativeToken {
-                    denom: "native-fee-token".to_string(),
-                },
-                amount: Uint128::one(),
-            },
-            max_concurrent_flows: 1u64,
-            incentive_code_id: 123,
-            max_flow_epoch_buffer: 3600u64,
-            min_unbonding_duration: 86400u64,
-            max_unbonding_duration: 259200u64,
-        };
+        let msg = mock_instantiate_msg();
         instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
         let info = mock_info("unauthorized", &[]);
         let msg = UpdateConfig {
-            owner: None,
             fee_collector_addr: None,
             fee_distributor_addr: None,
             create_flow_fee: None,
             max_concurrent_flows: Some(0u64),
+            reserved_flow_slots: None,
             incentive_code_id: None,
             max_flow_start_time_buffer: None,
             min_unbonding_duration: None,
             max_unbonding_duration: None,
+            emergency_exit_penalty: None,
+            epoch_manager_addr: None,
         };
 
         let err = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
@@ -254,15 +275,17 @@ mod tests {
         }
 
         let msg = UpdateConfig {
-            owner: None,
             fee_collector_addr: None,
             fee_distributor_addr: None,
             create_flow_fee: None,
             max_concurrent_flows: None,
+            reserved_flow_slots: None,
             incentive_code_id: None,
             max_flow_start_time_buffer: None,
             min_unbonding_duration: Some(300000u64),
             max_unbonding_duration: None,
+            emergency_exit_penalty: None,
+            epoch_manager_addr: None,
         };
 
         let err = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
@@ -272,15 +295,17 @@ mod tests {
         }
 
         let msg = UpdateConfig {
-            owner: None,
             fee_collector_addr: None,
             fee_distributor_addr: None,
             create_flow_fee: None,
             max_concurrent_flows: None,
+            reserved_flow_slots: None,
             incentive_code_id: None,
             max_flow_start_time_buffer: None,
             min_unbonding_duration: None,
             max_unbonding_duration: Some(1000u64),
+            emergency_exit_penalty: None,
+            epoch_manager_addr: None,
         };
 
         let err = execute(deps.as_mut(), mock_env(), info.clone(), msg.clone()).unwrap_err();
@@ -288,5 +313,25 @@ mod tests {
             ContractError::InvalidUnbondingRange { .. } => {}
             _ => panic!("should return ContractError::InvalidUnbondingRange"),
         }
+
+        let msg = UpdateConfig {
+            fee_collector_addr: None,
+            fee_distributor_addr: None,
+            create_flow_fee: None,
+            max_concurrent_flows: None,
+            reserved_flow_slots: None,
+            incentive_code_id: None,
+            max_flow_start_time_buffer: None,
+            min_unbonding_duration: None,
+            max_unbonding_duration: None,
+            emergency_exit_penalty: Some(Decimal::percent(150)),
+            epoch_manager_addr: None,
+        };
+
+        let err = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+        match err {
+            ContractError::InvalidEmergencyExitPenalty { .. } => {}
+            _ => panic!("should return ContractError::InvalidEmergencyExitPenalty"),
+        }
     }
 }
@@ -0,0 +1,172 @@
+use cosmwasm_std::{Decimal, DepsMut, Response};
+use white_whale_std::pool_network::incentive_factory::FlowCreator;
+
+use crate::{error::ContractError, state::FLOW_CREATORS};
+
+/// Adds and removes flow creators from the allowlist.
+pub fn update_flow_creators(
+    deps: DepsMut,
+    add: Vec<FlowCreator>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    for flow_creator in &add {
+        if flow_creator.fee_discount > Decimal::one() {
+            return Err(ContractError::InvalidFeeDiscount {
+                address: flow_creator.address.clone(),
+                fee_discount: flow_creator.fee_discount,
+            });
+        }
+
+        let address = deps.api.addr_validate(&flow_creator.address)?;
+        FLOW_CREATORS.save(deps.storage, &address, &flow_creator.fee_discount)?;
+    }
+
+    for address in &remove {
+        let address = deps.api.addr_validate(address)?;
+        FLOW_CREATORS.remove(deps.storage, &address);
+    }
+
+    let mut attributes = vec![("action", "update_flow_creators".to_string())];
+
+    // attributes can't be empty, so they are only added when there are flow creators to report
+    if !add.is_empty() {
+        attributes.push((
+            "added",
+            add.iter()
+                .map(|flow_creator| {
+                    format!("{}:{}", flow_creator.address, flow_creator.fee_discount)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        ));
+    }
+
+    if !remove.is_empty() {
+        attributes.push(("removed", remove.join(", ")));
+    }
+
+    Ok(Response::default().add_attributes(attributes))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{from_json, Addr, Decimal};
+    use white_whale_std::pool_network::incentive_factory::{
+        ExecuteMsg, FlowCreator, FlowCreatorResponse, FlowCreatorsResponse, InstantiateMsg,
+        QueryMsg,
+    };
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::error::ContractError;
+    use crate::testing::mock_instantiate_msg;
+
+    #[test]
+    fn update_flow_creators() {
+        let mut deps = mock_dependencies();
+
+        let msg = InstantiateMsg {
+            max_concurrent_flows: 5u64,
+            reserved_flow_slots: 2u64,
+            ..mock_instantiate_msg()
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        let msg = ExecuteMsg::UpdateFlowCreators {
+            add: vec![
+                FlowCreator {
+                    address: "creator1".to_string(),
+                    fee_discount: Decimal::percent(50),
+                },
+                FlowCreator {
+                    address: "creator2".to_string(),
+                    fee_discount: Decimal::one(),
+                },
+            ],
+            remove: vec![],
+        };
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("unauthorized", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        match err {
+            ContractError::Unauthorized => {}
+            _ => panic!("should return ContractError::Unauthorized"),
+        }
+
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        let flow_creators: FlowCreatorsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::FlowCreators {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            flow_creators,
+            vec![
+                FlowCreatorResponse {
+                    address: Addr::unchecked("creator1"),
+                    allowlisted: true,
+                    fee_discount: Decimal::percent(50),
+                },
+                FlowCreatorResponse {
+                    address: Addr::unchecked("creator2"),
+                    allowlisted: true,
+                    fee_discount: Decimal::one(),
+                },
+            ]
+        );
+
+        // remove one of the creators
+        let msg = ExecuteMsg::UpdateFlowCreators {
+            add: vec![],
+            remove: vec!["creator1".to_string()],
+        };
+        execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        let flow_creator: FlowCreatorResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::FlowCreator {
+                    address: "creator1".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            flow_creator,
+            FlowCreatorResponse {
+                address: Addr::unchecked("creator1"),
+                allowlisted: false,
+                fee_discount: Decimal::zero(),
+            }
+        );
+
+        // discounts can't be over 100%
+        let msg = ExecuteMsg::UpdateFlowCreators {
+            add: vec![FlowCreator {
+                address: "creator3".to_string(),
+                fee_discount: Decimal::percent(101),
+            }],
+            remove: vec![],
+        };
+        let err = execute(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap_err();
+        match err {
+            ContractError::InvalidFeeDiscount { .. } => {}
+            _ => panic!("should return ContractError::InvalidFeeDiscount"),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use white_whale_std::ownership::{self, OwnershipAction};
+
+use crate::{error::ContractError, state::CONFIG};
+
+/// Proposes, accepts or cancels a transfer of the ownership of the incentive factory. Once the
+/// ownership is accepted, the new owner is stored in the config.
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{from_json, Addr};
+    use white_whale_std::ownership::{OwnershipAction, OwnershipProposal};
+    use white_whale_std::pool_network::incentive_factory::{Config, ExecuteMsg, QueryMsg};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::testing::mock_instantiate_msg;
+
+    #[test]
+    fn update_ownership_in_two_steps() {
+        let mut deps = mock_dependencies();
+
+        let msg = mock_instantiate_msg();
+        instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+                new_owner: "new_owner".to_string(),
+                expiry: None,
+            }),
+        )
+        .unwrap();
+
+        let proposal: Option<OwnershipProposal> =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::OwnershipProposal {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            proposal.unwrap().proposed_owner,
+            Addr::unchecked("new_owner")
+        );
+
+        // the ownership isn't transferred until accepted
+        let config: Config =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+        assert_eq!(config.owner, Addr::unchecked("owner"));
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("new_owner", &[]),
+            ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+        )
+        .unwrap();
+
+        let config: Config =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+        assert_eq!(config.owner, Addr::unchecked("new_owner"));
+    }
+}
@@ -0,0 +1,61 @@
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, QuerierWrapper, StdResult, WasmMsg};
+
+use white_whale_std::pool_network::incentive::{
+    PositionsResponse, RewardsResponse, VestingResponse,
+};
+
+/// Creates the message to register the given incentive contract as a hook on the epoch manager.
+pub fn add_epoch_hook_msg(
+    epoch_manager_addr: &Addr,
+    incentive_addr: &Addr,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: epoch_manager_addr.to_string(),
+        msg: to_json_binary(
+            &white_whale_std::epoch_manager::epoch_manager::ExecuteMsg::AddHook {
+                contract_addr: incentive_addr.to_string(),
+            },
+        )?,
+        funds: vec![],
+    }
+    .into())
+}
+
+/// Checks whether the given address has positions, rewards or vested rewards to claim on the given
+/// incentive contract.
+pub fn has_positions_or_rewards(
+    querier: &QuerierWrapper,
+    incentive_addr: &Addr,
+    address: &Addr,
+) -> StdResult<bool> {
+    let positions: PositionsResponse = querier.query_wasm_smart(
+        incentive_addr,
+        &white_whale_std::pool_network::incentive::QueryMsg::Positions {
+            address: address.to_string(),
+        },
+    )?;
+
+    if !positions.positions.is_empty() {
+        return Ok(true);
+    }
+
+    let rewards: RewardsResponse = querier.query_wasm_smart(
+        incentive_addr,
+        &white_whale_std::pool_network::incentive::QueryMsg::Rewards {
+            address: address.to_string(),
+        },
+    )?;
+
+    if !rewards.rewards.is_empty() {
+        return Ok(true);
+    }
+
+    let vesting: VestingResponse = querier.query_wasm_smart(
+        incentive_addr,
+        &white_whale_std::pool_network::incentive::QueryMsg::Vesting {
+            address: address.to_string(),
+        },
+    )?;
+
+    Ok(!vesting.claimable.is_empty())
+}
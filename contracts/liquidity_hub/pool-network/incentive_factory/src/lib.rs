@@ -3,6 +3,7 @@ pub mod error;
 pub mod state;
 
 mod execute;
+mod helpers;
 mod queries;
 mod reply;
 
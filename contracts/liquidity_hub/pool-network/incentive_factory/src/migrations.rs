@@ -1,2 +1,148 @@
-// currently a stub file
-// until migrations are needed in the future
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, DepsMut, StdError};
+use cw_storage_plus::Item;
+
+use white_whale_std::ownership::{self, Role};
+use white_whale_std::pool_network::asset::Asset;
+use white_whale_std::pool_network::incentive_factory::Config;
+
+use crate::state::CONFIG;
+
+/// The config as of v1.1.0, before the reserved flow slots were added.
+#[cw_serde]
+struct ConfigV110 {
+    pub owner: Addr,
+    pub fee_collector_addr: Addr,
+    pub fee_distributor_addr: Addr,
+    pub create_flow_fee: Asset,
+    pub max_concurrent_flows: u64,
+    pub incentive_code_id: u64,
+    pub max_flow_epoch_buffer: u64,
+    pub min_unbonding_duration: u64,
+    pub max_unbonding_duration: u64,
+    pub epoch_manager_addr: Option<Addr>,
+}
+
+const CONFIG_V110: Item<ConfigV110> = Item::new("config");
+
+/// Migrates the config to v1.1.0, which adds the epoch manager the incentive contracts are
+/// registered on as hooks. It's left unset, so it needs to be set via UpdateConfig.
+pub fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
+    #[cw_serde]
+    struct ConfigV102 {
+        pub owner: Addr,
+        pub fee_collector_addr: Addr,
+        pub fee_distributor_addr: Addr,
+        pub create_flow_fee: Asset,
+        pub max_concurrent_flows: u64,
+        pub incentive_code_id: u64,
+        pub max_flow_epoch_buffer: u64,
+        pub min_unbonding_duration: u64,
+        pub max_unbonding_duration: u64,
+    }
+
+    const CONFIG_V102: Item<ConfigV102> = Item::new("config");
+    let config_v102 = CONFIG_V102.load(deps.storage)?;
+
+    CONFIG_V110.save(
+        deps.storage,
+        &ConfigV110 {
+            owner: config_v102.owner,
+            fee_collector_addr: config_v102.fee_collector_addr,
+            fee_distributor_addr: config_v102.fee_distributor_addr,
+            create_flow_fee: config_v102.create_flow_fee,
+            max_concurrent_flows: config_v102.max_concurrent_flows,
+            incentive_code_id: config_v102.incentive_code_id,
+            max_flow_epoch_buffer: config_v102.max_flow_epoch_buffer,
+            min_unbonding_duration: config_v102.min_unbonding_duration,
+            max_unbonding_duration: config_v102.max_unbonding_duration,
+            epoch_manager_addr: None,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// The config as of v1.2.0, before the emergency exit penalty was added.
+#[cw_serde]
+struct ConfigV120 {
+    pub owner: Addr,
+    pub fee_collector_addr: Addr,
+    pub fee_distributor_addr: Addr,
+    pub create_flow_fee: Asset,
+    pub max_concurrent_flows: u64,
+    pub reserved_flow_slots: u64,
+    pub incentive_code_id: u64,
+    pub max_flow_epoch_buffer: u64,
+    pub min_unbonding_duration: u64,
+    pub max_unbonding_duration: u64,
+    pub epoch_manager_addr: Option<Addr>,
+}
+
+const CONFIG_V120: Item<ConfigV120> = Item::new("config");
+
+/// Migrates the config to v1.2.0, which adds the flow slots reserved for allowlisted flow creators.
+/// No slots are reserved, so it needs to be set via UpdateConfig.
+pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
+    let config_v110 = CONFIG_V110.load(deps.storage)?;
+
+    CONFIG_V120.save(
+        deps.storage,
+        &ConfigV120 {
+            owner: config_v110.owner,
+            fee_collector_addr: config_v110.fee_collector_addr,
+            fee_distributor_addr: config_v110.fee_distributor_addr,
+            create_flow_fee: config_v110.create_flow_fee,
+            max_concurrent_flows: config_v110.max_concurrent_flows,
+            reserved_flow_slots: 0,
+            incentive_code_id: config_v110.incentive_code_id,
+            max_flow_epoch_buffer: config_v110.max_flow_epoch_buffer,
+            min_unbonding_duration: config_v110.min_unbonding_duration,
+            max_unbonding_duration: config_v110.max_unbonding_duration,
+            epoch_manager_addr: config_v110.epoch_manager_addr,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Migrates the config to v1.3.0, which adds the penalty for exiting positions before they finish
+/// unbonding. No penalty is taken, so it needs to be set via UpdateConfig.
+pub fn migrate_to_v130(deps: DepsMut) -> Result<(), StdError> {
+    let config_v120 = CONFIG_V120.load(deps.storage)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: config_v120.owner,
+            fee_collector_addr: config_v120.fee_collector_addr,
+            fee_distributor_addr: config_v120.fee_distributor_addr,
+            create_flow_fee: config_v120.create_flow_fee,
+            max_concurrent_flows: config_v120.max_concurrent_flows,
+            reserved_flow_slots: config_v120.reserved_flow_slots,
+            incentive_code_id: config_v120.incentive_code_id,
+            max_flow_epoch_buffer: config_v120.max_flow_epoch_buffer,
+            min_unbonding_duration: config_v120.min_unbonding_duration,
+            max_unbonding_duration: config_v120.max_unbonding_duration,
+            emergency_exit_penalty: Decimal::zero(),
+            epoch_manager_addr: config_v120.epoch_manager_addr,
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Migrates the guardian, which could pause features across the incentive contracts, to the pauser
+/// role that replaced it in v1.5.0.
+pub fn migrate_to_v150(mut deps: DepsMut) -> Result<(), StdError> {
+    const GUARDIAN: Item<Addr> = Item::new("guardian");
+
+    if let Some(guardian) = GUARDIAN.may_load(deps.storage)? {
+        ownership::update_role(deps.branch(), Role::Pauser, Some(guardian.into_string()))?;
+        GUARDIAN.remove(deps.storage);
+    }
+
+    Ok(())
+}
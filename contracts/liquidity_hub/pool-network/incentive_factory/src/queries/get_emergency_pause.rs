@@ -0,0 +1,13 @@
+use cosmwasm_std::{Deps, StdResult};
+use white_whale_std::ownership::query_roles;
+use white_whale_std::pause::EmergencyPauseResponse;
+
+use crate::state::PAUSED_FEATURES;
+
+/// Retrieves the pauser and the features paused across all the incentive contracts.
+pub fn get_emergency_pause(deps: Deps) -> StdResult<EmergencyPauseResponse> {
+    Ok(EmergencyPauseResponse {
+        pauser: query_roles(deps.storage)?.pauser,
+        paused_features: PAUSED_FEATURES.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
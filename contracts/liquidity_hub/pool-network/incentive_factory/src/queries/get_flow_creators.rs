@@ -0,0 +1,48 @@
+use cosmwasm_std::{Decimal, Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use white_whale_std::pool_network::incentive_factory::{FlowCreatorResponse, FlowCreatorsResponse};
+
+use crate::state::FLOW_CREATORS;
+
+/// Queries whether the given address is an allowlisted flow creator, and its fee discount.
+pub fn get_flow_creator(deps: Deps, address: String) -> StdResult<FlowCreatorResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let fee_discount = FLOW_CREATORS.may_load(deps.storage, &address)?;
+
+    Ok(FlowCreatorResponse {
+        address,
+        allowlisted: fee_discount.is_some(),
+        fee_discount: fee_discount.unwrap_or_else(Decimal::zero),
+    })
+}
+
+// settings for pagination
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Queries the allowlisted flow creators.
+pub fn get_flow_creators(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<FlowCreatorsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    FLOW_CREATORS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (address, fee_discount) = item?;
+
+            Ok(FlowCreatorResponse {
+                address,
+                allowlisted: true,
+                fee_discount,
+            })
+        })
+        .collect()
+}
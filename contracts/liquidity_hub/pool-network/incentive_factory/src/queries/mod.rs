@@ -1,7 +1,11 @@
 mod get_config;
+mod get_emergency_pause;
+mod get_flow_creators;
 mod get_incentive;
 mod get_incentives;
 
 pub use get_config::get_config;
+pub use get_emergency_pause::get_emergency_pause;
+pub use get_flow_creators::{get_flow_creator, get_flow_creators};
 pub use get_incentive::get_incentive;
 pub use get_incentives::get_incentives;
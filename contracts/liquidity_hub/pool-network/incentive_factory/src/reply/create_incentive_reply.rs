@@ -1,6 +1,8 @@
 use cosmwasm_std::{from_json, DepsMut, Reply, Response};
 use protobuf::Message;
 
+use crate::helpers::add_epoch_hook_msg;
+use crate::state::CONFIG;
 use crate::{
     error::ContractError, response::MsgInstantiateContractResponse, state::INCENTIVE_MAPPINGS,
 };
@@ -10,7 +12,8 @@ pub const CREATE_INCENTIVE_REPLY_ID: u64 = 1;
 
 /// Triggered after a new incentive contract is created.
 ///
-/// Triggered to allow us to register the new contract in state.
+/// Triggered to allow us to register the new contract in state, and as a hook on the epoch manager
+/// if there's one set so the global weight snapshots are taken automatically.
 pub fn create_incentive_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
     let res: MsgInstantiateContractResponse = Message::parse_from_bytes(
         msg.result
@@ -37,9 +40,17 @@ pub fn create_incentive_reply(deps: DepsMut, msg: Reply) -> Result<Response, Con
         &incentive_address,
     )?;
 
-    Ok(Response::default().add_attributes(vec![
-        ("action", "create_incentive_reply".to_string()),
-        ("incentive_address", incentive_address.to_string()),
-        ("lp_asset", incentive_data.lp_asset.to_string()),
-    ]))
+    let messages = CONFIG
+        .may_load(deps.storage)?
+        .and_then(|config| config.epoch_manager_addr)
+        .map(|epoch_manager_addr| add_epoch_hook_msg(&epoch_manager_addr, &incentive_address))
+        .transpose()?;
+
+    Ok(Response::default()
+        .add_messages(messages)
+        .add_attributes(vec![
+            ("action", "create_incentive_reply".to_string()),
+            ("incentive_address", incentive_address.to_string()),
+            ("lp_asset", incentive_data.lp_asset.to_string()),
+        ]))
 }
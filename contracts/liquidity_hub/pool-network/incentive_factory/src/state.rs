@@ -1,8 +1,15 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal};
 use cw_storage_plus::{Item, Map};
+use white_whale_std::pause::PausedFeatures;
 use white_whale_std::pool_network::incentive_factory::Config;
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
 /// Maps the address of the LP token to the incentive contract address
 pub const INCENTIVE_MAPPINGS: Map<&[u8], Addr> = Map::new("incentive_mappings");
+
+/// Maps the allowlisted flow creators to the discount they get on the flow creation fee
+pub const FLOW_CREATORS: Map<&Addr, Decimal> = Map::new("flow_creators");
+
+/// The features paused across all the incentive contracts. Nothing is paused if not set.
+pub const PAUSED_FEATURES: Item<PausedFeatures> = Item::new("paused_features");
@@ -1 +1,26 @@
+use cosmwasm_std::{Decimal, Uint128};
 
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::pool_network::incentive_factory::InstantiateMsg;
+
+/// The instantiate message used across the unit tests.
+pub(crate) fn mock_instantiate_msg() -> InstantiateMsg {
+    InstantiateMsg {
+        fee_collector_addr: "fee_collector_addr".to_string(),
+        fee_distributor_addr: "fee_distributor_addr".to_string(),
+        create_flow_fee: Asset {
+            info: AssetInfo::NativeToken {
+                denom: "native-fee-token".to_string(),
+            },
+            amount: Uint128::one(),
+        },
+        max_concurrent_flows: 1u64,
+        reserved_flow_slots: 0u64,
+        incentive_code_id: 123,
+        max_flow_epoch_buffer: 3600u64,
+        min_unbonding_duration: 86400u64,
+        max_unbonding_duration: 259200u64,
+        emergency_exit_penalty: Decimal::zero(),
+        epoch_manager_addr: None,
+    }
+}
@@ -7,6 +7,7 @@ use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use crate::contract::{MAX_AMP, MAX_AMP_CHANGE, MIN_AMP, MIN_RAMP_BLOCKS};
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use cosmwasm_std::coins;
+use white_whale_std::ownership::{self, OwnershipAction};
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use white_whale_std::pool_network::asset::is_factory_token;
 use white_whale_std::pool_network::asset::{
@@ -38,7 +39,8 @@ pub fn receive_cw20(
     cw20_msg: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
     let contract_addr = info.sender.clone();
-    let feature_toggle: FeatureToggle = CONFIG.load(deps.storage)?.feature_toggle;
+    let feature_toggle: FeatureToggle =
+        helpers::get_feature_toggle(&deps.querier, &CONFIG.load(deps.storage)?)?;
 
     match from_json(&cw20_msg.msg) {
         Ok(Cw20HookMsg::Swap {
@@ -128,7 +130,7 @@ pub fn provide_liquidity(
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     // check if the deposit feature is enabled
-    if !config.feature_toggle.deposits_enabled {
+    if !helpers::get_feature_toggle(&deps.querier, &config)?.deposits_enabled {
         return Err(ContractError::OperationDisabled(
             "provide_liquidity".to_string(),
         ));
@@ -540,7 +542,6 @@ pub fn update_config(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    owner: Option<String>,
     fee_collector_addr: Option<String>,
     pool_fees: Option<PoolFee>,
     feature_toggle: Option<FeatureToggle>,
@@ -551,12 +552,6 @@ pub fn update_config(
         return Err(ContractError::Std(StdError::generic_err("unauthorized")));
     }
 
-    if let Some(owner) = owner {
-        // validate address format
-        let _ = deps.api.addr_validate(&owner)?;
-        config.owner = deps.api.addr_validate(&owner)?;
-    }
-
     if let Some(pool_fees) = pool_fees {
         pool_fees.is_valid()?;
         config.pool_fees = pool_fees;
@@ -614,6 +609,26 @@ pub fn update_config(
     Ok(Response::new().add_attribute("action", "update_config"))
 }
 
+/// Proposes, accepts or cancels a transfer of the ownership of the pool. Once the ownership is
+/// accepted, the new owner is stored in the [Config].
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
+
 /// Collects all protocol fees accrued by the pool
 pub fn collect_protocol_fees(deps: DepsMut) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
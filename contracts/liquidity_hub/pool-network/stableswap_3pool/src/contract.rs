@@ -7,6 +7,7 @@ use cw2::{get_contract_version, set_contract_version};
 use protobuf::Message;
 use semver::Version;
 
+use white_whale_std::ownership::query_ownership_proposal;
 use white_whale_std::pool_network::asset::{AssetInfoRaw, TrioInfoRaw};
 use white_whale_std::pool_network::trio::{
     Config, ExecuteMsg, FeatureToggle, InstantiateMsg, MigrateMsg, QueryMsg,
@@ -141,6 +142,15 @@ pub fn execute(
             receiver,
         } => commands::provide_liquidity(deps, env, info, assets, slippage_tolerance, receiver),
         ExecuteMsg::WithdrawLiquidity {} => {
+            // check if the withdrawal feature is enabled
+            let feature_toggle: FeatureToggle =
+                helpers::get_feature_toggle(&deps.querier, &CONFIG.load(deps.storage)?)?;
+            if !feature_toggle.withdrawals_enabled {
+                return Err(ContractError::OperationDisabled(
+                    "withdraw_liquidity".to_string(),
+                ));
+            }
+
             // validate that the asset sent is the token factory LP token
             let trio_info = TRIO_INFO.load(deps.storage)?;
             let lp_token_denom = match trio_info.liquidity_token {
@@ -162,7 +172,8 @@ pub fn execute(
             to,
         } => {
             // check if the swap feature is enabled
-            let feature_toggle: FeatureToggle = CONFIG.load(deps.storage)?.feature_toggle;
+            let feature_toggle: FeatureToggle =
+                helpers::get_feature_toggle(&deps.querier, &CONFIG.load(deps.storage)?)?;
             if !feature_toggle.swaps_enabled {
                 return Err(ContractError::OperationDisabled("swap".to_string()));
             }
@@ -190,7 +201,6 @@ pub fn execute(
             )
         }
         ExecuteMsg::UpdateConfig {
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -199,7 +209,6 @@ pub fn execute(
             deps,
             env,
             info,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -207,6 +216,7 @@ pub fn execute(
         ),
 
         ExecuteMsg::CollectProtocolFees {} => commands::collect_protocol_fees(deps),
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
     }
 }
 
@@ -269,6 +279,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractErro
             ALL_TIME_BURNED_FEES,
             None,
         )?)?),
+        QueryMsg::OwnershipProposal {} => {
+            Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+        }
     }
 }
 
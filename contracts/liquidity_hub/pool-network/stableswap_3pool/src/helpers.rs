@@ -1,13 +1,14 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, Decimal, Decimal256, Deps, DepsMut, Env, ReplyOn, Response, StdError,
-    StdResult, Storage, SubMsg, Uint128, Uint256, WasmMsg,
+    to_json_binary, Decimal, Decimal256, Deps, DepsMut, Env, QuerierWrapper, ReplyOn, Response,
+    StdError, StdResult, Storage, SubMsg, Uint128, Uint256, WasmMsg,
 };
 use cw20::MinterResponse;
 use cw_storage_plus::Item;
 
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use cosmwasm_std::CosmosMsg;
+use white_whale_std::pause::query_paused_features;
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use white_whale_std::pool_network::asset::is_factory_token;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, AssetInfoRaw};
@@ -17,7 +18,7 @@ use white_whale_std::pool_network::denom_injective::MsgCreateDenom;
 use white_whale_std::pool_network::denom_osmosis::MsgCreateDenom;
 use white_whale_std::pool_network::querier::query_token_info;
 use white_whale_std::pool_network::token::InstantiateMsg as TokenInstantiateMsg;
-use white_whale_std::pool_network::trio::{InstantiateMsg, PoolFee};
+use white_whale_std::pool_network::trio::{Config, FeatureToggle, InstantiateMsg, PoolFee};
 
 use crate::contract::INSTANTIATE_REPLY_ID;
 use crate::error::ContractError;
@@ -341,3 +342,16 @@ pub fn create_lp_token(
         }))
     }
 }
+
+/// Gets the [FeatureToggle] of the trio, with the features paused factory-wide by the owner of the
+/// trio, i.e. the pool factory, disabled.
+pub fn get_feature_toggle(querier: &QuerierWrapper, config: &Config) -> StdResult<FeatureToggle> {
+    let paused_features = query_paused_features(querier, config.owner.clone())?;
+
+    Ok(FeatureToggle {
+        withdrawals_enabled: config.feature_toggle.withdrawals_enabled
+            && !paused_features.withdrawals,
+        deposits_enabled: config.feature_toggle.deposits_enabled && !paused_features.deposits,
+        swaps_enabled: config.feature_toggle.swaps_enabled && !paused_features.swaps,
+    })
+}
@@ -65,7 +65,6 @@ fn test_feature_toggle_swap_disabled() {
 
     // all features are enabled by default, let's disable swaps
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: Some(FeatureToggle {
@@ -186,7 +185,6 @@ fn test_feature_toggle_withdrawals_disabled() {
 
     // all features are enabled by default, let's disable withdrawals
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: Some(FeatureToggle {
@@ -274,7 +272,6 @@ fn test_feature_toggle_deposits_disabled() {
 
     // all features are enabled by default, let's disable deposits
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: Some(FeatureToggle {
@@ -10,13 +10,14 @@ use crate::state::LP_SYMBOL;
 #[cfg(feature = "osmosis_token_factory")]
 use cosmwasm_std::CosmosMsg;
 use white_whale_std::fee::Fee;
+use white_whale_std::ownership::OwnershipAction;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, TrioInfo};
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::MsgCreateDenom;
 use white_whale_std::pool_network::mock_querier::mock_dependencies;
 use white_whale_std::pool_network::swap::assert_max_spread;
 use white_whale_std::pool_network::token::InstantiateMsg as TokenInstantiateMsg;
-use white_whale_std::pool_network::trio::ExecuteMsg::UpdateConfig;
+use white_whale_std::pool_network::trio::ExecuteMsg::{UpdateConfig, UpdateOwnership};
 use white_whale_std::pool_network::trio::{Config, InstantiateMsg, MigrateMsg, PoolFee, QueryMsg};
 
 use crate::contract::{execute, instantiate, migrate, query, reply};
@@ -539,7 +540,6 @@ fn test_update_config_unsuccessful() {
 
     // update config with invalid fees
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: Some(PoolFee {
             protocol_fee: Fee {
@@ -566,7 +566,6 @@ fn test_update_config_unsuccessful() {
     // an unauthorized party tries to update the config
     let info = mock_info("unauthorized", &[]);
     let update_config_message = UpdateConfig {
-        owner: Some("unauthorized".to_string()),
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: None,
@@ -639,7 +638,6 @@ fn test_update_config_successful() {
     assert_eq!(config.pool_fees.swap_fee.share, Decimal::zero());
 
     let update_config_message = UpdateConfig {
-        owner: Some("new_admin".to_string()),
         fee_collector_addr: Some("new_collector".to_string()),
         pool_fees: Some(PoolFee {
             protocol_fee: Fee {
@@ -662,11 +660,91 @@ fn test_update_config_successful() {
         from_json(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
 
     // check for new config
-    assert_eq!(config.owner, Addr::unchecked("new_admin"));
+    assert_eq!(config.owner, Addr::unchecked("addr0000"));
     assert_eq!(config.fee_collector_addr, Addr::unchecked("new_collector"));
     assert_eq!(config.pool_fees.swap_fee.share, Decimal::percent(3u64));
 }
 
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn update_ownership_in_two_steps() {
+    let mut deps = mock_dependencies(&[]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+        (&"asset0000".to_string(), &[]),
+        (&"asset0001".to_string(), &[]),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0001".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8, 10u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        amp_factor: 1000,
+        token_factory_lp: false,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        UpdateOwnership(OwnershipAction::ProposeNewOwner {
+            new_owner: "new_admin".to_string(),
+            expiry: Some(100),
+        }),
+    )
+    .unwrap();
+
+    // the proposal can't be accepted once expired
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("new_admin", &[]),
+        UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+    )
+    .unwrap_err();
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_admin", &[]),
+        UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+    )
+    .unwrap();
+
+    let config: Config =
+        from_json(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.owner, Addr::unchecked("new_admin"));
+}
+
 #[test]
 fn test_assert_slippage_tolerance_invalid_ratio() {
     let res = assert_slippage_tolerance(
@@ -1,12 +1,17 @@
 use cosmwasm_std::{
-    to_json_binary, wasm_execute, CosmosMsg, DepsMut, Env, MessageInfo, ReplyOn, Response, SubMsg,
-    WasmMsg,
+    to_json_binary, wasm_execute, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo, Order,
+    QuerierWrapper, ReplyOn, Response, StdResult, SubMsg, WasmMsg,
 };
+use cw_storage_plus::Bound;
 
+use white_whale_std::ownership::{self, OwnershipAction};
+use white_whale_std::pause::PausedFeatures;
 use white_whale_std::pool_network;
 use white_whale_std::pool_network::asset::{AssetInfo, PairType};
+use white_whale_std::pool_network::factory::{PairCreationConfig, PairVariant};
 use white_whale_std::pool_network::pair::{
-    FeatureToggle, InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
+    CircuitBreaker, FeatureToggle, InstantiateMsg as PairInstantiateMsg,
+    MigrateMsg as PairMigrateMsg, PoolFee,
 };
 use white_whale_std::pool_network::trio::{
     FeatureToggle as TrioFeatureToggle, InstantiateMsg as TrioInstantiateMsg,
@@ -17,14 +22,15 @@ use white_whale_std::pool_network::{pair, trio};
 use crate::contract::{CREATE_PAIR_RESPONSE, CREATE_TRIO_RESPONSE};
 use crate::error::ContractError;
 use crate::state::{
-    add_allow_native_token, pair_key, trio_key, Config, TmpPairInfo, TmpTrioInfo, CONFIG, PAIRS,
+    add_allow_native_token, pair_key, read_pair_creation_config, read_paused_features, trio_key,
+    Config, PairVariantInfoRaw, TmpPairInfo, TmpTrioInfo, CONFIG, DEFAULT_LIMIT,
+    LEGACY_PAIRS_CURSOR, MAX_LIMIT, PAIRS, PAIR_CREATION_CONFIG, PAIR_VARIANTS, PAUSED_FEATURES,
     TMP_PAIR_INFO, TMP_TRIO_INFO, TRIOS,
 };
 
 /// Updates the contract's [Config]
 pub fn update_config(
     deps: DepsMut,
-    owner: Option<String>,
     fee_collector_addr: Option<String>,
     token_code_id: Option<u64>,
     pair_code_id: Option<u64>,
@@ -32,13 +38,6 @@ pub fn update_config(
 ) -> Result<Response, ContractError> {
     let mut config: Config = CONFIG.load(deps.storage)?;
 
-    if let Some(owner) = owner {
-        // validate address format
-        let _ = deps.api.addr_validate(&owner)?;
-
-        config.owner = deps.api.addr_canonicalize(&owner)?;
-    }
-
     if let Some(token_code_id) = token_code_id {
         config.token_code_id = token_code_id;
     }
@@ -65,7 +64,6 @@ pub fn update_config(
 pub fn update_pair_config(
     deps: DepsMut,
     pair_addr: String,
-    owner: Option<String>,
     fee_collector_addr: Option<String>,
     pool_fees: Option<PoolFee>,
     feature_toggle: Option<FeatureToggle>,
@@ -73,7 +71,6 @@ pub fn update_pair_config(
 ) -> Result<Response, ContractError> {
     #[cfg(not(feature = "osmosis"))]
     let msg = pool_network::pair::ExecuteMsg::UpdateConfig {
-        owner,
         fee_collector_addr,
         pool_fees,
         feature_toggle,
@@ -81,7 +78,6 @@ pub fn update_pair_config(
 
     #[cfg(feature = "osmosis")]
     let msg = pool_network::pair::ExecuteMsg::UpdateConfig {
-        owner,
         fee_collector_addr,
         pool_fees,
         feature_toggle,
@@ -99,7 +95,7 @@ pub fn update_pair_config(
 
 /// Creates a Pair
 pub fn create_pair(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     asset_infos: [AssetInfo; 2],
@@ -113,25 +109,33 @@ pub fn create_pair(
         return Err(ContractError::SameAsset {});
     }
 
-    let asset_1_decimal =
-        match asset_infos[0].query_decimals(env.contract.address.clone(), &deps.querier) {
-            Ok(decimal) => decimal,
-            Err(_) => {
-                return Err(ContractError::InvalidAsset {
-                    asset: asset_infos[0].to_string(),
-                });
-            }
-        };
+    // a legacy pair that is not registered yet could be created again as the same variant
+    if LEGACY_PAIRS_CURSOR.exists(deps.storage) {
+        return Err(ContractError::LegacyPairsNotRegistered {});
+    }
 
-    let asset_2_decimal =
-        match asset_infos[1].query_decimals(env.contract.address.clone(), &deps.querier) {
-            Ok(decimal) => decimal,
-            Err(_) => {
-                return Err(ContractError::InvalidAsset {
-                    asset: asset_infos[1].to_string(),
-                });
-            }
-        };
+    // pairs created by anyone other than the owner must use an approved fee tier and pay the
+    // listing fee, which is sent to the fee collector
+    let mut funds = info.funds;
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner {
+        let pair_creation_config = read_pair_creation_config(deps.storage)?;
+
+        if !pair_creation_config.fee_tiers.contains(&pool_fees) {
+            return Err(ContractError::InvalidFeeTier {});
+        }
+
+        if let Some(listing_fee) = pair_creation_config.listing_fee {
+            funds = deduct_listing_fee(funds, &listing_fee)?;
+            messages.push(CosmosMsg::Bank(BankMsg::Send {
+                to_address: config.fee_collector_addr.to_string(),
+                amount: vec![listing_fee],
+            }));
+        }
+    }
+
+    let asset_1_decimal = query_asset_decimals(&mut deps, &env, &asset_infos[0])?;
+    let asset_2_decimal = query_asset_decimals(&mut deps, &env, &asset_infos[1])?;
 
     let raw_infos = [
         asset_infos[0].to_raw(deps.api)?,
@@ -140,8 +144,10 @@ pub fn create_pair(
 
     let asset_decimals = [asset_1_decimal, asset_2_decimal];
 
+    // several pairs can exist for the same assets, as long as they are different variants
     let pair_key = pair_key(&raw_infos);
-    if let Ok(Some(_)) = PAIRS.may_load(deps.storage, &pair_key) {
+    let pair_variant = PairVariant::new(pair_type.clone(), &pool_fees)?;
+    if PAIR_VARIANTS.has(deps.storage, (&pair_key, &pair_variant.key())) {
         return Err(ContractError::ExistingPair {});
     }
 
@@ -152,6 +158,7 @@ pub fn create_pair(
             asset_infos: raw_infos,
             asset_decimals,
             pair_type: pair_type.clone(),
+            fee_tier: pair_variant.fee_tier,
         },
     )?;
 
@@ -167,12 +174,13 @@ pub fn create_pair(
             ("pair_label", pair_label.as_str()),
             ("pair_type", pair_type.get_label()),
         ])
+        .add_messages(messages)
         .add_submessage(SubMsg {
             id: CREATE_PAIR_RESPONSE,
             gas_limit: None,
             msg: CosmosMsg::Wasm(WasmMsg::Instantiate {
                 code_id: config.pair_code_id,
-                funds: info.funds,
+                funds,
                 admin: Some(env.contract.address.to_string()),
                 label: pair_label,
                 msg: to_json_binary(&PairInstantiateMsg {
@@ -189,12 +197,187 @@ pub fn create_pair(
         }))
 }
 
+/// Queries the decimals of the given asset. If the decimals of a native token haven't been added to
+/// the factory, they are fetched from the bank's denom metadata and stored.
+fn query_asset_decimals(
+    deps: &mut DepsMut,
+    env: &Env,
+    asset_info: &AssetInfo,
+) -> Result<u8, ContractError> {
+    if let Ok(decimals) = asset_info.query_decimals(env.contract.address.clone(), &deps.querier) {
+        return Ok(decimals);
+    }
+
+    if let AssetInfo::NativeToken { denom } = asset_info {
+        if let Some(decimals) = query_denom_metadata_decimals(&deps.querier, denom) {
+            add_allow_native_token(deps.storage, denom.to_string(), decimals)?;
+            return Ok(decimals);
+        }
+    }
+
+    Err(ContractError::InvalidAsset {
+        asset: asset_info.to_string(),
+    })
+}
+
+/// Gets the decimals of a native token from the exponent of its display unit in the denom metadata
+fn query_denom_metadata_decimals(querier: &QuerierWrapper, denom: &str) -> Option<u8> {
+    let metadata = querier.query_denom_metadata(denom).ok()?;
+
+    metadata
+        .denom_units
+        .iter()
+        .find(|unit| !metadata.display.is_empty() && unit.denom == metadata.display)
+        .and_then(|unit| u8::try_from(unit.exponent).ok())
+}
+
+/// Deducts the listing fee from the funds sent to create a pair, returning the remaining funds
+fn deduct_listing_fee(funds: Vec<Coin>, listing_fee: &Coin) -> Result<Vec<Coin>, ContractError> {
+    let paid = funds
+        .iter()
+        .find(|coin| coin.denom == listing_fee.denom)
+        .map(|coin| coin.amount)
+        .unwrap_or_default();
+
+    if paid < listing_fee.amount {
+        return Err(ContractError::ListingFeeNotPaid {
+            listing_fee: listing_fee.to_string(),
+        });
+    }
+
+    Ok(funds
+        .into_iter()
+        .filter_map(|mut coin| {
+            if coin.denom == listing_fee.denom {
+                coin.amount -= listing_fee.amount;
+            }
+
+            (!coin.amount.is_zero()).then_some(coin)
+        })
+        .collect())
+}
+
+/// Updates the [PairCreationConfig]
+pub fn update_pair_creation_config(
+    deps: DepsMut,
+    pair_creation_config: PairCreationConfig,
+) -> Result<Response, ContractError> {
+    if let Some(listing_fee) = &pair_creation_config.listing_fee {
+        if listing_fee.amount.is_zero() {
+            return Err(ContractError::InvalidListingFee {});
+        }
+    }
+
+    for fee_tier in &pair_creation_config.fee_tiers {
+        fee_tier.is_valid()?;
+    }
+
+    PAIR_CREATION_CONFIG.save(deps.storage, &pair_creation_config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_pair_creation_config"),
+        (
+            "permissionless",
+            &pair_creation_config.permissionless.to_string(),
+        ),
+    ]))
+}
+
+/// Updates the circuit breaker of a pair
+pub fn update_pair_circuit_breaker(
+    deps: DepsMut,
+    pair_addr: String,
+    circuit_breaker: Option<CircuitBreaker>,
+) -> Result<Response, ContractError> {
+    Ok(Response::default()
+        .add_message(wasm_execute(
+            deps.api.addr_validate(&pair_addr)?.to_string(),
+            &pool_network::pair::ExecuteMsg::UpdateCircuitBreaker { circuit_breaker },
+            vec![],
+        )?)
+        .add_attribute("action", "update_pair_circuit_breaker"))
+}
+
+/// Proposes, accepts or cancels a transfer of the ownership of the factory
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+    let owner = deps.api.addr_humanize(&config.owner)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = deps.api.addr_canonicalize(new_owner.as_str())?;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
+
+/// Proposes or cancels a transfer of the ownership of a pair
+pub fn update_pair_ownership(
+    deps: DepsMut,
+    pair_addr: String,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    Ok(Response::default()
+        .add_message(wasm_execute(
+            deps.api.addr_validate(&pair_addr)?.to_string(),
+            &pool_network::pair::ExecuteMsg::UpdateOwnership(action),
+            vec![],
+        )?)
+        .add_attribute("action", "update_pair_ownership"))
+}
+
+/// Proposes or cancels a transfer of the ownership of a trio
+pub fn update_trio_ownership(
+    deps: DepsMut,
+    trio_addr: String,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    Ok(Response::default()
+        .add_message(wasm_execute(
+            deps.api.addr_validate(&trio_addr)?.to_string(),
+            &pool_network::trio::ExecuteMsg::UpdateOwnership(action),
+            vec![],
+        )?)
+        .add_attribute("action", "update_trio_ownership"))
+}
+
+/// Pauses features across all the pairs and trios. The pauser can only pause features, while
+/// the owner can also unpause them.
+pub fn emergency_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    paused_features: PausedFeatures,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner
+        && paused_features.unpauses(&read_paused_features(deps.storage)?)
+    {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    PAUSED_FEATURES.save(deps.storage, &paused_features)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "emergency_pause".to_string()),
+        ("swaps", paused_features.swaps.to_string()),
+        ("deposits", paused_features.deposits.to_string()),
+        ("withdrawals", paused_features.withdrawals.to_string()),
+        ("flash_loans", paused_features.flash_loans.to_string()),
+    ]))
+}
+
 #[allow(clippy::too_many_arguments)]
 /// Updates a trio config
 pub fn update_trio_config(
     deps: DepsMut,
     trio_addr: String,
-    owner: Option<String>,
     fee_collector_addr: Option<String>,
     pool_fees: Option<TrioPoolFee>,
     feature_toggle: Option<TrioFeatureToggle>,
@@ -204,7 +387,6 @@ pub fn update_trio_config(
         .add_message(wasm_execute(
             deps.api.addr_validate(&trio_addr)?.to_string(),
             &pool_network::trio::ExecuteMsg::UpdateConfig {
-                owner,
                 fee_collector_addr,
                 pool_fees,
                 feature_toggle,
@@ -326,6 +508,7 @@ pub fn remove_pair(
     deps: DepsMut,
     _env: Env,
     asset_infos: [AssetInfo; 2],
+    pair_variant: Option<PairVariant>,
 ) -> Result<Response, ContractError> {
     let raw_infos = [
         asset_infos[0].to_raw(deps.api)?,
@@ -333,13 +516,42 @@ pub fn remove_pair(
     ];
 
     let pair_key = pair_key(&raw_infos);
-    let pair = PAIRS.may_load(deps.storage, &pair_key)?;
+    let pair = match pair_variant {
+        Some(pair_variant) => PAIR_VARIANTS
+            .may_load(deps.storage, (&pair_key, &pair_variant.key()))?
+            .map(|pair_variant_info| pair_variant_info.pair_info),
+        None => PAIRS.may_load(deps.storage, &pair_key)?,
+    };
 
     let Some(pair) = pair else {
         return Err(ContractError::UnExistingPair {});
     };
 
-    PAIRS.remove(deps.storage, &pair_key);
+    let pair_variants = PAIR_VARIANTS
+        .prefix(&pair_key)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, PairVariantInfoRaw)>>>()?;
+
+    let mut remaining_pair_variants = vec![];
+    for (pair_variant_key, pair_variant_info) in pair_variants {
+        if pair_variant_info.pair_info.contract_addr == pair.contract_addr {
+            PAIR_VARIANTS.remove(deps.storage, (&pair_key, &pair_variant_key));
+        } else {
+            remaining_pair_variants.push(pair_variant_info);
+        }
+    }
+
+    // if the default pair is removed, the next variant for the assets becomes the default one
+    if let Some(default_pair) = PAIRS.may_load(deps.storage, &pair_key)? {
+        if default_pair.contract_addr == pair.contract_addr {
+            match remaining_pair_variants.into_iter().next() {
+                Some(pair_variant_info) => {
+                    PAIRS.save(deps.storage, &pair_key, &pair_variant_info.pair_info)?
+                }
+                None => PAIRS.remove(deps.storage, &pair_key),
+            }
+        }
+    }
 
     Ok(Response::new().add_attributes(vec![
         ("action", "remove_pair"),
@@ -379,6 +591,53 @@ pub fn remove_trio(
     ]))
 }
 
+/// Registers the next batch of pairs created before pair variants were introduced as the variant
+/// matching their pair type and current pool fees.
+pub fn register_legacy_pairs(deps: DepsMut, limit: Option<u32>) -> Result<Response, ContractError> {
+    let Some(cursor) = LEGACY_PAIRS_CURSOR.may_load(deps.storage)? else {
+        return Err(ContractError::NoLegacyPairsToRegister {});
+    };
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = (!cursor.is_empty()).then(|| Bound::exclusive(cursor.as_slice()));
+    let pairs = PAIRS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (pair_key, pair_info) in &pairs {
+        let config: pair::ConfigResponse = deps.querier.query_wasm_smart(
+            deps.api.addr_humanize(&pair_info.contract_addr)?,
+            &pair::QueryMsg::Config {},
+        )?;
+
+        let pair_variant = PairVariant::new(pair_info.pair_type.clone(), &config.pool_fees)?;
+        PAIR_VARIANTS.save(
+            deps.storage,
+            (pair_key, &pair_variant.key()),
+            &PairVariantInfoRaw {
+                pair_variant,
+                pair_info: pair_info.clone(),
+            },
+        )?;
+    }
+
+    // all the legacy pairs are registered once a batch comes short
+    let completed = pairs.len() < limit;
+    match pairs.last() {
+        Some((last_pair_key, _)) if !completed => {
+            LEGACY_PAIRS_CURSOR.save(deps.storage, last_pair_key)?
+        }
+        _ => LEGACY_PAIRS_CURSOR.remove(deps.storage),
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "register_legacy_pairs".to_string()),
+        ("registered_pairs", pairs.len().to_string()),
+        ("completed", completed.to_string()),
+    ]))
+}
+
 /// Adds native/ibc token with decimals to the factory's whitelist so it can create pairs with that asset
 pub fn add_native_token_decimals(
     deps: DepsMut,
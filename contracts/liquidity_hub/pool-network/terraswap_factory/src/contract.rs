@@ -7,8 +7,13 @@ use cw2::{get_contract_version, set_contract_version};
 use protobuf::Message;
 
 use semver::Version;
+use white_whale_std::ownership::{
+    has_role, query_ownership_proposal, query_roles, update_role, Role,
+};
 use white_whale_std::pool_network::asset::{PairInfoRaw, TrioInfoRaw};
-use white_whale_std::pool_network::factory::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use white_whale_std::pool_network::factory::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, PairVariant, QueryMsg,
+};
 use white_whale_std::pool_network::querier::{
     query_pair_info_from_pair, query_trio_info_from_trio,
 };
@@ -16,7 +21,10 @@ use white_whale_std::pool_network::querier::{
 use crate::error::ContractError;
 use crate::error::ContractError::MigrateInvalidVersion;
 use crate::response::MsgInstantiateContractResponse;
-use crate::state::{Config, CONFIG, PAIRS, TMP_PAIR_INFO, TMP_TRIO_INFO, TRIOS};
+use crate::state::{
+    read_pair_creation_config, Config, PairVariantInfoRaw, CONFIG, PAIRS, PAIR_VARIANTS,
+    TMP_PAIR_INFO, TMP_TRIO_INFO, TRIOS,
+};
 use crate::{commands, queries};
 
 // version info for migration info
@@ -55,22 +63,59 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    // Only the owner can execute messages on the factory
+    // Only the owner can execute messages on the factory, except for the ones granted to the admin
+    // roles, creating pairs when the factory is in permissionless mode and the ownership transfer,
+    // which is checked when handled
     let config: Config = CONFIG.load(deps.storage)?;
-    if deps.api.addr_canonicalize(info.sender.as_str())? != config.owner {
+    let authorized = deps.api.addr_canonicalize(info.sender.as_str())? == config.owner
+        || match &msg {
+            ExecuteMsg::CreatePair { .. } => {
+                read_pair_creation_config(deps.storage)?.permissionless
+            }
+            ExecuteMsg::UpdateOwnership(_) | ExecuteMsg::RegisterLegacyPairs { .. } => true,
+            // the fee manager can only update the pool fees
+            #[cfg(not(feature = "osmosis"))]
+            ExecuteMsg::UpdatePairConfig {
+                fee_collector_addr: None,
+                feature_toggle: None,
+                ..
+            } => has_role(deps.storage, Role::FeeManager, &info.sender)?,
+            #[cfg(feature = "osmosis")]
+            ExecuteMsg::UpdatePairConfig {
+                fee_collector_addr: None,
+                feature_toggle: None,
+                cosmwasm_pool_interface: None,
+                ..
+            } => has_role(deps.storage, Role::FeeManager, &info.sender)?,
+            ExecuteMsg::UpdateTrioConfig {
+                fee_collector_addr: None,
+                feature_toggle: None,
+                amp_factor: None,
+                ..
+            }
+            | ExecuteMsg::UpdatePairCreationConfig { .. } => {
+                has_role(deps.storage, Role::FeeManager, &info.sender)?
+            }
+            ExecuteMsg::EmergencyPause { .. } => {
+                has_role(deps.storage, Role::Pauser, &info.sender)?
+            }
+            ExecuteMsg::MigratePair { .. } | ExecuteMsg::MigrateTrio { .. } => {
+                has_role(deps.storage, Role::Migrator, &info.sender)?
+            }
+            _ => false,
+        };
+    if !authorized {
         return Err(ContractError::Unauthorized {});
     }
 
     match msg {
         ExecuteMsg::UpdateConfig {
-            owner,
             fee_collector_addr,
             token_code_id,
             pair_code_id,
             trio_code_id,
         } => commands::update_config(
             deps,
-            owner,
             fee_collector_addr,
             token_code_id,
             pair_code_id,
@@ -104,8 +149,12 @@ pub fn execute(
             amp_factor,
             token_factory_lp,
         ),
-        ExecuteMsg::RemovePair { asset_infos } => commands::remove_pair(deps, env, asset_infos),
+        ExecuteMsg::RemovePair {
+            asset_infos,
+            pair_variant,
+        } => commands::remove_pair(deps, env, asset_infos, pair_variant),
         ExecuteMsg::RemoveTrio { asset_infos } => commands::remove_trio(deps, env, asset_infos),
+        ExecuteMsg::RegisterLegacyPairs { limit } => commands::register_legacy_pairs(deps, limit),
         ExecuteMsg::AddNativeTokenDecimals { denom, decimals } => {
             commands::add_native_token_decimals(deps, denom, decimals)
         }
@@ -118,7 +167,6 @@ pub fn execute(
         #[cfg(feature = "osmosis")]
         ExecuteMsg::UpdatePairConfig {
             pair_addr,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -126,7 +174,6 @@ pub fn execute(
         } => commands::update_pair_config(
             deps,
             pair_addr,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -135,14 +182,12 @@ pub fn execute(
         #[cfg(not(feature = "osmosis"))]
         ExecuteMsg::UpdatePairConfig {
             pair_addr,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
         } => commands::update_pair_config(
             deps,
             pair_addr,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -150,7 +195,6 @@ pub fn execute(
         ),
         ExecuteMsg::UpdateTrioConfig {
             trio_addr,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -158,12 +202,29 @@ pub fn execute(
         } => commands::update_trio_config(
             deps,
             trio_addr,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
             amp_factor,
         ),
+        ExecuteMsg::UpdatePairCreationConfig {
+            pair_creation_config,
+        } => commands::update_pair_creation_config(deps, pair_creation_config),
+        ExecuteMsg::UpdatePairCircuitBreaker {
+            pair_addr,
+            circuit_breaker,
+        } => commands::update_pair_circuit_breaker(deps, pair_addr, circuit_breaker),
+        ExecuteMsg::EmergencyPause { paused_features } => {
+            commands::emergency_pause(deps, info, paused_features)
+        }
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
+        ExecuteMsg::UpdatePairOwnership { pair_addr, action } => {
+            commands::update_pair_ownership(deps, pair_addr, action)
+        }
+        ExecuteMsg::UpdateTrioOwnership { trio_addr, action } => {
+            commands::update_trio_ownership(deps, trio_addr, action)
+        }
+        ExecuteMsg::UpdateRole { role, address } => Ok(update_role(deps, role, address)?),
     }
 }
 
@@ -190,18 +251,32 @@ fn create_pair_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractErro
     let pair_contract = deps.api.addr_validate(&res.address)?;
     let pair_info = query_pair_info_from_pair(&deps.querier, pair_contract.clone())?;
 
-    PAIRS.save(
+    let pair_info_raw = PairInfoRaw {
+        liquidity_token: pair_info.liquidity_token.to_raw(deps.api)?,
+        contract_addr: deps.api.addr_canonicalize(pair_contract.as_str())?,
+        asset_infos: tmp_pair_info.asset_infos,
+        asset_decimals: tmp_pair_info.asset_decimals,
+        pair_type: tmp_pair_info.pair_type.clone(),
+    };
+
+    let pair_variant = PairVariant {
+        pair_type: tmp_pair_info.pair_type,
+        fee_tier: tmp_pair_info.fee_tier,
+    };
+    PAIR_VARIANTS.save(
         deps.storage,
-        &tmp_pair_info.pair_key,
-        &PairInfoRaw {
-            liquidity_token: pair_info.liquidity_token.to_raw(deps.api)?,
-            contract_addr: deps.api.addr_canonicalize(pair_contract.as_str())?,
-            asset_infos: tmp_pair_info.asset_infos,
-            asset_decimals: tmp_pair_info.asset_decimals,
-            pair_type: tmp_pair_info.pair_type,
+        (&tmp_pair_info.pair_key, &pair_variant.key()),
+        &PairVariantInfoRaw {
+            pair_variant,
+            pair_info: pair_info_raw.clone(),
         },
     )?;
 
+    // the first pair created for the assets is the default one
+    if !PAIRS.has(deps.storage, &tmp_pair_info.pair_key) {
+        PAIRS.save(deps.storage, &tmp_pair_info.pair_key, &pair_info_raw)?;
+    }
+
     Ok(Response::new().add_attributes(vec![
         ("pair_contract_addr", pair_contract.as_str()),
         (
@@ -246,10 +321,23 @@ fn create_trio_reply(deps: DepsMut, msg: Reply) -> Result<Response, ContractErro
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_json_binary(&queries::query_config(deps)?),
-        QueryMsg::Pair { asset_infos } => to_json_binary(&queries::query_pair(deps, asset_infos)?),
-        QueryMsg::Pairs { start_after, limit } => {
-            to_json_binary(&queries::query_pairs(deps, start_after, limit)?)
+        QueryMsg::Pair {
+            asset_infos,
+            pair_variant,
+        } => to_json_binary(&queries::query_pair(deps, asset_infos, pair_variant)?),
+        QueryMsg::PairVariants { asset_infos } => {
+            to_json_binary(&queries::query_pair_variants(deps, asset_infos)?)
         }
+        QueryMsg::Pairs {
+            start_after,
+            start_after_variant,
+            limit,
+        } => to_json_binary(&queries::query_pairs(
+            deps,
+            start_after,
+            start_after_variant,
+            limit,
+        )?),
         QueryMsg::Trio { asset_infos } => to_json_binary(&queries::query_trio(deps, asset_infos)?),
         QueryMsg::Trios { start_after, limit } => {
             to_json_binary(&queries::query_trios(deps, start_after, limit)?)
@@ -257,6 +345,12 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::NativeTokenDecimals { denom } => {
             to_json_binary(&queries::query_native_token_decimal(deps, denom)?)
         }
+        QueryMsg::PairCreationConfig {} => {
+            to_json_binary(&queries::query_pair_creation_config(deps)?)
+        }
+        QueryMsg::EmergencyPause {} => to_json_binary(&queries::query_emergency_pause(deps)?),
+        QueryMsg::OwnershipProposal {} => to_json_binary(&query_ownership_proposal(deps.storage)?),
+        QueryMsg::Roles {} => to_json_binary(&query_roles(deps.storage)?),
     }
 }
 
@@ -287,6 +381,12 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Respons
     if storage_version < Version::parse("1.2.0")? {
         migrations::migrate_to_v120(deps.branch())?;
     }
+    if storage_version < Version::parse("1.4.0")? {
+        migrations::migrate_to_v140(deps.branch())?;
+    }
+    if storage_version < Version::parse("1.6.0")? {
+        migrations::migrate_to_v160(deps.branch())?;
+    }
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
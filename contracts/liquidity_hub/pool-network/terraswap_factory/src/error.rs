@@ -40,6 +40,21 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
+    #[error("The listing fee must be greater than zero")]
+    InvalidListingFee {},
+
+    #[error("The listing fee of {listing_fee} was not paid")]
+    ListingFeeNotPaid { listing_fee: String },
+
+    #[error("The given pool fees are not an approved fee tier")]
+    InvalidFeeTier {},
+
+    #[error("The pairs created before pair variants were introduced are still being registered, use RegisterLegacyPairs to finish")]
+    LegacyPairsNotRegistered {},
+
+    #[error("There are no pairs left to register as pair variants")]
+    NoLegacyPairsToRegister {},
+
     #[error("Attempt to migrate a pool to a different type. Make sure you are using the write Migrate message, e.g. MigratePair or MigrateTrio for pairs and trios respectively.")]
     MigratingWrongPool {},
 }
@@ -1,9 +1,10 @@
-use crate::state::Config;
+use crate::state::{Config, LEGACY_PAIRS_CURSOR, PAIRS};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, CanonicalAddr, DepsMut, Order, StdError, Uint128};
 use cw_storage_plus::{Item, Map};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use white_whale_std::ownership::{self, Role};
 use white_whale_std::pool_network::asset::{AssetInfo, AssetInfoRaw, PairType};
 
 #[cfg(not(feature = "osmosis"))]
@@ -197,3 +198,31 @@ pub fn migrate_to_v120(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// Starts registering the pairs created before pair variants were introduced as pair variants.
+/// Querying the pool fees of every pair at once could exceed the gas limit, so they are registered
+/// in batches afterwards with `RegisterLegacyPairs`.
+pub fn migrate_to_v140(deps: DepsMut) -> Result<(), StdError> {
+    if PAIRS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .next()
+        .is_some()
+    {
+        LEGACY_PAIRS_CURSOR.save(deps.storage, &vec![])?;
+    }
+
+    Ok(())
+}
+
+/// Migrates the guardian, which could pause features across the pairs and trios of the factory, to
+/// the pauser role that replaced it in v1.6.0.
+pub fn migrate_to_v160(mut deps: DepsMut) -> Result<(), StdError> {
+    const GUARDIAN: Item<Addr> = Item::new("guardian");
+
+    if let Some(guardian) = GUARDIAN.may_load(deps.storage)? {
+        ownership::update_role(deps.branch(), Role::Pauser, Some(guardian.into_string()))?;
+        GUARDIAN.remove(deps.storage);
+    }
+
+    Ok(())
+}
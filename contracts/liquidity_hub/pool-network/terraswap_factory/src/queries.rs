@@ -1,12 +1,16 @@
 use crate::state::{
-    pair_key, read_pairs, read_trios, trio_key, Config, ALLOW_NATIVE_TOKENS, CONFIG, PAIRS, TRIOS,
+    pair_key, read_pair_creation_config, read_pairs, read_paused_features, read_trios, trio_key,
+    Config, ALLOW_NATIVE_TOKENS, CONFIG, PAIRS, PAIR_VARIANTS, TRIOS,
 };
-use cosmwasm_std::{Deps, StdResult};
+use cosmwasm_std::{Deps, Order, StdResult};
+use white_whale_std::ownership::query_roles;
+use white_whale_std::pause::EmergencyPauseResponse;
 use white_whale_std::pool_network::asset::{
     AssetInfo, PairInfo, PairInfoRaw, TrioInfo, TrioInfoRaw,
 };
 use white_whale_std::pool_network::factory::{
-    ConfigResponse, NativeTokenDecimalsResponse, PairsResponse, TriosResponse,
+    ConfigResponse, NativeTokenDecimalsResponse, PairCreationConfig, PairVariant, PairVariantInfo,
+    PairVariantsResponse, PairsResponse, TriosResponse,
 };
 
 /// Queries [Config]
@@ -22,20 +26,58 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     })
 }
 
-/// Queries info about a given Pair
-pub fn query_pair(deps: Deps, asset_infos: [AssetInfo; 2]) -> StdResult<PairInfo> {
+/// Queries info about a given Pair. If no [PairVariant] is given, the default pair is returned.
+pub fn query_pair(
+    deps: Deps,
+    asset_infos: [AssetInfo; 2],
+    pair_variant: Option<PairVariant>,
+) -> StdResult<PairInfo> {
     let pair_key = pair_key(&[
         asset_infos[0].to_raw(deps.api)?,
         asset_infos[1].to_raw(deps.api)?,
     ]);
-    let pair_info: PairInfoRaw = PAIRS.load(deps.storage, &pair_key)?;
+    let pair_info: PairInfoRaw = match pair_variant {
+        Some(pair_variant) => {
+            PAIR_VARIANTS
+                .load(deps.storage, (&pair_key, &pair_variant.key()))?
+                .pair_info
+        }
+        None => PAIRS.load(deps.storage, &pair_key)?,
+    };
     pair_info.to_normal(deps.api)
 }
 
+/// Queries all the pair variants for the given assets
+pub fn query_pair_variants(
+    deps: Deps,
+    asset_infos: [AssetInfo; 2],
+) -> StdResult<PairVariantsResponse> {
+    let pair_key = pair_key(&[
+        asset_infos[0].to_raw(deps.api)?,
+        asset_infos[1].to_raw(deps.api)?,
+    ]);
+
+    let pairs = PAIR_VARIANTS
+        .prefix(&pair_key)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (_, pair_variant_info) = item?;
+
+            Ok(PairVariantInfo {
+                pair_variant: pair_variant_info.pair_variant,
+                pair_info: pair_variant_info.pair_info.to_normal(deps.api)?,
+            })
+        })
+        .collect::<StdResult<Vec<PairVariantInfo>>>()?;
+
+    Ok(PairVariantsResponse { pairs })
+}
+
 /// Queries all the pairs created by the factory
 pub fn query_pairs(
     deps: Deps,
     start_after: Option<[AssetInfo; 2]>,
+    start_after_variant: Option<PairVariant>,
     limit: Option<u32>,
 ) -> StdResult<PairsResponse> {
     let start_after = if let Some(start_after) = start_after {
@@ -47,8 +89,17 @@ pub fn query_pairs(
         None
     };
 
-    let pairs: Vec<PairInfo> = read_pairs(deps.storage, deps.api, start_after, limit)?;
-    let resp = PairsResponse { pairs };
+    let (pairs, last_pair_variant) = read_pairs(
+        deps.storage,
+        deps.api,
+        start_after,
+        start_after_variant,
+        limit,
+    )?;
+    let resp = PairsResponse {
+        pairs,
+        last_pair_variant,
+    };
 
     Ok(resp)
 }
@@ -95,3 +146,16 @@ pub fn query_native_token_decimal(
 
     Ok(NativeTokenDecimalsResponse { decimals })
 }
+
+/// Queries the pauser and the [PausedFeatures] across all the pairs and trios
+pub fn query_emergency_pause(deps: Deps) -> StdResult<EmergencyPauseResponse> {
+    Ok(EmergencyPauseResponse {
+        pauser: query_roles(deps.storage)?.pauser,
+        paused_features: read_paused_features(deps.storage)?,
+    })
+}
+
+/// Queries the [PairCreationConfig]
+pub fn query_pair_creation_config(deps: Deps) -> StdResult<PairCreationConfig> {
+    read_pair_creation_config(deps.storage)
+}
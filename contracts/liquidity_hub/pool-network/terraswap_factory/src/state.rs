@@ -1,9 +1,11 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Api, CanonicalAddr, Order, StdResult, Storage};
-use cw_storage_plus::{Bound, Item, Map};
+use cosmwasm_std::{Addr, Api, CanonicalAddr, Decimal, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Item, Map, PrefixBound};
+use white_whale_std::pause::PausedFeatures;
 use white_whale_std::pool_network::asset::{
     AssetInfoRaw, PairInfo, PairInfoRaw, PairType, TrioInfo, TrioInfoRaw,
 };
+use white_whale_std::pool_network::factory::{PairCreationConfig, PairVariant};
 
 #[cw_serde]
 pub struct Config {
@@ -16,17 +18,53 @@ pub struct Config {
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
+pub const PAIR_CREATION_CONFIG: Item<PairCreationConfig> = Item::new("pair_creation_config");
+
+pub const PAUSED_FEATURES: Item<PausedFeatures> = Item::new("paused_features");
+
+/// Reads the [PausedFeatures]. Defaults to nothing being paused.
+pub fn read_paused_features(storage: &dyn Storage) -> StdResult<PausedFeatures> {
+    Ok(PAUSED_FEATURES.may_load(storage)?.unwrap_or_default())
+}
+
+/// Reads the [PairCreationConfig]. Defaults to the permissioned mode, where only the owner can
+/// create pairs.
+pub fn read_pair_creation_config(storage: &dyn Storage) -> StdResult<PairCreationConfig> {
+    Ok(PAIR_CREATION_CONFIG
+        .may_load(storage)?
+        .unwrap_or(PairCreationConfig {
+            permissionless: false,
+            listing_fee: None,
+            fee_tiers: vec![],
+        }))
+}
+
 #[cw_serde]
 pub struct TmpPairInfo {
     pub pair_key: Vec<u8>,
     pub asset_infos: [AssetInfoRaw; 2],
     pub asset_decimals: [u8; 2],
     pub pair_type: PairType,
+    pub fee_tier: Decimal,
 }
 
 pub const TMP_PAIR_INFO: Item<TmpPairInfo> = Item::new("tmp_pair_info");
+/// The default pair for each asset combination, i.e. the first one created for the assets
 pub const PAIRS: Map<&[u8], PairInfoRaw> = Map::new("pair_info");
 
+#[cw_serde]
+pub struct PairVariantInfoRaw {
+    pub pair_variant: PairVariant,
+    pub pair_info: PairInfoRaw,
+}
+
+/// All the pairs created by the factory, keyed by their pair key and [PairVariant] key
+pub const PAIR_VARIANTS: Map<(&[u8], &str), PairVariantInfoRaw> = Map::new("pair_variants");
+
+/// The key of the last pair created before pair variants were introduced that was registered as a
+/// pair variant. It only exists while those pairs are being registered after the migration.
+pub const LEGACY_PAIRS_CURSOR: Item<Vec<u8>> = Item::new("legacy_pairs_cursor");
+
 pub fn pair_key(asset_infos: &[AssetInfoRaw; 2]) -> Vec<u8> {
     let mut asset_infos = asset_infos.to_vec();
     asset_infos.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
@@ -57,40 +95,54 @@ pub fn trio_key(asset_infos: &[AssetInfoRaw; 3]) -> Vec<u8> {
 }
 
 // settings for pagination
-const MAX_LIMIT: u32 = 30;
-const DEFAULT_LIMIT: u32 = 10;
+pub(crate) const MAX_LIMIT: u32 = 30;
+pub(crate) const DEFAULT_LIMIT: u32 = 10;
+/// Reads a page of the pairs, along with the variant of the last one to read the next page with.
+/// If a [PairVariant] is given, the page starts after that variant of the `start_after` assets,
+/// otherwise after all their variants.
 pub fn read_pairs(
     storage: &dyn Storage,
     api: &dyn Api,
     start_after: Option<[AssetInfoRaw; 2]>,
+    start_after_variant: Option<PairVariant>,
     limit: Option<u32>,
-) -> StdResult<Vec<PairInfo>> {
+) -> StdResult<(Vec<PairInfo>, Option<PairVariant>)> {
     let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
-    let start = calc_range_start(start_after).map(Bound::ExclusiveRaw);
-
-    PAIRS
-        .range(storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|item| {
-            let (_, v) = item?;
-            v.to_normal(api)
-        })
-        .collect::<StdResult<Vec<PairInfo>>>()
-}
-
-// this will set the first key after the provided key, by appending a 1 byte
-fn calc_range_start(start_after: Option<[AssetInfoRaw; 2]>) -> Option<Vec<u8>> {
-    start_after.map(|asset_infos| {
-        let mut asset_infos = asset_infos.to_vec();
-        asset_infos.sort_by(|a, b| a.as_bytes().cmp(b.as_bytes()));
-
-        let mut v = [asset_infos[0].as_bytes(), asset_infos[1].as_bytes()]
-            .concat()
-            .as_slice()
-            .to_vec();
-        v.push(1);
-        v
-    })
+    let start_key = start_after.map(|asset_infos| pair_key(&asset_infos));
+    let start_variant_key = start_after_variant.map(|pair_variant| pair_variant.key());
+
+    let pair_variant_infos = match (start_key.as_deref(), start_variant_key.as_deref()) {
+        (Some(start_key), Some(start_variant_key)) => PAIR_VARIANTS
+            .range(
+                storage,
+                Some(Bound::exclusive((start_key, start_variant_key))),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|item| Ok(item?.1))
+            .collect::<StdResult<Vec<_>>>()?,
+        (start_key, _) => PAIR_VARIANTS
+            .prefix_range(
+                storage,
+                start_key.map(PrefixBound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|item| Ok(item?.1))
+            .collect::<StdResult<Vec<_>>>()?,
+    };
+
+    let last_pair_variant = pair_variant_infos
+        .last()
+        .map(|pair_variant_info| pair_variant_info.pair_variant.clone());
+    let pairs = pair_variant_infos
+        .into_iter()
+        .map(|pair_variant_info| pair_variant_info.pair_info.to_normal(api))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok((pairs, last_pair_variant))
 }
 
 pub fn read_trios(
@@ -2,23 +2,27 @@ use cosmwasm_std::testing::{
     mock_dependencies_with_balance, mock_env, mock_info, MockApi, MockStorage, MOCK_CONTRACT_ADDR,
 };
 use cosmwasm_std::{
-    attr, coin, from_json, to_json_binary, Api, CanonicalAddr, Coin, CosmosMsg, Decimal, OwnedDeps,
-    Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+    attr, coin, from_json, to_json_binary, Addr, Api, BankMsg, CanonicalAddr, Coin, ContractResult,
+    CosmosMsg, Decimal, OwnedDeps, Reply, ReplyOn, Response, SubMsg, SubMsgResponse, SubMsgResult,
+    SystemResult, Uint128, WasmMsg,
 };
 
 use white_whale_std::fee::Fee;
+use white_whale_std::ownership::{OwnershipAction, Role, RolesResponse};
+use white_whale_std::pause::{EmergencyPauseResponse, PausedFeatures};
 use white_whale_std::pool_network;
 use white_whale_std::pool_network::asset::{
     AssetInfo, AssetInfoRaw, PairInfo, PairInfoRaw, PairType,
 };
 use white_whale_std::pool_network::factory::{
-    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, NativeTokenDecimalsResponse, QueryMsg,
+    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, NativeTokenDecimalsResponse,
+    PairCreationConfig, PairVariant, PairVariantsResponse, PairsResponse, QueryMsg,
 };
 use white_whale_std::pool_network::mock_querier::{
     mock_dependencies, mock_dependencies_trio, WasmMockQuerier, WasmMockTrioQuerier,
 };
 use white_whale_std::pool_network::pair::{
-    InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
+    CircuitBreaker, InstantiateMsg as PairInstantiateMsg, MigrateMsg as PairMigrateMsg, PoolFee,
 };
 use white_whale_std::pool_network::trio::{
     InstantiateMsg as TrioInstantiateMsg, MigrateMsg as TrioMigrateMsg, PoolFee as TrioPoolFee,
@@ -27,7 +31,8 @@ use white_whale_std::pool_network::trio::{
 use crate::contract::{execute, instantiate, migrate, query, reply};
 use crate::error::ContractError;
 use crate::state::{
-    pair_key, trio_key, TmpPairInfo, TmpTrioInfo, PAIRS, TMP_PAIR_INFO, TMP_TRIO_INFO,
+    pair_key, trio_key, PairVariantInfoRaw, TmpPairInfo, TmpTrioInfo, PAIRS, PAIR_VARIANTS,
+    TMP_PAIR_INFO, TMP_TRIO_INFO,
 };
 
 #[test]
@@ -93,20 +98,26 @@ fn update_config() {
     // we can just call .unwrap() to assert this was a success
     let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
 
-    // update owner
+    // propose a new owner
     let info = mock_info("addr0000", &[]);
 
-    let msg = ExecuteMsg::UpdateConfig {
-        owner: Some("addr0001".to_string()),
-        pair_code_id: None,
-        trio_code_id: None,
-        token_code_id: None,
-        fee_collector_addr: None,
-    };
+    let msg = ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+        new_owner: "addr0001".to_string(),
+        expiry: None,
+    });
 
     let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
     assert_eq!(0, res.messages.len());
 
+    // the ownership doesn't change until the new owner accepts it
+    let query_res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+    let config_res: ConfigResponse = from_json(&query_res).unwrap();
+    assert_eq!("addr0000".to_string(), config_res.owner);
+
+    let info = mock_info("addr0001", &[]);
+    let msg = ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {});
+    execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
     // it worked, let's query the state
     let query_res = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
     let config_res: ConfigResponse = from_json(&query_res).unwrap();
@@ -120,7 +131,6 @@ fn update_config() {
     let info = mock_info("addr0001", &[]);
 
     let msg = ExecuteMsg::UpdateConfig {
-        owner: None,
         pair_code_id: Some(100u64),
         trio_code_id: Some(300u64),
         token_code_id: Some(200u64),
@@ -143,7 +153,6 @@ fn update_config() {
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
     let msg = ExecuteMsg::UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pair_code_id: None,
         trio_code_id: None,
@@ -316,6 +325,7 @@ fn create_pair() {
             pair_key: pair_key(&raw_infos),
             asset_decimals: [6u8, 8u8],
             pair_type: PairType::ConstantProduct,
+            fee_tier: pool_fees.aggregate().unwrap(),
         }
     );
 }
@@ -423,6 +433,7 @@ fn create_stableswap_pair() {
             pair_key: pair_key(&raw_infos),
             asset_decimals: [6u8, 8u8],
             pair_type: PairType::StableSwap { amp: 100 },
+            fee_tier: pool_fees.aggregate().unwrap(),
         }
     );
 }
@@ -546,6 +557,7 @@ fn create_pair_native_token_and_ibc_token() {
             pair_key: pair_key(&raw_infos),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
+            fee_tier: pool_fees.aggregate().unwrap(),
         }
     );
 }
@@ -676,6 +688,7 @@ fn create_ibc_tokens_pair() {
             pair_key: pair_key(&raw_infos),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
+            fee_tier: pool_fees.aggregate().unwrap(),
         }
     );
 }
@@ -795,6 +808,7 @@ fn create_pair_ethereum_asset_and_ibc_token() {
             pair_key: pair_key(&raw_infos),
             asset_decimals: [6u8, 6u8],
             pair_type: PairType::ConstantProduct,
+            fee_tier: Decimal::percent(2u64),
         }
     );
 }
@@ -878,36 +892,67 @@ fn fail_to_create_existing_pair() {
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
 
-    // inject pair into PAIRS
+    // inject pair into PAIR_VARIANTS
     let raw_infos = [
         asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
         asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
     ];
     let pair_key = pair_key(&raw_infos);
+    let pair_variant = PairVariant {
+        pair_type: PairType::ConstantProduct,
+        fee_tier: Decimal::percent(2u64),
+    };
 
-    PAIRS
+    PAIR_VARIANTS
         .save(
             &mut deps.storage,
-            &pair_key,
-            &PairInfoRaw {
-                liquidity_token: AssetInfoRaw::Token {
-                    contract_addr: CanonicalAddr::from(vec![]),
+            (&pair_key, &pair_variant.key()),
+            &PairVariantInfoRaw {
+                pair_variant,
+                pair_info: PairInfoRaw {
+                    liquidity_token: AssetInfoRaw::Token {
+                        contract_addr: CanonicalAddr::from(vec![]),
+                    },
+                    contract_addr: deps.api.addr_canonicalize("pair_contract").unwrap(),
+                    asset_infos: raw_infos,
+                    asset_decimals: [6u8, 6u8],
+                    pair_type: PairType::ConstantProduct,
                 },
-                contract_addr: deps.api.addr_canonicalize("pair_contract").unwrap(),
-                asset_infos: raw_infos,
-                asset_decimals: [6u8, 6u8],
-                pair_type: PairType::ConstantProduct,
             },
         )
         .unwrap();
 
     // try to recreate the same pair
-    let res = execute(deps.as_mut(), env, info, msg);
+    let res = execute(deps.as_mut(), env.clone(), info.clone(), msg);
     match res {
         Ok(_) => panic!("Should return ContractError::ExistingPair"),
         Err(ContractError::ExistingPair { .. }) => (),
         _ => panic!("Should return ContractError::ExistingPair"),
     }
+
+    // a pair with another fee tier or pair type can be created for the same assets
+    let msg = ExecuteMsg::CreatePair {
+        asset_infos,
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            swap_fee: Fee {
+                share: Decimal::permille(5u64),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        pair_type: PairType::StableSwap { amp: 100 },
+        token_factory_lp: false,
+    };
+    execute(deps.as_mut(), env, info, msg).unwrap();
+
+    assert_eq!(
+        TMP_PAIR_INFO.load(&deps.storage).unwrap().fee_tier,
+        Decimal::permille(15u64)
+    );
 }
 
 #[cfg(not(feature = "osmosis"))]
@@ -1179,6 +1224,7 @@ fn reply_test() {
                 pair_key,
                 asset_decimals: [8u8, 8u8],
                 pair_type: PairType::ConstantProduct,
+                fee_tier: Decimal::percent(2u64),
             },
         )
         .unwrap();
@@ -1222,6 +1268,7 @@ fn reply_test() {
         mock_env(),
         QueryMsg::Pair {
             asset_infos: asset_infos.clone(),
+            pair_variant: None,
         },
     )
     .unwrap();
@@ -1241,6 +1288,455 @@ fn reply_test() {
     );
 }
 
+#[test]
+fn register_pair_variants() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let asset_infos = [
+        AssetInfo::Token {
+            contract_addr: "asset0000".to_string(),
+        },
+        AssetInfo::Token {
+            contract_addr: "asset0001".to_string(),
+        },
+    ];
+    let raw_infos = [
+        asset_infos[0].to_raw(deps.as_ref().api).unwrap(),
+        asset_infos[1].to_raw(deps.as_ref().api).unwrap(),
+    ];
+
+    let constant_product_variant = PairVariant {
+        pair_type: PairType::ConstantProduct,
+        fee_tier: Decimal::percent(2u64),
+    };
+    let stableswap_variant = PairVariant {
+        pair_type: PairType::StableSwap { amp: 100 },
+        fee_tier: Decimal::permille(5u64),
+    };
+
+    // create a constant product pair and a stableswap pair for the same assets
+    for (pair_variant, pair_contract) in [
+        (constant_product_variant.clone(), b"0000"),
+        (stableswap_variant.clone(), b"0001"),
+    ] {
+        TMP_PAIR_INFO
+            .save(
+                &mut deps.storage,
+                &TmpPairInfo {
+                    asset_infos: raw_infos.clone(),
+                    pair_key: pair_key(&raw_infos),
+                    asset_decimals: [8u8, 8u8],
+                    pair_type: pair_variant.pair_type,
+                    fee_tier: pair_variant.fee_tier,
+                },
+            )
+            .unwrap();
+
+        let reply_msg = Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some([&[10u8, 4u8][..], &pair_contract[..]].concat().into()),
+            }),
+        };
+        reply(deps.as_mut(), mock_env(), reply_msg).unwrap();
+    }
+
+    // the first pair created is the default one
+    let pair_res: PairInfo = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pair_variant: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pair_res.contract_addr, "0000");
+
+    let pair_res: PairInfo = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pair_variant: Some(stableswap_variant.clone()),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pair_res.contract_addr, "0001");
+
+    let pair_variants_res: PairVariantsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::PairVariants {
+                asset_infos: asset_infos.clone(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        pair_variants_res
+            .pairs
+            .iter()
+            .map(|pair| (
+                pair.pair_variant.clone(),
+                pair.pair_info.contract_addr.clone()
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (constant_product_variant.clone(), "0000".to_string()),
+            (stableswap_variant.clone(), "0001".to_string()),
+        ]
+    );
+
+    // the pairs are paginated by pair, so the variants of the same assets can be split over pages
+    let pairs_res: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: None,
+                start_after_variant: None,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pairs_res.pairs.len(), 1);
+    assert_eq!(pairs_res.pairs[0].contract_addr, "0000");
+    assert_eq!(pairs_res.last_pair_variant, Some(constant_product_variant));
+
+    let pairs_res: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: Some(pairs_res.pairs[0].asset_infos.clone()),
+                start_after_variant: pairs_res.last_pair_variant,
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pairs_res.pairs.len(), 1);
+    assert_eq!(pairs_res.pairs[0].contract_addr, "0001");
+
+    // without a variant, the page starts after all the variants of the assets
+    let pairs_res: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: Some(asset_infos.clone()),
+                start_after_variant: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(pairs_res.pairs.is_empty());
+    assert_eq!(pairs_res.last_pair_variant, None);
+
+    // removing the default pair promotes the remaining variant
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos: asset_infos.clone(),
+        pair_variant: None,
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let pair_res: PairInfo = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pair_variant: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(pair_res.contract_addr, "0001");
+
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos: asset_infos.clone(),
+        pair_variant: Some(stableswap_variant),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let raw_pair_key = pair_key(&raw_infos);
+    assert!(!PAIRS.has(&deps.storage, &raw_pair_key));
+    assert!(PAIR_VARIANTS
+        .prefix(&raw_pair_key)
+        .keys(&deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .next()
+        .is_none());
+}
+
+#[test]
+fn migrate_guardian_to_pauser_role() {
+    const GUARDIAN: cw_storage_plus::Item<Addr> = cw_storage_plus::Item::new("guardian");
+
+    let mut deps = mock_dependencies(&[]);
+    GUARDIAN
+        .save(deps.as_mut().storage, &Addr::unchecked("guardian"))
+        .unwrap();
+
+    crate::migrations::migrate_to_v160(deps.as_mut()).unwrap();
+
+    let res: RolesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Roles {}).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        RolesResponse {
+            fee_manager: None,
+            pauser: Some(Addr::unchecked("guardian")),
+            migrator: None,
+        }
+    );
+    assert!(!GUARDIAN.exists(deps.as_ref().storage));
+}
+
+#[test]
+fn register_legacy_pairs() {
+    let mut deps = cosmwasm_std::testing::mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        InstantiateMsg {
+            pair_code_id: 321u64,
+            trio_code_id: 456u64,
+            token_code_id: 123u64,
+            fee_collector_addr: "collector".to_string(),
+        },
+    )
+    .unwrap();
+
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::percent(2u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+        #[cfg(feature = "osmosis")]
+        osmosis_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+    let pair_config = pool_network::pair::Config {
+        owner: Addr::unchecked("addr0000"),
+        fee_collector_addr: Addr::unchecked("collector"),
+        pool_fees: pool_fees.clone(),
+        feature_toggle: pool_network::pair::FeatureToggle {
+            withdrawals_enabled: true,
+            deposits_enabled: true,
+            swaps_enabled: true,
+        },
+        #[cfg(feature = "osmosis")]
+        cosmwasm_pool_interface: Addr::unchecked("cosmwasm_pool_interface"),
+    };
+    deps.querier.update_wasm(move |_| {
+        SystemResult::Ok(ContractResult::Ok(to_json_binary(&pair_config).unwrap()))
+    });
+
+    // store three pairs the way they were before pair variants were introduced
+    for i in 0..3 {
+        let raw_infos = [
+            AssetInfoRaw::NativeToken {
+                denom: "uwhale".to_string(),
+            },
+            AssetInfoRaw::NativeToken {
+                denom: format!("denom{i}"),
+            },
+        ];
+        PAIRS
+            .save(
+                &mut deps.storage,
+                &pair_key(&raw_infos),
+                &PairInfoRaw {
+                    liquidity_token: AssetInfoRaw::Token {
+                        contract_addr: deps.api.addr_canonicalize(&format!("lp{i}")).unwrap(),
+                    },
+                    contract_addr: deps.api.addr_canonicalize(&format!("pair{i}")).unwrap(),
+                    asset_infos: raw_infos,
+                    asset_decimals: [6u8, 6u8],
+                    pair_type: PairType::ConstantProduct,
+                },
+            )
+            .unwrap();
+    }
+
+    crate::migrations::migrate_to_v140(deps.as_mut()).unwrap();
+
+    // no pairs can be created until the legacy pairs are registered
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::CreatePair {
+            asset_infos: [
+                AssetInfo::NativeToken {
+                    denom: "uwhale".to_string(),
+                },
+                AssetInfo::NativeToken {
+                    denom: "denom0".to_string(),
+                },
+            ],
+            pool_fees: pool_fees.clone(),
+            pair_type: PairType::ConstantProduct,
+            token_factory_lp: false,
+        },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::LegacyPairsNotRegistered {} => {}
+        _ => panic!("should return ContractError::LegacyPairsNotRegistered"),
+    }
+
+    // anyone can register them, in batches
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::RegisterLegacyPairs { limit: Some(2) },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "register_legacy_pairs"),
+            attr("registered_pairs", "2"),
+            attr("completed", "false"),
+        ]
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::RegisterLegacyPairs { limit: Some(2) },
+    )
+    .unwrap();
+    assert_eq!(
+        res.attributes,
+        vec![
+            attr("action", "register_legacy_pairs"),
+            attr("registered_pairs", "1"),
+            attr("completed", "true"),
+        ]
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::RegisterLegacyPairs { limit: None },
+    )
+    .unwrap_err();
+    match err {
+        ContractError::NoLegacyPairsToRegister {} => {}
+        _ => panic!("should return ContractError::NoLegacyPairsToRegister"),
+    }
+
+    // the pairs are registered as the variant matching their pool fees
+    let pairs_res: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: None,
+                start_after_variant: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        pairs_res
+            .pairs
+            .iter()
+            .map(|pair| pair.contract_addr.clone())
+            .collect::<Vec<_>>(),
+        vec!["pair0", "pair1", "pair2"]
+    );
+    assert_eq!(
+        pairs_res.last_pair_variant,
+        Some(PairVariant {
+            pair_type: PairType::ConstantProduct,
+            fee_tier: Decimal::percent(3u64),
+        })
+    );
+
+    // the registered pairs can be paginated
+    let first_page: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: None,
+                start_after_variant: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        first_page
+            .pairs
+            .iter()
+            .map(|pair| pair.contract_addr.clone())
+            .collect::<Vec<_>>(),
+        vec!["pair0", "pair1"]
+    );
+    assert_eq!(
+        first_page.pairs[1].liquidity_token,
+        AssetInfo::Token {
+            contract_addr: "lp1".to_string(),
+        }
+    );
+
+    let second_page: PairsResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: Some(first_page.pairs[1].asset_infos.clone()),
+                start_after_variant: first_page.last_pair_variant,
+                limit: Some(2),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        second_page
+            .pairs
+            .iter()
+            .map(|pair| pair.contract_addr.clone())
+            .collect::<Vec<_>>(),
+        vec!["pair2"]
+    );
+}
+
 #[test]
 fn normal_add_allow_native_token() {
     let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
@@ -1399,7 +1895,6 @@ fn execute_transactions_unauthorized() {
 
     // Try executing ExecuteMsg::UpdateConfig
     let msg = ExecuteMsg::UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         token_code_id: None,
         pair_code_id: None,
@@ -1585,7 +2080,10 @@ fn delete_pair() {
 
     assert!(pair.is_ok(), "pair key should exist");
 
-    let msg = ExecuteMsg::RemovePair { asset_infos };
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos,
+        pair_variant: None,
+    };
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
     let res = execute(deps.as_mut(), env, info, msg).unwrap();
@@ -1618,7 +2116,10 @@ fn delete_pair_failed_if_not_found() {
         },
     ];
 
-    let msg = ExecuteMsg::RemovePair { asset_infos };
+    let msg = ExecuteMsg::RemovePair {
+        asset_infos,
+        pair_variant: None,
+    };
     let env = mock_env();
     let info = mock_info("addr0000", &[]);
     let res = execute(deps.as_mut(), env, info, msg);
@@ -1667,7 +2168,6 @@ fn update_pair_config() {
     #[cfg(not(feature = "osmosis"))]
     let msg = ExecuteMsg::UpdatePairConfig {
         pair_addr: "pair_addr".to_string(),
-        owner: Some("new_owner".to_string()),
         fee_collector_addr: None,
         pool_fees: Some(pool_fee.clone()),
         feature_toggle: None,
@@ -1676,7 +2176,6 @@ fn update_pair_config() {
     #[cfg(feature = "osmosis")]
     let msg = ExecuteMsg::UpdatePairConfig {
         pair_addr: "pair_addr".to_string(),
-        owner: Some("new_owner".to_string()),
         fee_collector_addr: None,
         pool_fees: Some(pool_fee.clone()),
         feature_toggle: None,
@@ -1689,7 +2188,6 @@ fn update_pair_config() {
 
     #[cfg(not(feature = "osmosis"))]
     let expected_msg = pool_network::pair::ExecuteMsg::UpdateConfig {
-        owner: Some("new_owner".to_string()),
         fee_collector_addr: None,
         pool_fees: Some(pool_fee),
         feature_toggle: None,
@@ -1697,7 +2195,6 @@ fn update_pair_config() {
 
     #[cfg(feature = "osmosis")]
     let expected_msg = pool_network::pair::ExecuteMsg::UpdateConfig {
-        owner: Some("new_owner".to_string()),
         fee_collector_addr: None,
         pool_fees: Some(pool_fee),
         feature_toggle: None,
@@ -1954,3 +2451,441 @@ fn create_trio_with_native_tokens_token_factory_lp() {
         }
     );
 }
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn update_pair_creation_config() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let pair_creation_config = PairCreationConfig {
+        permissionless: true,
+        listing_fee: Some(coin(1_000u128, "uwhale")),
+        fee_tiers: vec![PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            swap_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        }],
+    };
+
+    // permissioned by default
+    let res: PairCreationConfig =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::PairCreationConfig {}).unwrap())
+            .unwrap();
+    assert!(!res.permissionless);
+
+    // only the owner can update it
+    let msg = ExecuteMsg::UpdatePairCreationConfig {
+        pair_creation_config: pair_creation_config.clone(),
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("noadmin", &[]), msg);
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // the listing fee can't be zero
+    let msg = ExecuteMsg::UpdatePairCreationConfig {
+        pair_creation_config: PairCreationConfig {
+            listing_fee: Some(coin(0u128, "uwhale")),
+            ..pair_creation_config.clone()
+        },
+    };
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg);
+    match res {
+        Err(ContractError::InvalidListingFee {}) => (),
+        _ => panic!("should return ContractError::InvalidListingFee"),
+    }
+
+    let msg = ExecuteMsg::UpdatePairCreationConfig {
+        pair_creation_config: pair_creation_config.clone(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let res: PairCreationConfig =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::PairCreationConfig {}).unwrap())
+            .unwrap();
+    assert_eq!(res, pair_creation_config);
+}
+
+#[test]
+fn emergency_pause() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let swaps_paused = PausedFeatures {
+        swaps: true,
+        ..PausedFeatures::default()
+    };
+
+    // nothing is paused by default
+    let res: EmergencyPauseResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::EmergencyPause {}).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        EmergencyPauseResponse {
+            pauser: None,
+            paused_features: PausedFeatures::default(),
+        }
+    );
+
+    // only the owner can grant the pauser role
+    let msg = ExecuteMsg::UpdateRole {
+        role: Role::Pauser,
+        address: Some("pauser".to_string()),
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("pauser", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    // the pauser can pause swaps
+    let msg = ExecuteMsg::EmergencyPause {
+        paused_features: swaps_paused.clone(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("pauser", &[]), msg).unwrap();
+
+    let res: EmergencyPauseResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::EmergencyPause {}).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        EmergencyPauseResponse {
+            pauser: Some(Addr::unchecked("pauser")),
+            paused_features: swaps_paused.clone(),
+        }
+    );
+
+    // but can't unpause them
+    let msg = ExecuteMsg::EmergencyPause {
+        paused_features: PausedFeatures::default(),
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("pauser", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // anyone else can't pause anything
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("noadmin", &[]),
+        ExecuteMsg::EmergencyPause {
+            paused_features: PausedFeatures {
+                deposits: true,
+                ..swaps_paused
+            },
+        },
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // the owner can unpause them
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    let res: EmergencyPauseResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::EmergencyPause {}).unwrap()).unwrap();
+    assert_eq!(res.paused_features, PausedFeatures::default());
+}
+
+#[test]
+fn update_pair_circuit_breaker() {
+    let mut deps = mock_dependencies(&[]);
+    deps = init(deps);
+
+    let circuit_breaker = Some(CircuitBreaker {
+        max_price_change: Decimal::percent(10u64),
+    });
+    let msg = ExecuteMsg::UpdatePairCircuitBreaker {
+        pair_addr: "pair0000".to_string(),
+        circuit_breaker: circuit_breaker.clone(),
+    };
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("noadmin", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg {
+            id: 0,
+            msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "pair0000".to_string(),
+                msg: to_json_binary(&pool_network::pair::ExecuteMsg::UpdateCircuitBreaker {
+                    circuit_breaker,
+                })
+                .unwrap(),
+                funds: vec![],
+            }),
+            gas_limit: None,
+            reply_on: ReplyOn::Never,
+        }]
+    );
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn create_pair_permissionless() {
+    let mut deps = mock_dependencies(&[coin(10u128, "uusd".to_string())]);
+    deps = init(deps);
+    deps.querier
+        .with_pool_factory(&[], &[("uusd".to_string(), 6u8)]);
+    let asset_infos = [
+        AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        AssetInfo::Token {
+            contract_addr: "asset0001".to_string(),
+        },
+    ];
+
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+
+    let msg = ExecuteMsg::UpdatePairCreationConfig {
+        pair_creation_config: PairCreationConfig {
+            permissionless: true,
+            listing_fee: Some(coin(1_000u128, "uwhale")),
+            fee_tiers: vec![pool_fees.clone()],
+        },
+    };
+    execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    // pool fees that are not an approved fee tier are rejected
+    let msg = ExecuteMsg::CreatePair {
+        asset_infos: asset_infos.clone(),
+        pool_fees: PoolFee {
+            swap_fee: Fee {
+                share: Decimal::percent(5u64),
+            },
+            ..pool_fees.clone()
+        },
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+    let info = mock_info("creator", &[coin(1_000u128, "uwhale")]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg);
+    match res {
+        Err(ContractError::InvalidFeeTier {}) => (),
+        _ => panic!("should return ContractError::InvalidFeeTier"),
+    }
+
+    // the listing fee must be paid
+    let msg = ExecuteMsg::CreatePair {
+        asset_infos: asset_infos.clone(),
+        pool_fees: pool_fees.clone(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+    let info = mock_info("creator", &[coin(999u128, "uwhale")]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg.clone());
+    match res {
+        Err(ContractError::ListingFeeNotPaid { .. }) => (),
+        _ => panic!("should return ContractError::ListingFeeNotPaid"),
+    }
+
+    // the listing fee goes to the fee collector and the rest of the funds to the pair
+    let info = mock_info("creator", &[coin(1_500u128, "uwhale"), coin(1u128, "uusd")]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg::new(BankMsg::Send {
+                to_address: "collector".to_string(),
+                amount: vec![coin(1_000u128, "uwhale")],
+            }),
+            SubMsg {
+                id: 1,
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+                msg: WasmMsg::Instantiate {
+                    msg: to_json_binary(&PairInstantiateMsg {
+                        asset_infos,
+                        token_code_id: 123u64,
+                        asset_decimals: [6u8, 8u8],
+                        pool_fees,
+                        fee_collector_addr: "collector".to_string(),
+                        pair_type: PairType::ConstantProduct,
+                        token_factory_lp: false,
+                    })
+                    .unwrap(),
+                    code_id: 321u64,
+                    funds: vec![coin(500u128, "uwhale"), coin(1u128, "uusd")],
+                    label: "uusd-mAAPL pair".to_string(),
+                    admin: Some(MOCK_CONTRACT_ADDR.to_string()),
+                }
+                .into(),
+            },
+        ]
+    );
+}
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn admin_roles() {
+    let mut deps = mock_dependencies(&[coin(1u128, "uluna".to_string())]);
+    deps = init(deps);
+
+    for (role, address) in [
+        (Role::FeeManager, "fee_manager"),
+        (Role::Migrator, "migrator"),
+    ] {
+        let msg = ExecuteMsg::UpdateRole {
+            role,
+            address: Some(address.to_string()),
+        };
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(address, &[]),
+            msg.clone(),
+        );
+        match res {
+            Err(ContractError::Unauthorized {}) => (),
+            _ => panic!("should return ContractError::Unauthorized"),
+        }
+        execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    }
+
+    let res: RolesResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Roles {}).unwrap()).unwrap();
+    assert_eq!(
+        res,
+        RolesResponse {
+            fee_manager: Some(Addr::unchecked("fee_manager")),
+            pauser: None,
+            migrator: Some(Addr::unchecked("migrator")),
+        }
+    );
+
+    // the fee manager can update the fees of a pair, but nothing else
+    let pool_fees = PoolFee {
+        protocol_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        swap_fee: Fee {
+            share: Decimal::percent(1u64),
+        },
+        burn_fee: Fee {
+            share: Decimal::zero(),
+        },
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("fee_manager", &[]),
+        ExecuteMsg::UpdatePairConfig {
+            pair_addr: "pair_addr".to_string(),
+            fee_collector_addr: None,
+            pool_fees: Some(pool_fees.clone()),
+            feature_toggle: None,
+        },
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("fee_manager", &[]),
+        ExecuteMsg::UpdatePairConfig {
+            pair_addr: "pair_addr".to_string(),
+            fee_collector_addr: Some("fee_manager".to_string()),
+            pool_fees: Some(pool_fees),
+            feature_toggle: None,
+        },
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    // only the migrator can migrate pairs on behalf of the owner
+    let msg = ExecuteMsg::MigratePair {
+        code_id: Some(123u64),
+        contract: "contract0000".to_string(),
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("fee_manager", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+    execute(deps.as_mut(), mock_env(), mock_info("migrator", &[]), msg).unwrap();
+
+    // the roles can't transfer the ownership of the pairs
+    let msg = ExecuteMsg::UpdatePairOwnership {
+        pair_addr: "pair_addr".to_string(),
+        action: OwnershipAction::ProposeNewOwner {
+            new_owner: "new_owner".to_string(),
+            expiry: None,
+        },
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("migrator", &[]),
+        msg.clone(),
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    let res = execute(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: "pair_addr".to_string(),
+            msg: to_json_binary(&pool_network::pair::ExecuteMsg::UpdateOwnership(
+                OwnershipAction::ProposeNewOwner {
+                    new_owner: "new_owner".to_string(),
+                    expiry: None,
+                }
+            ))
+            .unwrap(),
+            funds: vec![],
+        })]
+    );
+}
@@ -8,6 +8,7 @@ use cosmwasm_std::{
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
+use white_whale_std::ownership::{self, OwnershipAction};
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use white_whale_std::pool_network::asset::is_factory_token;
 use white_whale_std::pool_network::asset::{
@@ -18,7 +19,9 @@ use white_whale_std::pool_network::asset::{
 use white_whale_std::pool_network::denom_injective::{Coin, MsgBurn, MsgMint};
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::{Coin, MsgBurn, MsgMint};
-use white_whale_std::pool_network::pair::{Config, Cw20HookMsg, FeatureToggle, PoolFee};
+use white_whale_std::pool_network::pair::{
+    CircuitBreaker, Config, Cw20HookMsg, FeatureToggle, PoolFee,
+};
 use white_whale_std::pool_network::{swap, U256};
 
 use crate::error::ContractError;
@@ -27,8 +30,8 @@ use crate::helpers::{
     compute_d, compute_lp_mint_amount_for_stableswap_deposit, get_protocol_fee_for_asset,
 };
 use crate::state::{
-    store_fee, ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES,
-    CONFIG, PAIR_INFO,
+    store_fee, ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, CIRCUIT_BREAKER,
+    COLLECTED_PROTOCOL_FEES, CONFIG, PAIR_INFO,
 };
 
 const MINIMUM_COLLECTABLE_BALANCE: Uint128 = Uint128::new(1_000u128);
@@ -43,7 +46,8 @@ pub fn receive_cw20(
     cw20_msg: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
     let contract_addr = info.sender.clone();
-    let feature_toggle: FeatureToggle = CONFIG.load(deps.storage)?.feature_toggle;
+    let feature_toggle: FeatureToggle =
+        helpers::get_feature_toggle(&deps.querier, &CONFIG.load(deps.storage)?)?;
 
     match from_json(&cw20_msg.msg) {
         Ok(Cw20HookMsg::Swap {
@@ -130,7 +134,8 @@ pub fn provide_liquidity(
     receiver: Option<String>,
 ) -> Result<Response, ContractError> {
     // check if the deposit feature is enabled
-    let feature_toggle: FeatureToggle = CONFIG.load(deps.storage)?.feature_toggle;
+    let feature_toggle: FeatureToggle =
+        helpers::get_feature_toggle(&deps.querier, &CONFIG.load(deps.storage)?)?;
     if !feature_toggle.deposits_enabled {
         return Err(ContractError::OperationDisabled(
             "provide_liquidity".to_string(),
@@ -496,6 +501,34 @@ pub fn swap(
         swap_computation.spread_amount,
     )?;
 
+    // check the price move caused by the swap against the circuit breaker
+    let offer_pool_after = offer_pool.amount.checked_add(offer_amount)?;
+    let ask_pool_after = ask_pool.amount.checked_sub(
+        swap_computation
+            .return_amount
+            .checked_add(swap_computation.protocol_fee_amount)?
+            .checked_add(swap_computation.burn_fee_amount)?,
+    )?;
+    let (pools_before, pools_after) = if offer_asset.info.equal(&pools[0].info) {
+        (
+            [offer_pool.amount, ask_pool.amount],
+            [offer_pool_after, ask_pool_after],
+        )
+    } else {
+        (
+            [ask_pool.amount, offer_pool.amount],
+            [ask_pool_after, offer_pool_after],
+        )
+    };
+    helpers::assert_circuit_breaker(
+        deps.storage,
+        env.block.height,
+        &pair_info.pair_type,
+        pair_info.asset_decimals,
+        pools_before,
+        pools_after,
+    )?;
+
     let receiver = to.unwrap_or_else(|| sender.clone());
 
     let mut messages: Vec<CosmosMsg> = vec![];
@@ -601,7 +634,6 @@ pub fn swap(
 pub fn update_config(
     deps: DepsMut,
     info: MessageInfo,
-    owner: Option<String>,
     fee_collector_addr: Option<String>,
     pool_fees: Option<PoolFee>,
     feature_toggle: Option<FeatureToggle>,
@@ -612,12 +644,6 @@ pub fn update_config(
         return Err(ContractError::Std(StdError::generic_err("unauthorized")));
     }
 
-    if let Some(owner) = owner {
-        // validate address format
-        let _ = deps.api.addr_validate(&owner)?;
-        config.owner = deps.api.addr_validate(&owner)?;
-    }
-
     if let Some(pool_fees) = pool_fees {
         pool_fees.is_valid()?;
         config.pool_fees = pool_fees;
@@ -642,6 +668,55 @@ pub fn update_config(
     Ok(Response::new().add_attribute("action", "update_config"))
 }
 
+/// Proposes, accepts or cancels a transfer of the ownership of the pool. Once the ownership is
+/// accepted, the new owner is stored in the [Config].
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config: Config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
+
+/// Updates the [CircuitBreaker] of the pool. Only the owner of the contract can do this.
+pub fn update_circuit_breaker(
+    deps: DepsMut,
+    info: MessageInfo,
+    circuit_breaker: Option<CircuitBreaker>,
+) -> Result<Response, ContractError> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let max_price_change = match circuit_breaker {
+        Some(circuit_breaker) => {
+            circuit_breaker.is_valid()?;
+            CIRCUIT_BREAKER.save(deps.storage, &circuit_breaker)?;
+            circuit_breaker.max_price_change.to_string()
+        }
+        None => {
+            CIRCUIT_BREAKER.remove(deps.storage);
+            "none".to_string()
+        }
+    };
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_circuit_breaker".to_string()),
+        ("max_price_change", max_price_change),
+    ]))
+}
+
 /// Collects all protocol fees accrued by the pool
 pub fn collect_protocol_fees(deps: DepsMut) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
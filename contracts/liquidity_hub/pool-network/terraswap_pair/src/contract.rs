@@ -9,6 +9,7 @@ use cw2::{get_contract_version, set_contract_version};
 use protobuf::Message;
 use semver::Version;
 
+use white_whale_std::ownership::query_ownership_proposal;
 use white_whale_std::pool_network::asset::{AssetInfoRaw, PairInfoRaw};
 use white_whale_std::pool_network::pair::{
     Config, ExecuteMsg, FeatureToggle, InstantiateMsg, MigrateMsg, QueryMsg,
@@ -127,6 +128,15 @@ pub fn execute(
             receiver,
         } => commands::provide_liquidity(deps, env, info, assets, slippage_tolerance, receiver),
         ExecuteMsg::WithdrawLiquidity {} => {
+            // check if the withdrawal feature is enabled
+            let feature_toggle: FeatureToggle =
+                helpers::get_feature_toggle(&deps.querier, &CONFIG.load(deps.storage)?)?;
+            if !feature_toggle.withdrawals_enabled {
+                return Err(ContractError::OperationDisabled(
+                    "withdraw_liquidity".to_string(),
+                ));
+            }
+
             // validate that the asset sent is the token factory LP token
             let pair_info = PAIR_INFO.load(deps.storage)?;
             let lp_token_denom = match pair_info.liquidity_token {
@@ -147,7 +157,8 @@ pub fn execute(
             to,
         } => {
             // check if the swap feature is enabled
-            let feature_toggle: FeatureToggle = CONFIG.load(deps.storage)?.feature_toggle;
+            let feature_toggle: FeatureToggle =
+                helpers::get_feature_toggle(&deps.querier, &CONFIG.load(deps.storage)?)?;
             if !feature_toggle.swaps_enabled {
                 return Err(ContractError::OperationDisabled("swap".to_string()));
             }
@@ -175,7 +186,6 @@ pub fn execute(
         }
         #[cfg(feature = "osmosis")]
         ExecuteMsg::UpdateConfig {
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -183,7 +193,6 @@ pub fn execute(
         } => commands::update_config(
             deps,
             info,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
@@ -191,20 +200,22 @@ pub fn execute(
         ),
         #[cfg(not(feature = "osmosis"))]
         ExecuteMsg::UpdateConfig {
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
         } => commands::update_config(
             deps,
             info,
-            owner,
             fee_collector_addr,
             pool_fees,
             feature_toggle,
             None,
         ),
         ExecuteMsg::CollectProtocolFees {} => commands::collect_protocol_fees(deps),
+        ExecuteMsg::UpdateCircuitBreaker { circuit_breaker } => {
+            commands::update_circuit_breaker(deps, info, circuit_breaker)
+        }
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
     }
 }
 
@@ -256,6 +267,10 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             ALL_TIME_BURNED_FEES,
             None,
         )?)?),
+        QueryMsg::CircuitBreaker {} => Ok(to_json_binary(&queries::query_circuit_breaker(deps)?)?),
+        QueryMsg::OwnershipProposal {} => {
+            Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+        }
     }
 }
 
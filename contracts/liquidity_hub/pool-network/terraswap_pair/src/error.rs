@@ -1,6 +1,6 @@
 use cosmwasm_std::{
-    CheckedFromRatioError, CheckedMultiplyRatioError, ConversionOverflowError, DivideByZeroError,
-    OverflowError, StdError, Uint128,
+    CheckedFromRatioError, CheckedMultiplyRatioError, ConversionOverflowError, Decimal,
+    DivideByZeroError, OverflowError, StdError, Uint128,
 };
 use semver::Version;
 use thiserror::Error;
@@ -69,6 +69,9 @@ pub enum ContractError {
 
     #[error("The token factory feature is not enabled")]
     TokenFactoryNotEnabled {},
+
+    #[error("Circuit breaker tripped, the swap moves the pool price more than {max_price_change} within the block")]
+    CircuitBreakerTripped { max_price_change: Decimal },
 }
 
 impl From<semver::Error> for ContractError {
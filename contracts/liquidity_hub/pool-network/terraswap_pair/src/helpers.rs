@@ -4,24 +4,25 @@ use cosmwasm_schema::cw_serde;
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use cosmwasm_std::CosmosMsg;
 use cosmwasm_std::{
-    to_json_binary, Decimal, Decimal256, DepsMut, Env, ReplyOn, Response, StdError, StdResult,
-    Storage, SubMsg, Uint128, Uint256, Uint512, WasmMsg,
+    to_json_binary, Decimal, Decimal256, DepsMut, Env, QuerierWrapper, ReplyOn, Response, StdError,
+    StdResult, Storage, SubMsg, Uint128, Uint256, Uint512, WasmMsg,
 };
 use cw20::MinterResponse;
 use cw_storage_plus::Item;
 
+use white_whale_std::pause::query_paused_features;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, AssetInfoRaw, PairType};
 #[cfg(feature = "injective")]
 use white_whale_std::pool_network::denom_injective::MsgCreateDenom;
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::MsgCreateDenom;
-use white_whale_std::pool_network::pair::{InstantiateMsg, PoolFee};
+use white_whale_std::pool_network::pair::{Config, FeatureToggle, InstantiateMsg, PoolFee};
 use white_whale_std::pool_network::token::InstantiateMsg as TokenInstantiateMsg;
 
 use crate::contract::INSTANTIATE_REPLY_ID;
 use crate::error::ContractError;
 use crate::math::Decimal256Helper;
-use crate::state::{LP_SYMBOL, PAIR_INFO};
+use crate::state::{BLOCK_START_PRICE, CIRCUIT_BREAKER, LP_SYMBOL, PAIR_INFO};
 
 /// The amount of iterations to perform when calculating the Newton-Raphson approximation.
 const NEWTON_ITERATIONS: u64 = 32;
@@ -684,3 +685,118 @@ pub fn create_lp_token(
         }))
     }
 }
+
+/// Gets the [FeatureToggle] of the pair, with the features paused factory-wide by the owner of the
+/// pair, i.e. the pool factory, disabled.
+pub fn get_feature_toggle(querier: &QuerierWrapper, config: &Config) -> StdResult<FeatureToggle> {
+    let paused_features = query_paused_features(querier, config.owner.clone())?;
+
+    Ok(FeatureToggle {
+        withdrawals_enabled: config.feature_toggle.withdrawals_enabled
+            && !paused_features.withdrawals,
+        deposits_enabled: config.feature_toggle.deposits_enabled && !paused_features.deposits,
+        swaps_enabled: config.feature_toggle.swaps_enabled && !paused_features.swaps,
+    })
+}
+
+/// Computes the spot price of the first asset of the pair in terms of the second one, i.e. the
+/// marginal amount of the second asset a tiny amount of the first one is swapped for, fees aside.
+///
+/// For stableswap pairs, the price is derived from the invariant, which for two coins reads
+/// `ann * (x + y) + D = ann * D + D^3 / (4 * x * y)`, with `ann = amp * n_coins` as in the swap
+/// computation. Its partial derivatives give `price = (ann + D^3 / (4 * x^2 * y)) / (ann + D^3 / (4 * x * y^2))`,
+/// which tends to the reserve ratio `y / x` of the constant product pairs as `amp` goes to zero.
+pub fn compute_spot_price(
+    pools: [Uint128; 2],
+    pair_type: &PairType,
+    asset_decimals: [u8; 2],
+) -> Result<Decimal256, ContractError> {
+    match pair_type {
+        PairType::ConstantProduct => Ok(Decimal256::from_ratio(pools[1], pools[0])),
+        PairType::StableSwap { amp } => {
+            let x = Decimal256::decimal_with_precision(pools[0], asset_decimals[0])?;
+            let y = Decimal256::decimal_with_precision(pools[1], asset_decimals[1])?;
+            let d = calculate_stableswap_d(x, y, amp, asset_decimals[0].max(asset_decimals[1]))?;
+
+            let ann = Decimal256::from_ratio(
+                Uint256::from_u128((*amp).into()).checked_mul(N_COINS)?,
+                1u8,
+            );
+            let four = Decimal256::from_ratio(4u8, 1u8);
+            // work with D / x and D / y to keep the powers of D from overflowing
+            let d_over_x = d.checked_div(x)?;
+            let d_over_y = d.checked_div(y)?;
+
+            let numerator = ann.checked_add(
+                d_over_x
+                    .checked_mul(d_over_x)?
+                    .checked_mul(d_over_y)?
+                    .checked_div(four)?,
+            )?;
+            let denominator = ann.checked_add(
+                d_over_x
+                    .checked_mul(d_over_y)?
+                    .checked_mul(d_over_y)?
+                    .checked_div(four)?,
+            )?;
+
+            Ok(numerator.checked_div(denominator)?)
+        }
+    }
+}
+
+/// Checks the price move caused by a swap against the circuit breaker of the pool, if any. The spot
+/// price, which follows the curve of the pair, is compared with the one the pool had at the start of
+/// the block, which is stored on the first swap of every block.
+pub fn assert_circuit_breaker(
+    storage: &mut dyn Storage,
+    block_height: u64,
+    pair_type: &PairType,
+    asset_decimals: [u8; 2],
+    pools_before: [Uint128; 2],
+    pools_after: [Uint128; 2],
+) -> Result<(), ContractError> {
+    let circuit_breaker = match CIRCUIT_BREAKER.may_load(storage)? {
+        Some(circuit_breaker) => circuit_breaker,
+        None => return Ok(()),
+    };
+
+    // the price is not defined while any of the pools is empty
+    if pools_before
+        .iter()
+        .chain(pools_after.iter())
+        .any(|pool| pool.is_zero())
+    {
+        return Ok(());
+    }
+
+    let price_before = compute_spot_price(pools_before, pair_type, asset_decimals)?;
+    let price_after = compute_spot_price(pools_after, pair_type, asset_decimals)?;
+
+    let block_start_price = match BLOCK_START_PRICE.may_load(storage)? {
+        Some((height, price)) if height == block_height => price,
+        _ => {
+            BLOCK_START_PRICE.save(storage, &(block_height, price_before))?;
+            price_before
+        }
+    };
+
+    if block_start_price.is_zero() {
+        return Ok(());
+    }
+
+    let price_change = if price_after > block_start_price {
+        price_after - block_start_price
+    } else {
+        block_start_price - price_after
+    }
+    .checked_div(block_start_price)?;
+
+    if price_change > Decimal256::from(circuit_breaker.max_price_change) {
+        return Err(ContractError::CircuitBreakerTripped {
+            max_price_change: circuit_breaker.max_price_change,
+        });
+    }
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use white_whale_std::pool_network::asset::{
     get_total_share, Asset, AssetInfoRaw, PairInfo, PairInfoRaw, PairType,
 };
 use white_whale_std::pool_network::pair::{
-    ConfigResponse, PoolResponse, ProtocolFeesResponse, ReverseSimulationResponse,
+    CircuitBreaker, ConfigResponse, PoolResponse, ProtocolFeesResponse, ReverseSimulationResponse,
     SimulationResponse,
 };
 
@@ -16,7 +16,9 @@ use crate::helpers::{
     self, calculate_stableswap_y, get_protocol_fee_for_asset, StableSwapDirection,
 };
 use crate::math::Decimal256Helper;
-use crate::state::{get_fees_for_asset, COLLECTED_PROTOCOL_FEES, CONFIG, PAIR_INFO};
+use crate::state::{
+    get_fees_for_asset, CIRCUIT_BREAKER, COLLECTED_PROTOCOL_FEES, CONFIG, PAIR_INFO,
+};
 
 /// Queries the [PairInfo] of the pool
 pub fn query_pair_info(deps: Deps) -> Result<PairInfo, ContractError> {
@@ -312,6 +314,11 @@ pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
     Ok(config)
 }
 
+/// Queries the [CircuitBreaker] of the pool, if any
+pub fn query_circuit_breaker(deps: Deps) -> Result<Option<CircuitBreaker>, ContractError> {
+    Ok(CIRCUIT_BREAKER.may_load(deps.storage)?)
+}
+
 /// Queries the fees on the pool for the given fees_storage_item
 pub fn query_fees(
     deps: Deps,
@@ -1,8 +1,8 @@
-use cosmwasm_std::{StdError, StdResult, Storage, Uint128};
+use cosmwasm_std::{Decimal256, StdError, StdResult, Storage, Uint128};
 use cw_storage_plus::Item;
 
 use white_whale_std::pool_network::asset::{Asset, PairInfoRaw};
-use white_whale_std::pool_network::pair::Config;
+use white_whale_std::pool_network::pair::{CircuitBreaker, Config};
 
 pub const LP_SYMBOL: &str = "uLP";
 
@@ -17,6 +17,12 @@ pub const ALL_TIME_COLLECTED_PROTOCOL_FEES: Item<Vec<Asset>> =
 // Fees that have been burned by the pool since the pool's inception
 pub const ALL_TIME_BURNED_FEES: Item<Vec<Asset>> = Item::new("all_time_burned_fees");
 
+/// The circuit breaker of the pool. If not set, swaps are not limited by price moves.
+pub const CIRCUIT_BREAKER: Item<CircuitBreaker> = Item::new("circuit_breaker");
+/// The price of the pool at the start of the block the last swap happened in, i.e. (block height,
+/// price)
+pub const BLOCK_START_PRICE: Item<(u64, Decimal256)> = Item::new("block_start_price");
+
 /// Stores the fee for an asset in the given fees_storage_item
 pub fn store_fee(
     storage: &mut dyn Storage,
@@ -4,6 +4,7 @@ use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{to_json_binary, Coin, Decimal, Uint128};
 use cw20::Cw20ReceiveMsg;
 use white_whale_std::fee::Fee;
+use white_whale_std::pause::PausedFeatures;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairType};
 use white_whale_std::pool_network::mock_querier::mock_dependencies;
 use white_whale_std::pool_network::pair::ExecuteMsg::UpdateConfig;
@@ -61,7 +62,6 @@ fn test_feature_toggle_swap_disabled() {
 
     // all features are enabled by default, let's disable swaps
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: Some(FeatureToggle {
@@ -171,7 +171,6 @@ fn test_feature_toggle_withdrawals_disabled() {
 
     // all features are enabled by default, let's disable withdrawals
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: Some(FeatureToggle {
@@ -248,7 +247,6 @@ fn test_feature_toggle_deposits_disabled() {
 
     // all features are enabled by default, let's disable deposits
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: Some(FeatureToggle {
@@ -294,3 +292,83 @@ fn test_feature_toggle_deposits_disabled() {
         _ => panic!("should return OperationDisabled(provide_liquidity)"),
     }
 }
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn test_emergency_pause_disables_swaps() {
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: Uint128::from(200u128),
+    }]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+        (&"asset0000".to_string(), &[]),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::percent(1u64),
+            },
+            swap_fee: Fee {
+                share: Decimal::percent(3u64),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
+
+    // the pair's feature toggle enables swaps, but they are paused on the factory
+    deps.querier.with_paused_features(PausedFeatures {
+        swaps: true,
+        ..PausedFeatures::default()
+    });
+
+    let offer_amount = Uint128::from(1500000000u128);
+    let msg = ExecuteMsg::Swap {
+        offer_asset: Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            amount: offer_amount,
+        },
+        belief_price: None,
+        max_spread: None,
+        to: None,
+    };
+    let info = mock_info(
+        "addr0000",
+        &[Coin {
+            denom: "uusd".to_string(),
+            amount: offer_amount,
+        }],
+    );
+    let res = execute(deps.as_mut(), env, info, msg);
+
+    match res {
+        Err(ContractError::OperationDisabled(operation)) => assert_eq!(operation, "swap"),
+        _ => panic!("should return ContractError::OperationDisabled(swap)"),
+    }
+}
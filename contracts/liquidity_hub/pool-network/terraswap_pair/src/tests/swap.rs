@@ -1,7 +1,7 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    attr, coins, from_json, to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, Reply, ReplyOn,
-    SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+    attr, coins, from_json, to_json_binary, BankMsg, Coin, CosmosMsg, Decimal, Decimal256, Reply,
+    ReplyOn, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 
@@ -9,8 +9,8 @@ use white_whale_std::fee::Fee;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairType};
 use white_whale_std::pool_network::mock_querier::mock_dependencies;
 use white_whale_std::pool_network::pair::{
-    Cw20HookMsg, ExecuteMsg, InstantiateMsg, PoolFee, QueryMsg, ReverseSimulationResponse,
-    SimulationResponse,
+    CircuitBreaker, Cw20HookMsg, ExecuteMsg, InstantiateMsg, PoolFee, QueryMsg,
+    ReverseSimulationResponse, SimulationResponse,
 };
 
 #[cfg(feature = "osmosis")]
@@ -18,7 +18,7 @@ use anybuf::Anybuf;
 
 use crate::contract::{execute, instantiate, query, reply};
 use crate::error::ContractError;
-use crate::helpers::compute_swap;
+use crate::helpers::{compute_spot_price, compute_swap};
 use crate::queries::query_fees;
 use crate::state::{
     ALL_TIME_BURNED_FEES, ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES,
@@ -1481,3 +1481,174 @@ fn test_stableswap_with_no_swap_amount() {
         Uint128::zero()
     );
 }
+
+#[cfg(not(feature = "osmosis"))]
+#[test]
+fn circuit_breaker_trips_on_extreme_price_moves() {
+    let total_share = Uint128::from(30000000000u128);
+    let asset_pool_amount = Uint128::from(20000000000u128);
+    let collateral_pool_amount = Uint128::from(30000000000u128);
+    let offer_amount = Uint128::from(1500000000u128);
+
+    let mut deps = mock_dependencies(&[Coin {
+        denom: "uusd".to_string(),
+        amount: collateral_pool_amount + offer_amount,
+        /* user deposit must be pre-applied */
+    }]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &total_share)],
+        ),
+        (
+            &"asset0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &asset_pool_amount)],
+        ),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::from_ratio(1u128, 1000u128),
+            },
+            swap_fee: Fee {
+                share: Decimal::from_ratio(3u128, 1000u128),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+
+    let env = mock_env();
+    let info = mock_info("addr0000", &[]);
+    instantiate(deps.as_mut(), env.clone(), info.clone(), msg).unwrap();
+
+    // only the owner can set the circuit breaker
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0001", &[]),
+        ExecuteMsg::UpdateCircuitBreaker {
+            circuit_breaker: Some(CircuitBreaker {
+                max_price_change: Decimal::percent(5u64),
+            }),
+        },
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::UpdateCircuitBreaker {
+            circuit_breaker: Some(CircuitBreaker {
+                max_price_change: Decimal::percent(5u64),
+            }),
+        },
+    )
+    .unwrap();
+
+    let circuit_breaker: Option<CircuitBreaker> =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::CircuitBreaker {}).unwrap()).unwrap();
+    assert_eq!(
+        circuit_breaker,
+        Some(CircuitBreaker {
+            max_price_change: Decimal::percent(5u64),
+        })
+    );
+
+    // the swap moves the price of the pool by ~9%, which trips the circuit breaker
+    let swap_msg = ExecuteMsg::Swap {
+        offer_asset: Asset {
+            info: AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            amount: offer_amount,
+        },
+        belief_price: None,
+        max_spread: Some(Decimal::percent(10u64)),
+        to: None,
+    };
+    let swap_info = mock_info(
+        "addr0001",
+        &[Coin {
+            denom: "uusd".to_string(),
+            amount: offer_amount,
+        }],
+    );
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        swap_info.clone(),
+        swap_msg.clone(),
+    );
+    match res {
+        Err(ContractError::CircuitBreakerTripped { max_price_change }) => {
+            assert_eq!(max_price_change, Decimal::percent(5u64))
+        }
+        _ => panic!("should return ContractError::CircuitBreakerTripped"),
+    }
+
+    // a looser circuit breaker lets the swap through
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("addr0000", &[]),
+        ExecuteMsg::UpdateCircuitBreaker {
+            circuit_breaker: Some(CircuitBreaker {
+                max_price_change: Decimal::percent(10u64),
+            }),
+        },
+    )
+    .unwrap();
+    execute(deps.as_mut(), env, swap_info, swap_msg).unwrap();
+}
+
+#[test]
+fn spot_price_follows_the_curve_of_the_pair() {
+    // constant product pairs are priced by the ratio of the reserves
+    let price = compute_spot_price(
+        [Uint128::new(2_000_000), Uint128::new(3_000_000)],
+        &PairType::ConstantProduct,
+        [6u8, 6u8],
+    )
+    .unwrap();
+    assert_eq!(price, Decimal256::from_ratio(3u128, 2u128));
+
+    // a balanced stableswap pair is priced at par, regardless of the decimals of the assets
+    let price = compute_spot_price(
+        [Uint128::new(1_000_000_000), Uint128::new(100_000_000_000)],
+        &PairType::StableSwap { amp: 100 },
+        [6u8, 8u8],
+    )
+    .unwrap();
+    assert_eq!(price, Decimal256::one());
+
+    // an imbalanced stableswap pair stays much closer to par than the ratio of its reserves
+    let price = compute_spot_price(
+        [Uint128::new(1_000_000_000), Uint128::new(2_000_000_000)],
+        &PairType::StableSwap { amp: 100 },
+        [6u8, 6u8],
+    )
+    .unwrap();
+    assert!(price > Decimal256::one());
+    assert!(price < Decimal256::from_ratio(11u128, 10u128));
+}
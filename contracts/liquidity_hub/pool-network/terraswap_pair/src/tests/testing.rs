@@ -8,11 +8,16 @@ use cosmwasm_std::{
 use cw20::MinterResponse;
 
 use white_whale_std::fee::Fee;
+use white_whale_std::ownership::{
+    OwnershipAction, OwnershipProposal, DEFAULT_OWNERSHIP_PROPOSAL_EXPIRY,
+};
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
 #[cfg(feature = "osmosis_token_factory")]
 use white_whale_std::pool_network::denom_osmosis::MsgCreateDenom;
 use white_whale_std::pool_network::mock_querier::mock_dependencies;
-use white_whale_std::pool_network::pair::ExecuteMsg::UpdateConfig;
+use white_whale_std::pool_network::pair::ExecuteMsg::{
+    UpdateCircuitBreaker, UpdateConfig, UpdateOwnership,
+};
 use white_whale_std::pool_network::pair::{Config, InstantiateMsg, PoolFee, QueryMsg};
 use white_whale_std::pool_network::swap::assert_max_spread;
 use white_whale_std::pool_network::token::InstantiateMsg as TokenInstantiateMsg;
@@ -417,7 +422,6 @@ fn test_update_config_unsuccessful() {
 
     // update config with invalid fees
     let update_config_message = UpdateConfig {
-        owner: None,
         fee_collector_addr: None,
         pool_fees: Some(PoolFee {
             protocol_fee: Fee {
@@ -443,7 +447,6 @@ fn test_update_config_unsuccessful() {
     // an unauthorized party tries to update the config
     let info = mock_info("unauthorized", &[]);
     let update_config_message = UpdateConfig {
-        owner: Some("unauthorized".to_string()),
         fee_collector_addr: None,
         pool_fees: None,
         feature_toggle: None,
@@ -569,7 +572,6 @@ fn test_update_config_successful() {
 
     #[cfg(not(feature = "osmosis"))]
     let update_config_message = UpdateConfig {
-        owner: Some("new_admin".to_string()),
         fee_collector_addr: Some("new_collector".to_string()),
         pool_fees: Some(pool_fees),
         feature_toggle: None,
@@ -577,7 +579,6 @@ fn test_update_config_successful() {
 
     #[cfg(feature = "osmosis")]
     let update_config_message = UpdateConfig {
-        owner: Some("new_admin".to_string()),
         fee_collector_addr: Some("new_collector".to_string()),
         pool_fees: Some(pool_fees),
         feature_toggle: None,
@@ -590,7 +591,7 @@ fn test_update_config_successful() {
         from_json(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
 
     // check for new config
-    assert_eq!(config.owner, Addr::unchecked("new_admin"));
+    assert_eq!(config.owner, Addr::unchecked("addr0000"));
     assert_eq!(config.fee_collector_addr, Addr::unchecked("new_collector"));
     assert_eq!(config.pool_fees.swap_fee.share, Decimal::percent(3u64));
     #[cfg(feature = "osmosis")]
@@ -603,6 +604,116 @@ fn test_update_config_successful() {
     }
 }
 
+#[test]
+fn update_ownership_in_two_steps() {
+    let mut deps = mock_dependencies(&[]);
+
+    deps.querier.with_token_balances(&[
+        (
+            &"liquidity0000".to_string(),
+            &[(&MOCK_CONTRACT_ADDR.to_string(), &Uint128::zero())],
+        ),
+        (&"asset0000".to_string(), &[]),
+    ]);
+
+    let msg = InstantiateMsg {
+        asset_infos: [
+            AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+        ],
+        token_code_id: 10u64,
+        asset_decimals: [6u8, 8u8],
+        pool_fees: PoolFee {
+            protocol_fee: Fee {
+                share: Decimal::zero(),
+            },
+            swap_fee: Fee {
+                share: Decimal::zero(),
+            },
+            burn_fee: Fee {
+                share: Decimal::zero(),
+            },
+            #[cfg(feature = "osmosis")]
+            osmosis_fee: Fee {
+                share: Decimal::zero(),
+            },
+        },
+        fee_collector_addr: "collector".to_string(),
+        pair_type: PairType::ConstantProduct,
+        token_factory_lp: false,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("addr0000", &[]), msg).unwrap();
+
+    // only the owner can propose a new owner
+    let propose_msg = UpdateOwnership(OwnershipAction::ProposeNewOwner {
+        new_owner: "new_admin".to_string(),
+        expiry: None,
+    });
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("unauthorized", &[]),
+        propose_msg.clone(),
+    )
+    .unwrap_err();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        propose_msg,
+    )
+    .unwrap();
+
+    // the ownership doesn't change until the proposed owner accepts it
+    let config: Config =
+        from_json(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.owner, Addr::unchecked("addr0000"));
+
+    let proposal: Option<OwnershipProposal> =
+        from_json(&query(deps.as_ref(), mock_env(), QueryMsg::OwnershipProposal {}).unwrap())
+            .unwrap();
+    assert_eq!(
+        proposal,
+        Some(OwnershipProposal {
+            proposed_owner: Addr::unchecked("new_admin"),
+            expires_at: mock_env()
+                .block
+                .time
+                .plus_seconds(DEFAULT_OWNERSHIP_PROPOSAL_EXPIRY),
+        })
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("new_admin", &[]),
+        UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+    )
+    .unwrap();
+
+    let config: Config =
+        from_json(&query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap()).unwrap();
+    assert_eq!(config.owner, Addr::unchecked("new_admin"));
+
+    // the previous owner has no say over the pool anymore
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("addr0000", &[]),
+        UpdateCircuitBreaker {
+            circuit_breaker: None,
+        },
+    );
+    match res {
+        Err(ContractError::Unauthorized {}) => (),
+        _ => panic!("should return ContractError::Unauthorized"),
+    }
+}
+
 #[test]
 fn test_assert_slippage_tolerance_invalid_ratio() {
     let res = assert_slippage_tolerance(
@@ -11,8 +11,9 @@ use cw20::Cw20ReceiveMsg;
 use semver::Version;
 
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairInfo};
+use white_whale_std::pool_network::factory::PairVariant;
 use white_whale_std::pool_network::pair::SimulationResponse;
-use white_whale_std::pool_network::querier::{query_pair_info, reverse_simulate, simulate};
+use white_whale_std::pool_network::querier::{query_pair_variant_info, reverse_simulate, simulate};
 use white_whale_std::pool_network::router::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
     SimulateSwapOperationsResponse, SwapOperation, SwapRoute, SwapRouteResponse,
@@ -349,15 +350,22 @@ fn simulate_swap_operations(
 
     let mut offer_amount = offer_amount;
     for operation in operations.into_iter() {
+        let pair_variant = operation.get_pair_variant();
         match operation {
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+            }
+            | SwapOperation::TerraSwapVariant {
+                offer_asset_info,
+                ask_asset_info,
+                ..
             } => {
-                let pair_info: PairInfo = query_pair_info(
+                let pair_info: PairInfo = query_pair_variant_info(
                     &deps.querier,
                     terraswap_factory.clone(),
                     &[offer_asset_info.clone(), ask_asset_info.clone()],
+                    pair_variant,
                 )?;
 
                 let res: SimulationResponse = simulate(
@@ -393,10 +401,16 @@ fn reverse_simulate_swap_operations(
 
     let mut ask_amount = ask_amount;
     for operation in operations.into_iter().rev() {
+        let pair_variant = operation.get_pair_variant();
         ask_amount = match operation {
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+            }
+            | SwapOperation::TerraSwapVariant {
+                offer_asset_info,
+                ask_asset_info,
+                ..
             } => {
                 let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
 
@@ -406,6 +420,7 @@ fn reverse_simulate_swap_operations(
                     ask_amount,
                     offer_asset_info,
                     ask_asset_info,
+                    pair_variant,
                 )?
             }
         }
@@ -420,11 +435,13 @@ fn reverse_simulate_return_amount(
     ask_amount: Uint128,
     offer_asset_info: AssetInfo,
     ask_asset_info: AssetInfo,
+    pair_variant: Option<PairVariant>,
 ) -> StdResult<Uint128> {
-    let pair_info: PairInfo = query_pair_info(
+    let pair_info: PairInfo = query_pair_variant_info(
         &deps.querier,
         factory,
         &[offer_asset_info, ask_asset_info.clone()],
+        pair_variant,
     )?;
 
     let res = reverse_simulate(
@@ -487,6 +504,11 @@ fn assert_operations(operations: &[SwapOperation]) -> Result<(), ContractError>
             SwapOperation::TerraSwap {
                 offer_asset_info,
                 ask_asset_info,
+            }
+            | SwapOperation::TerraSwapVariant {
+                offer_asset_info,
+                ask_asset_info,
+                ..
             } => (offer_asset_info.clone(), ask_asset_info.clone()),
         };
 
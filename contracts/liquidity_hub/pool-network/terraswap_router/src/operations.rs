@@ -10,7 +10,9 @@ use cw20::Cw20ExecuteMsg;
 use white_whale_std::pool_network;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairInfo};
 use white_whale_std::pool_network::pair::ExecuteMsg as PairExecuteMsg;
-use white_whale_std::pool_network::querier::{query_balance, query_pair_info, query_token_balance};
+use white_whale_std::pool_network::querier::{
+    query_balance, query_pair_variant_info, query_token_balance,
+};
 use white_whale_std::pool_network::router::SwapOperation;
 
 /// Execute swap operation
@@ -27,17 +29,24 @@ pub fn execute_swap_operation(
         return Err(ContractError::Unauthorized {});
     }
 
+    let pair_variant = operation.get_pair_variant();
     let messages: Vec<CosmosMsg> = match operation {
         SwapOperation::TerraSwap {
             offer_asset_info,
             ask_asset_info,
+        }
+        | SwapOperation::TerraSwapVariant {
+            offer_asset_info,
+            ask_asset_info,
+            ..
         } => {
             let config: Config = CONFIG.load(deps.as_ref().storage)?;
             let terraswap_factory = deps.api.addr_humanize(&config.terraswap_factory)?;
-            let pair_info: PairInfo = query_pair_info(
+            let pair_info: PairInfo = query_pair_variant_info(
                 &deps.querier,
                 terraswap_factory,
                 &[offer_asset_info.clone(), ask_asset_info],
+                pair_variant,
             )?;
 
             let amount = match offer_asset_info.clone() {
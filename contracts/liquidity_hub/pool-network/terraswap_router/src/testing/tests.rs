@@ -1,12 +1,13 @@
 use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
 use cosmwasm_std::{
-    attr, coin, from_json, to_json_binary, Addr, Coin, CosmosMsg, StdError, SubMsg, Uint128,
-    WasmMsg,
+    attr, coin, from_json, to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdError, SubMsg,
+    Uint128, WasmMsg,
 };
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
 use white_whale_std::pool_network;
 
 use white_whale_std::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType};
+use white_whale_std::pool_network::factory::PairVariant;
 use white_whale_std::pool_network::mock_querier::mock_dependencies;
 use white_whale_std::pool_network::pair::ExecuteMsg as PairExecuteMsg;
 use white_whale_std::pool_network::router::{
@@ -452,6 +453,110 @@ fn execute_swap_operation() {
     );
 }
 
+#[test]
+fn execute_swap_operation_with_pair_variant() {
+    let mut deps = mock_dependencies(&[]);
+    let msg = InstantiateMsg {
+        terraswap_factory: "terraswapfactory".to_string(),
+    };
+
+    let info = mock_info("addr0000", &[]);
+    let _res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
+
+    let pair_variant = PairVariant {
+        pair_type: PairType::StableSwap { amp: 100 },
+        fee_tier: Decimal::permille(5u64),
+    };
+
+    // the default pair and a stableswap variant for the same assets
+    deps.querier.with_pool_factory(
+        &[
+            (
+                &"uusdasset0000".to_string(),
+                &PairInfo {
+                    asset_infos: [
+                        AssetInfo::NativeToken {
+                            denom: "uusd".to_string(),
+                        },
+                        AssetInfo::Token {
+                            contract_addr: "asset0000".to_string(),
+                        },
+                    ],
+                    contract_addr: "pair0000".to_string(),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "liquidity0000".to_string(),
+                    },
+                    asset_decimals: [6u8, 6u8],
+                    pair_type: PairType::ConstantProduct,
+                },
+            ),
+            (
+                &format!("uusdasset0000{}", pair_variant.key()),
+                &PairInfo {
+                    asset_infos: [
+                        AssetInfo::NativeToken {
+                            denom: "uusd".to_string(),
+                        },
+                        AssetInfo::Token {
+                            contract_addr: "asset0000".to_string(),
+                        },
+                    ],
+                    contract_addr: "pair0001".to_string(),
+                    liquidity_token: AssetInfo::Token {
+                        contract_addr: "liquidity0001".to_string(),
+                    },
+                    asset_decimals: [6u8, 6u8],
+                    pair_type: PairType::StableSwap { amp: 100 },
+                },
+            ),
+        ],
+        &[("uusd".to_string(), 6u8)],
+    );
+    deps.querier.with_balance(&[(
+        &MOCK_CONTRACT_ADDR.to_string(),
+        [Coin {
+            amount: Uint128::from(1000000u128),
+            denom: "uusd".to_string(),
+        }]
+        .to_vec(),
+    )]);
+
+    let msg = ExecuteMsg::ExecuteSwapOperation {
+        operation: SwapOperation::TerraSwapVariant {
+            offer_asset_info: AssetInfo::NativeToken {
+                denom: "uusd".to_string(),
+            },
+            ask_asset_info: AssetInfo::Token {
+                contract_addr: "asset0000".to_string(),
+            },
+            pair_variant,
+        },
+        to: None,
+        max_spread: None,
+    };
+
+    let info = mock_info(MOCK_CONTRACT_ADDR, &[]);
+    let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(
+            asset_into_swap_msg(
+                deps.as_ref(),
+                Addr::unchecked("pair0001"),
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "uusd".to_string(),
+                    },
+                    amount: Uint128::from(1000000u128),
+                },
+                None,
+                None,
+            )
+            .unwrap()
+        )],
+    );
+}
+
 #[test]
 fn query_buy_with_routes() {
     let mut deps = mock_dependencies(&[]);
@@ -16,9 +16,12 @@ use white_whale_std::vault_network::vault::{
 use crate::state::{initialize_fee, ALL_TIME_BURNED_FEES};
 use crate::{
     error::VaultError,
-    execute::{callback, collect_protocol_fees, deposit, flash_loan, receive, update_config},
+    execute::{
+        callback, collect_protocol_fees, deposit, flash_loan, receive, update_config,
+        update_ownership,
+    },
     migrations,
-    queries::{get_config, get_fees, get_payback_amount, get_share},
+    queries::{get_config, get_fees, get_ownership_proposal, get_payback_amount, get_share},
     state::{ALL_TIME_COLLECTED_PROTOCOL_FEES, COLLECTED_PROTOCOL_FEES, CONFIG, LOAN_COUNTER},
 };
 
@@ -191,6 +194,7 @@ pub fn execute(
         ExecuteMsg::CollectProtocolFees {} => collect_protocol_fees(deps),
         ExecuteMsg::Receive(msg) => receive(deps, env, info, msg),
         ExecuteMsg::Callback(msg) => callback(deps, env, info, msg),
+        ExecuteMsg::UpdateOwnership(action) => update_ownership(deps, env, info, action),
     }
 }
 
@@ -253,5 +257,6 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, VaultError>
         ),
         QueryMsg::GetPaybackAmount { amount } => get_payback_amount(deps, amount),
         QueryMsg::BurnedFees {} => get_fees(deps, true, ALL_TIME_BURNED_FEES, None),
+        QueryMsg::OwnershipProposal {} => get_ownership_proposal(deps),
     }
 }
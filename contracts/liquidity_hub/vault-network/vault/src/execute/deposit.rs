@@ -5,6 +5,7 @@ use cosmwasm_std::{
 };
 use cw20::{AllowanceResponse, Cw20ExecuteMsg};
 
+use white_whale_std::pause::query_paused_features;
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use white_whale_std::pool_network::asset::is_factory_token;
 use white_whale_std::pool_network::asset::AssetInfo;
@@ -27,8 +28,10 @@ pub fn deposit(
 ) -> Result<Response, VaultError> {
     let config = CONFIG.load(deps.storage)?;
 
-    // check that deposits are enabled
-    if !config.deposit_enabled {
+    // check that deposits are enabled and not paused across the vaults of the factory
+    if !config.deposit_enabled
+        || query_paused_features(&deps.querier, config.owner.clone())?.deposits
+    {
         return Err(VaultError::DepositsDisabled {});
     }
 
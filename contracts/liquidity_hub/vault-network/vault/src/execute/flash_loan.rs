@@ -3,6 +3,7 @@ use cosmwasm_std::{
     StdError, Uint128, WasmMsg,
 };
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+use white_whale_std::pause::query_paused_features;
 use white_whale_std::pool_network::asset::AssetInfo;
 use white_whale_std::vault_network::vault::{CallbackMsg, ExecuteMsg};
 
@@ -18,9 +19,11 @@ pub fn flash_loan(
     amount: Uint128,
     msg: Binary,
 ) -> Result<Response, VaultError> {
-    // check that flash loans are enabled
+    // check that flash loans are enabled and not paused across the vaults of the factory
     let config = CONFIG.load(deps.storage)?;
-    if !config.flash_loan_enabled {
+    if !config.flash_loan_enabled
+        || query_paused_features(&deps.querier, config.owner.clone())?.flash_loans
+    {
         return Err(VaultError::FlashLoansDisabled {});
     }
 
@@ -4,6 +4,7 @@ mod deposit;
 mod flash_loan;
 pub(crate) mod receive;
 mod update_config;
+mod update_ownership;
 
 pub use callback::callback;
 pub use collect_protocol_fee::collect_protocol_fees;
@@ -11,3 +12,4 @@ pub use deposit::deposit;
 pub use flash_loan::flash_loan;
 pub use receive::receive;
 pub use update_config::update_config;
+pub use update_ownership::update_ownership;
@@ -3,6 +3,7 @@ use cosmwasm_std::{
 };
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 
+use white_whale_std::pause::query_paused_features;
 #[cfg(any(feature = "osmosis_token_factory", feature = "injective"))]
 use white_whale_std::pool_network::asset::is_factory_token;
 use white_whale_std::pool_network::asset::{get_total_share, AssetInfo};
@@ -22,8 +23,10 @@ pub fn withdraw(
 ) -> Result<Response, VaultError> {
     let config = CONFIG.load(deps.storage)?;
 
-    // check that withdrawals are enabled
-    if !config.withdraw_enabled {
+    // check that withdrawals are enabled and not paused across the vaults of the factory
+    if !config.withdraw_enabled
+        || query_paused_features(&deps.querier, config.owner.clone())?.withdrawals
+    {
         return Err(VaultError::WithdrawsDisabled {});
     }
 
@@ -201,7 +204,6 @@ mod tests {
                 flash_loan_enabled: None,
                 deposit_enabled: None,
                 withdraw_enabled: Some(false),
-                new_owner: None,
                 new_fee_collector_addr: None,
                 new_vault_fees: None,
             }),
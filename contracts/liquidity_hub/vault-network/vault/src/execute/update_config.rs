@@ -14,7 +14,6 @@ pub fn update_config(
         flash_loan_enabled,
         withdraw_enabled,
         deposit_enabled,
-        new_owner,
         new_fee_collector_addr,
         new_vault_fees,
     } = params;
@@ -35,9 +34,6 @@ pub fn update_config(
     if let Some(deposit_enabled) = deposit_enabled {
         config.deposit_enabled = deposit_enabled;
     }
-    if let Some(new_owner) = new_owner {
-        config.owner = deps.api.addr_validate(&new_owner)?;
-    }
     if let Some(new_fee_collector_addr) = new_fee_collector_addr {
         config.fee_collector_addr = deps.api.addr_validate(&new_fee_collector_addr)?;
     }
@@ -104,7 +100,6 @@ mod test {
                 flash_loan_enabled: None,
                 deposit_enabled: None,
                 withdraw_enabled: None,
-                new_owner: None,
                 new_fee_collector_addr: None,
                 new_vault_fees: None,
             }),
@@ -143,7 +138,6 @@ mod test {
                 flash_loan_enabled: None,
                 deposit_enabled: None,
                 withdraw_enabled: None,
-                new_owner: None,
                 new_fee_collector_addr: None,
                 new_vault_fees: None,
             }),
@@ -197,7 +191,6 @@ mod test {
                 flash_loan_enabled: None,
                 deposit_enabled: None,
                 withdraw_enabled: None,
-                new_owner: None,
                 new_fee_collector_addr: None,
                 new_vault_fees: Some(VaultFee {
                     protocol_fee: Fee {
@@ -262,7 +255,6 @@ mod test {
                 flash_loan_enabled: Some(true),
                 deposit_enabled: Some(true),
                 withdraw_enabled: Some(true),
-                new_owner: Some("new_owner".to_string()),
                 new_fee_collector_addr: Some("new_fee_collector".to_string()),
                 new_vault_fees: Some(new_fee.clone()),
             }),
@@ -276,7 +268,7 @@ mod test {
                 ("flash_loan_enabled", "true"),
                 ("withdraw_enabled", "true"),
                 ("deposit_enabled", "true"),
-                ("owner", "new_owner"),
+                ("owner", "creator"),
                 ("fee_collector_addr", "new_fee_collector"),
             ])
         );
@@ -286,7 +278,7 @@ mod test {
         assert_eq!(
             config_after,
             Config {
-                owner: Addr::unchecked("new_owner"),
+                owner: mock_creator().sender,
                 lp_asset: AssetInfo::Token {
                     contract_addr: "lp_token".to_string()
                 },
@@ -0,0 +1,9 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+
+use white_whale_std::ownership::query_ownership_proposal;
+
+use crate::error::VaultError;
+
+pub fn get_ownership_proposal(deps: Deps) -> Result<Binary, VaultError> {
+    Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+}
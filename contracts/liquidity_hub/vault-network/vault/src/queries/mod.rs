@@ -1,9 +1,11 @@
 mod get_config;
+mod get_ownership_proposal;
 mod get_payback_amount;
 mod get_protocol_fees;
 mod get_share;
 
 pub use get_config::get_config;
+pub use get_ownership_proposal::get_ownership_proposal;
 pub use get_payback_amount::get_payback_amount;
 pub use get_protocol_fees::get_fees;
 pub use get_share::get_share;
@@ -1,17 +1,24 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+};
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
 
+use white_whale_std::ownership::{
+    has_role, query_ownership_proposal, query_roles, update_role, Role,
+};
+use white_whale_std::vault_network::vault::UpdateConfigParams;
 use white_whale_std::vault_network::vault_factory::{
     Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
 };
 
 use crate::err::{StdResult, VaultFactoryError};
 use crate::execute::{
-    create_vault, migrate_vaults, remove_vault, update_config, update_vault_config,
+    create_vault, emergency_pause, migrate_vaults, remove_vault, update_config, update_ownership,
+    update_vault_config, update_vault_ownership,
 };
 use crate::migrations;
-use crate::queries::{get_config, get_vault, get_vaults};
+use crate::queries::{get_config, get_emergency_pause, get_vault, get_vaults};
 use crate::state::CONFIG;
 
 const CONTRACT_NAME: &str = "white_whale-vault_factory";
@@ -39,9 +46,33 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> StdResult<Response> {
-    // permission check
+    // permission check, the admin roles can only execute the messages granted to them, while the
+    // ownership transfer is checked when handled
     let config = CONFIG.load(deps.storage)?;
-    if config.owner != info.sender {
+    let authorized = config.owner == info.sender
+        || match &msg {
+            ExecuteMsg::UpdateOwnership(_) => true,
+            // the fee manager can only update the vault fees
+            ExecuteMsg::UpdateVaultConfig {
+                params:
+                    UpdateConfigParams {
+                        flash_loan_enabled: None,
+                        deposit_enabled: None,
+                        withdraw_enabled: None,
+                        new_fee_collector_addr: None,
+                        ..
+                    },
+                ..
+            } => has_role(deps.storage, Role::FeeManager, &info.sender)?,
+            ExecuteMsg::EmergencyPause { .. } => {
+                has_role(deps.storage, Role::Pauser, &info.sender)?
+            }
+            ExecuteMsg::MigrateVaults { .. } => {
+                has_role(deps.storage, Role::Migrator, &info.sender)?
+            }
+            _ => false,
+        };
+    if !authorized {
         return Err(VaultFactoryError::Unauthorized {});
     }
 
@@ -60,11 +91,18 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
         } => migrate_vaults(deps, vault_addr, vault_code_id),
         ExecuteMsg::RemoveVault { asset_info } => remove_vault(deps, asset_info),
         ExecuteMsg::UpdateConfig {
-            owner,
             fee_collector_addr,
             vault_id,
             token_id,
-        } => update_config(deps, owner, fee_collector_addr, vault_id, token_id),
+        } => update_config(deps, fee_collector_addr, vault_id, token_id),
+        ExecuteMsg::EmergencyPause { paused_features } => {
+            emergency_pause(deps, info, paused_features)
+        }
+        ExecuteMsg::UpdateOwnership(action) => update_ownership(deps, env, info, action),
+        ExecuteMsg::UpdateVaultOwnership { vault_addr, action } => {
+            update_vault_ownership(deps, vault_addr, action)
+        }
+        ExecuteMsg::UpdateRole { role, address } => Ok(update_role(deps, role, address)?),
     }
 }
 
@@ -89,6 +127,10 @@ pub fn migrate(mut deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Resp
         migrations::migrate_to_v110(deps.branch())?;
     }
 
+    if storage_version < Version::parse("1.3.0")? {
+        migrations::migrate_to_v130(deps.branch())?;
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
@@ -123,5 +165,10 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Config {} => get_config(deps),
         QueryMsg::Vault { asset_info } => get_vault(deps, asset_info),
         QueryMsg::Vaults { start_after, limit } => get_vaults(deps, start_after, limit),
+        QueryMsg::EmergencyPause {} => get_emergency_pause(deps),
+        QueryMsg::OwnershipProposal {} => {
+            Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+        }
+        QueryMsg::Roles {} => Ok(to_json_binary(&query_roles(deps.storage)?)?),
     }
 }
@@ -0,0 +1,124 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response};
+use white_whale_std::pause::PausedFeatures;
+
+use crate::err::{StdResult, VaultFactoryError};
+use crate::state::{CONFIG, PAUSED_FEATURES};
+
+/// Pauses features across all the vaults. The pauser can only pause features, while the owner
+/// can also unpause them.
+pub fn emergency_pause(
+    deps: DepsMut,
+    info: MessageInfo,
+    paused_features: PausedFeatures,
+) -> StdResult<Response> {
+    let config = CONFIG.load(deps.storage)?;
+    let current_paused_features = PAUSED_FEATURES.may_load(deps.storage)?.unwrap_or_default();
+
+    if config.owner != info.sender && paused_features.unpauses(&current_paused_features) {
+        return Err(VaultFactoryError::Unauthorized {});
+    }
+
+    PAUSED_FEATURES.save(deps.storage, &paused_features)?;
+
+    Ok(Response::new().add_attributes(vec![
+        ("method", "emergency_pause".to_string()),
+        ("deposits", paused_features.deposits.to_string()),
+        ("withdrawals", paused_features.withdrawals.to_string()),
+        ("flash_loans", paused_features.flash_loans.to_string()),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_info, Addr};
+    use white_whale_std::ownership::Role;
+    use white_whale_std::pause::{EmergencyPauseResponse, PausedFeatures};
+    use white_whale_std::vault_network::vault_factory::{ExecuteMsg, QueryMsg};
+
+    use crate::{
+        contract::{execute, query},
+        err::VaultFactoryError,
+        tests::{mock_creator, mock_instantiate::mock_instantiate},
+    };
+
+    #[test]
+    fn pauser_can_only_pause() {
+        let (mut deps, env) = mock_instantiate(1, 2);
+
+        let flash_loans_paused = PausedFeatures {
+            flash_loans: true,
+            ..PausedFeatures::default()
+        };
+
+        // the pauser role is not granted yet
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("pauser", &[]),
+            ExecuteMsg::EmergencyPause {
+                paused_features: flash_loans_paused.clone(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(res, VaultFactoryError::Unauthorized {});
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            ExecuteMsg::UpdateRole {
+                role: Role::Pauser,
+                address: Some("pauser".to_string()),
+            },
+        )
+        .unwrap();
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("pauser", &[]),
+            ExecuteMsg::EmergencyPause {
+                paused_features: flash_loans_paused.clone(),
+            },
+        )
+        .unwrap();
+
+        let emergency_pause: EmergencyPauseResponse =
+            from_json(query(deps.as_ref(), env.clone(), QueryMsg::EmergencyPause {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            emergency_pause,
+            EmergencyPauseResponse {
+                pauser: Some(Addr::unchecked("pauser")),
+                paused_features: flash_loans_paused,
+            }
+        );
+
+        // the pauser can't unpause flash loans
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("pauser", &[]),
+            ExecuteMsg::EmergencyPause {
+                paused_features: PausedFeatures::default(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(res, VaultFactoryError::Unauthorized {});
+
+        // but the owner can
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            ExecuteMsg::EmergencyPause {
+                paused_features: PausedFeatures::default(),
+            },
+        )
+        .unwrap();
+
+        let emergency_pause: EmergencyPauseResponse =
+            from_json(query(deps.as_ref(), env, QueryMsg::EmergencyPause {}).unwrap()).unwrap();
+        assert_eq!(emergency_pause.paused_features, PausedFeatures::default());
+    }
+}
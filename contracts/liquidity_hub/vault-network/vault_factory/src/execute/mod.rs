@@ -1,11 +1,17 @@
 mod create_vault;
+mod emergency_pause;
 mod migrate_vaults;
 mod remove_vault;
 mod update_config;
+mod update_ownership;
 mod update_vault_config;
+mod update_vault_ownership;
 
 pub use create_vault::create_vault;
+pub use emergency_pause::emergency_pause;
 pub use migrate_vaults::migrate_vaults;
 pub use remove_vault::remove_vault;
 pub use update_config::update_config;
+pub use update_ownership::update_ownership;
 pub use update_vault_config::update_vault_config;
+pub use update_vault_ownership::update_vault_ownership;
@@ -7,16 +7,11 @@ use crate::{
 
 pub fn update_config(
     deps: DepsMut,
-    new_owner: Option<String>,
     new_fee_collector_addr: Option<String>,
     new_vault_id: Option<u64>,
     new_token_id: Option<u64>,
 ) -> StdResult<Response> {
     let new_config = CONFIG.update::<_, VaultFactoryError>(deps.storage, |mut config| {
-        if let Some(new_owner) = new_owner {
-            config.owner = deps.api.addr_validate(&new_owner)?;
-        };
-
         if let Some(new_fee_collector_addr) = new_fee_collector_addr {
             config.fee_collector_addr = deps.api.addr_validate(&new_fee_collector_addr)?;
         }
@@ -56,48 +51,12 @@ mod tests {
         tests::{mock_creator, mock_execute, mock_instantiate::mock_instantiate},
     };
 
-    #[test]
-    fn does_update_owner() {
-        let (res, deps, env) = mock_execute(
-            1,
-            2,
-            ExecuteMsg::UpdateConfig {
-                owner: Some("other_acc".to_string()),
-                fee_collector_addr: None,
-                vault_id: None,
-                token_id: None,
-            },
-        );
-
-        // check response
-        assert_eq!(
-            res.unwrap(),
-            Response::new().add_attributes(vec![
-                ("method", "update_config"),
-                ("owner", "other_acc"),
-                ("fee_collector_addr", "fee_collector"),
-                ("vault_id", "1"),
-                ("token_id", "2")
-            ])
-        );
-
-        // check query
-        let config: Config =
-            from_json(&query(deps.as_ref(), env, QueryMsg::Config {}).unwrap()).unwrap();
-        assert_eq!(config.owner, Addr::unchecked("other_acc"));
-
-        // check storage
-        let config = CONFIG.load(&deps.storage).unwrap();
-        assert_eq!(config.owner, Addr::unchecked("other_acc"));
-    }
-
     #[test]
     fn does_update_fee_collector_addr() {
         let (res, deps, env) = mock_execute(
             1,
             2,
             ExecuteMsg::UpdateConfig {
-                owner: None,
                 fee_collector_addr: Some("other_acc".to_string()),
                 vault_id: None,
                 token_id: None,
@@ -132,7 +91,6 @@ mod tests {
             1,
             2,
             ExecuteMsg::UpdateConfig {
-                owner: None,
                 fee_collector_addr: None,
                 vault_id: Some(3u64),
                 token_id: Some(4u64),
@@ -174,7 +132,6 @@ mod tests {
             1,
             2,
             ExecuteMsg::UpdateConfig {
-                owner: None,
                 fee_collector_addr: None,
                 vault_id: None,
                 token_id: None,
@@ -221,7 +178,6 @@ mod tests {
             env,
             unauthorized_sender.clone(),
             ExecuteMsg::UpdateConfig {
-                owner: Some(unauthorized_sender.sender.clone().into_string()),
                 fee_collector_addr: Some(unauthorized_sender.sender.into_string()),
                 vault_id: None,
                 token_id: None,
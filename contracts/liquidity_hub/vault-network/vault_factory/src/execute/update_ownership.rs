@@ -0,0 +1,80 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use white_whale_std::ownership::{self, OwnershipAction};
+
+use crate::err::StdResult;
+use crate::state::CONFIG;
+
+/// Proposes, accepts or cancels a transfer of the ownership of the factory. Once the ownership is
+/// accepted, the new owner is stored in the config.
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> StdResult<Response> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::mock_info, Addr};
+    use white_whale_std::ownership::OwnershipAction;
+    use white_whale_std::vault_network::vault_factory::{Config, ExecuteMsg, QueryMsg};
+
+    use crate::{
+        contract::{execute, query},
+        err::VaultFactoryError,
+        tests::{mock_creator, mock_instantiate::mock_instantiate},
+    };
+
+    #[test]
+    fn does_update_owner_once_accepted() {
+        let (mut deps, env) = mock_instantiate(1, 2);
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_creator(),
+            ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+                new_owner: "other_acc".to_string(),
+                expiry: None,
+            }),
+        )
+        .unwrap();
+
+        // the proposed owner can't act as the owner before accepting the ownership
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("other_acc", &[]),
+            ExecuteMsg::UpdateConfig {
+                fee_collector_addr: None,
+                vault_id: None,
+                token_id: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(res, VaultFactoryError::Unauthorized {});
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("other_acc", &[]),
+            ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+        )
+        .unwrap();
+
+        let config: Config =
+            from_json(query(deps.as_ref(), env, QueryMsg::Config {}).unwrap()).unwrap();
+        assert_eq!(config.owner, Addr::unchecked("other_acc"));
+    }
+}
@@ -87,7 +87,6 @@ mod tests {
                     flash_loan_enabled: Some(false),
                     deposit_enabled: None,
                     withdraw_enabled: None,
-                    new_owner: None,
                     new_vault_fees: None,
                     new_fee_collector_addr: None,
                 },
@@ -155,7 +154,6 @@ mod tests {
                     flash_loan_enabled: None,
                     deposit_enabled: None,
                     withdraw_enabled: None,
-                    new_owner: Some("new_owner".to_string()),
                     new_vault_fees: None,
                     new_fee_collector_addr: None,
                 },
@@ -0,0 +1,20 @@
+use cosmwasm_std::{wasm_execute, DepsMut, Response};
+use white_whale_std::ownership::OwnershipAction;
+
+use crate::err::StdResult;
+
+/// Proposes or cancels a transfer of the ownership of a vault. The proposed owner accepts the
+/// ownership on the vault itself.
+pub fn update_vault_ownership(
+    deps: DepsMut,
+    vault_addr: String,
+    action: OwnershipAction,
+) -> StdResult<Response> {
+    Ok(Response::new()
+        .add_message(wasm_execute(
+            deps.api.addr_validate(vault_addr.as_str())?.to_string(),
+            &white_whale_std::vault_network::vault::ExecuteMsg::UpdateOwnership(action),
+            vec![],
+        )?)
+        .add_attribute("method", "update_vault_ownership"))
+}
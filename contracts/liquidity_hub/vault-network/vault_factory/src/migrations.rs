@@ -1,6 +1,7 @@
 use cosmwasm_std::{Addr, DepsMut, Order, StdError};
-use cw_storage_plus::Map;
+use cw_storage_plus::{Item, Map};
 
+use white_whale_std::ownership::{self, Role};
 use white_whale_std::pool_network::asset::AssetInfo;
 
 use crate::state::{TMP_VAULT_ASSET, VAULTS};
@@ -33,3 +34,16 @@ pub fn migrate_to_v110(deps: DepsMut) -> Result<(), StdError> {
 
     Ok(())
 }
+
+/// Migrates the guardian, which could pause features across the vaults of the factory, to the
+/// pauser role that replaced it in v1.3.0.
+pub fn migrate_to_v130(mut deps: DepsMut) -> Result<(), StdError> {
+    const GUARDIAN: Item<Addr> = Item::new("guardian");
+
+    if let Some(guardian) = GUARDIAN.may_load(deps.storage)? {
+        ownership::update_role(deps.branch(), Role::Pauser, Some(guardian.into_string()))?;
+        GUARDIAN.remove(deps.storage);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use white_whale_std::ownership::query_roles;
+use white_whale_std::pause::EmergencyPauseResponse;
+
+use crate::err::StdResult;
+use crate::state::PAUSED_FEATURES;
+
+/// Retrieves the pauser and the features paused across all the vaults.
+pub fn get_emergency_pause(deps: Deps) -> StdResult<Binary> {
+    Ok(to_json_binary(&EmergencyPauseResponse {
+        pauser: query_roles(deps.storage)?.pauser,
+        paused_features: PAUSED_FEATURES.may_load(deps.storage)?.unwrap_or_default(),
+    })?)
+}
@@ -1,6 +1,8 @@
 mod config;
+mod emergency_pause;
 mod vault;
 
 pub use self::vault::get_vault;
 pub use self::vault::get_vaults;
 pub use config::get_config;
+pub use emergency_pause::get_emergency_pause;
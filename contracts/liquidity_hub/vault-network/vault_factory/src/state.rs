@@ -1,5 +1,6 @@
 use cosmwasm_std::{Addr, Api, Order, StdResult, Storage};
 use cw_storage_plus::{Bound, Item, Map};
+use white_whale_std::pause::PausedFeatures;
 use white_whale_std::pool_network::asset::AssetInfo;
 
 use white_whale_std::vault_network::vault_factory::{Config, VaultInfo};
@@ -11,6 +12,9 @@ pub const VAULTS: Map<&[u8], (Addr, AssetInfo)> = Map::new("vaults");
 /// Used to temporarily store the asset being instantiated between `create_vault` and `reply` callback
 pub const TMP_VAULT_ASSET: Item<(Vec<u8>, AssetInfo)> = Item::new("tmp_vault_asset");
 
+/// The features paused across all the vaults. Nothing is paused if not set.
+pub const PAUSED_FEATURES: Item<PausedFeatures> = Item::new("paused_features");
+
 // settings for pagination
 const MAX_LIMIT: u32 = 30;
 const DEFAULT_LIMIT: u32 = 10;
@@ -6,8 +6,8 @@ use white_whale_std::vault_network::vault_router::{
 };
 
 use crate::err::{StdResult, VaultRouterError};
-use crate::execute::{complete_loan, flash_loan, next_loan, update_config};
-use crate::queries::get_config;
+use crate::execute::{complete_loan, flash_loan, next_loan, update_config, update_ownership};
+use crate::queries::{get_config, get_ownership_proposal};
 use crate::state::CONFIG;
 
 const CONTRACT_NAME: &str = "white_whale-vault_router";
@@ -57,10 +57,10 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> S
             initiator,
             loaned_assets,
         } => complete_loan(deps, env, info, initiator, loaned_assets),
-        ExecuteMsg::UpdateConfig {
-            owner,
-            vault_factory_addr,
-        } => update_config(deps, info, owner, vault_factory_addr),
+        ExecuteMsg::UpdateConfig { vault_factory_addr } => {
+            update_config(deps, info, vault_factory_addr)
+        }
+        ExecuteMsg::UpdateOwnership(action) => update_ownership(deps, env, info, action),
     }
 }
 
@@ -89,5 +89,6 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> StdResult<Response
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => get_config(deps),
+        QueryMsg::OwnershipProposal {} => get_ownership_proposal(deps),
     }
 }
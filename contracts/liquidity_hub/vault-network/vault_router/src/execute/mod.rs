@@ -2,8 +2,10 @@ mod complete_loan;
 mod flash_loan;
 mod next_loan;
 mod update_config;
+mod update_ownership;
 
 pub use complete_loan::complete_loan;
 pub use flash_loan::flash_loan;
 pub use next_loan::next_loan;
 pub use update_config::update_config;
+pub use update_ownership::update_ownership;
@@ -8,7 +8,6 @@ use crate::{
 pub fn update_config(
     deps: DepsMut,
     info: MessageInfo,
-    new_owner: Option<String>,
     new_vault_factory_addr: Option<String>,
 ) -> StdResult<Response> {
     let new_config = CONFIG.update::<_, VaultRouterError>(deps.storage, |mut config| {
@@ -17,10 +16,6 @@ pub fn update_config(
             return Err(VaultRouterError::Unauthorized {});
         }
 
-        if let Some(new_owner) = new_owner {
-            config.owner = deps.api.addr_validate(&new_owner)?;
-        };
-
         if let Some(new_vault_factory_addr) = new_vault_factory_addr {
             config.vault_factory = deps.api.addr_validate(&new_vault_factory_addr)?;
         }
@@ -58,7 +53,6 @@ mod tests {
             env,
             bad_actor.clone(),
             ExecuteMsg::UpdateConfig {
-                owner: Some(bad_actor.sender.into_string()),
                 vault_factory_addr: Some("new_vault_address".to_string()),
             },
         );
@@ -69,14 +63,13 @@ mod tests {
     #[test]
     fn does_update_config() {
         let new_config = Config {
-            owner: Addr::unchecked("new_owner"),
+            owner: mock_creator().sender,
             vault_factory: Addr::unchecked("new_factory"),
         };
 
         let (res, deps, ..) = mock_execute(
             "old_factory",
             ExecuteMsg::UpdateConfig {
-                owner: Some(new_config.owner.clone().into_string()),
                 vault_factory_addr: Some(new_config.vault_factory.clone().into_string()),
             },
         );
@@ -103,7 +96,6 @@ mod tests {
         let (res, deps, ..) = mock_execute(
             "factory",
             ExecuteMsg::UpdateConfig {
-                owner: None,
                 vault_factory_addr: None,
             },
         );
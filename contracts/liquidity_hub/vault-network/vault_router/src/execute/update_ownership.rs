@@ -0,0 +1,86 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+
+use white_whale_std::ownership::{self, OwnershipAction};
+
+use crate::{err::StdResult, state::CONFIG};
+
+/// Proposes, accepts or cancels a transfer of the ownership of the router. Once the ownership is
+/// accepted, the new owner is stored in the config.
+pub fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> StdResult<Response> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{from_json, testing::mock_info, Addr};
+
+    use white_whale_std::ownership::{OwnershipAction, OwnershipProposal};
+    use white_whale_std::vault_network::vault_router::{ExecuteMsg, QueryMsg};
+
+    use crate::{
+        contract::{execute, query},
+        state::CONFIG,
+        tests::{mock_creator, mock_instantiate::mock_instantiate},
+    };
+
+    #[test]
+    fn transfers_ownership_once_accepted() {
+        let (mut deps, env) = mock_instantiate("factory");
+
+        // only the owner can propose a new owner
+        let msg = ExecuteMsg::UpdateOwnership(OwnershipAction::ProposeNewOwner {
+            new_owner: "new_owner".to_string(),
+            expiry: Some(100),
+        });
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("new_owner", &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        execute(deps.as_mut(), env.clone(), mock_creator(), msg).unwrap();
+
+        // the ownership doesn't change until accepted
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap().owner,
+            mock_creator().sender
+        );
+        let proposal: Option<OwnershipProposal> =
+            from_json(query(deps.as_ref(), env.clone(), QueryMsg::OwnershipProposal {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            proposal,
+            Some(OwnershipProposal {
+                proposed_owner: Addr::unchecked("new_owner"),
+                expires_at: env.block.time.plus_seconds(100),
+            })
+        );
+
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("new_owner", &[]),
+            ExecuteMsg::UpdateOwnership(OwnershipAction::AcceptOwnership {}),
+        )
+        .unwrap();
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap().owner,
+            Addr::unchecked("new_owner")
+        );
+    }
+}
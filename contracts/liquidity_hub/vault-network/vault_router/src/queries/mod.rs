@@ -1,3 +1,5 @@
 mod config;
+mod ownership_proposal;
 
 pub use config::get_config;
+pub use ownership_proposal::get_ownership_proposal;
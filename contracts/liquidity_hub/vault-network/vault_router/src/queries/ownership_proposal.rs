@@ -0,0 +1,10 @@
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+
+use white_whale_std::ownership::query_ownership_proposal;
+
+use crate::err::StdResult;
+
+/// Retrieves the pending ownership proposal, if any.
+pub fn get_ownership_proposal(deps: Deps) -> StdResult<Binary> {
+    Ok(to_json_binary(&query_ownership_proposal(deps.storage)?)?)
+}
@@ -2,6 +2,7 @@ use cosmwasm_std::{
     Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Order, Response, StdResult,
     Timestamp, Uint128, Uint64,
 };
+use white_whale_std::ownership::{self, OwnershipAction};
 use white_whale_std::pool_network::asset;
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
@@ -27,7 +28,7 @@ pub(crate) fn bond(
     };
 
     helpers::validate_funds(&deps, &info, &asset, denom.clone())?;
-    helpers::validate_claimed(&deps, &info)?;
+    helpers::validate_claimed(&deps, &info.sender)?;
     helpers::validate_bonding_for_current_epoch(&deps, &env)?;
 
     let mut bond = BOND
@@ -86,8 +87,9 @@ pub(crate) fn unbond(
         AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
     };
 
-    helpers::validate_claimed(&deps, &info)?;
+    helpers::validate_claimed(&deps, &info.sender)?;
     helpers::validate_bonding_for_current_epoch(&deps, &env)?;
+    helpers::validate_not_voted(&deps, &info.sender)?;
 
     if let Some(mut unbond) = BOND.key((&info.sender, &denom)).may_load(deps.storage)? {
         // check if the address has enough bond
@@ -137,6 +139,88 @@ pub(crate) fn unbond(
     }
 }
 
+/// Transfers the provided bonded amount, together with its share of the bond weight, to the
+/// recipient. The global index is left untouched as the total bonded amount and weight don't change.
+pub(crate) fn transfer_bond(
+    mut deps: DepsMut,
+    timestamp: Timestamp,
+    info: MessageInfo,
+    env: Env,
+    asset: Asset,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    if asset.amount.is_zero() {
+        return Err(ContractError::InvalidTransferAmount {});
+    }
+
+    let denom = match asset.info.clone() {
+        AssetInfo::NativeToken { denom } => denom,
+        AssetInfo::Token { .. } => return Err(ContractError::InvalidBondingAsset {}),
+    };
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if recipient == info.sender {
+        return Err(ContractError::InvalidTransferRecipient {});
+    }
+
+    // both parties need to have claimed their rewards, otherwise the transferred weight could be
+    // used to claim the same rewards twice
+    helpers::validate_claimed(&deps, &info.sender)?;
+    helpers::validate_claimed(&deps, &recipient)?;
+    helpers::validate_bonding_for_current_epoch(&deps, &env)?;
+    // the voting power of the sender was already used in the current gauges epoch, so the bond
+    // can't be moved to another address to vote with it again
+    helpers::validate_not_voted(&deps, &info.sender)?;
+
+    let mut sender_bond = BOND
+        .key((&info.sender, &denom))
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NothingToTransfer {})?;
+
+    if sender_bond.asset.amount < asset.amount {
+        return Err(ContractError::InsufficientBondToTransfer {});
+    }
+
+    // update the sender's weight and deduct the share of it that is being transferred
+    sender_bond = update_local_weight(&mut deps, info.sender.clone(), timestamp, sender_bond)?;
+    let transferred_weight =
+        sender_bond.weight * Decimal::from_ratio(asset.amount, sender_bond.asset.amount);
+    sender_bond.weight = sender_bond.weight.checked_sub(transferred_weight)?;
+    sender_bond.asset.amount = sender_bond.asset.amount.checked_sub(asset.amount)?;
+
+    if sender_bond.asset.amount.is_zero() {
+        BOND.remove(deps.storage, (&info.sender, &denom));
+    } else {
+        BOND.save(deps.storage, (&info.sender, &denom), &sender_bond)?;
+    }
+
+    // bring the recipient's weight up to date before adding the transferred amount and weight
+    let mut recipient_bond = BOND
+        .key((&recipient, &denom))
+        .may_load(deps.storage)?
+        .unwrap_or(Bond {
+            asset: Asset {
+                amount: Uint128::zero(),
+                ..asset.clone()
+            },
+            ..Bond::default()
+        });
+
+    recipient_bond = update_local_weight(&mut deps, recipient.clone(), timestamp, recipient_bond)?;
+    recipient_bond.asset.amount = recipient_bond.asset.amount.checked_add(asset.amount)?;
+    recipient_bond.weight = recipient_bond.weight.checked_add(transferred_weight)?;
+
+    BOND.save(deps.storage, (&recipient, &denom), &recipient_bond)?;
+
+    Ok(Response::default().add_attributes(vec![
+        ("action", "transfer_bond".to_string()),
+        ("address", info.sender.to_string()),
+        ("recipient", recipient.to_string()),
+        ("asset", asset.to_string()),
+        ("weight", transferred_weight.to_string()),
+    ]))
+}
+
 /// Withdraws the rewards for the provided address
 pub(crate) fn withdraw(
     deps: DepsMut,
@@ -193,10 +277,10 @@ pub(crate) fn withdraw(
 pub(crate) fn update_config(
     deps: DepsMut,
     info: MessageInfo,
-    owner: Option<String>,
     unbonding_period: Option<Uint64>,
     growth_rate: Option<Decimal>,
     fee_distributor_addr: Option<String>,
+    gauges_addr: Option<String>,
 ) -> Result<Response, ContractError> {
     // check the owner is the one who sent the message
     let mut config = CONFIG.load(deps.storage)?;
@@ -204,10 +288,6 @@ pub(crate) fn update_config(
         return Err(ContractError::Unauthorized {});
     }
 
-    if let Some(owner) = owner {
-        config.owner = deps.api.addr_validate(&owner)?;
-    }
-
     if let Some(unbonding_period) = unbonding_period {
         config.unbonding_period = unbonding_period;
     }
@@ -221,6 +301,10 @@ pub(crate) fn update_config(
         config.fee_distributor_addr = deps.api.addr_validate(&fee_distributor_addr)?;
     }
 
+    if let Some(gauges_addr) = gauges_addr {
+        config.gauges_addr = Some(deps.api.addr_validate(&gauges_addr)?);
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::default().add_attributes(vec![
@@ -230,3 +314,22 @@ pub(crate) fn update_config(
         ("growth_rate", config.growth_rate.to_string()),
     ]))
 }
+
+/// Proposes, accepts or cancels a transfer of the ownership of the contract
+pub(crate) fn update_ownership(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    action: OwnershipAction,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    let (response, new_owner) =
+        ownership::update_ownership(deps.branch(), &env, &info, &config.owner, action)?;
+
+    if let Some(new_owner) = new_owner {
+        config.owner = new_owner;
+        CONFIG.save(deps.storage, &config)?;
+    }
+
+    Ok(response)
+}
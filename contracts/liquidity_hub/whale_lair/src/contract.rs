@@ -2,6 +2,7 @@ use cosmwasm_std::{entry_point, Addr};
 use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
 use cw2::{get_contract_version, set_contract_version};
 use semver::Version;
+use white_whale_std::ownership::query_ownership_proposal;
 use white_whale_std::pool_network::asset::AssetInfo;
 
 use white_whale_std::whale_lair::{Config, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
@@ -47,6 +48,7 @@ pub fn instantiate(
         growth_rate: msg.growth_rate,
         bonding_assets: msg.bonding_assets,
         fee_distributor_addr: Addr::unchecked(""),
+        gauges_addr: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
@@ -80,19 +82,23 @@ pub fn execute(
         ExecuteMsg::Withdraw { denom } => {
             commands::withdraw(deps, env.block.time, info.sender, denom)
         }
+        ExecuteMsg::TransferBond { asset, recipient } => {
+            commands::transfer_bond(deps, env.block.time, info, env, asset, recipient)
+        }
         ExecuteMsg::UpdateConfig {
-            owner,
             unbonding_period,
             growth_rate,
             fee_distributor_addr,
+            gauges_addr,
         } => commands::update_config(
             deps,
             info,
-            owner,
             unbonding_period,
             growth_rate,
             fee_distributor_addr,
+            gauges_addr,
         ),
+        ExecuteMsg::UpdateOwnership(action) => commands::update_ownership(deps, env, info, action),
     }
 }
 
@@ -137,6 +143,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::TotalBonded {} => to_json_binary(&queries::query_total_bonded(deps)?),
         QueryMsg::GlobalIndex {} => to_json_binary(&queries::query_global_index(deps)?),
+        QueryMsg::OwnershipProposal {} => to_json_binary(&query_ownership_proposal(deps.storage)?),
     }
 }
 
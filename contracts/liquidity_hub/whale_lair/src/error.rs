@@ -45,6 +45,18 @@ pub enum ContractError {
     #[error("Nothing to withdraw.")]
     NothingToWithdraw {},
 
+    #[error("Nothing to transfer.")]
+    NothingToTransfer {},
+
+    #[error("The amount of tokens to transfer must be greater than zero.")]
+    InvalidTransferAmount {},
+
+    #[error("The amount of tokens to transfer is greater than the amount of tokens bonded.")]
+    InsufficientBondToTransfer {},
+
+    #[error("Can't transfer a bond to the same address.")]
+    InvalidTransferRecipient {},
+
     #[error("Attempt to migrate to version {new_version}, but contract is on a higher version {current_version}")]
     MigrateInvalidVersion {
         new_version: Version,
@@ -56,6 +68,9 @@ pub enum ContractError {
 
     #[error("Trying to bond/unbond at a late time before the new/latest epoch has been created")]
     NewEpochNotCreatedYet {},
+
+    #[error("The bond is locked until the gauges epoch {epoch_id} is over, as it was used to vote on it")]
+    BondLockedByVote { epoch_id: u64 },
 }
 
 impl From<semver::Error> for ContractError {
@@ -1,6 +1,7 @@
-use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, StdResult, Timestamp, Uint64};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, MessageInfo, StdResult, Timestamp, Uint64};
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
 use white_whale_std::fee_distributor::{ClaimableEpochsResponse, EpochResponse};
+use white_whale_std::gauges::{CurrentEpochResponse, VoteResponse};
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 
 use crate::error::ContractError;
@@ -42,7 +43,7 @@ pub fn validate_funds(
 }
 
 /// if user has unclaimed rewards, fail with an exception prompting them to claim
-pub fn validate_claimed(deps: &DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+pub fn validate_claimed(deps: &DepsMut, address: &Addr) -> Result<(), ContractError> {
     // Query fee distributor
     // if user has unclaimed rewards, fail with an exception prompting them to claim
     let config = CONFIG.load(deps.storage)?;
@@ -52,7 +53,7 @@ pub fn validate_claimed(deps: &DepsMut, info: &MessageInfo) -> Result<(), Contra
     let claimable_rewards: ClaimableEpochsResponse = deps.querier.query_wasm_smart(
         fee_distributor,
         &white_whale_std::fee_distributor::QueryMsg::Claimable {
-            address: info.sender.to_string(),
+            address: address.to_string(),
         },
     )?;
 
@@ -64,6 +65,37 @@ pub fn validate_claimed(deps: &DepsMut, info: &MessageInfo) -> Result<(), Contra
     Ok(())
 }
 
+/// Validates that the address didn't vote on the current epoch of the gauges contract, if any. The
+/// bonds of the voters are locked until the epoch is over, so their voting power can't be moved
+/// around and used more than once.
+pub fn validate_not_voted(deps: &DepsMut, address: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let Some(gauges) = config.gauges_addr else {
+        return Ok(());
+    };
+
+    let current_epoch: CurrentEpochResponse = deps.querier.query_wasm_smart(
+        gauges.to_string(),
+        &white_whale_std::gauges::QueryMsg::CurrentEpoch {},
+    )?;
+
+    let vote_response: VoteResponse = deps.querier.query_wasm_smart(
+        gauges,
+        &white_whale_std::gauges::QueryMsg::Vote {
+            address: address.to_string(),
+            epoch_id: current_epoch.epoch_id,
+        },
+    )?;
+
+    if vote_response.vote.is_some() {
+        return Err(ContractError::BondLockedByVote {
+            epoch_id: current_epoch.epoch_id,
+        });
+    }
+
+    Ok(())
+}
+
 /// Validates that the current time is not more than a day after the epoch start time. Helps preventing
 /// global_index timestamp issues when querying the weight.
 pub fn validate_bonding_for_current_epoch(deps: &DepsMut, env: &Env) -> Result<(), ContractError> {
@@ -28,6 +28,7 @@ pub fn migrate_to_v090(deps: DepsMut) -> Result<(), StdError> {
         growth_rate: config_v080.growth_rate,
         bonding_assets: config_v080.bonding_assets,
         fee_distributor_addr: Addr::unchecked(""), // set it empty, then update with the new value
+        gauges_addr: None,
     };
 
     CONFIG.save(deps.storage, &config)?;
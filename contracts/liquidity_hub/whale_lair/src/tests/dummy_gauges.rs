@@ -0,0 +1,53 @@
+use cosmwasm_std::{to_json_binary, Addr, Response, StdError, Uint128};
+use cw_multi_test::ContractWrapper;
+use cw_storage_plus::Map;
+
+use white_whale_std::gauges::{
+    CurrentEpochResponse, ExecuteMsg, InstantiateMsg, QueryMsg, Vote, VoteResponse,
+};
+
+const DUMMY_GAUGES_EPOCH: u64 = 1u64;
+const DUMMY_VOTES: Map<&Addr, Vote> = Map::new("votes");
+
+/// Creates a gauges contract that only records the votes cast on its first epoch, so the whale lair
+/// can be tested locking the bonds of the voters.
+pub fn create_dummy_gauges_contract(
+) -> ContractWrapper<ExecuteMsg, InstantiateMsg, QueryMsg, StdError, StdError, StdError> {
+    ContractWrapper::new(
+        |deps, _env, info, msg| match msg {
+            ExecuteMsg::Vote { votes } => {
+                DUMMY_VOTES.save(
+                    deps.storage,
+                    &info.sender,
+                    &Vote {
+                        voting_power: Uint128::one(),
+                        votes,
+                    },
+                )?;
+                Ok(Response::new())
+            }
+            _ => Err(StdError::generic_err("unsupported")),
+        },
+        |_deps, _env, _info, _msg| Ok(Response::new()),
+        |deps, _env, msg| match msg {
+            QueryMsg::CurrentEpoch {} => to_json_binary(&CurrentEpochResponse {
+                epoch_id: DUMMY_GAUGES_EPOCH,
+            }),
+            QueryMsg::Vote { address, epoch_id } => {
+                let address = Addr::unchecked(address);
+                let vote = if epoch_id == DUMMY_GAUGES_EPOCH {
+                    DUMMY_VOTES.may_load(deps.storage, &address)?
+                } else {
+                    None
+                };
+
+                to_json_binary(&VoteResponse {
+                    address,
+                    epoch_id,
+                    vote,
+                })
+            }
+            _ => Err(StdError::generic_err("unsupported")),
+        },
+    )
+}
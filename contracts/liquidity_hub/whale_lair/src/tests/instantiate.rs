@@ -35,6 +35,7 @@ fn test_instantiate_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            gauges_addr: None,
         });
 }
 
@@ -1,6 +1,9 @@
 mod bond;
+mod dummy_gauges;
 mod instantiate;
 mod robot;
+mod transfer_bond;
 mod unbond;
 mod update_config;
+mod update_ownership;
 mod withdraw;
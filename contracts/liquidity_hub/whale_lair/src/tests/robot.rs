@@ -1,7 +1,8 @@
-use cosmwasm_std::{coin, Addr, Coin, Decimal, StdResult, Uint64};
+use cosmwasm_std::{coin, Addr, Coin, Decimal, StdResult, Uint128, Uint64};
 use cw_multi_test::{App, AppResponse, Executor};
 
 use white_whale_std::epoch_manager::epoch_manager::EpochConfig;
+use white_whale_std::ownership::{OwnershipAction, OwnershipProposal};
 use white_whale_std::pool_network::asset::{Asset, AssetInfo};
 use white_whale_std::whale_lair::{
     BondedResponse, BondingWeightResponse, Config, ExecuteMsg, InstantiateMsg, QueryMsg,
@@ -12,11 +13,14 @@ use white_whale_testing::integration::contracts::{
 };
 use white_whale_testing::integration::integration_mocks::mock_app_with_balance;
 
+use crate::tests::dummy_gauges::create_dummy_gauges_contract;
+
 pub struct TestingRobot {
     app: App,
     pub sender: Addr,
     pub another_sender: Addr,
     whale_lair_addr: Addr,
+    gauges_addr: Addr,
 }
 
 /// instantiate / execute messages
@@ -51,6 +55,7 @@ impl TestingRobot {
             sender,
             another_sender,
             whale_lair_addr: Addr::unchecked(""),
+            gauges_addr: Addr::unchecked(""),
         }
     }
 
@@ -118,9 +123,14 @@ impl TestingRobot {
                         duration: Uint64::new(86_400_000_000_000u64), // a day
                         genesis_epoch: Uint64::new(1678802400_000000000u64), // March 14, 2023 2:00:00 PM
                     },
-                    distribution_asset: AssetInfo::NativeToken {
-                        denom: "uwhale".to_string(),
-                    },
+                    distribution_assets: vec![
+                        white_whale_std::fee_distributor::DistributionAsset {
+                            asset_info: AssetInfo::NativeToken {
+                                denom: "uwhale".to_string(),
+                            },
+                            aggregation_ratio: Decimal::one(),
+                        },
+                    ],
                 },
                 &[],
                 "fee_distributor",
@@ -131,9 +141,9 @@ impl TestingRobot {
         // So that we can check claims before letting them bond/unbond
         let msg = ExecuteMsg::UpdateConfig {
             fee_distributor_addr: Some(fee_distributor_address.clone().to_string()),
-            owner: None,
             unbonding_period: None,
             growth_rate: None,
+            gauges_addr: None,
         };
         self.app
             .execute_contract(self.sender.clone(), whale_lair_addr.clone(), &msg, &[])
@@ -143,6 +153,67 @@ impl TestingRobot {
         self
     }
 
+    /// Instantiates a dummy gauges contract and sets it on the config of the whale lair.
+    pub(crate) fn instantiate_dummy_gauges(&mut self) -> &mut Self {
+        let gauges_id = self
+            .app
+            .store_code(Box::new(create_dummy_gauges_contract()));
+
+        let gauges_addr = self
+            .app
+            .instantiate_contract(
+                gauges_id,
+                self.sender.clone(),
+                &white_whale_std::gauges::InstantiateMsg {
+                    whale_lair_addr: self.whale_lair_addr.to_string(),
+                    incentive_factory_addr: "incentive_factory".to_string(),
+                    epoch_manager_addr: "epoch_manager".to_string(),
+                    emission_denom: "uwhale".to_string(),
+                    emissions_per_epoch: Uint128::zero(),
+                    flow_duration: 1u64,
+                },
+                &[],
+                "gauges",
+                None,
+            )
+            .unwrap();
+
+        let msg = ExecuteMsg::UpdateConfig {
+            fee_distributor_addr: None,
+            unbonding_period: None,
+            growth_rate: None,
+            gauges_addr: Some(gauges_addr.to_string()),
+        };
+        self.app
+            .execute_contract(self.sender.clone(), self.whale_lair_addr.clone(), &msg, &[])
+            .unwrap();
+        self.gauges_addr = gauges_addr;
+
+        self
+    }
+
+    pub(crate) fn vote(
+        &mut self,
+        sender: Addr,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = white_whale_std::gauges::ExecuteMsg::Vote {
+            votes: vec![white_whale_std::gauges::GaugeVote {
+                lp_asset: AssetInfo::NativeToken {
+                    denom: "lp".to_string(),
+                },
+                weight: Decimal::one(),
+            }],
+        };
+
+        response(
+            self.app
+                .execute_contract(sender, self.gauges_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
     pub(crate) fn instantiate_err(
         &mut self,
         unbonding_period: Uint64,
@@ -208,19 +279,35 @@ impl TestingRobot {
         self
     }
 
+    pub(crate) fn transfer_bond(
+        &mut self,
+        sender: Addr,
+        asset: Asset,
+        recipient: String,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::TransferBond { asset, recipient };
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
+
     pub(crate) fn update_config(
         &mut self,
         sender: Addr,
-        owner: Option<String>,
         unbonding_period: Option<Uint64>,
         growth_rate: Option<Decimal>,
         response: impl Fn(Result<AppResponse, anyhow::Error>),
     ) -> &mut Self {
         let msg = ExecuteMsg::UpdateConfig {
-            owner,
             unbonding_period,
             growth_rate,
             fee_distributor_addr: None,
+            gauges_addr: None,
         };
 
         response(
@@ -230,6 +317,22 @@ impl TestingRobot {
 
         self
     }
+
+    pub(crate) fn update_ownership(
+        &mut self,
+        sender: Addr,
+        action: OwnershipAction,
+        response: impl Fn(Result<AppResponse, anyhow::Error>),
+    ) -> &mut Self {
+        let msg = ExecuteMsg::UpdateOwnership(action);
+
+        response(
+            self.app
+                .execute_contract(sender, self.whale_lair_addr.clone(), &msg, &[]),
+        );
+
+        self
+    }
 }
 
 fn instantiate_contract(
@@ -378,6 +481,21 @@ impl TestingRobot {
 
 /// assertions
 impl TestingRobot {
+    pub(crate) fn query_ownership_proposal(
+        &mut self,
+        response: impl Fn(StdResult<(&mut Self, Option<OwnershipProposal>)>),
+    ) -> &mut Self {
+        let proposal: Option<OwnershipProposal> = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.whale_lair_addr, &QueryMsg::OwnershipProposal {})
+            .unwrap();
+
+        response(Ok((self, proposal)));
+
+        self
+    }
+
     pub(crate) fn assert_config(&mut self, expected: Config) -> &mut Self {
         self.query_config(|res| {
             let config = res.unwrap().1;
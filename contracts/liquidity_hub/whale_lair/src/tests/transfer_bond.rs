@@ -0,0 +1,239 @@
+use cosmwasm_std::{coins, Decimal, Timestamp, Uint128};
+
+use white_whale_std::pool_network::asset::{Asset, AssetInfo};
+use white_whale_std::whale_lair::{BondedResponse, BondingWeightResponse};
+
+use crate::tests::robot::TestingRobot;
+use crate::ContractError;
+
+#[test]
+fn test_transfer_bond_successfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .fast_forward(10u64)
+        .transfer_bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(400u128),
+            },
+            another_sender.to_string(),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .assert_bonded_response(
+            sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(600u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(600u128),
+                }],
+                first_bonded_epoch_id: Default::default(),
+            },
+        )
+        .assert_bonded_response(
+            another_sender.to_string(),
+            BondedResponse {
+                total_bonded: Uint128::new(400u128),
+                bonded_assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "ampWHALE".to_string(),
+                    },
+                    amount: Uint128::new(400u128),
+                }],
+                first_bonded_epoch_id: Default::default(),
+            },
+        )
+        .assert_bonding_weight_response(
+            sender.to_string(),
+            BondingWeightResponse {
+                address: sender.to_string(),
+                weight: Uint128::new(6_600u128),
+                global_weight: Uint128::new(11_000u128),
+                share: Decimal::from_ratio(6_600u128, 11_000u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .assert_bonding_weight_response(
+            another_sender.to_string(),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(4_400u128),
+                global_weight: Uint128::new(11_000u128),
+                share: Decimal::from_ratio(4_400u128, 11_000u128),
+                timestamp: Timestamp::from_nanos(1571797429879305533u64),
+            },
+        )
+        .query_total_bonded(|res| {
+            let bonded_response = res.unwrap().1;
+            assert_eq!(bonded_response.total_bonded, Uint128::new(1_000u128));
+        })
+        .fast_forward(10u64)
+        .assert_bonding_weight_response(
+            another_sender.to_string(),
+            BondingWeightResponse {
+                address: another_sender.to_string(),
+                weight: Uint128::new(8_400u128),
+                global_weight: Uint128::new(21_000u128),
+                share: Decimal::from_ratio(8_400u128, 21_000u128),
+                timestamp: Timestamp::from_nanos(1571797439879305533u64),
+            },
+        );
+}
+
+#[test]
+fn test_transfer_bond_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .transfer_bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            another_sender.to_string(),
+            |res| {
+                // nothing bonded yet
+                assert!(res.is_err());
+            },
+        )
+        .bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(1_000u128),
+            },
+            &coins(1_000u128, "ampWHALE"),
+            |_res| {},
+        )
+        .transfer_bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(2_000u128),
+            },
+            another_sender.to_string(),
+            |res| {
+                // can't transfer more than what was bonded
+                assert!(res.is_err());
+            },
+        )
+        .transfer_bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::zero(),
+            },
+            another_sender.to_string(),
+            |res| {
+                assert!(res.is_err());
+            },
+        )
+        .transfer_bond(
+            sender.clone(),
+            Asset {
+                info: AssetInfo::NativeToken {
+                    denom: "ampWHALE".to_string(),
+                },
+                amount: Uint128::new(500u128),
+            },
+            sender.to_string(),
+            |res| {
+                // can't transfer to itself
+                assert!(res.is_err());
+            },
+        );
+}
+
+#[test]
+fn test_bond_is_locked_after_voting_on_gauges() {
+    let mut robot = TestingRobot::default();
+    let sender = robot.sender.clone();
+    let another_sender = robot.another_sender.clone();
+
+    let asset = |amount: u128| Asset {
+        info: AssetInfo::NativeToken {
+            denom: "ampWHALE".to_string(),
+        },
+        amount: Uint128::new(amount),
+    };
+
+    robot
+        .instantiate_default()
+        .instantiate_dummy_gauges()
+        .bond(
+            sender.clone(),
+            asset(1_000u128),
+            &coins(1_000u128, "ampWHALE"),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .fast_forward(10u64)
+        // the bond can be moved around before voting
+        .transfer_bond(
+            sender.clone(),
+            asset(100u128),
+            another_sender.to_string(),
+            |res| {
+                res.unwrap();
+            },
+        )
+        .vote(sender.clone(), |res| {
+            res.unwrap();
+        })
+        .transfer_bond(
+            sender.clone(),
+            asset(400u128),
+            another_sender.to_string(),
+            |res| {
+                assert_eq!(
+                    res.unwrap_err().root_cause().to_string(),
+                    ContractError::BondLockedByVote { epoch_id: 1 }.to_string()
+                );
+            },
+        )
+        .unbond(sender.clone(), asset(400u128), |res| {
+            assert_eq!(
+                res.unwrap_err().root_cause().to_string(),
+                ContractError::BondLockedByVote { epoch_id: 1 }.to_string()
+            );
+        })
+        // addresses that didn't vote can still unbond
+        .unbond(another_sender.clone(), asset(100u128), |res| {
+            res.unwrap();
+        });
+}
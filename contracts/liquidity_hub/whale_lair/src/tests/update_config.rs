@@ -25,10 +25,10 @@ fn test_update_config_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            gauges_addr: None,
         })
         .update_config(
             owner.clone(),
-            None,
             Some(Uint64::new(500u64)),
             Some(Decimal::from_ratio(
                 Uint128::new(1u128),
@@ -49,16 +49,11 @@ fn test_update_config_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            gauges_addr: None,
         })
-        .update_config(
-            owner,
-            Some("new_owner".to_string()),
-            None,
-            Some(Decimal::one()),
-            |_res| {},
-        )
+        .update_config(owner.clone(), None, Some(Decimal::one()), |_res| {})
         .assert_config(Config {
-            owner: Addr::unchecked("new_owner"),
+            owner,
             unbonding_period: Uint64::new(500u64),
             growth_rate: Decimal::one(),
             bonding_assets: vec![
@@ -70,6 +65,7 @@ fn test_update_config_successfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            gauges_addr: None,
         });
 }
 
@@ -92,10 +88,10 @@ fn test_update_config_unsuccessfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            gauges_addr: None,
         })
         .update_config(
             Addr::unchecked("unauthorized"),
-            None,
             Some(Uint64::new(500u64)),
             Some(Decimal::from_ratio(
                 Uint128::new(1u128),
@@ -122,10 +118,10 @@ fn test_update_config_unsuccessfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            gauges_addr: None,
         })
         .update_config(
             Addr::unchecked("owner"),
-            None,
             Some(Uint64::new(500u64)),
             Some(Decimal::from_ratio(
                 Uint128::new(2u128),
@@ -152,5 +148,6 @@ fn test_update_config_unsuccessfully() {
                 },
             ],
             fee_distributor_addr: Addr::unchecked("contract2"),
+            gauges_addr: None,
         });
 }
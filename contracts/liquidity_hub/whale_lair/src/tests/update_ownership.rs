@@ -0,0 +1,100 @@
+use cosmwasm_std::Addr;
+
+use white_whale_std::ownership::OwnershipAction;
+
+use crate::tests::robot::TestingRobot;
+
+#[test]
+fn test_update_ownership_successfully() {
+    let mut robot = TestingRobot::default();
+    let owner = robot.sender.clone();
+    let new_owner = robot.another_sender.clone();
+
+    robot
+        .instantiate_default()
+        .update_ownership(
+            owner.clone(),
+            OwnershipAction::ProposeNewOwner {
+                new_owner: new_owner.to_string(),
+                expiry: None,
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_ownership_proposal(|res| {
+            let (_, proposal) = res.unwrap();
+            assert_eq!(proposal.unwrap().proposed_owner, new_owner);
+        })
+        // the ownership isn't transferred until the proposed owner accepts it
+        .query_config(|res| {
+            let (_, config) = res.unwrap();
+            assert_eq!(config.owner, owner);
+        })
+        .update_ownership(
+            new_owner.clone(),
+            OwnershipAction::AcceptOwnership {},
+            |res| {
+                res.unwrap();
+            },
+        )
+        .query_config(|res| {
+            let (_, config) = res.unwrap();
+            assert_eq!(config.owner, new_owner);
+        })
+        .query_ownership_proposal(|res| {
+            let (_, proposal) = res.unwrap();
+            assert_eq!(proposal, None);
+        });
+}
+
+#[test]
+fn test_update_ownership_unsuccessfully() {
+    let mut robot = TestingRobot::default();
+    let owner = robot.sender.clone();
+
+    robot
+        .instantiate_default()
+        // only the owner can propose a new owner
+        .update_ownership(
+            Addr::unchecked("unauthorized"),
+            OwnershipAction::ProposeNewOwner {
+                new_owner: "unauthorized".to_string(),
+                expiry: None,
+            },
+            |res| {
+                res.unwrap_err();
+            },
+        )
+        .update_ownership(
+            owner.clone(),
+            OwnershipAction::ProposeNewOwner {
+                new_owner: "new_owner".to_string(),
+                expiry: Some(10u64),
+            },
+            |res| {
+                res.unwrap();
+            },
+        )
+        // only the proposed owner can accept the ownership
+        .update_ownership(
+            Addr::unchecked("unauthorized"),
+            OwnershipAction::AcceptOwnership {},
+            |res| {
+                res.unwrap_err();
+            },
+        )
+        // the proposal can't be accepted once expired
+        .fast_forward(10u64)
+        .update_ownership(
+            Addr::unchecked("new_owner"),
+            OwnershipAction::AcceptOwnership {},
+            |res| {
+                res.unwrap_err();
+            },
+        )
+        .query_config(|res| {
+            let (_, config) = res.unwrap();
+            assert_eq!(config.owner, owner);
+        });
+}
@@ -5,10 +5,14 @@ use std::fmt::Display;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Timestamp, Uint64};
 
+use crate::ownership::{OwnershipAction, OwnershipProposal};
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub start_epoch: EpochV2,
     pub epoch_config: EpochConfig,
+    /// How missed epochs are handled when creating a new epoch.
+    pub catch_up_mode: CatchUpMode,
 }
 
 #[cw_serde]
@@ -21,9 +25,22 @@ pub enum ExecuteMsg {
         contract_addr: String,
     },
     UpdateConfig {
-        owner: Option<String>,
         epoch_config: Option<EpochConfig>,
+        catch_up_mode: Option<CatchUpMode>,
+    },
+    /// Dispatches the epoch changed hook again to a hook that failed for the given epoch.
+    RetryHook {
+        contract_addr: String,
+        epoch_id: u64,
+    },
+    /// Adds or removes hook managers, i.e. addresses other than the owner allowed to add and
+    /// remove hooks, such as factory contracts registering the contracts they create.
+    UpdateHookManagers {
+        add: Vec<String>,
+        remove: Vec<String>,
     },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -40,6 +57,22 @@ pub enum QueryMsg {
     /// Returns the epoch with the given id.
     #[returns(EpochResponse)]
     Epoch { id: u64 },
+
+    /// Returns the hooks that failed when the given epoch was created.
+    #[returns(FailedHooksResponse)]
+    FailedHooks { epoch_id: u64 },
+
+    /// Returns the hooks registered in the contract.
+    #[returns(HooksResponse)]
+    Hooks {},
+
+    /// Returns the addresses allowed to add and remove hooks besides the owner.
+    #[returns(HookManagersResponse)]
+    HookManagers {},
+
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 #[cw_serde]
@@ -48,6 +81,7 @@ pub struct MigrateMsg {}
 #[cw_serde]
 pub struct Config {
     pub epoch_config: EpochConfig,
+    pub catch_up_mode: CatchUpMode,
 }
 
 impl Config {
@@ -55,6 +89,7 @@ impl Config {
         ConfigResponse {
             owner,
             epoch_config: self.epoch_config,
+            catch_up_mode: self.catch_up_mode,
         }
     }
 }
@@ -63,6 +98,29 @@ impl Config {
 pub struct ConfigResponse {
     pub owner: Addr,
     pub epoch_config: EpochConfig,
+    pub catch_up_mode: CatchUpMode,
+}
+
+/// Defines how the epoch manager behaves when more than one epoch has elapsed since the current
+/// epoch started.
+#[cw_serde]
+pub enum CatchUpMode {
+    /// Moves forward a single epoch per call, firing the hooks for it.
+    Disabled,
+    /// Jumps straight to the current epoch, firing the hooks once for it.
+    FireOnce,
+    /// Creates all the missed epochs, firing the hooks for each one of them with its own id.
+    FireForEach,
+}
+
+impl Display for CatchUpMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatchUpMode::Disabled => write!(f, "disabled"),
+            CatchUpMode::FireOnce => write!(f, "fire_once"),
+            CatchUpMode::FireForEach => write!(f, "fire_for_each"),
+        }
+    }
 }
 
 #[cw_serde]
@@ -70,6 +128,34 @@ pub struct EpochResponse {
     pub epoch: EpochV2,
 }
 
+#[cw_serde]
+pub struct FailedHook {
+    /// The hook contract that failed to process the epoch changed hook.
+    pub contract_addr: Addr,
+    /// The epoch the hook failed for, which is sent again as is when retrying the hook.
+    pub epoch: EpochV2,
+    /// The error returned by the hook contract on its last attempt.
+    pub error: String,
+    /// The number of times the hook has been dispatched for the epoch.
+    pub attempts: u64,
+}
+
+#[cw_serde]
+pub struct FailedHooksResponse {
+    pub epoch_id: u64,
+    pub hooks: Vec<FailedHook>,
+}
+
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<String>,
+}
+
+#[cw_serde]
+pub struct HookManagersResponse {
+    pub hook_managers: Vec<Addr>,
+}
+
 #[cw_serde]
 pub struct ClaimableEpochsResponse {
     pub epochs: Vec<EpochV2>,
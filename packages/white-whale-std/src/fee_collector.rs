@@ -1,7 +1,10 @@
-use crate::fee_distributor::Epoch;
+use crate::fee_distributor::{DistributionAsset, Epoch};
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 use crate::pool_network::asset::{Asset, AssetInfo};
+use crate::pool_network::factory::PairVariant;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Coin, Decimal, Uint64};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128, Uint64};
+use std::fmt::{Display, Formatter};
 
 #[cw_serde]
 pub struct InstantiateMsg {}
@@ -10,17 +13,45 @@ pub struct InstantiateMsg {}
 pub enum ExecuteMsg {
     /// Collects protocol fees based on the configuration indicated by [FeesFor]
     CollectFees { collect_fees_for: FeesFor },
-    /// Swaps the assets (fees) sitting in the fee collector into the distribution asset set by the
-    /// fee collector. A [SwapRoute] should be available at the router to be able to make the swaps.
+    /// Swaps the assets (fees) sitting in the fee collector into the distribution assets set by the
+    /// fee distributor, following their target ratios. A [SwapRoute] should be available at the
+    /// router to be able to make the swaps.
     AggregateFees { aggregate_fees_for: FeesFor },
     /// Forward fees to the fee distributor. This will collect and aggregate the fees, to send them back to the fee distributor.
     ForwardFees {
         epoch: Epoch,
-        forward_fees_as: AssetInfo,
+        forward_fees_as: Vec<DistributionAsset>,
+    },
+    /// Collects and aggregates the fees of the next page of vaults and pools, continuing from where
+    /// the previous call left off. Meant to be called repeatedly, i.e. by a keeper, to go through
+    /// all the children of the factories across several transactions.
+    SweepFees { limit: Option<u32> },
+    /// Updates the settings used to protect the fee aggregation swaps, and sets the reference
+    /// prices of the given asset pairs. Only the owner can do this.
+    UpdateAggregationConfig {
+        /// The maximum spread allowed on the aggregation swaps.
+        max_spread: Decimal,
+        /// The maximum deviation allowed between the price of an aggregation swap and the reference
+        /// price of the asset pair, if any.
+        max_price_deviation: Decimal,
+        /// The time in seconds a reference price is checked against after being set.
+        reference_price_ttl: u64,
+        /// The reference prices to set, replacing the current ones of the same asset pairs.
+        reference_prices: Vec<ReferencePrice>,
+    },
+    /// Sets the maximum amount of the given assets that can be aggregated at once. Only the owner
+    /// can do this.
+    UpdateAggregationLimits { limits: Vec<AggregationLimit> },
+    /// Splits the take rate among the given recipients. If there are no splits, the whole take rate
+    /// goes to the take rate dao address. Only the owner can do this.
+    UpdateTakeRateSplits { splits: Vec<TakeRateSplit> },
+    /// Sets the share of the aggregated fees that is swapped to the burn asset and burned when the
+    /// fees are forwarded. [None] turns the buyback off. Only the owner can do this.
+    UpdateBuybackConfig {
+        buyback_config: Option<BuybackConfig>,
     },
     /// Updates the config
     UpdateConfig {
-        owner: Option<String>,
         pool_router: Option<String>,
         fee_distributor: Option<String>,
         pool_factory: Option<String>,
@@ -29,6 +60,8 @@ pub enum ExecuteMsg {
         take_rate_dao_address: Option<String>,
         is_take_rate_active: Option<bool>,
     },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -55,8 +88,56 @@ pub enum QueryMsg {
         all_time: Option<bool>,
     },
     /// Queries the take rate taken for the given epoch id.
-    #[returns(Coin)]
+    #[returns(Vec<Coin>)]
     TakeRateHistory { epoch_id: Uint64 },
+    /// Queries the recipients the take rate is split among.
+    #[returns(Vec<TakeRateSplit>)]
+    TakeRateSplits {},
+    /// Queries the take rate each recipient got for the given epoch id, in the order of the splits.
+    #[returns(Vec<TakeRateRecipientHistory>)]
+    TakeRateRecipientsHistory { epoch_id: Uint64 },
+    /// Queries the settings used to protect the fee aggregation swaps.
+    #[returns(AggregationConfig)]
+    AggregationConfig {},
+    /// Queries the reference prices the aggregation swaps are checked against.
+    #[returns(Vec<ReferencePriceRecord>)]
+    ReferencePrices {},
+    /// Queries the maximum amounts of the assets that can be aggregated at once.
+    #[returns(Vec<AggregationLimit>)]
+    AggregationLimits {},
+    /// Queries the progress of the fee collection over the children of the factories.
+    #[returns(FeeCollectionProgressResponse)]
+    FeeCollectionProgress {},
+    /// Queries the buyback settings. Returns [None] if the buyback is off.
+    #[returns(Option<BuybackConfig>)]
+    BuybackConfig {},
+    /// Queries the assets burned by the buyback at the given epoch, together with the cumulative
+    /// burns up to that epoch.
+    #[returns(BurnRecord)]
+    BurnHistory { epoch_id: Uint64 },
+    /// Queries the assets burned by the buyback since it was first turned on.
+    #[returns(Vec<Asset>)]
+    TotalBurned {},
+    /// Queries the fees collected from each contract when the fees were forwarded for the given
+    /// epoch, along with the amount aggregated, the take rate and the burns. The contracts are
+    /// paginated, `start_after` being the address of the last contract of the previous page.
+    #[returns(EpochFeeReportResponse)]
+    EpochFeeReport {
+        epoch_id: Uint64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Queries the fees collected from the given pool, trio or vault on each epoch. `start_after`
+    /// is the last epoch id of the previous page.
+    #[returns(PoolFeeHistoryResponse)]
+    PoolFeeHistory {
+        pool: String,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 #[cw_serde]
@@ -72,6 +153,12 @@ pub enum FactoryType {
     /// Pool Factory
     Pool {
         start_after: Option<[AssetInfo; 2]>,
+        start_after_variant: Option<PairVariant>,
+        limit: Option<u32>,
+    },
+    /// Pool Factory, for the trios it created
+    Trio {
+        start_after: Option<[AssetInfo; 3]>,
         limit: Option<u32>,
     },
 }
@@ -88,11 +175,49 @@ pub enum ContractType {
     Vault {},
     /// Pool/Pair contract type
     Pool {},
+    /// Trio/3pool contract type
+    Trio {},
 }
 
 #[cw_serde]
 pub struct ForwardFeesResponse {
     pub epoch: Epoch,
+    /// The aggregation swaps that were skipped while forwarding the fees. The assets are kept in
+    /// the contract and carried over to the next aggregation.
+    pub skipped_assets: Vec<SkippedAsset>,
+    /// The amounts above the aggregation limits, which are carried over to the next aggregation.
+    pub carried_over_assets: Vec<Asset>,
+}
+
+/// An aggregation swap that was skipped.
+#[cw_serde]
+pub struct SkippedAsset {
+    /// The asset that was not swapped, and its amount.
+    pub offer_asset: Asset,
+    /// The distribution asset it was meant to be swapped into.
+    pub ask_asset_info: AssetInfo,
+    pub reason: SkipReason,
+}
+
+/// The reasons an aggregation swap can be skipped for.
+#[cw_serde]
+pub enum SkipReason {
+    /// There's no swap route for the pair of assets.
+    NoSwapRoute,
+    /// The swap simulation failed.
+    SimulationFailed,
+    /// The price of the swap deviates too much from the reference price of the pair of assets.
+    PriceDeviation,
+}
+
+impl Display for SkipReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipReason::NoSwapRoute => write!(f, "no_swap_route"),
+            SkipReason::SimulationFailed => write!(f, "simulation_failed"),
+            SkipReason::PriceDeviation => write!(f, "price_deviation"),
+        }
+    }
 }
 
 #[cw_serde]
@@ -106,3 +231,172 @@ pub struct Config {
     pub take_rate_dao_address: Addr,
     pub is_take_rate_active: bool,
 }
+
+/// Tracks the fee collection over the children of a factory, so the fees can be collected and
+/// aggregated in batches across several transactions.
+#[cw_serde]
+pub struct FeeCollectionCursor {
+    /// The next page of the factory's children to collect the fees from.
+    pub next_page: FactoryType,
+    /// The amount of contracts the fees were collected from in the current sweep.
+    pub swept_contracts: u64,
+    /// The amount of times the fees were collected from all of the factory's children.
+    pub completed_sweeps: u64,
+}
+
+impl FeeCollectionCursor {
+    /// Creates a cursor starting at the given page.
+    pub fn new(first_page: FactoryType) -> Self {
+        Self {
+            next_page: first_page,
+            swept_contracts: 0,
+            completed_sweeps: 0,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct FeeCollectionProgressResponse {
+    /// The fee collection progress over the vaults.
+    pub vaults: FeeCollectionCursor,
+    /// The fee collection progress over the pools.
+    pub pools: FeeCollectionCursor,
+    /// The fee collection progress over the trios.
+    pub trios: FeeCollectionCursor,
+}
+
+/// The settings used to protect the fee aggregation swaps against slippage and price manipulation.
+#[cw_serde]
+pub struct AggregationConfig {
+    /// The maximum spread allowed on each swap operation of the aggregation swaps. It's also used
+    /// to compute the minimum amount to receive out of the swap simulation.
+    pub max_spread: Decimal,
+    /// The maximum deviation allowed between the price of an aggregation swap and the reference
+    /// price of the asset pair, if one is set. Swaps deviating further are skipped, and the asset
+    /// is carried over to the next aggregation.
+    pub max_price_deviation: Decimal,
+    /// The time in seconds a reference price is checked against after being set. Once older, the
+    /// swaps of the asset pair are only bound by the simulation and the max spread again.
+    pub reference_price_ttl: u64,
+}
+
+impl Default for AggregationConfig {
+    fn default() -> Self {
+        Self {
+            max_spread: Decimal::percent(50u64),
+            max_price_deviation: Decimal::percent(5u64),
+            reference_price_ttl: 86_400u64,
+        }
+    }
+}
+
+/// The price of an asset pair set by the owner, which the aggregation swaps are checked against.
+#[cw_serde]
+pub struct ReferencePrice {
+    pub offer_asset_info: AssetInfo,
+    pub ask_asset_info: AssetInfo,
+    /// The price, in ask asset per offer asset.
+    pub price: Decimal,
+}
+
+/// A reference price, along with the time it was set.
+#[cw_serde]
+pub struct ReferencePriceRecord {
+    pub reference_price: ReferencePrice,
+    /// The time the reference price was set, in seconds.
+    pub updated_at: u64,
+}
+
+/// The maximum amount of an asset that can be aggregated at once. Whatever is above the limit is
+/// carried over to the next aggregation.
+#[cw_serde]
+pub struct AggregationLimit {
+    pub asset_info: AssetInfo,
+    /// The maximum amount to aggregate. [None] removes the limit.
+    pub max_amount: Option<Uint128>,
+}
+
+/// A share of the take rate that goes to a given recipient.
+#[cw_serde]
+pub struct TakeRateSplit {
+    pub recipient: TakeRateRecipient,
+    /// The share of the take rate the recipient gets. The shares of all the splits must add up to 1.
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub enum TakeRateRecipient {
+    /// The take rate is sent to the given address, i.e. an insurance fund or a grants program.
+    Address { address: String },
+    /// The take rate is burned.
+    Burn {},
+}
+
+impl std::fmt::Display for TakeRateRecipient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TakeRateRecipient::Address { address } => write!(f, "{address}"),
+            TakeRateRecipient::Burn {} => write!(f, "burn"),
+        }
+    }
+}
+
+/// The take rate a recipient got for a given epoch.
+#[cw_serde]
+pub struct TakeRateRecipientHistory {
+    pub recipient: TakeRateRecipient,
+    pub take_rate: Vec<Coin>,
+}
+
+/// The settings of the buyback, used to burn a share of the aggregated fees.
+#[cw_serde]
+pub struct BuybackConfig {
+    /// The asset the fees are swapped to and burned, i.e. WHALE.
+    pub burn_asset: AssetInfo,
+    /// The share of the aggregated fees used for the buyback.
+    pub share: Decimal,
+}
+
+/// The assets burned by the buyback at a given epoch.
+#[cw_serde]
+pub struct BurnRecord {
+    /// The assets burned at the epoch.
+    pub burned: Vec<Asset>,
+    /// The assets burned up to and including the epoch.
+    pub cumulative_burned: Vec<Asset>,
+}
+
+/// The fees collected from a contract.
+#[cw_serde]
+pub struct ContractFees {
+    pub contract: Contract,
+    pub fees: Vec<Asset>,
+}
+
+#[cw_serde]
+pub struct EpochFeeReportResponse {
+    pub epoch_id: Uint64,
+    /// The fees aggregated into the distribution assets, before the take rate and the buyback.
+    pub aggregated_fees: Vec<Asset>,
+    /// The fees forwarded to the fee distributor.
+    pub forwarded_fees: Vec<Asset>,
+    /// The take rate kept from the aggregated fees.
+    pub take_rate: Vec<Coin>,
+    /// The assets burned by the buyback.
+    pub burned: Vec<Asset>,
+    /// The fees collected from each contract.
+    pub contract_fees: Vec<ContractFees>,
+}
+
+/// The fees collected from a contract at a given epoch.
+#[cw_serde]
+pub struct EpochFees {
+    pub epoch_id: Uint64,
+    pub fees: Vec<Asset>,
+}
+
+#[cw_serde]
+pub struct PoolFeeHistoryResponse {
+    pub pool: String,
+    pub history: Vec<EpochFees>,
+}
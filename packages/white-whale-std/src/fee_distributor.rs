@@ -1,8 +1,9 @@
 use crate::epoch_manager::epoch_manager::EpochConfig;
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 use crate::pool_network::asset::{Asset, AssetInfo};
 use crate::whale_lair::GlobalIndex;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Timestamp, Uint64};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint64};
 use std::fmt;
 use std::fmt::Display;
 
@@ -13,7 +14,24 @@ pub struct Config {
     pub fee_collector_addr: Addr,
     pub grace_period: Uint64,
     pub epoch_config: EpochConfig,
-    pub distribution_asset: AssetInfo,
+    pub distribution_assets: Vec<DistributionAsset>,
+}
+
+/// An asset the fees are distributed as, together with the share of the aggregated fees that should
+/// be converted into it.
+#[cw_serde]
+pub struct DistributionAsset {
+    pub asset_info: AssetInfo,
+    /// Share of each newly aggregated fee asset that is swapped into this asset. Fees collected in a
+    /// distribution asset are forwarded as they are, so the basket isn't rebalanced to these ratios.
+    /// The ratios of all the distribution assets must add up to 1.
+    pub aggregation_ratio: Decimal,
+}
+
+impl Display for DistributionAsset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.asset_info, self.aggregation_ratio)
+    }
 }
 
 #[cw_serde]
@@ -54,8 +72,8 @@ pub struct InstantiateMsg {
     pub grace_period: Uint64,
     /// Configuration for the epoch.
     pub epoch_config: EpochConfig,
-    /// The asset that is going to be distributed by the contracdt.
-    pub distribution_asset: AssetInfo,
+    /// The assets that are going to be distributed by the contract, with their target ratios.
+    pub distribution_assets: Vec<DistributionAsset>,
 }
 
 #[cw_serde]
@@ -70,13 +88,15 @@ pub enum ExecuteMsg {
 
     /// Updates the [Config] of the contract.
     UpdateConfig {
-        owner: Option<String>,
         bonding_contract_addr: Option<String>,
         fee_collector_addr: Option<String>,
         grace_period: Option<Uint64>,
-        distribution_asset: Option<AssetInfo>,
+        distribution_assets: Option<Vec<DistributionAsset>>,
         epoch_config: Option<EpochConfig>,
     },
+
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -101,6 +121,24 @@ pub enum QueryMsg {
     /// Returns the [Epoch]s that can be claimed by an address.
     #[returns(ClaimableEpochsResponse)]
     Claimable { address: String },
+
+    /// Returns the rewards claimed by an address on each epoch, ordered by epoch id.
+    /// Allows pagination with start_after and limit.
+    #[returns(ClaimHistoryResponse)]
+    ClaimHistory {
+        address: String,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the estimated rewards an address would get by claiming now, based on its bonding
+    /// weight on each of the epochs it can claim.
+    #[returns(EstimatedRewardsResponse)]
+    EstimatedRewards { address: String },
+
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 #[cw_serde]
@@ -113,5 +151,27 @@ pub struct ClaimableEpochsResponse {
     pub epochs: Vec<Epoch>,
 }
 
+/// Rewards for a given epoch.
+#[cw_serde]
+pub struct EpochRewards {
+    pub epoch_id: Uint64,
+    pub rewards: Vec<Asset>,
+}
+
+/// Response for the ClaimHistory query.
+#[cw_serde]
+pub struct ClaimHistoryResponse {
+    pub claims: Vec<EpochRewards>,
+}
+
+/// Response for the EstimatedRewards query.
+#[cw_serde]
+pub struct EstimatedRewardsResponse {
+    /// The aggregated rewards across all the claimable epochs.
+    pub total: Vec<Asset>,
+    /// The rewards for each of the claimable epochs.
+    pub epochs: Vec<EpochRewards>,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
@@ -0,0 +1,165 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+
+use crate::epoch_manager::hooks::EpochChangedHookMsg;
+use crate::ownership::{OwnershipAction, OwnershipProposal};
+use crate::pool_network::asset::AssetInfo;
+
+#[cw_serde]
+pub struct Config {
+    /// Owner of the contract.
+    pub owner: Addr,
+    /// The whale lair contract, where the voting power of the voters is taken from.
+    pub whale_lair_addr: Addr,
+    /// The incentive factory contract, used to find the incentive contract of each gauge.
+    pub incentive_factory_addr: Addr,
+    /// The epoch manager contract, which calls the contract when a new epoch is created.
+    pub epoch_manager_addr: Addr,
+    /// The denom of the protocol emissions split across the gauges.
+    pub emission_denom: String,
+    /// The amount of emissions split across the gauges every epoch.
+    pub emissions_per_epoch: Uint128,
+    /// The number of epochs the flows opened on the incentive contracts last.
+    pub flow_duration: u64,
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The whale lair contract, where the voting power of the voters is taken from.
+    pub whale_lair_addr: String,
+    /// The incentive factory contract, used to find the incentive contract of each gauge.
+    pub incentive_factory_addr: String,
+    /// The epoch manager contract, which calls the contract when a new epoch is created.
+    pub epoch_manager_addr: String,
+    /// The denom of the protocol emissions split across the gauges.
+    pub emission_denom: String,
+    /// The amount of emissions split across the gauges every epoch.
+    pub emissions_per_epoch: Uint128,
+    /// The number of epochs the flows opened on the incentive contracts last.
+    pub flow_duration: u64,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Casts the vote of the sender for the current epoch, splitting its whale lair weight across
+    /// the given gauges. Replaces the vote previously cast by the sender in the same epoch.
+    Vote { votes: Vec<GaugeVote> },
+    /// Adds the funds sent along the message as a bribe for the voters of the given gauge, to be
+    /// split across them proportionally to the votes they gave to it in the given epoch. If the
+    /// gauge gets no votes in that epoch, the bribe is carried over to the next one.
+    ///
+    /// If unspecified, the bribe is added for the current epoch.
+    AddBribe {
+        lp_asset: AssetInfo,
+        epoch_id: Option<u64>,
+    },
+    /// Claims the bribes for the gauges the sender voted for in the given epoch. Bribes can be
+    /// claimed once the epoch is over.
+    ClaimBribes { epoch_id: u64 },
+    /// Hook called by the epoch manager when a new epoch is created. Tallies the votes of the
+    /// epoch that ended and opens the flows on the incentive contracts of the voted gauges. Flows
+    /// that fail to open don't revert the hook, the error is recorded on the gauge instead.
+    EpochChangedHook(EpochChangedHookMsg),
+    /// Updates the [Config] of the contract.
+    ///
+    /// Unspecified fields will not be updated.
+    UpdateConfig {
+        whale_lair_addr: Option<String>,
+        incentive_factory_addr: Option<String>,
+        epoch_manager_addr: Option<String>,
+        emissions_per_epoch: Option<Uint128>,
+        flow_duration: Option<u64>,
+    },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns the [Config] of the contract.
+    #[returns(Config)]
+    Config {},
+
+    /// Returns the epoch in which votes are currently being cast.
+    #[returns(CurrentEpochResponse)]
+    CurrentEpoch {},
+
+    /// Returns the gauges voted in the given epoch, with their votes and bribes.
+    #[returns(GaugesResponse)]
+    Gauges { epoch_id: u64 },
+
+    /// Returns the vote cast by the given address in the given epoch.
+    #[returns(VoteResponse)]
+    Vote { address: String, epoch_id: u64 },
+
+    /// Returns the bribes the given address can claim for the given epoch.
+    #[returns(ClaimableBribesResponse)]
+    ClaimableBribes { address: String, epoch_id: u64 },
+
+    /// Returns the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+/// The share of a voter's voting power given to a gauge.
+#[cw_serde]
+pub struct GaugeVote {
+    /// The LP asset of the incentive contract the gauge directs emissions to.
+    pub lp_asset: AssetInfo,
+    /// The share of the voting power given to the gauge.
+    pub weight: Decimal,
+}
+
+/// A vote cast by an address in a given epoch.
+#[cw_serde]
+pub struct Vote {
+    /// The voting power of the voter when the vote was cast.
+    pub voting_power: Uint128,
+    /// The gauges voted for.
+    pub votes: Vec<GaugeVote>,
+}
+
+/// A gauge in a given epoch.
+#[cw_serde]
+pub struct Gauge {
+    /// The LP asset of the incentive contract.
+    pub lp_asset: AssetInfo,
+    /// The incentive contract the gauge directs emissions to.
+    pub incentive_addr: Addr,
+    /// The total votes the gauge received.
+    pub votes: Uint128,
+    /// The bribes added for the voters of the gauge.
+    pub bribes: Vec<Coin>,
+    /// The error returned by the incentive contract if the flow for the gauge couldn't be opened
+    /// once the epoch was over. The emissions of the gauge are then kept in the contract.
+    pub flow_error: Option<String>,
+}
+
+#[cw_serde]
+pub struct CurrentEpochResponse {
+    pub epoch_id: u64,
+}
+
+#[cw_serde]
+pub struct GaugesResponse {
+    pub epoch_id: u64,
+    /// The total votes cast in the epoch.
+    pub total_votes: Uint128,
+    pub gauges: Vec<Gauge>,
+}
+
+#[cw_serde]
+pub struct VoteResponse {
+    pub address: Addr,
+    pub epoch_id: u64,
+    pub vote: Option<Vote>,
+}
+
+#[cw_serde]
+pub struct ClaimableBribesResponse {
+    pub bribes: Vec<Coin>,
+}
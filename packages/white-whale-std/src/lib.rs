@@ -2,7 +2,10 @@ pub mod epoch_manager;
 pub mod fee;
 pub mod fee_collector;
 pub mod fee_distributor;
+pub mod gauges;
 pub mod migrate_guards;
+pub mod ownership;
+pub mod pause;
 pub mod pool_network;
 pub mod traits;
 pub mod vault_network;
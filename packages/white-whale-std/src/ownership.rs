@@ -0,0 +1,336 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    Addr, DepsMut, Env, MessageInfo, Response, StdError, StdResult, Storage, Timestamp,
+};
+use cw_storage_plus::{Item, Map};
+
+/// The time the proposed owner has to accept the ownership when no expiry is given, in seconds,
+/// i.e. 7 days.
+pub const DEFAULT_OWNERSHIP_PROPOSAL_EXPIRY: u64 = 604_800;
+
+const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+const ROLES: Map<&str, Addr> = Map::new("roles");
+
+/// The actions to transfer the ownership of a contract in two steps: the owner proposes a new
+/// owner, which then has to accept the ownership before the proposal expires.
+#[cw_serde]
+pub enum OwnershipAction {
+    /// Proposes a new owner, replacing the pending proposal if any. Only the owner can do this.
+    ProposeNewOwner {
+        new_owner: String,
+        /// The time the proposed owner has to accept the ownership, in seconds. If unspecified,
+        /// [DEFAULT_OWNERSHIP_PROPOSAL_EXPIRY] is used.
+        expiry: Option<u64>,
+    },
+    /// Accepts the pending proposal, making the sender the owner. Only the proposed owner can do
+    /// this, before the proposal expires.
+    AcceptOwnership {},
+    /// Cancels the pending proposal. Only the owner can do this.
+    CancelOwnershipProposal {},
+}
+
+/// A pending ownership transfer.
+#[cw_serde]
+pub struct OwnershipProposal {
+    pub proposed_owner: Addr,
+    pub expires_at: Timestamp,
+}
+
+/// The admin roles the owner can grant, so the day to day operations don't require the owner key.
+#[cw_serde]
+pub enum Role {
+    /// Can update the fees of the contracts.
+    FeeManager,
+    /// Can pause features, but not unpause them.
+    Pauser,
+    /// Can migrate the contracts.
+    Migrator,
+}
+
+impl Role {
+    fn key(&self) -> &'static str {
+        match self {
+            Role::FeeManager => "fee_manager",
+            Role::Pauser => "pauser",
+            Role::Migrator => "migrator",
+        }
+    }
+}
+
+/// The response for the `Roles` query, with the address holding each role, if any.
+#[cw_serde]
+pub struct RolesResponse {
+    pub fee_manager: Option<Addr>,
+    pub pauser: Option<Addr>,
+    pub migrator: Option<Addr>,
+}
+
+/// Handles the given [OwnershipAction] on a contract owned by `owner`. Returns the new owner once
+/// the ownership is accepted, which the contract has to persist in its own config.
+pub fn update_ownership(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    owner: &Addr,
+    action: OwnershipAction,
+) -> StdResult<(Response, Option<Addr>)> {
+    match action {
+        OwnershipAction::ProposeNewOwner { new_owner, expiry } => {
+            ensure_owner(info, owner)?;
+
+            let proposed_owner = deps.api.addr_validate(&new_owner)?;
+            if proposed_owner == *owner {
+                return Err(StdError::generic_err(
+                    "The proposed owner is already the owner",
+                ));
+            }
+
+            let expiry = expiry.unwrap_or(DEFAULT_OWNERSHIP_PROPOSAL_EXPIRY);
+            if expiry == 0 {
+                return Err(StdError::generic_err(
+                    "The ownership proposal expiry must be greater than zero",
+                ));
+            }
+
+            let proposal = OwnershipProposal {
+                proposed_owner,
+                expires_at: env.block.time.plus_seconds(expiry),
+            };
+            OWNERSHIP_PROPOSAL.save(deps.storage, &proposal)?;
+
+            Ok((
+                Response::new().add_attributes(vec![
+                    ("action", "propose_new_owner".to_string()),
+                    ("proposed_owner", proposal.proposed_owner.to_string()),
+                    ("expires_at", proposal.expires_at.to_string()),
+                ]),
+                None,
+            ))
+        }
+        OwnershipAction::AcceptOwnership {} => {
+            let proposal = OWNERSHIP_PROPOSAL
+                .may_load(deps.storage)?
+                .ok_or_else(|| StdError::generic_err("There is no pending ownership proposal"))?;
+
+            if info.sender != proposal.proposed_owner {
+                return Err(StdError::generic_err(
+                    "Only the proposed owner can accept the ownership",
+                ));
+            }
+            if env.block.time >= proposal.expires_at {
+                return Err(StdError::generic_err("The ownership proposal has expired"));
+            }
+
+            OWNERSHIP_PROPOSAL.remove(deps.storage);
+
+            Ok((
+                Response::new().add_attributes(vec![
+                    ("action", "accept_ownership".to_string()),
+                    ("previous_owner", owner.to_string()),
+                    ("new_owner", proposal.proposed_owner.to_string()),
+                ]),
+                Some(proposal.proposed_owner),
+            ))
+        }
+        OwnershipAction::CancelOwnershipProposal {} => {
+            ensure_owner(info, owner)?;
+
+            if !OWNERSHIP_PROPOSAL.exists(deps.storage) {
+                return Err(StdError::generic_err(
+                    "There is no pending ownership proposal",
+                ));
+            }
+            OWNERSHIP_PROPOSAL.remove(deps.storage);
+
+            Ok((
+                Response::new().add_attribute("action", "cancel_ownership_proposal"),
+                None,
+            ))
+        }
+    }
+}
+
+/// Returns the pending ownership proposal, if any. Expired proposals are returned as well, as they
+/// stay stored until they are replaced or cancelled.
+pub fn query_ownership_proposal(storage: &dyn Storage) -> StdResult<Option<OwnershipProposal>> {
+    OWNERSHIP_PROPOSAL.may_load(storage)
+}
+
+/// Grants the role to the given address, or revokes it if `None`. It's up to the contract to
+/// make sure only the owner can do this.
+pub fn update_role(deps: DepsMut, role: Role, address: Option<String>) -> StdResult<Response> {
+    let address = match address {
+        Some(address) => {
+            let address = deps.api.addr_validate(&address)?;
+            ROLES.save(deps.storage, role.key(), &address)?;
+            address.to_string()
+        }
+        None => {
+            ROLES.remove(deps.storage, role.key());
+            "none".to_string()
+        }
+    };
+
+    Ok(Response::new().add_attributes(vec![
+        ("action", "update_role"),
+        ("role", role.key()),
+        ("address", address.as_str()),
+    ]))
+}
+
+/// Returns true if the given address holds the role.
+pub fn has_role(storage: &dyn Storage, role: Role, address: &Addr) -> StdResult<bool> {
+    Ok(ROLES
+        .may_load(storage, role.key())?
+        .is_some_and(|holder| holder == *address))
+}
+
+/// Returns the address holding each role.
+pub fn query_roles(storage: &dyn Storage) -> StdResult<RolesResponse> {
+    Ok(RolesResponse {
+        fee_manager: ROLES.may_load(storage, Role::FeeManager.key())?,
+        pauser: ROLES.may_load(storage, Role::Pauser.key())?,
+        migrator: ROLES.may_load(storage, Role::Migrator.key())?,
+    })
+}
+
+fn ensure_owner(info: &MessageInfo, owner: &Addr) -> StdResult<()> {
+    if info.sender != *owner {
+        return Err(StdError::generic_err("Only the owner can do this"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    use super::*;
+
+    #[test]
+    fn transfers_ownership_in_two_steps() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let owner = Addr::unchecked("owner");
+
+        // only the owner can propose
+        update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("someone", &[]),
+            &owner,
+            OwnershipAction::ProposeNewOwner {
+                new_owner: "new_owner".to_string(),
+                expiry: None,
+            },
+        )
+        .unwrap_err();
+
+        let (_, new_owner) = update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("owner", &[]),
+            &owner,
+            OwnershipAction::ProposeNewOwner {
+                new_owner: "new_owner".to_string(),
+                expiry: Some(100),
+            },
+        )
+        .unwrap();
+        assert_eq!(new_owner, None);
+        assert_eq!(
+            query_ownership_proposal(&deps.storage).unwrap(),
+            Some(OwnershipProposal {
+                proposed_owner: Addr::unchecked("new_owner"),
+                expires_at: env.block.time.plus_seconds(100),
+            })
+        );
+
+        // only the proposed owner can accept
+        update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("owner", &[]),
+            &owner,
+            OwnershipAction::AcceptOwnership {},
+        )
+        .unwrap_err();
+
+        let (_, new_owner) = update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("new_owner", &[]),
+            &owner,
+            OwnershipAction::AcceptOwnership {},
+        )
+        .unwrap();
+        assert_eq!(new_owner, Some(Addr::unchecked("new_owner")));
+        assert_eq!(query_ownership_proposal(&deps.storage).unwrap(), None);
+
+        // expired proposals can't be accepted
+        update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("owner", &[]),
+            &owner,
+            OwnershipAction::ProposeNewOwner {
+                new_owner: "new_owner".to_string(),
+                expiry: Some(100),
+            },
+        )
+        .unwrap();
+        env.block.time = env.block.time.plus_seconds(100);
+        let err = update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("new_owner", &[]),
+            &owner,
+            OwnershipAction::AcceptOwnership {},
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            StdError::generic_err("The ownership proposal has expired")
+        );
+
+        // cancelled proposals can't be accepted either
+        update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("owner", &[]),
+            &owner,
+            OwnershipAction::CancelOwnershipProposal {},
+        )
+        .unwrap();
+        update_ownership(
+            deps.as_mut(),
+            &env,
+            &mock_info("new_owner", &[]),
+            &owner,
+            OwnershipAction::AcceptOwnership {},
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn grants_and_revokes_roles() {
+        let mut deps = mock_dependencies();
+        let pauser = Addr::unchecked("pauser");
+
+        update_role(deps.as_mut(), Role::Pauser, Some(pauser.to_string())).unwrap();
+        assert!(has_role(&deps.storage, Role::Pauser, &pauser).unwrap());
+        assert!(!has_role(&deps.storage, Role::Migrator, &pauser).unwrap());
+        assert_eq!(
+            query_roles(&deps.storage).unwrap(),
+            RolesResponse {
+                fee_manager: None,
+                pauser: Some(pauser.clone()),
+                migrator: None,
+            }
+        );
+
+        update_role(deps.as_mut(), Role::Pauser, None).unwrap();
+        assert!(!has_role(&deps.storage, Role::Pauser, &pauser).unwrap());
+    }
+}
@@ -0,0 +1,92 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
+
+/// The features that can be paused across all the contracts created by a factory at once, i.e.
+/// pairs and trios for the pool factory, vaults for the vault factory and incentives for the
+/// incentive factory.
+#[cw_serde]
+#[derive(Default)]
+pub struct PausedFeatures {
+    pub swaps: bool,
+    pub deposits: bool,
+    pub withdrawals: bool,
+    pub flash_loans: bool,
+}
+
+impl PausedFeatures {
+    /// Returns true if any of the features paused in `current` would be unpaused by `self`.
+    pub fn unpauses(&self, current: &PausedFeatures) -> bool {
+        (current.swaps && !self.swaps)
+            || (current.deposits && !self.deposits)
+            || (current.withdrawals && !self.withdrawals)
+            || (current.flash_loans && !self.flash_loans)
+    }
+}
+
+/// The response for the `EmergencyPause` query on the factories
+#[cw_serde]
+pub struct EmergencyPauseResponse {
+    /// The address holding the pauser role, which can pause features on behalf of the owner, if any
+    pub pauser: Option<Addr>,
+    pub paused_features: PausedFeatures,
+}
+
+#[cw_serde]
+enum EmergencyPauseQueryMsg {
+    EmergencyPause {},
+}
+
+/// Queries the features paused on the given factory. Nothing is paused if the address is not a
+/// contract, i.e. the contract was not created by a factory. Otherwise the query must succeed, so a
+/// factory that hasn't been migrated to support the emergency pause yet blocks the features instead
+/// of leaving them unpaused.
+pub fn query_paused_features(
+    querier: &QuerierWrapper,
+    factory_addr: impl Into<String>,
+) -> StdResult<PausedFeatures> {
+    let factory_addr = factory_addr.into();
+
+    if querier
+        .query_wasm_contract_info(factory_addr.clone())
+        .is_err()
+    {
+        return Ok(PausedFeatures::default());
+    }
+
+    Ok(querier
+        .query_wasm_smart::<EmergencyPauseResponse>(
+            factory_addr,
+            &EmergencyPauseQueryMsg::EmergencyPause {},
+        )?
+        .paused_features)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PausedFeatures;
+
+    #[test]
+    fn unpauses() {
+        let current = PausedFeatures {
+            swaps: true,
+            deposits: true,
+            ..PausedFeatures::default()
+        };
+
+        // pausing more features doesn't unpause anything
+        let more_paused = PausedFeatures {
+            withdrawals: true,
+            ..current.clone()
+        };
+        assert!(!more_paused.unpauses(&current));
+        assert!(!current.unpauses(&current));
+
+        // lifting the swap pause does
+        let swaps_unpaused = PausedFeatures {
+            swaps: false,
+            ..current.clone()
+        };
+        assert!(swaps_unpaused.unpauses(&current));
+        assert!(PausedFeatures::default().unpauses(&current));
+    }
+}
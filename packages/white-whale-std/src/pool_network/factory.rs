@@ -1,7 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Decimal, StdResult};
 
+use crate::ownership::{OwnershipAction, OwnershipProposal, Role, RolesResponse};
+use crate::pause::{EmergencyPauseResponse, PausedFeatures};
 use crate::pool_network::asset::{AssetInfo, PairInfo, PairType, TrioInfo};
-use crate::pool_network::pair::{FeatureToggle, PoolFee};
+use crate::pool_network::pair::{CircuitBreaker, FeatureToggle, PoolFee};
 use crate::pool_network::trio::{
     FeatureToggle as TrioFeatureToggle, PoolFee as TrioPoolFee, RampAmp,
 };
@@ -18,9 +21,8 @@ pub struct InstantiateMsg {
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    /// Updates contract's config, i.e. relevant code_ids, fee_collector address and owner
+    /// Updates contract's config, i.e. relevant code_ids and fee_collector address
     UpdateConfig {
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         token_code_id: Option<u64>,
         pair_code_id: Option<u64>,
@@ -30,7 +32,6 @@ pub enum ExecuteMsg {
     /// Updates a pair config
     UpdatePairConfig {
         pair_addr: String,
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
@@ -39,7 +40,6 @@ pub enum ExecuteMsg {
     /// Updates a pair config
     UpdatePairConfig {
         pair_addr: String,
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
@@ -48,7 +48,6 @@ pub enum ExecuteMsg {
     /// Updates a trio config
     UpdateTrioConfig {
         trio_addr: String,
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<TrioPoolFee>,
         feature_toggle: Option<TrioFeatureToggle>,
@@ -87,10 +86,50 @@ pub enum ExecuteMsg {
         contract: String,
         code_id: Option<u64>,
     },
-    /// Removes pair contract given asset infos
-    RemovePair { asset_infos: [AssetInfo; 2] },
+    /// Removes pair contract given asset infos. If no pair variant is given, the default pair for
+    /// the assets is removed.
+    RemovePair {
+        asset_infos: [AssetInfo; 2],
+        pair_variant: Option<PairVariant>,
+    },
     /// Removes trio contract given asset infos
     RemoveTrio { asset_infos: [AssetInfo; 3] },
+    /// Registers the next batch of pairs created before pair variants were introduced as the
+    /// variant matching their pair type and pool fees. To be called repeatedly after migrating the
+    /// factory to v1.4.0, until all the pairs are registered. No pairs can be created until then.
+    /// Anyone can do this.
+    RegisterLegacyPairs { limit: Option<u32> },
+    /// Updates the rules for creating pairs, i.e. whether anyone can create pairs, the listing fee
+    /// and the approved fee tiers.
+    UpdatePairCreationConfig {
+        pair_creation_config: PairCreationConfig,
+    },
+    /// Updates the circuit breaker of a pair. If `None`, the circuit breaker of the pair is disabled.
+    UpdatePairCircuitBreaker {
+        pair_addr: String,
+        circuit_breaker: Option<CircuitBreaker>,
+    },
+    /// Pauses features across all the pairs and trios of the factory. The pauser can only pause
+    /// features, while the owner can pause and unpause them.
+    EmergencyPause { paused_features: PausedFeatures },
+    /// Proposes, accepts or cancels a transfer of the ownership of the factory.
+    UpdateOwnership(OwnershipAction),
+    /// Proposes or cancels a transfer of the ownership of a pair. The proposed owner accepts it on
+    /// the pair itself.
+    UpdatePairOwnership {
+        pair_addr: String,
+        action: OwnershipAction,
+    },
+    /// Proposes or cancels a transfer of the ownership of a trio. The proposed owner accepts it on
+    /// the trio itself.
+    UpdateTrioOwnership {
+        trio_addr: String,
+        action: OwnershipAction,
+    },
+    /// Grants an admin role to the given address, or revokes it if `None`. The fee manager can
+    /// update the pool fees of the pairs and trios and the pair creation config, the pauser can
+    /// pause features and the migrator can migrate pairs and trios.
+    UpdateRole { role: Role, address: Option<String> },
 }
 
 #[cw_serde]
@@ -99,15 +138,26 @@ pub enum QueryMsg {
     /// Retrieves the configuration of the factory.
     #[returns(ConfigResponse)]
     Config {},
-    /// Retrieves the info for the pair with the given asset_infos.
+    /// Retrieves the info for the pair with the given asset_infos. If no pair variant is given, the
+    /// default pair for the assets is returned, i.e. the first one that was created.
     #[returns(PairInfo)]
-    Pair { asset_infos: [AssetInfo; 2] },
-    /// Retrieves the pairs created by the factory. This query has pagination enabled, querying ten
-    /// items by default if not specified otherwise. The max amount of items that can be queried at
-    /// once is 30. `start_after` is the last asset_info of a page.
+    Pair {
+        asset_infos: [AssetInfo; 2],
+        pair_variant: Option<PairVariant>,
+    },
+    /// Retrieves all the pair variants created for the given asset_infos.
+    #[returns(PairVariantsResponse)]
+    PairVariants { asset_infos: [AssetInfo; 2] },
+    /// Retrieves the pairs created by the factory, including all the variants for the same assets.
+    /// This query has pagination enabled, querying ten pairs by default if not specified otherwise.
+    /// The max amount of pairs that can be queried at once is 30. `start_after` is the asset_infos
+    /// of the last pair of a page, and `start_after_variant` its variant, as returned in the
+    /// [PairsResponse]. If no variant is given, the page starts after all the variants of the
+    /// `start_after` assets.
     #[returns(PairsResponse)]
     Pairs {
         start_after: Option<[AssetInfo; 2]>,
+        start_after_variant: Option<PairVariant>,
         limit: Option<u32>,
     },
     /// Retrieves the info for the trio with the given asset_infos.
@@ -124,6 +174,18 @@ pub enum QueryMsg {
     /// Retrieves the decimals for the given native or ibc denom.
     #[returns(NativeTokenDecimalsResponse)]
     NativeTokenDecimals { denom: String },
+    /// Retrieves the rules for creating pairs.
+    #[returns(PairCreationConfig)]
+    PairCreationConfig {},
+    /// Retrieves the pauser and the features paused across all the pairs and trios.
+    #[returns(EmergencyPauseResponse)]
+    EmergencyPause {},
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
+    /// Retrieves the addresses holding the admin roles.
+    #[returns(RolesResponse)]
+    Roles {},
 }
 
 // We define a custom struct for each query response
@@ -144,6 +206,8 @@ pub struct MigrateMsg {}
 #[cw_serde]
 pub struct PairsResponse {
     pub pairs: Vec<PairInfo>,
+    /// The variant of the last pair of the page, to query the next page with.
+    pub last_pair_variant: Option<PairVariant>,
 }
 
 #[cw_serde]
@@ -155,3 +219,50 @@ pub struct TriosResponse {
 pub struct NativeTokenDecimalsResponse {
     pub decimals: u8,
 }
+
+/// Rules for creating pairs on the factory.
+#[cw_serde]
+pub struct PairCreationConfig {
+    /// If true, anyone can create pairs. Otherwise only the owner can.
+    pub permissionless: bool,
+    /// Fee charged to non-owners for creating a pair. It is sent to the fee collector.
+    pub listing_fee: Option<Coin>,
+    /// The pool fees non-owners can pick from when creating a pair.
+    pub fee_tiers: Vec<PoolFee>,
+}
+
+/// Identifies one of the pairs created for the same assets, as several pairs with different pair
+/// types or fee tiers can exist for them.
+#[cw_serde]
+pub struct PairVariant {
+    pub pair_type: PairType,
+    /// The aggregated pool fees the pair was created with
+    pub fee_tier: Decimal,
+}
+
+impl PairVariant {
+    /// Creates the [PairVariant] for a pair with the given type and pool fees
+    pub fn new(pair_type: PairType, pool_fees: &PoolFee) -> StdResult<Self> {
+        Ok(Self {
+            pair_type,
+            fee_tier: pool_fees.aggregate()?,
+        })
+    }
+
+    /// Gets the key identifying the variant. The amplification of stableswap pairs is not part of
+    /// it, i.e. only one stableswap pair can exist per fee tier.
+    pub fn key(&self) -> String {
+        format!("{}-{}", self.pair_type.get_label(), self.fee_tier)
+    }
+}
+
+#[cw_serde]
+pub struct PairVariantInfo {
+    pub pair_variant: PairVariant,
+    pub pair_info: PairInfo,
+}
+
+#[cw_serde]
+pub struct PairVariantsResponse {
+    pub pairs: Vec<PairVariantInfo>,
+}
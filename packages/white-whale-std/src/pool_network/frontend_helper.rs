@@ -2,6 +2,7 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Decimal};
 
 use super::asset::Asset;
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -23,13 +24,35 @@ pub enum ExecuteMsg {
         /// The amount of time in seconds to unbond tokens for when incentivizing.
         unbonding_duration: u64,
     },
+    /// Deposits assets into a trio (3pool) and opens or expands a position on the respective
+    /// incentive contract with the received LP tokens.
+    DepositTrio {
+        /// The address of the trio to deposit.
+        trio_address: String,
+        /// The assets to deposit into the trio.
+        assets: [Asset; 3],
+        /// The maximum slippage tolerated when providing liquidity.
+        slippage_tolerance: Option<Decimal>,
+        /// The amount of time in seconds to unbond tokens for when incentivizing.
+        unbonding_duration: u64,
+    },
+    /// Deposits an asset into a vault and opens or expands a position on the respective incentive
+    /// contract with the received LP tokens.
+    DepositVault {
+        /// The address of the vault to deposit.
+        vault_address: String,
+        /// The asset to deposit into the vault.
+        asset: Asset,
+        /// The amount of time in seconds to unbond tokens for when incentivizing.
+        unbonding_duration: u64,
+    },
     /// Updates the configuration of the frontend helper.
     UpdateConfig {
         /// The new incentive_factory_addr.
         incentive_factory_addr: Option<String>,
-        /// The new owner.
-        owner: Option<String>,
     },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -41,6 +64,9 @@ pub enum QueryMsg {
     /// Retrieves the current contract configuration.
     #[returns(ConfigResponse)]
     Config {},
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 pub type ConfigResponse = Config;
@@ -61,5 +87,28 @@ pub struct TempState {
     /// The person who is creating the position after depositing.
     pub receiver: Addr,
     /// The address that is being deposited to.
-    pub pair_addr: Addr,
+    pub pool_addr: Addr,
+    /// The type of pool that is being deposited to.
+    pub pool_type: DepositPoolType,
+}
+
+/// The types of pools the frontend helper can deposit to.
+#[cw_serde]
+pub enum DepositPoolType {
+    /// A pair pool.
+    Pair,
+    /// A trio (3pool) pool.
+    Trio,
+    /// A vault.
+    Vault,
+}
+
+impl std::fmt::Display for DepositPoolType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DepositPoolType::Pair => write!(f, "pair"),
+            DepositPoolType::Trio => write!(f, "trio"),
+            DepositPoolType::Vault => write!(f, "vault"),
+        }
+    }
 }
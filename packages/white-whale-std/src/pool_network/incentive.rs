@@ -2,8 +2,9 @@ use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal256, Uint128};
+use cosmwasm_std::{Addr, Decimal, Decimal256, Uint128};
 
+use crate::epoch_manager::hooks::EpochChangedHookMsg;
 use crate::pool_network::asset::{Asset, AssetInfo};
 
 #[cw_serde]
@@ -18,6 +19,11 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// Makes a snapshot of the current global weight, at the current epoch.
     TakeGlobalWeightSnapshot {},
+    /// Hook called by the epoch manager when a new epoch is created. Takes the global weight
+    /// snapshot for the current epoch of the fee distributor if it hasn't been taken yet.
+    ///
+    /// Can only be called by the epoch manager set on the incentive factory.
+    EpochChangedHook(EpochChangedHookMsg),
     /// Opens a new liquidity flow
     OpenFlow {
         /// The epoch at which the flow will start. If unspecified, the flow will start at the
@@ -32,10 +38,15 @@ pub enum ExecuteMsg {
         flow_asset: Asset,
         /// If set, the label will be used to identify the flow, in addition to the flow_id.
         flow_label: Option<String>,
+        /// The amount of epochs the rewards claimed from the flow vest linearly over. If
+        /// unspecified, the rewards are paid out when claimed.
+        vesting_duration: Option<u64>,
     },
     /// Closes an existing liquidity flow.
     ///
-    /// Sender of the message must either be the contract admin or the creator of the flow.
+    /// Sender of the message must either be the contract admin or the creator of the flow. The
+    /// unclaimed flow assets are refunded to the creator, so the admin can use it to force-close
+    /// spam flows.
     CloseFlow {
         /// The identifier of the flow to close.
         flow_identifier: FlowIdentifier,
@@ -70,11 +81,38 @@ pub enum ExecuteMsg {
     ClosePosition {
         /// The unbonding duration of the position to close.
         unbonding_duration: u64,
+        /// The amount of LP tokens to close from the position, leaving the rest open.
+        ///
+        /// If unspecified, the whole position is closed.
+        amount: Option<Uint128>,
+    },
+    /// Locks an existing position perpetually. The position gets the maximum weight, which it keeps
+    /// until it is closed. Once closed, it unbonds over the maximum unbonding duration.
+    PerpetualLockPosition {
+        /// The unbonding duration of the position to lock.
+        unbonding_duration: u64,
+    },
+    /// Rolls the closed positions that finished unbonding back into the open position with the
+    /// given unbonding duration, which is created if it doesn't exist.
+    RolloverPositions {
+        /// The unbonding duration of the position to roll the unbonded LP tokens into.
+        unbonding_duration: u64,
     },
     /// Withdraws the LP tokens from a closed position once the unbonding duration has passed.
     Withdraw {},
+    /// Withdraws the LP tokens from all the closed positions, including the ones that haven't
+    /// finished unbonding. A penalty proportional to the time left to unbond is taken from those
+    /// and sent to the fee collector.
+    EmergencyExit {},
     /// Claims the flow rewards.
     Claim {},
+    /// Claims the flow rewards that have vested so far.
+    ClaimVested {},
+    /// Claims the flow rewards and the vested flow rewards, and withdraws the closed positions of
+    /// the given address, sending them to it.
+    ///
+    /// Can only be called by the incentive factory.
+    ClaimFor { address: String },
     /// Expands an existing flow.
     ExpandFlow {
         /// The identifier of the flow to expand, whether an id or a label.
@@ -102,9 +140,8 @@ pub struct Flow {
     pub flow_asset: Asset,
     /// The amount of the `flow_asset` that has been claimed so far.
     pub claimed_amount: Uint128,
-    /// The type of curve the flow has.
+    /// The type of curve the flow has, which determines how the flow asset is emitted across epochs.
     pub curve: Curve,
-    //todo not doing anything for now
     /// The epoch at which the flow starts.
     pub start_epoch: u64,
     /// The epoch at which the flow ends.
@@ -114,6 +151,8 @@ pub struct Flow {
     /// A map containing the amount of tokens it was expanded to at a given epoch. This is used
     /// to calculate the right amount of tokens to distribute at a given epoch when a flow is expanded.
     pub asset_history: BTreeMap<u64, (Uint128, u64)>,
+    /// The amount of epochs the rewards claimed from the flow vest linearly over, if any.
+    pub vesting_duration: Option<u64>,
 }
 
 /// Represents a position that accumulates flow rewards.
@@ -200,6 +239,21 @@ pub enum QueryMsg {
         /// The address to query the rewards share for.
         address: String,
     },
+    /// Retrieves the emissions expected on the next epoch from the open flows, given the current
+    /// weights, for an address, a hypothetical new position, or both combined.
+    #[returns(ExpectedEmissionsResponse)]
+    ExpectedEmissions {
+        /// The address to get the expected emissions for.
+        address: Option<String>,
+        /// A hypothetical new position to get the expected emissions for.
+        new_position: Option<NewPosition>,
+    },
+    /// Retrieves the flow rewards vesting for an address, and the amount that can be claimed.
+    #[returns(VestingResponse)]
+    Vesting {
+        /// The address to get the vesting rewards for.
+        address: String,
+    },
 }
 
 /// Stores the reply data set in the response when instantiating an incentive contract.
@@ -223,16 +277,60 @@ pub struct Config {
 }
 
 /// The type of distribution curve to exist.
+///
+/// Every curve assigns a weight to each epoch of the flow, relative to the flow's start epoch. The
+/// amount emitted at a given epoch is the amount left to emit, multiplied by the weight of that
+/// epoch over the sum of the weights of the remaining epochs of the flow.
 #[cw_serde]
 pub enum Curve {
     /// A linear curve that releases assets as we approach the end of the flow period.
     Linear,
+    /// An exponential decay curve, where the weight of each epoch is the weight of the previous
+    /// epoch multiplied by the `decay_factor`. The decay factor must be between 0 and 1, exclusive.
+    ExponentialDecay { decay_factor: Decimal },
+    /// A step schedule, where each step sets the weight for the epochs starting at its offset until
+    /// the next step. The first step must start at offset 0, and there can be up to 100 steps.
+    Step { steps: Vec<CurveStep> },
+    /// A custom emission table with the weight for each epoch of the flow, starting at the flow's
+    /// start epoch, with up to 100 entries. Epochs beyond the table, i.e. after a flow expansion or
+    /// when the table is shorter than the flow, use the last weight.
+    Custom { weights: Vec<Decimal> },
+}
+
+/// A step of a [Curve::Step] schedule.
+#[cw_serde]
+pub struct CurveStep {
+    /// The offset, in epochs from the flow's start epoch, at which the step starts.
+    pub epoch_offset: u64,
+    /// The weight of the emissions for the epochs within the step.
+    pub weight: Decimal,
 }
 
 impl std::fmt::Display for Curve {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Curve::Linear => write!(f, "Linear"),
+            Curve::ExponentialDecay { decay_factor } => {
+                write!(f, "ExponentialDecay(decay_factor: {})", decay_factor)
+            }
+            Curve::Step { steps } => write!(
+                f,
+                "Step({})",
+                steps
+                    .iter()
+                    .map(|step| format!("{}:{}", step.epoch_offset, step.weight))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Curve::Custom { weights } => write!(
+                f,
+                "Custom({})",
+                weights
+                    .iter()
+                    .map(|weight| weight.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
 }
@@ -244,6 +342,17 @@ pub struct FlowResponse {
     //TODO why is this returning a Option<Flow>? why not a flow directly?
     /// The flow that was searched for.
     pub flow: Option<Flow>,
+    /// The projected emissions of the flow for each epoch within the queried range, according to
+    /// the flow's curve.
+    pub emission_schedule: Vec<EpochEmission>,
+}
+
+#[cw_serde]
+pub struct EpochEmission {
+    /// The epoch id.
+    pub epoch_id: u64,
+    /// The amount of the flow asset emitted at the epoch.
+    pub emission: Uint128,
 }
 
 #[cw_serde]
@@ -282,6 +391,27 @@ pub struct PositionsResponse {
     pub positions: Vec<QueryPosition>,
 }
 
+/// Flow rewards that were claimed and vest linearly over a number of epochs.
+#[cw_serde]
+pub struct Vesting {
+    /// The total rewards vesting.
+    pub asset: Asset,
+    /// The amount of the rewards that has been claimed so far.
+    pub claimed_amount: Uint128,
+    /// The epoch at which the rewards start vesting, i.e. when they were claimed from the flow.
+    pub start_epoch: u64,
+    /// The epoch at which the rewards are fully vested.
+    pub end_epoch: u64,
+}
+
+#[cw_serde]
+pub struct VestingResponse {
+    /// The flow rewards vesting for the address.
+    pub vestings: Vec<Vesting>,
+    /// The vested rewards that can be claimed with `ClaimVested`.
+    pub claimable: Vec<Asset>,
+}
+
 #[cw_serde]
 pub struct RewardsResponse {
     /// The rewards that is available to a user if they executed the `claim` function at this point.
@@ -305,6 +435,43 @@ pub struct RewardsShareResponse {
     pub epoch_id: u64,
 }
 
+/// A hypothetical position used to project the emissions it would get.
+#[cw_serde]
+pub struct NewPosition {
+    /// The amount of LP tokens in the position.
+    pub amount: Uint128,
+    /// The unbonding duration of the position.
+    pub unbonding_duration: u64,
+}
+
+#[cw_serde]
+pub struct ExpectedEmissionsResponse {
+    /// The epoch the emissions are expected on.
+    pub epoch_id: u64,
+    /// The weight considered, i.e. the address weight plus the weight of the new position.
+    pub weight: Uint128,
+    /// The global weight considered, including the weight of the new position.
+    pub global_weight: Uint128,
+    /// The share of the emissions expected, i.e. weight / global_weight.
+    pub share: Decimal256,
+    /// The expected emissions for each of the flows emitting on the epoch.
+    pub flows: Vec<FlowExpectedEmission>,
+    /// The expected emissions aggregated by asset.
+    pub emissions: Vec<Asset>,
+}
+
+#[cw_serde]
+pub struct FlowExpectedEmission {
+    /// The id of the flow.
+    pub flow_id: u64,
+    /// The label of the flow, if any.
+    pub flow_label: Option<String>,
+    /// The total amount emitted by the flow on the epoch.
+    pub flow_emission: Asset,
+    /// The share of the flow emission expected.
+    pub expected_emission: Asset,
+}
+
 #[cw_serde]
 pub enum FlowIdentifier {
     Id(u64),
@@ -1,6 +1,8 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Decimal};
 
+use crate::ownership::{OwnershipAction, OwnershipProposal, Role, RolesResponse};
+use crate::pause::{EmergencyPauseResponse, PausedFeatures};
 use crate::pool_network::asset::{Asset, AssetInfo};
 
 #[cw_serde]
@@ -13,6 +15,8 @@ pub struct InstantiateMsg {
     pub create_flow_fee: Asset,
     /// The maximum amount of flows that can exist for a single LP token at a single time.
     pub max_concurrent_flows: u64,
+    /// The amount of `max_concurrent_flows` slots only allowlisted flow creators can fill.
+    pub reserved_flow_slots: u64,
     /// The code ID of the incentive contract.
     pub incentive_code_id: u64,
     /// The maximum epoch buffer for a new flow (in epochs).
@@ -23,6 +27,15 @@ pub struct InstantiateMsg {
     pub min_unbonding_duration: u64,
     /// The maximum amount of seconds that a user must bond their tokens for.
     pub max_unbonding_duration: u64,
+    /// The penalty taken from closed positions that exit before finishing unbonding, scaled by the
+    /// time left to unbond relative to `max_unbonding_duration`.
+    pub emergency_exit_penalty: Decimal,
+    /// The epoch manager the incentive contracts are registered on as hooks, so the global weight
+    /// snapshots are taken automatically. If unspecified, snapshots need to be taken manually.
+    ///
+    /// The factory must be a hook manager on the epoch manager to register the incentive
+    /// contracts it creates.
+    pub epoch_manager_addr: Option<String>,
 }
 
 #[cw_serde]
@@ -33,10 +46,6 @@ pub enum ExecuteMsg {
     ///
     /// Unspecified fields will not be updated.
     UpdateConfig {
-        /// The owner of the contract.
-        ///
-        /// If unspecified, the owner address will not change.
-        owner: Option<String>,
         /// The new fee collector address to send flow creation fees to.
         ///
         /// If unspecified, the fee collector address will not change.
@@ -53,6 +62,11 @@ pub enum ExecuteMsg {
         ///
         /// If unspecified, the max concurrent flows will not change.
         max_concurrent_flows: Option<u64>,
+        /// The amount of concurrent flow slots only allowlisted flow creators can fill. Can't be
+        /// more than `max_concurrent_flows`.
+        ///
+        /// If unspecified, the reserved flow slots will not change.
+        reserved_flow_slots: Option<u64>,
         /// The new code ID of the incentive contract.
         ///
         /// If unspecified, the incentive contract id will not change.
@@ -70,6 +84,27 @@ pub enum ExecuteMsg {
         ///
         /// If unspecified, the `max_unbonding_duration` will not change.
         max_unbonding_duration: Option<u64>,
+        /// The penalty taken from closed positions that exit before finishing unbonding, from 0
+        /// to 1.
+        ///
+        /// If unspecified, the `emergency_exit_penalty` will not change.
+        emergency_exit_penalty: Option<Decimal>,
+        /// The new epoch manager to register the incentive contracts on as hooks. The factory must
+        /// be a hook manager on it. The existing incentive contracts are registered with
+        /// [ExecuteMsg::RegisterEpochHooks], while the new ones are registered on creation.
+        ///
+        /// If unspecified, the epoch manager will not change.
+        epoch_manager_addr: Option<String>,
+    },
+    /// Registers a page of the existing incentive contracts as hooks on the epoch manager, i.e.
+    /// after setting a new epoch manager. The factory must be a hook manager on it.
+    RegisterEpochHooks {
+        /// An optional parameter specifying what incentive contract to start registering after.
+        start_after: Option<AssetInfo>,
+        /// The amount of incentive contracts to register.
+        ///
+        /// If unspecified, will default to a value specified by the contract.
+        limit: Option<u32>,
     },
     MigrateIncentives {
         /// The address of the incentive contract. If unspecified, will migrate all incentive contracts.
@@ -77,6 +112,47 @@ pub enum ExecuteMsg {
         /// The new code ID to migrate the incentive contract to.
         code_id: u64,
     },
+    /// Adds or removes flow creators from the allowlist. Allowlisted creators can fill the reserved
+    /// flow slots and get a discount on the flow creation fee.
+    ///
+    /// Adding a creator that is already allowlisted updates its fee discount.
+    UpdateFlowCreators {
+        /// The flow creators to add to the allowlist.
+        add: Vec<FlowCreator>,
+        /// The addresses of the flow creators to remove from the allowlist.
+        remove: Vec<String>,
+    },
+    /// Claims the flow rewards and the vested flow rewards, and withdraws the closed positions of
+    /// the sender across the given incentive contracts, in a single transaction.
+    ///
+    /// Only the incentive contracts where the sender has positions, rewards or vested rewards are
+    /// claimed on, skipping the ones that can't be queried. Can be called by anyone.
+    ClaimAll {
+        /// The LP assets of the incentive contracts to claim on, up to 30. If not provided, the
+        /// incentive contracts are looked up in pages and the first 30 where the sender has
+        /// something to claim are claimed on.
+        lp_assets: Option<Vec<AssetInfo>>,
+    },
+    /// Pauses features across all the incentive contracts, i.e. deposits for opening, expanding or
+    /// rolling over positions and withdrawals for closing, withdrawing or emergency exiting them and
+    /// for claiming on behalf of the factory.
+    ///
+    /// The pauser can only pause features, while the owner can pause and unpause them.
+    EmergencyPause {
+        /// The features to pause.
+        paused_features: PausedFeatures,
+    },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
+    /// Grants an admin role to the given address, or revokes it if unspecified.
+    ///
+    /// The pauser can pause features and the migrator can migrate the incentive contracts.
+    UpdateRole {
+        /// The role to grant or revoke.
+        role: Role,
+        /// The address to grant the role to.
+        address: Option<String>,
+    },
 }
 
 #[cw_serde]
@@ -101,6 +177,31 @@ pub enum QueryMsg {
         /// If unspecified, will default to a value specified by the contract.
         limit: Option<u32>,
     },
+    /// Retrieves whether the given address is an allowlisted flow creator, and its fee discount.
+    #[returns(FlowCreatorResponse)]
+    FlowCreator {
+        /// The address of the flow creator.
+        address: String,
+    },
+    /// Retrieves the allowlisted flow creators.
+    #[returns(FlowCreatorsResponse)]
+    FlowCreators {
+        /// An optional parameter specifying what flow creator to start searching after.
+        start_after: Option<String>,
+        /// The amount of flow creators to return.
+        ///
+        /// If unspecified, will default to a value specified by the contract.
+        limit: Option<u32>,
+    },
+    /// Retrieves the pauser and the features paused across all the incentive contracts.
+    #[returns(EmergencyPauseResponse)]
+    EmergencyPause {},
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
+    /// Retrieves the addresses holding the admin roles.
+    #[returns(RolesResponse)]
+    Roles {},
 }
 
 #[cw_serde]
@@ -118,6 +219,8 @@ pub struct Config {
     pub create_flow_fee: Asset,
     /// The maximum amount of flows that can exist at any one time.
     pub max_concurrent_flows: u64,
+    /// The amount of `max_concurrent_flows` slots only allowlisted flow creators can fill.
+    pub reserved_flow_slots: u64,
     /// The code ID of the incentive contract.
     pub incentive_code_id: u64,
     /// The maximum amount of epochs in the future a new flow is allowed to start in.
@@ -126,6 +229,11 @@ pub struct Config {
     pub min_unbonding_duration: u64,
     /// The maximum amount of seconds that a user must bond their tokens for.
     pub max_unbonding_duration: u64,
+    /// The penalty taken from closed positions that exit before finishing unbonding, scaled by the
+    /// time left to unbond relative to `max_unbonding_duration`.
+    pub emergency_exit_penalty: Decimal,
+    /// The epoch manager the incentive contracts are registered on as hooks.
+    pub epoch_manager_addr: Option<Addr>,
 }
 
 pub type ConfigResponse = Config;
@@ -139,3 +247,23 @@ pub struct IncentivesContract {
     pub lp_reference: Vec<u8>,
 }
 pub type IncentivesResponse = Vec<IncentivesContract>;
+
+/// A flow creator to add to the allowlist.
+#[cw_serde]
+pub struct FlowCreator {
+    /// The address of the flow creator.
+    pub address: String,
+    /// The discount applied to the flow creation fee, from 0 (no discount) to 1 (no fee).
+    pub fee_discount: Decimal,
+}
+
+#[cw_serde]
+pub struct FlowCreatorResponse {
+    /// The address of the flow creator.
+    pub address: Addr,
+    /// Whether the flow creator is allowlisted.
+    pub allowlisted: bool,
+    /// The discount applied to the flow creation fee. Zero if the creator is not allowlisted.
+    pub fee_discount: Decimal,
+}
+pub type FlowCreatorsResponse = Vec<FlowCreatorResponse>;
@@ -10,6 +10,7 @@ use cosmwasm_std::{
 };
 use cw20::{BalanceResponse as Cw20BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
+use crate::pause::{EmergencyPauseResponse, PausedFeatures};
 use crate::pool_network::asset::{Asset, AssetInfo, PairInfo, PairType, TrioInfo};
 use crate::pool_network::factory::{NativeTokenDecimalsResponse, QueryMsg as FactoryQueryMsg};
 use crate::pool_network::pair::{PoolResponse as PairPoolResponse, QueryMsg as PairQueryMsg};
@@ -92,6 +93,7 @@ pub fn balances_to_map(
 pub struct PoolFactoryQuerier {
     pairs: HashMap<String, PairInfo>,
     native_token_decimals: HashMap<String, u8>,
+    paused_features: PausedFeatures,
 }
 
 impl PoolFactoryQuerier {
@@ -99,6 +101,7 @@ impl PoolFactoryQuerier {
         PoolFactoryQuerier {
             pairs: pairs_to_map(pairs),
             native_token_decimals: native_token_decimals_to_map(native_token_decimals),
+            paused_features: PausedFeatures::default(),
         }
     }
 }
@@ -158,8 +161,19 @@ impl WasmMockQuerier {
     pub fn handle_query(&self, request: &QueryRequest<Empty>) -> QuerierResult {
         match &request {
             QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => match from_json(msg) {
-                Ok(FactoryQueryMsg::Pair { asset_infos }) => {
-                    let key = [asset_infos[0].to_string(), asset_infos[1].to_string()].join("");
+                Ok(FactoryQueryMsg::Pair {
+                    asset_infos,
+                    pair_variant,
+                }) => {
+                    // pair variants are registered with their key appended to the asset infos
+                    let key = [
+                        asset_infos[0].to_string(),
+                        asset_infos[1].to_string(),
+                        pair_variant
+                            .map(|pair_variant| pair_variant.key())
+                            .unwrap_or_default(),
+                    ]
+                    .join("");
                     let mut sort_key: Vec<char> = key.chars().collect();
                     sort_key.sort_by(|a, b| b.cmp(a));
                     match self
@@ -188,6 +202,13 @@ impl WasmMockQuerier {
                         }),
                     }
                 }
+                Ok(FactoryQueryMsg::EmergencyPause {}) => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&EmergencyPauseResponse {
+                        pauser: None,
+                        paused_features: self.pool_factory_querier.paused_features.clone(),
+                    })
+                    .unwrap(),
+                )),
                 _ => {
                     match from_json(msg) {
                         Ok(PairQueryMsg::Pool {}) => SystemResult::Ok(ContractResult::from(
@@ -392,6 +413,13 @@ impl WasmMockTrioQuerier {
                         }),
                     }
                 }
+                Ok(FactoryQueryMsg::EmergencyPause {}) => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&EmergencyPauseResponse {
+                        pauser: None,
+                        paused_features: self.pool_factory_querier.paused_features.clone(),
+                    })
+                    .unwrap(),
+                )),
                 _ => {
                     match from_json(msg) {
                         Ok(TrioQueryMsg::Pool {}) => SystemResult::Ok(ContractResult::from(
@@ -588,6 +616,11 @@ impl WasmMockQuerier {
         self.pool_factory_querier = PoolFactoryQuerier::new(pairs, native_token_decimals);
     }
 
+    // configure the features paused factory-wide
+    pub fn with_paused_features(&mut self, paused_features: PausedFeatures) {
+        self.pool_factory_querier.paused_features = paused_features;
+    }
+
     pub fn with_balance(&mut self, balances: &[(&String, Vec<Coin>)]) {
         for (addr, balance) in balances {
             self.base.update_balance(addr.to_string(), balance.clone());
@@ -618,6 +651,11 @@ impl WasmMockTrioQuerier {
         self.pool_factory_querier = PoolFactoryQuerier::new(pairs, native_token_decimals);
     }
 
+    // configure the features paused factory-wide
+    pub fn with_paused_features(&mut self, paused_features: PausedFeatures) {
+        self.pool_factory_querier.paused_features = paused_features;
+    }
+
     pub fn with_balance(&mut self, balances: &[(&String, Vec<Coin>)]) {
         for (addr, balance) in balances {
             self.base.update_balance(addr.to_string(), balance.clone());
@@ -656,6 +694,7 @@ mod mock_exception {
                     denom: "ulunc".to_string(),
                 },
             ],
+            pair_variant: None,
         })
         .unwrap();
         assert_eq!(
@@ -1,4 +1,5 @@
 use crate::fee::Fee;
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Uint128};
 use cw20::Cw20ReceiveMsg;
@@ -43,7 +44,6 @@ pub enum ExecuteMsg {
     #[cfg(feature = "osmosis")]
     /// Updates the pair pool config
     UpdateConfig {
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
@@ -52,13 +52,18 @@ pub enum ExecuteMsg {
     #[cfg(not(feature = "osmosis"))]
     /// Updates the pair pool config
     UpdateConfig {
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
     },
     /// Collects the Protocol fees accrued by the pool
     CollectProtocolFees {},
+    /// Updates the circuit breaker of the pool. If `None`, the circuit breaker is disabled.
+    UpdateCircuitBreaker {
+        circuit_breaker: Option<CircuitBreaker>,
+    },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -104,6 +109,12 @@ pub enum QueryMsg {
     /// perform the swap.
     #[returns(ReverseSimulationResponse)]
     ReverseSimulation { ask_asset: Asset },
+    /// Retrieves the circuit breaker of the pool, if any.
+    #[returns(Option<CircuitBreaker>)]
+    CircuitBreaker {},
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 /// Pool feature toggle
@@ -114,6 +125,26 @@ pub struct FeatureToggle {
     pub swaps_enabled: bool,
 }
 
+/// Rejects swaps that move the price of the pool more than `max_price_change` away from the price
+/// the pool had at the start of the block.
+#[cw_serde]
+pub struct CircuitBreaker {
+    pub max_price_change: Decimal,
+}
+
+impl CircuitBreaker {
+    /// Checks that the circuit breaker trips on some price change.
+    pub fn is_valid(&self) -> StdResult<()> {
+        if self.max_price_change.is_zero() {
+            return Err(StdError::generic_err(
+                "Invalid circuit breaker, max_price_change must be greater than zero",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// Fees used by the pools on the pool network
 #[cw_serde]
 pub struct PoolFee {
@@ -1,5 +1,7 @@
 use crate::pool_network::asset::{Asset, AssetInfo, PairInfo, TrioInfo};
-use crate::pool_network::factory::{NativeTokenDecimalsResponse, QueryMsg as FactoryQueryMsg};
+use crate::pool_network::factory::{
+    NativeTokenDecimalsResponse, PairVariant, QueryMsg as FactoryQueryMsg,
+};
 use crate::pool_network::pair::{
     QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse,
 };
@@ -79,11 +81,23 @@ pub fn query_pair_info(
     querier: &QuerierWrapper,
     factory_contract: Addr,
     asset_infos: &[AssetInfo; 2],
+) -> StdResult<PairInfo> {
+    query_pair_variant_info(querier, factory_contract, asset_infos, None)
+}
+
+/// Queries the pair of the given [PairVariant]. Falls back to the default pair for the assets if
+/// no variant is given.
+pub fn query_pair_variant_info(
+    querier: &QuerierWrapper,
+    factory_contract: Addr,
+    asset_infos: &[AssetInfo; 2],
+    pair_variant: Option<PairVariant>,
 ) -> StdResult<PairInfo> {
     querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
         contract_addr: factory_contract.to_string(),
         msg: to_json_binary(&FactoryQueryMsg::Pair {
             asset_infos: asset_infos.clone(),
+            pair_variant,
         })?,
     }))
 }
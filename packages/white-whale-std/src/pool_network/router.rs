@@ -5,6 +5,7 @@ use cosmwasm_std::{Decimal, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 use crate::pool_network::asset::AssetInfo;
+use crate::pool_network::factory::PairVariant;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -17,12 +18,28 @@ pub enum SwapOperation {
         offer_asset_info: AssetInfo,
         ask_asset_info: AssetInfo,
     },
+    /// Swaps through the pair of the given variant, when several pairs exist for the same assets.
+    TerraSwapVariant {
+        offer_asset_info: AssetInfo,
+        ask_asset_info: AssetInfo,
+        pair_variant: PairVariant,
+    },
 }
 
 impl SwapOperation {
     pub fn get_target_asset_info(&self) -> AssetInfo {
         match self {
-            SwapOperation::TerraSwap { ask_asset_info, .. } => ask_asset_info.clone(),
+            SwapOperation::TerraSwap { ask_asset_info, .. }
+            | SwapOperation::TerraSwapVariant { ask_asset_info, .. } => ask_asset_info.clone(),
+        }
+    }
+
+    /// Gets the pair variant the operation swaps through. [None] means the default pair for the
+    /// assets is used.
+    pub fn get_pair_variant(&self) -> Option<PairVariant> {
+        match self {
+            SwapOperation::TerraSwap { .. } => None,
+            SwapOperation::TerraSwapVariant { pair_variant, .. } => Some(pair_variant.clone()),
         }
     }
 }
@@ -37,6 +54,15 @@ impl fmt::Display for SwapOperation {
                 f,
                 "TerraSwap {{ offer_asset_info: {offer_asset_info}, ask_asset_info: {ask_asset_info} }}"
             ),
+            SwapOperation::TerraSwapVariant {
+                offer_asset_info,
+                ask_asset_info,
+                pair_variant,
+            } => write!(
+                f,
+                "TerraSwap {{ offer_asset_info: {offer_asset_info}, ask_asset_info: {ask_asset_info}, pair_variant: {} }}",
+                pair_variant.key()
+            ),
         }
     }
 }
@@ -3,6 +3,7 @@ use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 use crate::fee::Fee;
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 
 use crate::pool_network::asset::{Asset, AssetInfo, TrioInfo};
 
@@ -43,7 +44,6 @@ pub enum ExecuteMsg {
     },
     /// Updates the trio pool config
     UpdateConfig {
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         pool_fees: Option<PoolFee>,
         feature_toggle: Option<FeatureToggle>,
@@ -51,6 +51,8 @@ pub enum ExecuteMsg {
     },
     /// Collects the Protocol fees accrued by the pool
     CollectProtocolFees {},
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -109,6 +111,9 @@ pub enum QueryMsg {
         ask_asset: Asset,
         offer_asset: Asset,
     },
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 /// Pool feature toggle
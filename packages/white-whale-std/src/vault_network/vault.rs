@@ -1,4 +1,5 @@
 use crate::fee::VaultFee;
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 use crate::pool_network::asset::{Asset, AssetInfo};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Binary, Uint128};
@@ -50,8 +51,6 @@ pub struct UpdateConfigParams {
     pub deposit_enabled: Option<bool>,
     /// if users should be able to withdraw funds from the contract.
     pub withdraw_enabled: Option<bool>,
-    /// The new owner of the contract.
-    pub new_owner: Option<String>,
     /// The new fees used for the vault
     pub new_vault_fees: Option<VaultFee>,
     /// The new address of the fee collector
@@ -78,6 +77,8 @@ pub enum ExecuteMsg {
     UpdateConfig(UpdateConfigParams),
     Receive(Cw20ReceiveMsg),
     Callback(CallbackMsg),
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -98,6 +99,9 @@ pub enum QueryMsg {
     /// Retrieves the [`Uint128`] amount that must be sent back to the contract to pay off a loan taken out.
     #[returns(PaybackAmountResponse)]
     GetPaybackAmount { amount: Uint128 },
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 #[cw_serde]
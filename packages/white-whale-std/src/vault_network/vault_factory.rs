@@ -1,4 +1,6 @@
 use crate::fee::VaultFee;
+use crate::ownership::{OwnershipAction, OwnershipProposal, Role, RolesResponse};
+use crate::pause::{EmergencyPauseResponse, PausedFeatures};
 use crate::pool_network::asset::AssetInfo;
 use crate::vault_network::vault;
 use cosmwasm_schema::{cw_serde, QueryResponses};
@@ -45,11 +47,25 @@ pub enum ExecuteMsg {
     /// Updates the configuration of the vault factory.
     /// If a field is not specified, it will not be modified.
     UpdateConfig {
-        owner: Option<String>,
         fee_collector_addr: Option<String>,
         vault_id: Option<u64>,
         token_id: Option<u64>,
     },
+    /// Pauses features across all the vaults of the factory. The pauser can only pause features,
+    /// while the owner can pause and unpause them.
+    EmergencyPause { paused_features: PausedFeatures },
+    /// Proposes, accepts or cancels a transfer of the ownership of the factory.
+    UpdateOwnership(OwnershipAction),
+    /// Proposes or cancels a transfer of the ownership of a vault. The proposed owner accepts it
+    /// on the vault itself.
+    UpdateVaultOwnership {
+        vault_addr: String,
+        action: OwnershipAction,
+    },
+    /// Grants an admin role to the given address, or revokes it if `None`. The fee manager can
+    /// update the vault configs, the pauser can pause features and the migrator can migrate
+    /// vaults.
+    UpdateRole { role: Role, address: Option<String> },
 }
 
 /// The query message
@@ -68,6 +84,15 @@ pub enum QueryMsg {
         start_after: Option<Vec<u8>>,
         limit: Option<u32>,
     },
+    /// Retrieves the pauser and the features paused across all the vaults.
+    #[returns(EmergencyPauseResponse)]
+    EmergencyPause {},
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
+    /// Retrieves the addresses holding the admin roles.
+    #[returns(RolesResponse)]
+    Roles {},
 }
 
 /// The migrate message
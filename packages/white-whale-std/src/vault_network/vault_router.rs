@@ -1,6 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, CosmosMsg};
 
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 use crate::pool_network::asset::{Asset, AssetInfo};
 
 /// The instantiation message
@@ -24,10 +25,9 @@ pub enum ExecuteMsg {
     /// Updates the configuration of the vault router.
     ///
     /// If a field is not specified, it will not be modified.
-    UpdateConfig {
-        owner: Option<String>,
-        vault_factory_addr: Option<String>,
-    },
+    UpdateConfig { vault_factory_addr: Option<String> },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
     /// Performs the next loan.
     ///
     /// Should only be called by internal contract.
@@ -63,6 +63,9 @@ pub enum QueryMsg {
     /// Retrieves the configuration of the vault router.
     #[returns(Config)]
     Config {},
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 /// The migrate message
@@ -1,3 +1,4 @@
+use crate::ownership::{OwnershipAction, OwnershipProposal};
 use crate::pool_network::asset::{Asset, AssetInfo};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128, Uint64};
@@ -15,6 +16,9 @@ pub struct Config {
     pub bonding_assets: Vec<AssetInfo>,
     /// Address of the fee distributor contract.
     pub fee_distributor_addr: Addr,
+    /// Address of the gauges contract, if any. Addresses that voted on the current epoch of the
+    /// gauges can't unbond nor transfer their bonds until the epoch is over.
+    pub gauges_addr: Option<Addr>,
 }
 
 #[cw_serde]
@@ -73,13 +77,18 @@ pub enum ExecuteMsg {
     Unbond { asset: Asset },
     /// Sends withdrawable unbonded tokens to the user.
     Withdraw { denom: String },
+    /// Transfers the specified bonded [Asset], together with its accumulated weight, to the
+    /// recipient.
+    TransferBond { asset: Asset, recipient: String },
     /// Updates the [Config] of the contract.
     UpdateConfig {
-        owner: Option<String>,
         unbonding_period: Option<Uint64>,
         growth_rate: Option<Decimal>,
         fee_distributor_addr: Option<String>,
+        gauges_addr: Option<String>,
     },
+    /// Proposes, accepts or cancels a transfer of the ownership of the contract.
+    UpdateOwnership(OwnershipAction),
 }
 
 #[cw_serde]
@@ -123,6 +132,10 @@ pub enum QueryMsg {
     /// Returns the global index of the contract.
     #[returns(GlobalIndex)]
     GlobalIndex {},
+
+    /// Retrieves the pending ownership proposal, if any.
+    #[returns(Option<OwnershipProposal>)]
+    OwnershipProposal {},
 }
 
 #[cw_serde]